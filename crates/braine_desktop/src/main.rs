@@ -336,7 +336,7 @@ fn save_exec_tier_pref(tier: &str) {
     if let Some(parent) = path.parent() {
         let _ = std::fs::create_dir_all(parent);
     }
-    let _ = std::fs::write(path, tier);
+    let _ = braine::storage::write_file_atomic(path, tier.as_bytes());
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -410,6 +410,9 @@ enum Request {
     TriggerBurst,
     TriggerSync,
     TriggerImprint,
+    SetAutoDreamOnFlip {
+        enabled: bool,
+    },
     SaveBrain,
     LoadBrain,
     ResetBrain,
@@ -1895,6 +1898,12 @@ fn main() -> Result<(), slint::PlatformError> {
         let c = client.clone();
         ui.on_trigger_imprint(move || c.send(Request::TriggerImprint));
     }
+    {
+        let c = client.clone();
+        ui.on_set_auto_dream_on_flip(move |enabled| {
+            c.send(Request::SetAutoDreamOnFlip { enabled })
+        });
+    }
 
     // Storage
     {
@@ -0,0 +1,219 @@
+//! `wasm-bindgen` bindings for the `braine` substrate (`--features bindings`).
+//!
+//! Exposes `Brain`, `BrainConfig`, `Stimulus` and `ActionPolicy` as JS classes
+//! with camelCase methods. `wasm-pack` generates the matching `.d.ts` file;
+//! see `scripts/wasm-pack.sh`.
+//!
+//! ```js
+//! import { Brain, BrainConfig, ActionPolicy } from "braine_web";
+//! const brain = new Brain(new BrainConfig(256, 12).withSeed(42));
+//! brain.defineSensor("vision", 8);
+//! brain.defineAction("left", 4);
+//! brain.applyStimulus("vision", 1.0);
+//! brain.step();
+//! const { action, score } = brain.selectAction(ActionPolicy.deterministic());
+//! ```
+
+use braine::substrate::{self, Stimulus as CoreStimulus};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &'static str = r#"
+/** Result of `Brain.selectAction`. */
+export interface ActionChoice {
+    action: string;
+    score: number;
+}
+
+/** Result of `Brain.diagnostics` (mirrors `braine::substrate::Diagnostics`). */
+export interface Diagnostics {
+    unit_count: number;
+    connection_count: number;
+    pruned_last_step: number;
+    births_last_step: number;
+    avg_amp: number;
+    avg_weight: number;
+    memory_bytes: number;
+    execution_tier: "Scalar" | "Simd" | "Parallel" | "Gpu";
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "ActionChoice")]
+    pub type ActionChoice;
+
+    #[wasm_bindgen(typescript_type = "Diagnostics")]
+    pub type Diagnostics;
+}
+
+/// Serialize `value` with serde and hand it to JS as a plain object.
+fn to_js<T: serde::Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    let json = serde_json::to_string(value).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    js_sys::JSON::parse(&json)
+}
+
+/// Substrate size and learning knobs (`braine::substrate::BrainConfig`).
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct BrainConfig {
+    inner: substrate::BrainConfig,
+}
+
+#[wasm_bindgen]
+impl BrainConfig {
+    #[wasm_bindgen(constructor)]
+    pub fn new(unit_count: usize, connectivity_per_unit: usize) -> BrainConfig {
+        BrainConfig {
+            inner: substrate::BrainConfig::with_size(unit_count, connectivity_per_unit),
+        }
+    }
+
+    /// Copy of this config with a fixed RNG seed.
+    #[wasm_bindgen(js_name = withSeed)]
+    pub fn with_seed(&self, seed: u32) -> BrainConfig {
+        BrainConfig {
+            inner: self.inner.clone().with_seed(u64::from(seed)),
+        }
+    }
+
+    #[wasm_bindgen(getter, js_name = unitCount)]
+    pub fn unit_count(&self) -> usize {
+        self.inner.unit_count
+    }
+
+    #[wasm_bindgen(getter, js_name = connectivityPerUnit)]
+    pub fn connectivity_per_unit(&self) -> usize {
+        self.inner.connectivity_per_unit
+    }
+
+    /// Every field as a plain object.
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Result<JsValue, JsValue> {
+        to_js(&self.inner)
+    }
+}
+
+/// A named input and its strength, for `Brain.apply`.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Stimulus {
+    name: String,
+    strength: f32,
+}
+
+#[wasm_bindgen]
+impl Stimulus {
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: String, strength: f32) -> Stimulus {
+        Stimulus { name, strength }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+}
+
+/// How `Brain.selectAction` picks among the action groups.
+#[wasm_bindgen]
+pub struct ActionPolicy {
+    inner: substrate::ActionPolicy,
+}
+
+#[wasm_bindgen]
+impl ActionPolicy {
+    /// Always pick the highest-scoring action.
+    pub fn deterministic() -> ActionPolicy {
+        ActionPolicy {
+            inner: substrate::ActionPolicy::Deterministic,
+        }
+    }
+
+    /// Pick a random action with probability `epsilon`.
+    #[wasm_bindgen(js_name = epsilonGreedy)]
+    pub fn epsilon_greedy(epsilon: f32) -> ActionPolicy {
+        ActionPolicy {
+            inner: substrate::ActionPolicy::EpsilonGreedy { epsilon },
+        }
+    }
+}
+
+/// The cognitive substrate (`braine::substrate::Brain`).
+#[wasm_bindgen]
+pub struct Brain {
+    inner: substrate::Brain,
+}
+
+#[wasm_bindgen]
+impl Brain {
+    #[wasm_bindgen(constructor)]
+    pub fn new(config: &BrainConfig) -> Brain {
+        Brain {
+            inner: substrate::Brain::new(config.inner.clone()),
+        }
+    }
+
+    #[wasm_bindgen(js_name = defineSensor)]
+    pub fn define_sensor(&mut self, name: &str, width: usize) {
+        self.inner.define_sensor(name, width);
+    }
+
+    #[wasm_bindgen(js_name = defineAction)]
+    pub fn define_action(&mut self, name: &str, width: usize) {
+        self.inner.define_action(name, width);
+    }
+
+    /// Inject `amplitude` into the sensor group `name` for the next step.
+    #[wasm_bindgen(js_name = applyStimulus)]
+    pub fn apply_stimulus(&mut self, name: &str, amplitude: f32) {
+        self.inner
+            .apply_stimulus(CoreStimulus::new(name, amplitude));
+    }
+
+    /// Like `applyStimulus`, taking a `Stimulus`.
+    pub fn apply(&mut self, stimulus: &Stimulus) {
+        self.inner
+            .apply_stimulus(CoreStimulus::new(&stimulus.name, stimulus.strength));
+    }
+
+    #[wasm_bindgen(js_name = setNeuromodulator)]
+    pub fn set_neuromodulator(&mut self, value: f32) {
+        self.inner.set_neuromodulator(value);
+    }
+
+    pub fn step(&mut self) {
+        self.inner.step();
+    }
+
+    /// Pick an action (`ActionPolicy.deterministic()` when omitted); returns `ActionChoice`.
+    #[wasm_bindgen(js_name = selectAction)]
+    pub fn select_action(&mut self, policy: Option<ActionPolicy>) -> Result<ActionChoice, JsValue> {
+        let mut policy = policy.unwrap_or_else(ActionPolicy::deterministic).inner;
+        let (action, score) = self.inner.select_action(&mut policy);
+        let out = js_sys::Object::new();
+        js_sys::Reflect::set(&out, &"action".into(), &action.into())?;
+        js_sys::Reflect::set(&out, &"score".into(), &score.into())?;
+        Ok(out.unchecked_into())
+    }
+
+    #[wasm_bindgen(js_name = reinforceAction)]
+    pub fn reinforce_action(&mut self, action: &str, delta_bias: f32) {
+        self.inner.reinforce_action(action, delta_bias);
+    }
+
+    #[wasm_bindgen(js_name = commitObservation)]
+    pub fn commit_observation(&mut self) {
+        self.inner.commit_observation();
+    }
+
+    /// Current substrate statistics as a `Diagnostics` object.
+    pub fn diagnostics(&self) -> Result<Diagnostics, JsValue> {
+        to_js(&self.inner.diagnostics()).map(JsCast::unchecked_into)
+    }
+}
@@ -4,9 +4,13 @@
 //! targets without requiring wasm toolchains.
 //!
 //! Enable the real app with: `--features web` (and a wasm32 target).
+//! JS bindings for the substrate alone live behind `--features bindings`.
 
 pub mod ui_model;
 
+#[cfg(feature = "bindings")]
+pub mod bindings;
+
 /// Placeholder function for non-web (or non-wasm) builds.
 #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
 pub fn placeholder() {
@@ -15,6 +15,7 @@ use charts::RollingHistory;
 use text_web::TextWebGame;
 mod brain_factory;
 mod canvas;
+mod daemon_link;
 mod files;
 mod float_fmt;
 mod indexeddb;
@@ -229,6 +230,7 @@ const LOCALSTORAGE_THEME_KEY: &str = "braine_theme";
 const LOCALSTORAGE_GAME_STATS_PREFIX: &str = "braine_game_stats_v1.";
 const LOCALSTORAGE_SETTINGS_KEY: &str = "braine_settings_v1";
 const LOCALSTORAGE_EXEC_TIER_KEY: &str = "braine_exec_tier_v1";
+const LOCALSTORAGE_DAEMON_URL_KEY: &str = "braine_daemon_url_v1";
 const LOCALSTORAGE_BRAINVIZ_NODE_TAGS_KEY: &str = "braine_brainviz_node_tags_v1";
 const LOCALSTORAGE_BRAINVIZ_SYMBOL_TAGS_KEY: &str = "braine_brainviz_symbol_tags_v1";
 
@@ -1637,6 +1639,40 @@ fn App() -> impl IntoView {
         local_storage_remove(LOCALSTORAGE_EXEC_TIER_KEY);
     }
 
+    // Daemon link (persisted URL): off until the user configures one in Settings.
+    let (daemon_url, set_daemon_url) =
+        signal(local_storage_get_string(LOCALSTORAGE_DAEMON_URL_KEY).unwrap_or_default());
+    let (daemon_status, set_daemon_status) = signal(daemon_link::LinkStatus::Disconnected);
+    let (daemon_info, set_daemon_info) = signal(String::new());
+    let connect_daemon = move |url: &str| {
+        let on_status = move |st| {
+            let _ = set_daemon_status.try_set(st);
+        };
+        if let Err(e) = daemon_link::connect(url, on_status) {
+            set_daemon_status.set(daemon_link::LinkStatus::Disconnected);
+            push_toast(ToastLevel::Error, format!("Daemon link failed: {e}"));
+        }
+    };
+    if !daemon_url.get_untracked().is_empty() {
+        connect_daemon(&daemon_url.get_untracked());
+    }
+    let do_daemon_info = move || {
+        let request = serde_json::json!({ "type": "SyncGetInfo" });
+        let sent = daemon_link::send(request, move |reply| {
+            let text = match reply {
+                Ok(v) => format!(
+                    "Daemon brain: age {} steps · {} units · {} weights",
+                    v["age_steps"], v["unit_count"], v["weights_len"]
+                ),
+                Err(e) => format!("Daemon error: {e}"),
+            };
+            let _ = set_daemon_info.try_set(text);
+        });
+        if let Err(e) = sent {
+            push_toast(ToastLevel::Error, format!("Daemon: {e}"));
+        }
+    };
+
     // WebGPU support on wasm32:
     // The core GPU backend must be initialized asynchronously (no blocking waits),
     // then the brain can be switched to `ExecutionTier::Gpu`.
@@ -4724,12 +4760,13 @@ fn App() -> impl IntoView {
                                             </p>
                                             <div style="background: var(--bg); padding: 12px; border-radius: 8px; font-family: monospace; font-size: 0.85rem; line-height: 1.6; color: var(--text);">
                                                 "Header: BRAINE01 (8 bytes)"<br/>
-                                                "Version: u32 (currently 2)"<br/><br/>
-                                                "Chunks (LZ4 compressed):"<br/>
+                                                "Version: u32 (currently 4)"<br/><br/>
+                                                "Chunks (LZ4 compressed, CRC32):"<br/>
                                                 "  CFG0 - BrainConfig"<br/>
                                                 "  PRNG - RNG state"<br/>
                                                 "  STAT - age_steps, neuromod"<br/>
-                                                "  UNIT - units + connections"<br/>
+                                                "  UNST - unit states"<br/>
+                                                "  CSR0 - connections"<br/>
                                                 "  GRPS - sensor/action groups"<br/>
                                                 "  SYMB - symbol table"<br/>
                                                 "  CAUS - causal memory"
@@ -4938,7 +4975,7 @@ fn App() -> impl IntoView {
                                         <h3 style="margin: 0 0 10px 0; font-size: 1rem; color: var(--accent);">"Braine APIs (By Category)"</h3>
                                         <p style="margin: 0; color: var(--muted); font-size: 0.9rem; line-height: 1.7;">
                                             "Braine’s long-lived state is owned by a central daemon (brained). Desktop and CLI clients talk to it over newline-delimited JSON on TCP 127.0.0.1:9876. "
-                                            "The web app runs fully in-browser (WASM) today and does not connect to the daemon. "
+                                            "The web app runs fully in-browser (WASM). When a daemon is running with --ws-port 9877 it also opens a WebSocket link to ws://127.0.0.1:9877 (same JSON messages, one per text frame). "
                                             "This section lists the daemon API categories and the most important request/response shapes."
                                         </p>
                                     </div>
@@ -5002,7 +5039,7 @@ fn App() -> impl IntoView {
                                                 <div style="color: var(--muted);">"input: { type: \"SyncGetInfo\" }"</div>
                                                 <div style="color: var(--muted);">"output: { type: \"SyncInfo\", fingerprint, weights_len, unit_count, age_steps }"</div>
                                                 <div style="margin-top: 8px;"><strong>"SyncApplyDelta"</strong>" → SyncApplied/Error"</div>
-                                                <div style="color: var(--muted);">"input: { type: \"SyncApplyDelta\", delta, expected_weights_len, expected_fingerprint, delta_max?, autosave? }"</div>
+                                                <div style="color: var(--muted);">"input: { type: \"SyncApplyDelta\", delta, expected_weights_len, expected_fingerprint, delta_max?, autosave?, mask_kind? }"</div>
                                                 <div style="color: var(--muted);">"output: { type: \"SyncApplied\", applied_edges, saved }"</div>
                                             </div>
                                         </div>
@@ -8333,6 +8370,61 @@ fn App() -> impl IntoView {
                                             </div>
                                         </div>
 
+                                        <div class="card">
+                                            <h3 class="card-title">"🔌 Daemon link"</h3>
+                                            <p class="subtle">
+                                                "Connect to a local brained started with --ws-port. "
+                                                "Leave empty to stay fully in-browser."
+                                            </p>
+                                            <div class="row end wrap" style="justify-content: flex-start; gap: 10px; margin-top: 12px;">
+                                                <input
+                                                    class="input"
+                                                    type="text"
+                                                    placeholder=daemon_link::DEFAULT_DAEMON_WS_URL
+                                                    prop:value=move || daemon_url.get()
+                                                    on:input=move |ev| set_daemon_url.set(event_target_value(&ev))
+                                                    aria-label="Daemon WebSocket URL"
+                                                />
+                                                <button
+                                                    class="btn sm primary"
+                                                    on:click=move |_| {
+                                                        let url = daemon_url.get_untracked().trim().to_string();
+                                                        if url.is_empty() {
+                                                            push_toast(ToastLevel::Error, "Enter a daemon URL first".to_string());
+                                                            return;
+                                                        }
+                                                        local_storage_set_string(LOCALSTORAGE_DAEMON_URL_KEY, &url);
+                                                        connect_daemon(&url);
+                                                    }
+                                                >
+                                                    "Connect"
+                                                </button>
+                                                <button
+                                                    class="btn sm"
+                                                    on:click=move |_| {
+                                                        local_storage_remove(LOCALSTORAGE_DAEMON_URL_KEY);
+                                                        set_daemon_url.set(String::new());
+                                                        set_daemon_info.set(String::new());
+                                                        daemon_link::disconnect();
+                                                    }
+                                                    title="Close the link and stop connecting on reload"
+                                                >
+                                                    "Disconnect"
+                                                </button>
+                                                <button
+                                                    class="btn sm"
+                                                    disabled=move || daemon_status.get() != daemon_link::LinkStatus::Connected
+                                                    on:click=move |_| do_daemon_info()
+                                                >
+                                                    "Fetch info"
+                                                </button>
+                                            </div>
+                                            <div class="subtle" style="font-size: 0.80rem; margin-top: 8px;">
+                                                {move || format!("Status: {}", daemon_status.get().label())}
+                                            </div>
+                                            <div class="subtle" style="font-size: 0.80rem;">{move || daemon_info.get()}</div>
+                                        </div>
+
                                         <For
                                             each=move || settings_schema::sections_ordered().into_iter()
                                             key=|s| s.title
@@ -0,0 +1,174 @@
+//! Optional link to a local `brained` over its WebSocket endpoint.
+//!
+//! Off unless a daemon URL is configured (Settings → Daemon link). When no
+//! daemon is listening the app keeps running fully in-browser. Messages use the
+//! daemon's JSON protocol, one object per text frame. Every request carries a
+//! `request_id` and its reply is matched by that id; pushed lines without one
+//! are ignored.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+
+/// Suggested endpoint for `brained --ws-port 9877` (the daemon's WebSocket is off by default).
+pub const DEFAULT_DAEMON_WS_URL: &str = "ws://127.0.0.1:9877";
+
+/// Connection state reported to the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkStatus {
+    Connecting,
+    Connected,
+    Disconnected,
+}
+
+impl LinkStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            LinkStatus::Connecting => "connecting…",
+            LinkStatus::Connected => "connected",
+            LinkStatus::Disconnected => "disconnected",
+        }
+    }
+}
+
+type Reply = Box<dyn FnOnce(Result<serde_json::Value, String>)>;
+
+struct Link {
+    ws: web_sys::WebSocket,
+    next_id: u64,
+    pending: HashMap<u64, Reply>,
+    on_status: Rc<dyn Fn(LinkStatus)>,
+}
+
+impl Link {
+    /// Fail everything still waiting and report the link as down.
+    fn shut(self) {
+        for waiter in self.pending.into_values() {
+            waiter(Err("daemon link closed".to_string()));
+        }
+        (self.on_status)(LinkStatus::Disconnected);
+    }
+}
+
+thread_local! {
+    static LINK: RefCell<Option<Link>> = const { RefCell::new(None) };
+}
+
+/// Open the daemon WebSocket at `url`, replacing any existing link.
+///
+/// `on_status` is called on every state change, starting with `Connecting`.
+pub fn connect(url: &str, on_status: impl Fn(LinkStatus) + 'static) -> Result<(), String> {
+    disconnect();
+    let ws = web_sys::WebSocket::new(url).map_err(|e| format!("{e:?}"))?;
+    let on_status: Rc<dyn Fn(LinkStatus)> = Rc::new(on_status);
+    on_status(LinkStatus::Connecting);
+
+    let url_owned = url.to_string();
+    let status = on_status.clone();
+    let on_open = Closure::<dyn FnMut()>::new(move || {
+        web_sys::console::log_1(&JsValue::from_str(&format!(
+            "braine: connected to daemon at {url_owned}"
+        )));
+        status(LinkStatus::Connected);
+    });
+    ws.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    on_open.forget();
+
+    let on_message =
+        Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |e: web_sys::MessageEvent| {
+            let Some(text) = e.data().as_string() else {
+                return;
+            };
+            let Ok(v) = serde_json::from_str::<serde_json::Value>(&text) else {
+                return;
+            };
+            let Some(id) = v.get("request_id").and_then(|id| id.as_u64()) else {
+                return;
+            };
+            let waiter = LINK.with(|l| {
+                l.borrow_mut()
+                    .as_mut()
+                    .and_then(|link| link.pending.remove(&id))
+            });
+            let Some(waiter) = waiter else {
+                return;
+            };
+            if v.get("type").and_then(|t| t.as_str()) == Some("Error") {
+                let message = v.get("message").and_then(|m| m.as_str());
+                waiter(Err(message.unwrap_or("daemon error").to_string()));
+            } else {
+                waiter(Ok(v));
+            }
+        });
+    ws.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    on_message.forget();
+
+    // No daemon (or it went away): drop the link and fail whatever was in flight.
+    let ws_id = ws.clone();
+    let on_close = Closure::<dyn FnMut(web_sys::Event)>::new(move |_e: web_sys::Event| {
+        // A socket closed by `disconnect` (or replaced) is no longer the current link.
+        let link = LINK.with(|l| {
+            let mut l = l.borrow_mut();
+            if l.as_ref().is_some_and(|link| link.ws == ws_id) {
+                l.take()
+            } else {
+                None
+            }
+        });
+        if let Some(link) = link {
+            link.shut();
+        }
+    });
+    ws.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+    on_close.forget();
+
+    LINK.with(|l| {
+        *l.borrow_mut() = Some(Link {
+            ws,
+            next_id: 1,
+            pending: HashMap::new(),
+            on_status,
+        })
+    });
+    Ok(())
+}
+
+/// Close the link, if any; requests still in flight fail.
+pub fn disconnect() {
+    let link = LINK.with(|l| l.borrow_mut().take());
+    if let Some(link) = link {
+        let _ = link.ws.close();
+        link.shut();
+    }
+}
+
+/// Send one request (a JSON object with a `type` field) and call `on_reply` with its response.
+///
+/// Any `request_id` in `request` is replaced. Daemon `Error` responses arrive as `Err(message)`.
+pub fn send(
+    request: serde_json::Value,
+    on_reply: impl FnOnce(Result<serde_json::Value, String>) + 'static,
+) -> Result<(), String> {
+    let serde_json::Value::Object(mut request) = request else {
+        return Err("daemon request must be a JSON object".to_string());
+    };
+    LINK.with(|l| {
+        let mut l = l.borrow_mut();
+        let Some(link) = l.as_mut() else {
+            return Err("no daemon link".to_string());
+        };
+        if link.ws.ready_state() != web_sys::WebSocket::OPEN {
+            return Err("daemon link is not open".to_string());
+        }
+        let id = link.next_id;
+        link.next_id += 1;
+        request.insert("request_id".to_string(), id.into());
+        let text = serde_json::to_string(&request).map_err(|e| e.to_string())?;
+        link.ws.send_with_str(&text).map_err(|e| format!("{e:?}"))?;
+        link.pending.insert(id, Box::new(on_reply));
+        Ok(())
+    })
+}
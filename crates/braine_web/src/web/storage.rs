@@ -59,15 +59,29 @@ impl From<&braine_games::stats::GameStats> for PersistedGameStats {
 
 impl PersistedGameStats {
     pub(super) fn into_game_stats(self) -> braine_games::stats::GameStats {
-        braine_games::stats::GameStats {
-            correct: self.correct,
-            incorrect: self.incorrect,
-            trials: self.trials,
-            recent: self.recent,
-            learning_at_trial: self.learning_at_trial,
-            learned_at_trial: self.learned_at_trial,
-            mastered_at_trial: self.mastered_at_trial,
-        }
+        use braine_games::stats::{MILESTONE_LEARNED, MILESTONE_LEARNING, MILESTONE_MASTERED};
+
+        let mut stats = braine_games::stats::GameStats::new();
+        stats.correct = self.correct;
+        stats.incorrect = self.incorrect;
+        stats.trials = self.trials;
+        // Only the standard three milestones are persisted here.
+        let reached: Vec<(String, u32)> = [
+            (MILESTONE_LEARNING, self.learning_at_trial),
+            (MILESTONE_LEARNED, self.learned_at_trial),
+            (MILESTONE_MASTERED, self.mastered_at_trial),
+        ]
+        .into_iter()
+        .filter_map(|(name, t)| Some((name.to_string(), t?)))
+        .collect();
+        stats
+            .learning_milestone_detector_mut()
+            .restore(self.trials, &self.recent, &reached);
+        stats.recent = self.recent;
+        stats.learning_at_trial = self.learning_at_trial;
+        stats.learned_at_trial = self.learned_at_trial;
+        stats.mastered_at_trial = self.mastered_at_trial;
+        stats
     }
 }
 
@@ -1,3 +1,4 @@
+use braine::substrate::RewardEdges;
 use serde::{Deserialize, Serialize};
 
 /// A bounded, slow-loop advisor integration point.
@@ -25,7 +26,7 @@ fn default_mode() -> String {
     "stub".to_string()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdvisorContext {
     #[serde(default)]
     pub game: String,
@@ -47,10 +48,53 @@ pub struct AdvisorContext {
     #[serde(default)]
     pub meaning_alpha: f32,
 
+    /// EMA of |reward prediction error| (`LearningStats::td_prediction_error_ema`).
+    /// 1.0 means uncalibrated or no TD rewards seen yet.
+    #[serde(default = "default_td_prediction_error_ema")]
+    pub td_prediction_error_ema: f32,
+
     #[serde(default)]
     pub text_regime: Option<u32>,
+
+    /// Top causal edges (`Brain::serialize_causal_graph`), when requested.
+    #[serde(default)]
+    pub causal_graph_json: Option<String>,
+
+    /// Global meaning per action (`Brain::action_reward_edges_all`), when action scores are requested.
+    #[serde(default)]
+    pub action_meaning: Vec<(String, RewardEdges)>,
+    /// Meaning per action for the current stimulus (`Brain::pair_reward_edges_all_actions`).
+    #[serde(default)]
+    pub pair_meaning: Vec<(String, RewardEdges)>,
 }
 
+fn default_td_prediction_error_ema() -> f32 {
+    1.0
+}
+
+impl Default for AdvisorContext {
+    fn default() -> Self {
+        Self {
+            game: String::new(),
+            context_key: String::new(),
+            trials: 0,
+            accuracy: 0.0,
+            recent_rate: 0.0,
+            last_reward: 0.0,
+            exploration_eps: 0.0,
+            meaning_alpha: 0.0,
+            td_prediction_error_ema: default_td_prediction_error_ema(),
+            text_regime: None,
+            causal_graph_json: None,
+            action_meaning: Vec::new(),
+            pair_meaning: Vec::new(),
+        }
+    }
+}
+
+/// Below this RPE EMA the brain's implicit value function counts as calibrated.
+const TD_CALIBRATED_ERROR: f32 = 0.15;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AdvisorAdvice {
     /// How long (in trials) the receiver should consider this advice “active”.
@@ -176,6 +220,10 @@ impl AdvisorRuntime {
         } else if ctx.trials >= 20 && ctx.recent_rate > 0.85 {
             rationale_parts.push("recent performance high; annealing exploration".to_string());
             exploration_target = Some((ctx.exploration_eps * 0.85).max(0.02));
+        } else if ctx.trials >= 20 && ctx.td_prediction_error_ema < TD_CALIBRATED_ERROR {
+            rationale_parts
+                .push("reward predictions well calibrated; annealing exploration".to_string());
+            exploration_target = Some((ctx.exploration_eps * 0.9).max(0.02));
         }
 
         // Keep meaning_alpha stable by default; small nudge only when very stuck.
@@ -245,11 +293,33 @@ mod tests {
             last_reward: -0.05,
             exploration_eps: 0.1,
             meaning_alpha: 0.2,
+            td_prediction_error_ema: 1.0,
             text_regime: None,
+            causal_graph_json: None,
+            action_meaning: Vec::new(),
+            pair_meaning: Vec::new(),
         };
 
         let a = rt.invoke_stub(&ctx);
         assert!(a.exploration_eps.is_some());
         assert!(a.exploration_eps.unwrap() > 0.1);
     }
+
+    #[test]
+    fn stub_anneals_exploration_when_rpe_is_small() {
+        let mut rt = AdvisorRuntime::default();
+        let mut ctx = AdvisorContext {
+            game: "spot".to_string(),
+            trials: 50,
+            recent_rate: 0.7,
+            exploration_eps: 0.2,
+            td_prediction_error_ema: 0.8,
+            ..AdvisorContext::default()
+        };
+        assert!(rt.invoke_stub(&ctx).exploration_eps.is_none());
+
+        ctx.td_prediction_error_ema = 0.05;
+        let a = rt.invoke_stub(&ctx);
+        assert!(a.exploration_eps.unwrap() < 0.2);
+    }
 }
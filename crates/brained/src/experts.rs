@@ -1,22 +1,55 @@
 use braine::{
     storage,
-    substrate::{Brain, BrainDelta},
+    substrate::{Brain, BrainDelta, BrainInput, BrainOutput},
 };
+use braine_games::stats::{BootstrapCI, ChangePointDetector};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::{self, Read, Write};
 
-#[derive(Debug, Clone, Copy, Default)]
+/// Outcomes kept per context (and per expert episode) for bootstrap comparisons.
+const OUTCOME_WINDOW: usize = 100;
+
+/// Fewer outcomes than this on either side skips the bootstrap check.
+const BOOTSTRAP_MIN_OUTCOMES: usize = 8;
+
+/// Prefix of [`ExpertManager::save_state_bytes`] output, followed by a format byte.
+const EXPERTS_MAGIC: &[u8; 4] = b"EXPR";
+/// Framing format written after [`EXPERTS_MAGIC`]; the body keeps its own version.
+const EXPERTS_FORMAT_VERSION: u8 = 1;
+
+fn push_outcome(outcomes: &mut Vec<bool>, reward: f32) {
+    outcomes.push(reward > 0.0);
+    if outcomes.len() > OUTCOME_WINDOW {
+        outcomes.remove(0);
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 struct ContextStats {
     first_seen_trial: u32,
     trials_seen: u32,
     reward_fast_ema: f32,
     reward_slow_ema: f32,
     best_slow_ema: f32,
+
+    /// Online CUSUM over this context's rewards (not persisted; re-warms on load).
+    reward_change: ChangePointDetector,
+    /// `trials_seen` at the most recent detected reward regime shift.
+    last_shift_at: Option<u32>,
+    /// Recent win/loss outcomes (`reward > 0`); not persisted.
+    recent: Vec<bool>,
 }
 
 impl ContextStats {
-    fn note_reward(&mut self, parent_trials: u32, reward: f32) {
+    fn note_reward(&mut self, parent_trials: u32, reward: f32, shift_delta: f32) {
+        // The CUSUM allowance is half the smallest shift we want to detect.
+        self.reward_change.drift = 0.5 * shift_delta.max(0.0);
+        if self.reward_change.feed(reward).is_some() {
+            self.last_shift_at = Some(self.trials_seen.saturating_add(1));
+        }
+        push_outcome(&mut self.recent, reward);
+
         if self.trials_seen == 0 {
             self.first_seen_trial = parent_trials;
             self.trials_seen = 1;
@@ -28,7 +61,7 @@ impl ContextStats {
 
         self.trials_seen = self.trials_seen.saturating_add(1);
 
-        // Two-timescale EMAs for shift/collapse detection.
+        // Two-timescale EMAs for collapse detection.
         // Fast responds within ~5 trials; slow responds within ~20 trials.
         let a_fast = 0.20;
         let a_slow = 0.05;
@@ -83,7 +116,8 @@ pub struct ExpertPolicy {
     /// Per-edge delta clamp applied during consolidation.
     pub consolidate_delta_max: f32,
 
-    /// Spawn trigger: reward regime shift threshold on |EMA_fast - EMA_slow|.
+    /// Spawn trigger: smallest reward-mean shift the per-context CUSUM
+    /// change-point detector should report (its allowance is half this value).
     pub reward_shift_ema_delta_threshold: f32,
 
     /// Spawn trigger: performance collapse threshold on (best_slow_ema - fast_ema).
@@ -106,6 +140,11 @@ pub struct ExpertPolicy {
 
     /// Maximum depth of expert nesting (1 = parent->experts only).
     pub max_depth: u32,
+
+    /// Soft routing: with no exact match, route to the expert whose context is
+    /// causally most similar, if the similarity reaches this threshold.
+    /// Runtime-only (not persisted); `None` = exact matching.
+    pub similarity_routing_threshold: Option<f32>,
 }
 
 impl Default for ExpertPolicy {
@@ -125,6 +164,7 @@ impl Default for ExpertPolicy {
             promote_reward_ema: 0.2,
             allow_nested: false,
             max_depth: 1,
+            similarity_routing_threshold: None,
         }
     }
 }
@@ -176,6 +216,16 @@ pub struct ExpertsSummary {
     pub performance_collapse_drop_threshold: f32,
     #[serde(default)]
     pub performance_collapse_baseline_min: f32,
+
+    #[serde(default)]
+    pub similarity_routing: bool,
+    #[serde(default)]
+    pub similarity_routing_threshold: f32,
+
+    #[serde(default)]
+    pub ensemble_enabled: bool,
+    #[serde(default)]
+    pub ensemble_top_k: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -216,10 +266,21 @@ struct Expert {
     completed_trials: u32,
     episode_trials: u32,
     reward_ema: f32,
+
+    /// Parent outcomes for this context at spawn time (not persisted).
+    parent_baseline: Vec<bool>,
+    /// Outcomes while this expert controlled the context (not persisted).
+    recent: Vec<bool>,
 }
 
 impl Expert {
-    fn new(id: u32, context_key: String, parent: &Brain, inherited_policy: &ExpertPolicy) -> Self {
+    fn new(
+        id: u32,
+        context_key: String,
+        parent: &Brain,
+        parent_baseline: Vec<bool>,
+        inherited_policy: &ExpertPolicy,
+    ) -> Self {
         let brain = parent.clone();
         let fork_point = parent.clone();
         let mut children = ExpertManager::new();
@@ -235,8 +296,37 @@ impl Expert {
             completed_trials: 0,
             episode_trials: 0,
             reward_ema: 0.0,
+            parent_baseline,
+            recent: Vec::new(),
         }
     }
+
+    /// Whether this expert's outcomes beat the parent's baseline with
+    /// non-overlapping bootstrap confidence intervals.
+    ///
+    /// Returns `None` when either side has too few outcomes to judge (e.g.
+    /// novel contexts, or right after a reload).
+    fn beats_parent(&self) -> Option<bool> {
+        if self.recent.len() < BOOTSTRAP_MIN_OUTCOMES
+            || self.parent_baseline.len() < BOOTSTRAP_MIN_OUTCOMES
+        {
+            return None;
+        }
+        let (child_lo, _) = BootstrapCI::from_recent_default(&self.recent);
+        let (_, parent_hi) = BootstrapCI::from_recent_default(&self.parent_baseline);
+        Some(child_lo > parent_hi)
+    }
+
+    /// Vote weight in an ensemble: recent win rate, falling back to the
+    /// reward EMA mapped into [0, 1] before any outcomes are recorded.
+    fn performance_weight(&self) -> f32 {
+        let w = if self.recent.is_empty() {
+            0.5 * (self.reward_ema + 1.0)
+        } else {
+            self.recent.iter().filter(|&&x| x).count() as f32 / self.recent.len() as f32
+        };
+        w.max(1.0e-3)
+    }
 }
 
 pub struct ExpertManager {
@@ -251,6 +341,12 @@ pub struct ExpertManager {
 
     last_spawn_reason: String,
     last_consolidation: String,
+
+    /// Runtime-only: average the active experts' rankings instead of routing
+    /// to a single controller (see [`Self::ensemble_vote`]).
+    ensemble_enabled: bool,
+    /// Maximum ensemble members (0 = all active experts for the context).
+    ensemble_top_k: usize,
 }
 
 impl ExpertManager {
@@ -265,6 +361,8 @@ impl ExpertManager {
             context_stats: HashMap::new(),
             last_spawn_reason: String::new(),
             last_consolidation: String::new(),
+            ensemble_enabled: false,
+            ensemble_top_k: 0,
         }
     }
 
@@ -289,6 +387,30 @@ impl ExpertManager {
         }
     }
 
+    pub fn set_ensemble_mode(&mut self, enabled: bool, top_k: usize) {
+        self.ensemble_enabled = enabled;
+        self.ensemble_top_k = top_k;
+    }
+
+    pub fn ensemble_enabled(&self) -> bool {
+        self.enabled && self.ensemble_enabled
+    }
+
+    /// Route unseen contexts to the most causally similar active expert when
+    /// `causal_context_similarity` reaches `threshold` (applies to nested managers too).
+    pub fn context_similarity_routing(&mut self, threshold: f32) {
+        let mut p = self.policy.clone();
+        p.similarity_routing_threshold = Some(threshold.clamp(0.0, 1.0));
+        self.set_policy(p);
+    }
+
+    /// Back to exact context-key routing.
+    pub fn disable_context_similarity_routing(&mut self) {
+        let mut p = self.policy.clone();
+        p.similarity_routing_threshold = None;
+        self.set_policy(p);
+    }
+
     pub fn set_policy(&mut self, policy: ExpertPolicy) {
         self.policy = policy;
 
@@ -320,10 +442,11 @@ impl ExpertManager {
         reward: f32,
     ) {
         if controller_path.is_empty() {
+            let shift_delta = self.policy.reward_shift_ema_delta_threshold;
             self.context_stats
                 .entry(context_key.to_string())
                 .or_default()
-                .note_reward(parent_trials, reward);
+                .note_reward(parent_trials, reward, shift_delta);
             return;
         }
 
@@ -339,11 +462,12 @@ impl ExpertManager {
                 if !cur.policy.allow_nested || cur.policy.max_depth <= (i as u32 + 1) {
                     return;
                 }
+                let shift_delta = e.children.policy.reward_shift_ema_delta_threshold;
                 e.children
                     .context_stats
                     .entry(context_key.to_string())
                     .or_default()
-                    .note_reward(parent_trials, reward);
+                    .note_reward(parent_trials, reward, shift_delta);
                 return;
             }
             cur = &mut e.children;
@@ -355,6 +479,7 @@ impl ExpertManager {
         context_key: &str,
         parent_trials: u32,
         parent: &Brain,
+        plateaued: bool,
     ) -> Option<String> {
         if !self.enabled {
             return None;
@@ -370,12 +495,12 @@ impl ExpertManager {
         }
 
         // Saturation detection: treat "should grow" as a proxy for saturation / attractor brittleness.
-        let saturated = parent.should_grow(0.35);
+        let saturated = parent.is_saturated(0.35);
 
         let stats = self
             .context_stats
             .get(context_key)
-            .copied()
+            .cloned()
             .unwrap_or_default();
         let novel = stats.trials_seen == 1;
 
@@ -383,13 +508,11 @@ impl ExpertManager {
         let min_for_shift = 12;
         let min_for_collapse = 20;
 
-        // Reward regime shift: two-timescale EMA divergence.
-        let ema_delta = (stats.reward_fast_ema - stats.reward_slow_ema).abs();
-        let sign_flip = stats.reward_fast_ema.abs() > 0.2
-            && stats.reward_slow_ema.abs() > 0.2
-            && stats.reward_fast_ema.signum() != stats.reward_slow_ema.signum();
+        // Reward regime shift: a recent CUSUM change point on this context's rewards.
         let reward_shift = stats.trials_seen >= min_for_shift
-            && (ema_delta >= self.policy.reward_shift_ema_delta_threshold || sign_flip);
+            && stats
+                .last_shift_at
+                .is_some_and(|t| stats.trials_seen.saturating_sub(t) < min_for_shift);
 
         // Performance collapse: fast EMA drops far below prior best slow EMA.
         let perf_collapse = stats.trials_seen >= min_for_collapse
@@ -397,7 +520,10 @@ impl ExpertManager {
             && stats.reward_fast_ema
                 <= stats.best_slow_ema - self.policy.performance_collapse_drop_threshold;
 
-        let any_signal = novel || reward_shift || perf_collapse || saturated;
+        // Learning plateau: the caller's learning curve has stalled below mastery.
+        let plateau = plateaued && stats.trials_seen >= min_for_collapse;
+
+        let any_signal = novel || reward_shift || perf_collapse || plateau || saturated;
         if !any_signal {
             return None;
         }
@@ -417,6 +543,9 @@ impl ExpertManager {
         if perf_collapse {
             reasons.push("performance_collapse");
         }
+        if plateau {
+            reasons.push("plateau");
+        }
         if saturated {
             reasons.push("saturation");
         }
@@ -429,8 +558,10 @@ impl ExpertManager {
         context_key: &str,
         parent_trials: u32,
         parent: &Brain,
+        plateaued: bool,
     ) {
-        let Some(signal_reason) = self.should_spawn_for_signals(context_key, parent_trials, parent)
+        let Some(signal_reason) =
+            self.should_spawn_for_signals(context_key, parent_trials, parent, plateaued)
         else {
             return;
         };
@@ -442,16 +573,25 @@ impl ExpertManager {
             "spawned expert id={} ctx='{}' (signals={}, trials={})",
             id, context_key, signal_reason, parent_trials
         );
+        let parent_baseline = self
+            .context_stats
+            .get(context_key)
+            .map(|s| s.recent.clone())
+            .unwrap_or_default();
         self.experts.push(Expert::new(
             id,
             context_key.to_string(),
             parent,
+            parent_baseline,
             &self.policy,
         ));
     }
 
     /// Spawn under the currently controlling expert chain (nested spawn), using
-    /// explicit novelty/shift/collapse/saturation signals.
+    /// explicit novelty/shift/collapse/plateau/saturation signals.
+    ///
+    /// `plateaued` reports that the caller's learning curve has stalled below
+    /// mastery (see `GameStats::plateau_detector`).
     ///
     /// If `controller_path` is empty, this behaves like `maybe_spawn_for_signals`.
     pub fn maybe_spawn_for_signals_under_path(
@@ -460,9 +600,10 @@ impl ExpertManager {
         controller_path: &[u32],
         parent_trials: u32,
         root_parent: &Brain,
+        plateaued: bool,
     ) {
         if controller_path.is_empty() {
-            self.maybe_spawn_for_signals(context_key, parent_trials, root_parent);
+            self.maybe_spawn_for_signals(context_key, parent_trials, root_parent, plateaued);
             return;
         }
 
@@ -479,7 +620,7 @@ impl ExpertManager {
                     return;
                 }
                 e.children
-                    .maybe_spawn_for_signals(context_key, parent_trials, &e.brain);
+                    .maybe_spawn_for_signals(context_key, parent_trials, &e.brain, plateaued);
                 return;
             }
 
@@ -507,6 +648,25 @@ impl ExpertManager {
             .position(|e| e.context_key == context_key)
     }
 
+    /// Expert that should control `context_key`: the exact match, or with
+    /// similarity routing the expert whose context `parent` finds most similar.
+    fn route_index(&self, context_key: &str, parent: &Brain) -> Option<usize> {
+        if let Some(idx) = self.active_expert_index(context_key) {
+            return Some(idx);
+        }
+        let threshold = self.policy.similarity_routing_threshold?;
+        self.experts
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let sim = parent.causal_context_similarity(context_key, &e.context_key);
+                (i, sim)
+            })
+            .filter(|&(_, sim)| sim > 0.0 && sim >= threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)))
+            .map(|(i, _)| i)
+    }
+
     pub fn active_expert_summary(&self, context_key: &str) -> Option<ActiveExpertSummary> {
         let e = self.experts.iter().find(|e| e.context_key == context_key)?;
         if self.policy.allow_nested {
@@ -522,6 +682,68 @@ impl ExpertManager {
         })
     }
 
+    /// Evaluate expert `id` (searched recursively) on `inputs` without touching it.
+    ///
+    /// Each input runs on its own clone via [`Brain::step_batch`], so candidate
+    /// stimuli/contexts can be compared in one call (in parallel with the
+    /// `parallel` feature).
+    pub fn score_child(&self, id: u32, inputs: &[BrainInput]) -> Option<Vec<BrainOutput>> {
+        for e in &self.experts {
+            if e.id == id {
+                return Some(e.brain.step_batch(inputs));
+            }
+            if let Some(out) = e.children.score_child(id, inputs) {
+                return Some(out);
+            }
+        }
+        None
+    }
+
+    /// Performance-weighted average of `ranked_actions_with_meaning(ctx, alpha)`
+    /// over the active experts for `ctx` (including nested ones).
+    ///
+    /// Members are the `ensemble_top_k` best by recent win rate (all when 0).
+    /// Returns actions sorted by score, or an empty list if no expert is active.
+    pub fn ensemble_vote(&self, ctx: &str, alpha: f32) -> Vec<(String, f32)> {
+        let mut members: Vec<&Expert> = Vec::new();
+        self.collect_ensemble(ctx, &mut members);
+        members.sort_by(|a, b| {
+            b.performance_weight()
+                .total_cmp(&a.performance_weight())
+                .then(a.id.cmp(&b.id))
+        });
+        if self.ensemble_top_k > 0 {
+            members.truncate(self.ensemble_top_k);
+        }
+
+        let mut total_w = 0.0f32;
+        let mut acc: HashMap<String, f32> = HashMap::new();
+        for e in members {
+            let w = e.performance_weight();
+            total_w += w;
+            for (name, score) in e.brain.ranked_actions_with_meaning(ctx, alpha) {
+                *acc.entry(name).or_insert(0.0) += w * score;
+            }
+        }
+        if total_w <= 0.0 {
+            return Vec::new();
+        }
+
+        let mut ranked: Vec<(String, f32)> =
+            acc.into_iter().map(|(n, s)| (n, s / total_w)).collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+
+    fn collect_ensemble<'a>(&'a self, ctx: &str, out: &mut Vec<&'a Expert>) {
+        for e in self.experts.iter().filter(|e| e.context_key == ctx) {
+            out.push(e);
+            if self.policy.allow_nested {
+                e.children.collect_ensemble(ctx, out);
+            }
+        }
+    }
+
     pub fn total_active_count_recursive(&self) -> u32 {
         let mut total = self.experts.len() as u32;
         for e in &self.experts {
@@ -544,6 +766,12 @@ impl ExpertManager {
             reward_shift_ema_delta_threshold: self.policy.reward_shift_ema_delta_threshold,
             performance_collapse_drop_threshold: self.policy.performance_collapse_drop_threshold,
             performance_collapse_baseline_min: self.policy.performance_collapse_baseline_min,
+
+            similarity_routing: self.policy.similarity_routing_threshold.is_some(),
+            similarity_routing_threshold: self.policy.similarity_routing_threshold.unwrap_or(0.0),
+
+            ensemble_enabled: self.ensemble_enabled,
+            ensemble_top_k: self.ensemble_top_k as u32,
         }
     }
 
@@ -586,7 +814,7 @@ impl ExpertManager {
             return parent;
         }
 
-        let Some(idx) = self.active_expert_index(context_key) else {
+        let Some(idx) = self.route_index(context_key, parent) else {
             return parent;
         };
 
@@ -620,7 +848,7 @@ impl ExpertManager {
             return parent;
         }
 
-        let Some(idx) = self.active_expert_index(context_key) else {
+        let Some(idx) = self.route_index(context_key, parent) else {
             return parent;
         };
 
@@ -675,8 +903,12 @@ impl ExpertManager {
             e.reward_ema = (1.0 - alpha) * e.reward_ema + alpha * reward;
             e.completed_trials = e.completed_trials.saturating_add(1);
             e.episode_trials = e.episode_trials.saturating_add(1);
+            push_outcome(&mut e.recent, reward);
+            // The EMA gate always applies; when both sides have enough outcomes the
+            // child must also beat the parent's baseline beyond bootstrap noise.
             e.episode_trials >= self.policy.episode_trials
                 && e.reward_ema >= self.policy.promote_reward_ema
+                && e.beats_parent().unwrap_or(true)
         };
 
         let episode_done = self.experts[idx].episode_trials >= self.policy.episode_trials;
@@ -706,9 +938,19 @@ impl ExpertManager {
                 );
             }
         } else {
+            let e = &self.experts[idx];
+            let within_noise =
+                e.reward_ema >= self.policy.promote_reward_ema && e.beats_parent() == Some(false);
             self.last_consolidation = format!(
-                "culled expert id={} ctx='{}' (ema={:.3})",
-                self.experts[idx].id, self.experts[idx].context_key, self.experts[idx].reward_ema
+                "culled expert id={} ctx='{}' (ema={:.3}{})",
+                e.id,
+                e.context_key,
+                e.reward_ema,
+                if within_noise {
+                    ", not better than parent at 95% CI"
+                } else {
+                    ""
+                }
             );
         }
 
@@ -730,18 +972,48 @@ impl ExpertManager {
     // Persistence (binary, deterministic)
     // ─────────────────────────────────────────────────────────────────────────
 
+    /// Serialize as `EXPR`, a format byte, then the versioned state body.
     pub fn save_state_bytes(&self) -> io::Result<Vec<u8>> {
         let mut out: Vec<u8> = Vec::new();
+        out.extend_from_slice(EXPERTS_MAGIC);
+        out.push(EXPERTS_FORMAT_VERSION);
         self.write_state_to(&mut out)?;
         Ok(out)
     }
 
+    /// Load bytes from [`ExpertManager::save_state_bytes`].
+    ///
+    /// Unframed bodies written before the `EXPR` header existed still load;
+    /// anything else fails with `InvalidData` ("corrupt experts header").
     pub fn load_state_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
-        let mut cursor = std::io::Cursor::new(bytes);
+        let body = match bytes.strip_prefix(EXPERTS_MAGIC.as_slice()) {
+            Some(rest) => match rest.split_first() {
+                Some((&EXPERTS_FORMAT_VERSION, body)) => body,
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "unsupported experts format version",
+                    ))
+                }
+            },
+            None if Self::is_legacy_state(bytes) => bytes,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "corrupt experts header",
+                ))
+            }
+        };
+        let mut cursor = std::io::Cursor::new(body);
         *self = Self::read_state_from(&mut cursor)?;
         Ok(())
     }
 
+    /// Unframed state: starts directly with a little-endian body version 1..=3.
+    fn is_legacy_state(bytes: &[u8]) -> bool {
+        matches!(bytes, [1..=3, 0, 0, 0, ..])
+    }
+
     fn write_state_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
         // Version
         storage::write_u32_le(w, 3)?;
@@ -895,6 +1167,7 @@ impl ExpertManager {
             promote_reward_ema,
             allow_nested,
             max_depth,
+            similarity_routing_threshold: None,
         };
 
         let last_spawn_reason = storage::read_string(r)?;
@@ -926,6 +1199,7 @@ impl ExpertManager {
                         reward_fast_ema,
                         reward_slow_ema,
                         best_slow_ema,
+                        ..ContextStats::default()
                     },
                 );
             }
@@ -950,8 +1224,9 @@ impl ExpertManager {
             let mut fcur = std::io::Cursor::new(fork_bytes);
             let fork_point = Brain::load_image_from(&mut fcur)?;
             let children = {
-                let mut ccur = std::io::Cursor::new(child_bytes);
-                Box::new(Self::read_state_from(&mut ccur)?)
+                let mut children = Self::new();
+                children.load_state_bytes(&child_bytes)?;
+                Box::new(children)
             };
 
             experts.push(Expert {
@@ -964,6 +1239,8 @@ impl ExpertManager {
                 completed_trials,
                 episode_trials,
                 reward_ema,
+                parent_baseline: Vec::new(),
+                recent: Vec::new(),
             });
         }
 
@@ -977,6 +1254,8 @@ impl ExpertManager {
             context_stats,
             last_spawn_reason,
             last_consolidation,
+            ensemble_enabled: false,
+            ensemble_top_k: 0,
         })
     }
 }
@@ -996,6 +1275,32 @@ mod tests {
         Brain::new(cfg)
     }
 
+    #[test]
+    fn state_bytes_are_framed_and_legacy_bodies_still_load() {
+        let mut em = ExpertManager::new();
+        em.set_enabled(true);
+        em.note_trial_for_spawn_target_under_path("ctx_a", &[], 1, 0.0);
+        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 1, &small_brain(), false);
+
+        let bytes = em.save_state_bytes().unwrap();
+        assert_eq!(&bytes[..5], b"EXPR\x01");
+        let mut loaded = ExpertManager::new();
+        loaded.load_state_bytes(&bytes).unwrap();
+        assert_eq!(loaded.experts.len(), 1);
+
+        let mut legacy = ExpertManager::new();
+        legacy.load_state_bytes(&bytes[5..]).unwrap();
+        assert_eq!(legacy.experts.len(), 1);
+
+        let mut bad = bytes.clone();
+        bad[0] = b'X';
+        let err = ExpertManager::new().load_state_bytes(&bad).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        bad[0] = b'E';
+        bad[4] = 9;
+        assert!(ExpertManager::new().load_state_bytes(&bad).is_err());
+    }
+
     #[test]
     fn spawns_on_novel_context_after_first_trial() {
         let mut em = ExpertManager::new();
@@ -1003,7 +1308,7 @@ mod tests {
         let brain = small_brain();
 
         em.note_trial_for_spawn_target_under_path("ctx_a", &[], 1, 0.0);
-        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 1, &brain);
+        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 1, &brain, false);
 
         assert_eq!(em.experts.len(), 1);
         assert!(em.last_spawn_reason.contains("novel_context"));
@@ -1017,12 +1322,12 @@ mod tests {
 
         // First trial spawns (novel).
         em.note_trial_for_spawn_target_under_path("ctx_a", &[], 1, 0.2);
-        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 1, &brain);
+        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 1, &brain, false);
         assert_eq!(em.experts.len(), 1);
 
         // While active expert exists, should not spawn another.
         em.note_trial_for_spawn_target_under_path("ctx_a", &[], 2, 0.2);
-        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 2, &brain);
+        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 2, &brain, false);
         assert_eq!(em.experts.len(), 1);
     }
 
@@ -1045,11 +1350,220 @@ mod tests {
             em.note_trial_for_spawn_target_under_path("ctx_b", &[], t, -0.9);
         }
 
-        em.maybe_spawn_for_signals_under_path("ctx_b", &[], 35, &brain);
+        em.maybe_spawn_for_signals_under_path("ctx_b", &[], 35, &brain, false);
         assert_eq!(em.experts.len(), 1);
         assert!(
             em.last_spawn_reason.contains("performance_collapse")
                 || em.last_spawn_reason.contains("reward_shift")
         );
     }
+
+    #[test]
+    fn spawns_on_detected_reward_shift_without_collapse() {
+        let mut em = ExpertManager::new();
+        em.set_enabled(true);
+        let brain = small_brain();
+
+        // A poor but stable regime: no baseline to collapse from.
+        for t in 1..=30 {
+            em.note_trial_for_spawn_target_under_path("ctx_c", &[], t, -0.6);
+            if t > 1 {
+                em.maybe_spawn_for_signals_under_path("ctx_c", &[], t, &brain, false);
+                assert_eq!(em.experts.len(), 0, "spurious spawn at trial {t}");
+            }
+        }
+
+        // Rewards jump up: only the change-point detector should fire.
+        for t in 31..=45 {
+            em.note_trial_for_spawn_target_under_path("ctx_c", &[], t, 0.9);
+            em.maybe_spawn_for_signals_under_path("ctx_c", &[], t, &brain, false);
+            if !em.experts.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(em.experts.len(), 1);
+        assert!(em.last_spawn_reason.contains("reward_shift"));
+        assert!(!em.last_spawn_reason.contains("performance_collapse"));
+    }
+
+    #[test]
+    fn score_child_runs_batch_on_expert_brain() {
+        let mut em = ExpertManager::new();
+        em.set_enabled(true);
+        let brain = small_brain();
+        em.note_trial_for_spawn_target_under_path("ctx_a", &[], 1, 0.0);
+        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 1, &brain, false);
+        let id = em.active_expert_summary("ctx_a").expect("expert").id;
+
+        let input = BrainInput {
+            stimuli: Vec::new(),
+            steps: 2,
+            context_key: "ctx_a".to_string(),
+            meaning_alpha: 1.0,
+            verbose: false,
+        };
+        let out = em.score_child(id, &[input.clone(), input]).expect("scored");
+        assert_eq!(out.len(), 2);
+        assert!(em.score_child(id + 100, &[]).is_none());
+    }
+
+    fn run_episode(em: &mut ExpertManager, parent: &mut Brain, ctx: &str, child_wins: usize) {
+        let id = em.active_expert_summary(ctx).expect("expert").id;
+        let n = em.policy.episode_trials as usize;
+        for t in 0..n {
+            let reward = if t < child_wins { 1.0 } else { -1.0 };
+            em.on_trial_completed_path(&[id], reward, parent);
+        }
+    }
+
+    #[test]
+    fn consolidation_requires_child_to_beat_parent_ci() {
+        let mut parent = small_brain();
+        let mut em = ExpertManager::new();
+        em.set_enabled(true);
+        em.set_policy(ExpertPolicy {
+            promote_reward_ema: -1.0,
+            ..ExpertPolicy::default()
+        });
+
+        // Parent wins 50% of trials in this context.
+        for t in 1..=40 {
+            let reward = if t % 2 == 0 { 1.0 } else { -1.0 };
+            em.note_trial_for_spawn_target_under_path("ctx_e", &[], t, reward);
+        }
+        em.maybe_spawn_for_signals_under_path("ctx_e", &[], 40, &parent, true);
+        assert_eq!(em.experts.len(), 1);
+
+        // Child at ~60%: EMA gate passes, but the CIs overlap.
+        run_episode(&mut em, &mut parent, "ctx_e", 19);
+        assert!(em.experts.is_empty());
+        assert!(
+            em.last_consolidation.contains("not better than parent"),
+            "{}",
+            em.last_consolidation
+        );
+
+        // Child wins every trial: clearly better, so it consolidates.
+        em.cooldown_by_context.clear();
+        em.maybe_spawn_for_signals_under_path("ctx_e", &[], 41, &parent, true);
+        assert_eq!(em.experts.len(), 1);
+        run_episode(&mut em, &mut parent, "ctx_e", 32);
+        assert!(em.experts.is_empty());
+        assert!(
+            !em.last_consolidation.starts_with("culled"),
+            "{}",
+            em.last_consolidation
+        );
+    }
+
+    #[test]
+    fn ensemble_vote_weights_experts_by_recent_performance() {
+        let mut parent = small_brain();
+        parent.define_sensor("ctx_f", 4);
+        parent.define_action("left", 4);
+        parent.define_action("right", 4);
+
+        let mut em = ExpertManager::new();
+        em.set_enabled(true);
+        em.set_policy(ExpertPolicy {
+            allow_nested: true,
+            max_depth: 2,
+            ..ExpertPolicy::default()
+        });
+        assert!(em.ensemble_vote("ctx_f", 1.0).is_empty());
+
+        em.note_trial_for_spawn_target_under_path("ctx_f", &[], 1, 0.0);
+        em.maybe_spawn_for_signals_under_path("ctx_f", &[], 1, &parent, false);
+        let outer = em.experts[0].id;
+        em.note_trial_for_spawn_target_under_path("ctx_f", &[outer], 2, 0.0);
+        em.maybe_spawn_for_signals_under_path("ctx_f", &[outer], 2, &parent, false);
+        assert_eq!(em.total_active_count_recursive(), 2);
+
+        // The outer expert prefers "left" and keeps losing; the nested one prefers
+        // "right" and keeps winning.
+        let outer_brain = &mut em.experts[0].brain;
+        for _ in 0..20 {
+            outer_brain.reinforce_action("left", 50.0);
+            outer_brain.step();
+        }
+        em.experts[0].recent = vec![false; 10];
+        let inner = &mut em.experts[0].children.experts[0];
+        for _ in 0..20 {
+            inner.brain.reinforce_action("right", 50.0);
+            inner.brain.step();
+        }
+        inner.recent = vec![true; 10];
+
+        let solo = em.experts[0]
+            .brain
+            .ranked_actions_with_meaning("ctx_f", 1.0);
+        assert_eq!(solo[0].0, "left");
+
+        let voted = em.ensemble_vote("ctx_f", 1.0);
+        assert_eq!(voted[0].0, "right");
+
+        // top_k = 1 keeps only the best performer.
+        em.set_ensemble_mode(true, 1);
+        let inner_only = em.experts[0].children.experts[0]
+            .brain
+            .ranked_actions_with_meaning("ctx_f", 1.0);
+        let voted = em.ensemble_vote("ctx_f", 1.0);
+        assert_eq!(voted[0].0, inner_only[0].0);
+        assert!((voted[0].1 - inner_only[0].1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn similarity_routing_matches_causally_similar_context() {
+        let mut parent = small_brain();
+        // "ctx_a" and "ctx_a::bright" are both followed by "go_left"; "ctx_b" by "go_right".
+        for _ in 0..4 {
+            for (ctx, next) in [
+                ("ctx_a", "go_left"),
+                ("ctx_a::bright", "go_left"),
+                ("ctx_b", "go_right"),
+            ] {
+                parent.note_compound_symbol(&[ctx]);
+                parent.commit_observation();
+                parent.note_action(next);
+                parent.commit_observation();
+            }
+        }
+
+        let mut em = ExpertManager::new();
+        em.set_enabled(true);
+        em.note_trial_for_spawn_target_under_path("ctx_a", &[], 1, 0.0);
+        em.maybe_spawn_for_signals_under_path("ctx_a", &[], 1, &parent, false);
+        let id = em.experts[0].id;
+
+        let route =
+            |em: &ExpertManager, ctx: &str| em.controller_for_context_ref(ctx, &parent).route.path;
+        assert_eq!(route(&em, "ctx_a"), vec![id]);
+        assert!(route(&em, "ctx_a::bright").is_empty());
+
+        em.context_similarity_routing(0.8);
+        assert_eq!(route(&em, "ctx_a::bright"), vec![id]);
+        assert!(route(&em, "ctx_b").is_empty());
+        assert!(em.summary().similarity_routing);
+
+        em.disable_context_similarity_routing();
+        assert!(route(&em, "ctx_a::bright").is_empty());
+    }
+
+    #[test]
+    fn spawns_on_learning_plateau() {
+        let mut em = ExpertManager::new();
+        em.set_enabled(true);
+        let brain = small_brain();
+
+        // Stable mediocre rewards: no shift or collapse.
+        for t in 1..=25 {
+            em.note_trial_for_spawn_target_under_path("ctx_d", &[], t, 0.1);
+        }
+        em.maybe_spawn_for_signals_under_path("ctx_d", &[], 25, &brain, false);
+        assert_eq!(em.experts.len(), 0);
+
+        em.maybe_spawn_for_signals_under_path("ctx_d", &[], 25, &brain, true);
+        assert_eq!(em.experts.len(), 1);
+        assert!(em.last_spawn_reason.contains("plateau"));
+    }
 }
@@ -8,12 +8,17 @@ use braine_games::pong::{PongAction, PongEvent, PongSim};
 use std::time::{Duration, Instant};
 
 pub use braine_games::bandit::BanditGame;
+pub use braine_games::conditional_discrimination::ConditionalDiscriminationGame;
+pub use braine_games::gridworld::GridWorldGame;
+pub use braine_games::match_to_sample::{DelayAccuracy, MatchToSampleGame};
 pub use braine_games::maze::MazeGame;
+pub use braine_games::n_back::NBackGame;
 pub use braine_games::replay::{ReplayDataset, ReplayGame};
 pub use braine_games::spot::SpotGame;
 pub use braine_games::spot_reversal::SpotReversalGame;
 pub use braine_games::spot_xy::SpotXYGame;
 pub use braine_games::stats::GameStats;
+pub use braine_games::temporal_xor::TemporalXORGame;
 pub use braine_games::text_next_token::TextNextTokenGame;
 
 // ─────────────────────────────────────────────────────────────────────────
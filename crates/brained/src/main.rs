@@ -15,19 +15,29 @@ use braine::storage;
 use braine::substrate::RoutingModuleSummary;
 use braine::substrate::Stimulus;
 use braine::substrate::{
-    ActionScoreBreakdown, Brain, BrainConfig, BrainDelta, OwnedStimulus, RewardEdges, UnitPlotPoint,
+    action_confidence, ActionDebugInfo, ActionScoreBreakdown, Brain, BrainConfig, BrainDelta,
+    BrainInput, BrainOutput, NeurogenesisPolicy, NoisePattern, OwnedStimulus, PruningPolicy,
+    RewardEdges, StepProfile, UnitPlotPoint,
 };
+use futures_util::{SinkExt as _, StreamExt as _};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::{Read as _, Seek as _, SeekFrom, Write as _};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+#[cfg(unix)]
+use tokio::net::UnixListener;
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, mpsc, RwLock};
 use tokio::time;
-use tracing::{error, info, warn};
+use tokio_tungstenite::tungstenite::handshake::server::{
+    ErrorResponse as WsErrorResponse, Request as WsRequest, Response as WsResponse,
+};
+use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
+use tracing::{debug, error, info, warn};
 
 mod advisor;
 mod experts;
@@ -37,11 +47,70 @@ mod state_image;
 
 use experts::{ExpertManager, ExpertsPersistenceMode, ParentLearningPolicy};
 use game::{
-    BanditGame, MazeGame, PongGame, ReplayDataset, ReplayGame, SpotGame, SpotReversalGame,
-    SpotXYGame, TextNextTokenGame,
+    BanditGame, ConditionalDiscriminationGame, DelayAccuracy, GridWorldGame, MatchToSampleGame,
+    MazeGame, NBackGame, PongGame, ReplayDataset, ReplayGame, SpotGame, SpotReversalGame,
+    SpotXYGame, TemporalXORGame, TextNextTokenGame,
 };
 use paths::AppPaths;
 
+/// Trials considered for HUD learning-curve trend / plateau detection.
+const HUD_TREND_WINDOW: usize = 100;
+
+/// `|slope|` below which the learning curve counts as a plateau (accuracy per trial).
+const PLATEAU_SLOPE_THRESHOLD: f32 = 0.001;
+
+/// Per-axis cap on `ComputeMeaningMatrix` inputs (stimuli and actions each).
+const MEANING_MATRIX_MAX: usize = 32;
+
+/// Cap on `GetCausalTemporalOrder` symbols; the sort is quadratic in their number.
+const CAUSAL_TEMPORAL_ORDER_MAX: usize = 256;
+
+/// `pair_rate` of the imprint that follows a SpotReversal flip (`SetAutoDreamOnFlip`).
+const FLIP_IMPRINT_PAIR_RATE: f32 = 0.8;
+
+/// Upper bound on `ProfileStep { num_steps }`.
+const PROFILE_STEPS_MAX: u32 = 1000;
+
+/// Upper bound on the number of inputs in one `ScoreExpert` batch.
+const SCORE_EXPERT_MAX_INPUTS: usize = 64;
+
+/// Upper bound on `GetCausalEventLog { n }` (the brain's default log capacity).
+const CAUSAL_EVENTS_MAX: u32 = 1024;
+
+/// Upper bound on `GetCausalPredecessors { k }`.
+const CAUSAL_PREDECESSORS_MAX: u32 = 64;
+
+/// Upper bound on `GetCausalGraph { max_depth }`.
+const CAUSAL_GRAPH_MAX_DEPTH: u32 = 8;
+
+/// Node and edge budgets of a `GetCausalGraph` response.
+const CAUSAL_GRAPH_MAX_NODES: usize = 256;
+const CAUSAL_GRAPH_MAX_EDGES: usize = 4096;
+
+/// Upper bound on `SetExpertEnsembleMode { top_k }`.
+const EXPERT_ENSEMBLE_TOP_K_MAX: u32 = 64;
+
+/// Buffered frames per `WatchDiag` subscriber before the slowest one starts skipping.
+const DIAG_BROADCAST_CAPACITY: usize = 16;
+
+/// `BrainStats::learning_converged` is set when `plasticity_rate` falls below this.
+const PLASTICITY_CONVERGED_THRESHOLD: f32 = 1e-5;
+
+/// Learning steps `plasticity_rate` must have seen before `learning_converged` can be set.
+const PLASTICITY_CONVERGED_MIN_STEPS: u32 = 100;
+
+/// EMA rate of the exploit-decision confidence that drives exploration annealing.
+const ACTION_CONFIDENCE_EMA_ALPHA: f32 = 0.05;
+
+/// Confidence EMA above which exploration anneals faster than the base 1% per trial.
+const ACTION_CONFIDENCE_HIGH: f32 = 0.5;
+
+/// Consecutive oscillation samples sent in `BrainStats::osc_trace`.
+const OSC_TRACE_STEPS: usize = 128;
+
+/// Byte cap for the canonical brain file; saves that would exceed it fail.
+const BRAIN_FILE_MAX_BYTES: usize = 1 << 30;
+
 fn default_experts_max_depth() -> u32 {
     1
 }
@@ -62,6 +131,10 @@ fn default_experts_persistence_mode() -> String {
     "full".to_string()
 }
 
+fn default_expert_similarity_threshold() -> f32 {
+    0.8
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
 enum ActiveGame {
@@ -70,8 +143,13 @@ enum ActiveGame {
     SpotReversal(SpotReversalGame),
     SpotXY(SpotXYGame),
     Maze(MazeGame),
+    GridWorld(GridWorldGame),
     Pong(PongGame),
     Text(TextNextTokenGame),
+    NBack(NBackGame),
+    ConditionalDiscrimination(ConditionalDiscriminationGame),
+    TemporalXor(TemporalXORGame),
+    MatchToSample(MatchToSampleGame),
     Replay(ReplayGame),
 }
 
@@ -83,8 +161,13 @@ impl ActiveGame {
             ActiveGame::SpotReversal(_) => "spot_reversal",
             ActiveGame::SpotXY(_) => "spotxy",
             ActiveGame::Maze(_) => "maze",
+            ActiveGame::GridWorld(_) => "gridworld",
             ActiveGame::Pong(_) => "pong",
             ActiveGame::Text(_) => "text",
+            ActiveGame::NBack(_) => "n_back",
+            ActiveGame::ConditionalDiscrimination(_) => "conditional_discrimination",
+            ActiveGame::TemporalXor(_) => "temporal_xor",
+            ActiveGame::MatchToSample(_) => "match_to_sample",
             ActiveGame::Replay(_) => "replay",
         }
     }
@@ -96,8 +179,13 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => g.update_timing(trial_period_ms),
             ActiveGame::SpotXY(g) => g.update_timing(trial_period_ms),
             ActiveGame::Maze(g) => g.update_timing(trial_period_ms),
+            ActiveGame::GridWorld(g) => g.update_timing(trial_period_ms),
             ActiveGame::Pong(g) => g.update_timing(trial_period_ms),
             ActiveGame::Text(g) => g.update_timing(trial_period_ms),
+            ActiveGame::NBack(g) => g.update_timing(trial_period_ms),
+            ActiveGame::ConditionalDiscrimination(g) => g.update_timing(trial_period_ms),
+            ActiveGame::TemporalXor(g) => g.update_timing(trial_period_ms),
+            ActiveGame::MatchToSample(g) => g.update_timing(trial_period_ms),
             ActiveGame::Replay(g) => g.update_timing(trial_period_ms),
         }
     }
@@ -109,8 +197,13 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => g.stimulus_name(),
             ActiveGame::SpotXY(g) => g.stimulus_name(),
             ActiveGame::Maze(g) => g.stimulus_name(),
+            ActiveGame::GridWorld(g) => g.stimulus_name(),
             ActiveGame::Pong(g) => g.stimulus_name(),
             ActiveGame::Text(g) => g.stimulus_name(),
+            ActiveGame::NBack(g) => g.stimulus_name(),
+            ActiveGame::ConditionalDiscrimination(g) => g.stimulus_name(),
+            ActiveGame::TemporalXor(g) => g.stimulus_name(),
+            ActiveGame::MatchToSample(g) => g.stimulus_name(),
             ActiveGame::Replay(g) => g.stimulus_name(),
         }
     }
@@ -122,8 +215,15 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => std::borrow::Cow::Borrowed(g.correct_action()),
             ActiveGame::SpotXY(g) => std::borrow::Cow::Borrowed(g.correct_action()),
             ActiveGame::Maze(_) => std::borrow::Cow::Borrowed(""),
+            ActiveGame::GridWorld(_) => std::borrow::Cow::Borrowed(""),
             ActiveGame::Pong(g) => std::borrow::Cow::Borrowed(g.correct_action()),
             ActiveGame::Text(g) => std::borrow::Cow::Owned(g.correct_action()),
+            ActiveGame::NBack(g) => std::borrow::Cow::Borrowed(g.correct_action()),
+            ActiveGame::ConditionalDiscrimination(g) => {
+                std::borrow::Cow::Borrowed(g.correct_action())
+            }
+            ActiveGame::TemporalXor(g) => std::borrow::Cow::Borrowed(g.correct_action()),
+            ActiveGame::MatchToSample(g) => std::borrow::Cow::Borrowed(g.correct_action()),
             ActiveGame::Replay(g) => std::borrow::Cow::Borrowed(g.correct_action()),
         }
     }
@@ -136,8 +236,13 @@ impl ActiveGame {
             }
             ActiveGame::SpotXY(g) => g.allowed_actions(),
             ActiveGame::Maze(g) => g.allowed_actions(),
+            ActiveGame::GridWorld(g) => g.allowed_actions(),
             ActiveGame::Pong(g) => g.allowed_actions(),
             ActiveGame::Text(g) => g.allowed_actions(),
+            ActiveGame::NBack(g) => g.allowed_actions(),
+            ActiveGame::ConditionalDiscrimination(g) => g.allowed_actions(),
+            ActiveGame::TemporalXor(g) => g.allowed_actions(),
+            ActiveGame::MatchToSample(g) => g.allowed_actions(),
             ActiveGame::Replay(g) => g.allowed_actions(),
         }
     }
@@ -149,8 +254,13 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => g.response_made,
             ActiveGame::SpotXY(g) => g.response_made,
             ActiveGame::Maze(g) => g.response_made,
+            ActiveGame::GridWorld(g) => g.response_made,
             ActiveGame::Pong(g) => g.response_made,
             ActiveGame::Text(g) => g.response_made,
+            ActiveGame::NBack(g) => g.response_made,
+            ActiveGame::ConditionalDiscrimination(g) => g.response_made,
+            ActiveGame::TemporalXor(g) => g.response_made,
+            ActiveGame::MatchToSample(g) => g.response_made,
             ActiveGame::Replay(g) => g.response_made,
         }
     }
@@ -162,8 +272,13 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => g.trial_frame,
             ActiveGame::SpotXY(g) => g.trial_frame,
             ActiveGame::Maze(g) => g.trial_frame,
+            ActiveGame::GridWorld(g) => g.trial_frame,
             ActiveGame::Pong(g) => g.trial_frame,
             ActiveGame::Text(g) => g.trial_frame,
+            ActiveGame::NBack(g) => g.trial_frame,
+            ActiveGame::ConditionalDiscrimination(g) => g.trial_frame,
+            ActiveGame::TemporalXor(g) => g.trial_frame,
+            ActiveGame::MatchToSample(g) => g.trial_frame,
             ActiveGame::Replay(g) => g.trial_frame,
         }
     }
@@ -176,12 +291,20 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => g.spot_is_left,
             // For SpotXY, reuse this field as "correct side is left" (x < 0).
             ActiveGame::SpotXY(g) => g.pos_x < 0.0,
-            // For Maze, this field is not meaningful.
-            ActiveGame::Maze(_) => false,
+            // For Maze/GridWorld, this field is not meaningful.
+            ActiveGame::Maze(_) | ActiveGame::GridWorld(_) => false,
             // For Pong, reuse this field as "ball is above paddle".
             ActiveGame::Pong(g) => g.sim.state.ball_y > g.sim.state.paddle_y,
             // For Text, this field is not meaningful.
             ActiveGame::Text(_) => false,
+            // For N-back, this field is not meaningful.
+            ActiveGame::NBack(_) => false,
+            // For conditional discrimination, reuse this field as "context A is active".
+            ActiveGame::ConditionalDiscrimination(g) => g.context_is_a,
+            // For temporal XOR, reuse this field as "current stimulus is A".
+            ActiveGame::TemporalXor(g) => g.stim_is_a,
+            // For match-to-sample, this field is not meaningful.
+            ActiveGame::MatchToSample(_) => false,
 
             // For Replay, this field is not meaningful.
             ActiveGame::Replay(_) => false,
@@ -205,11 +328,31 @@ impl ActiveGame {
                 let _ = trial_period_ms;
                 g.score_action(action)
             }
+            ActiveGame::GridWorld(g) => {
+                let _ = trial_period_ms;
+                g.score_action(action)
+            }
             ActiveGame::Pong(g) => g.score_action(action, trial_period_ms),
             ActiveGame::Text(g) => {
                 let _ = trial_period_ms;
                 g.score_action(action)
             }
+            ActiveGame::NBack(g) => {
+                let _ = trial_period_ms;
+                g.score_action(action)
+            }
+            ActiveGame::ConditionalDiscrimination(g) => {
+                let _ = trial_period_ms;
+                g.score_action(action)
+            }
+            ActiveGame::TemporalXor(g) => {
+                let _ = trial_period_ms;
+                g.score_action(action)
+            }
+            ActiveGame::MatchToSample(g) => {
+                let _ = trial_period_ms;
+                g.score_action(action)
+            }
             ActiveGame::Replay(g) => {
                 let _ = trial_period_ms;
                 g.score_action(action)
@@ -224,8 +367,13 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => &g.stats,
             ActiveGame::SpotXY(g) => &g.stats,
             ActiveGame::Maze(g) => &g.stats,
+            ActiveGame::GridWorld(g) => &g.stats,
             ActiveGame::Pong(g) => &g.stats,
             ActiveGame::Text(g) => &g.stats,
+            ActiveGame::NBack(g) => &g.stats,
+            ActiveGame::ConditionalDiscrimination(g) => &g.stats,
+            ActiveGame::TemporalXor(g) => &g.stats,
+            ActiveGame::MatchToSample(g) => &g.stats,
             ActiveGame::Replay(g) => &g.stats,
         }
     }
@@ -237,8 +385,13 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => &mut g.stats,
             ActiveGame::SpotXY(g) => &mut g.stats,
             ActiveGame::Maze(g) => &mut g.stats,
+            ActiveGame::GridWorld(g) => &mut g.stats,
             ActiveGame::Pong(g) => &mut g.stats,
             ActiveGame::Text(g) => &mut g.stats,
+            ActiveGame::NBack(g) => &mut g.stats,
+            ActiveGame::ConditionalDiscrimination(g) => &mut g.stats,
+            ActiveGame::TemporalXor(g) => &mut g.stats,
+            ActiveGame::MatchToSample(g) => &mut g.stats,
             ActiveGame::Replay(g) => &mut g.stats,
         }
     }
@@ -250,8 +403,13 @@ impl ActiveGame {
             ActiveGame::SpotReversal(g) => g.last_action.as_deref(),
             ActiveGame::SpotXY(g) => g.last_action.as_deref(),
             ActiveGame::Maze(g) => g.last_action.as_deref(),
+            ActiveGame::GridWorld(g) => g.last_action.as_deref(),
             ActiveGame::Pong(g) => g.last_action.as_deref(),
             ActiveGame::Text(g) => g.last_action.as_deref(),
+            ActiveGame::NBack(g) => g.last_action.as_deref(),
+            ActiveGame::ConditionalDiscrimination(g) => g.last_action.as_deref(),
+            ActiveGame::TemporalXor(g) => g.last_action.as_deref(),
+            ActiveGame::MatchToSample(g) => g.last_action.as_deref(),
             ActiveGame::Replay(g) => g.last_action.as_deref(),
         }
     }
@@ -260,8 +418,13 @@ impl ActiveGame {
         match self {
             ActiveGame::SpotXY(g) => Some(g.stimulus_key()),
             ActiveGame::Maze(g) => Some(g.stimulus_key()),
+            ActiveGame::GridWorld(g) => Some(g.stimulus_key()),
             ActiveGame::Pong(g) => Some(g.stimulus_key()),
             ActiveGame::Text(g) => Some(g.stimulus_key()),
+            ActiveGame::NBack(g) => Some(g.stimulus_key()),
+            ActiveGame::ConditionalDiscrimination(g) => Some(g.stimulus_key()),
+            ActiveGame::TemporalXor(g) => Some(g.stimulus_key()),
+            ActiveGame::MatchToSample(g) => Some(g.stimulus_key()),
             ActiveGame::Replay(g) => Some(g.stimulus_key()),
             _ => None,
         }
@@ -328,6 +491,13 @@ enum Request {
         trial_period_ms: Option<u32>,
         #[serde(default)]
         max_units: Option<u32>,
+        #[serde(default)]
+        intrinsic_reward_scale: Option<f32>,
+        #[serde(default)]
+        causal_window_frames: Option<u32>,
+        /// Replaces `BrainConfig::experimental_flags`.
+        #[serde(default)]
+        experimental_flags: Option<HashMap<String, f32>>,
     },
 
     // Manual gates (freeze/paralyze)
@@ -353,6 +523,10 @@ enum Request {
         expected_fingerprint: u64,
         #[serde(default = "default_true")]
         autosave: bool,
+        /// Restrict which edges the delta may touch:
+        /// "action" | "sensor" | "not_action" | "not_sensor". Omit to apply all.
+        #[serde(default)]
+        mask_kind: Option<String>,
     },
 
     // Inference (read-only)
@@ -369,6 +543,64 @@ enum Request {
         steps: u32,
         #[serde(default)]
         meaning_alpha: Option<f32>,
+        /// Also return `action_debug` (`Brain::ranked_actions_with_meaning_debug`).
+        #[serde(default)]
+        verbose: bool,
+    },
+
+    // Analysis (read-only)
+    /// Pair meaning (`reward_pos - reward_neg` causal strength) for every
+    /// stimulus × action combination, for heatmaps.
+    ComputeMeaningMatrix {
+        stimuli: Vec<String>,
+        actions: Vec<String>,
+    },
+    /// Average per-phase timing of `Brain::profile_cycle` over `num_steps` cycles of a cloned brain.
+    ProfileStep {
+        num_steps: u32,
+    },
+    /// The last `n` causal edge updates recorded by the live brain's telemetry.
+    GetCausalEventLog {
+        n: u32,
+    },
+    /// The `k` symbols whose directed causal edges most strongly predict `symbol`.
+    GetCausalPredecessors {
+        symbol: String,
+        k: u32,
+    },
+    /// Product of the directed causal strengths along `symbols[0] -> symbols[1] -> ...`.
+    GetCausalChainScore {
+        symbols: Vec<String>,
+    },
+    /// Most likely temporal order of `symbols` according to directed causal edges.
+    GetCausalTemporalOrder {
+        symbols: Vec<String>,
+    },
+    /// Causal memory around `context_key` (default: the current stimulus),
+    /// expanded breadth-first up to `max_depth` hops.
+    GetCausalGraph {
+        #[serde(default)]
+        context_key: Option<String>,
+        max_depth: u32,
+        /// Edges whose directed and co-occurrence counts are both below this are skipped.
+        #[serde(default)]
+        min_weight: f32,
+    },
+    /// Action score breakdowns for every registered sensor context at once.
+    GetAllContextScores {
+        #[serde(default)]
+        meaning_alpha: Option<f32>,
+    },
+    /// IO group membership of the parent brain's units (`Brain::export_unit_metadata_json`).
+    ExportUnitMetadata,
+    /// Flat scalar metrics of the parent brain (`Brain::metrics`).
+    GetMetrics,
+    /// Every connection weight of the parent brain, for external analysis.
+    ///
+    /// `format` is `csv`, `json_sparse` or `binary_f32`. Requires `running=false`
+    /// so the snapshot is not taken mid-step.
+    ExportWeights {
+        format: String,
     },
 
     /// Run a single externally-specified trial on the *live* brain.
@@ -384,6 +616,10 @@ enum Request {
         #[serde(default)]
         forced_action: Option<String>,
         reward: f32,
+        /// When set, the neuromodulator is the reward prediction error
+        /// `reward - predicted_reward` (TD learning) instead of the raw reward.
+        #[serde(default)]
+        predicted_reward: Option<f32>,
         #[serde(default = "default_true")]
         learn: bool,
         #[serde(default = "default_infer_steps")]
@@ -392,7 +628,30 @@ enum Request {
         meaning_alpha: Option<f32>,
     },
 
-    GetState,
+    GetState {
+        /// Fill `HudData::sensor_activities` / `action_activities` (off by default).
+        #[serde(default)]
+        include_group_activities: bool,
+    },
+    /// Push `State` snapshots on this connection every `interval_ms` (clamped to
+    /// 16..=60000) until the client disconnects or sends `StopStream`.
+    StreamState {
+        interval_ms: u32,
+    },
+    StopStream,
+    /// Push a `Diagnostics` response on this connection every `every_frames`
+    /// frames (minimum 1) until the client disconnects or sends `UnwatchDiag`.
+    WatchDiag {
+        every_frames: u32,
+    },
+    UnwatchDiag,
+    /// Execute `requests` in order under a single state lock.
+    ///
+    /// Every request runs even if an earlier one fails; running-gated requests
+    /// are validated up front against the `Start`/`Stop` sequence in the batch.
+    BatchRequests {
+        requests: Vec<Request>,
+    },
     /// Fetch the tunable parameter schema for a game (UI uses this to render knobs).
     GetGameParams {
         game: String,
@@ -403,6 +662,27 @@ enum Request {
     SetMaxUnits {
         max_units: u32,
     },
+    /// Choose when auto-neurogenesis grows the parent brain: `saturation`
+    /// `{ threshold, amount }`, `periodic` `{ every_steps, amount }` or
+    /// `reward_driven` `{ min_reward, amount }`.
+    SetNeurogenesisPolicy {
+        policy_name: String,
+        #[serde(default)]
+        params: serde_json::Value,
+    },
+    /// Choose how the parent brain prunes connections: `threshold` `{ below }`,
+    /// `top_k` `{ k_per_unit }`, `probabilistic` `{ rate, temperature }` or
+    /// `age` `{ max_age_steps }`.
+    SetPruningPolicy {
+        policy_name: String,
+        #[serde(default)]
+        params: serde_json::Value,
+    },
+    /// Apply a one-shot `NoisePattern` to the parent brain's unit state
+    /// (robustness probes, simulated sensory noise).
+    InjectNoise {
+        pattern: NoisePattern,
+    },
 
     // Storage / snapshots
     SaveSnapshot,
@@ -415,11 +695,21 @@ enum Request {
         max_edges: u32,
         #[serde(default)]
         include_isolated: bool,
+        /// Substrate only: keep edges whose source is in this group ("" = any unit).
+        #[serde(default)]
+        from_group: Option<String>,
+        /// Substrate only: keep edges whose target is in this group ("" = any unit).
+        #[serde(default)]
+        to_group: Option<String>,
     },
     Start,
     Stop,
     SetGame {
         game: String,
+        /// Also clear causal memory (see `ResetCausality`) so the old task's
+        /// associations don't bias the new one.
+        #[serde(default)]
+        reset_causality: bool,
     },
     SetGameParam {
         game: String,
@@ -437,13 +727,38 @@ enum Request {
     HumanAction {
         action: String,
     },
-    TriggerDream,
+    TriggerDream {
+        /// Which remembered observations to replay; omitted means `Internal`.
+        #[serde(default)]
+        source: Option<braine::substrate::DreamSource>,
+    },
     TriggerBurst,
     TriggerSync,
-    TriggerImprint,
+    TriggerImprint {
+        /// Restrict the imprint to this sensor group; omitted imprints the whole context.
+        #[serde(default)]
+        group: Option<String>,
+        /// Rate for sensor → interneuron edges (default 0.6).
+        #[serde(default)]
+        sensor_rate: Option<f32>,
+        /// Rate for interneuron → action edges (default 0, i.e. skipped).
+        #[serde(default)]
+        action_rate: Option<f32>,
+        /// Rate for direct sensor → action edges (default 0; 0.6 with `group`).
+        /// With `group` this is the only rate accepted.
+        #[serde(default)]
+        pair_rate: Option<f32>,
+    },
+    /// Dream when the SpotReversal rule flips and imprint the reversed mapping
+    /// (`pair_rate` 0.8) on the first rewarded trial after the flip.
+    SetAutoDreamOnFlip {
+        enabled: bool,
+    },
     SaveBrain,
     LoadBrain,
     ResetBrain,
+    /// Clear causal memory on the parent and expert brains; weights are kept.
+    ResetCausality,
     Shutdown,
     SetFramerate {
         fps: u32,
@@ -489,6 +804,25 @@ enum Request {
         persistence_mode: String,
     },
     CullExperts,
+    /// Switch between single-controller routing and a performance-weighted
+    /// vote over the active experts for the context (`top_k = 0`: all).
+    SetExpertEnsembleMode {
+        enabled: bool,
+        #[serde(default)]
+        top_k: u32,
+    },
+    /// Route contexts without an exact expert to the most causally similar
+    /// active expert when similarity reaches `threshold` (0..1).
+    SetExpertSimilarityRouting {
+        enabled: bool,
+        #[serde(default = "default_expert_similarity_threshold")]
+        threshold: f32,
+    },
+    /// Evaluate expert `id` on each input, each on its own clone (read-only).
+    ScoreExpert {
+        id: u32,
+        inputs: Vec<BrainInput>,
+    },
 
     // Advisor / LLM integration (slow loop; bounded config nudges)
     AdvisorGet,
@@ -508,6 +842,8 @@ enum Request {
     AdvisorContext {
         #[serde(default)]
         include_action_scores: bool,
+        #[serde(default)]
+        include_causal_graph: bool,
     },
 
     /// What an LLM returns (bounded advice). The daemon clamps and applies.
@@ -517,11 +853,88 @@ enum Request {
 
     // Replay dataset (dataset-driven evaluation)
     ReplayGetDataset,
+    /// Exactly one of `dataset` (JSON) or `csv_base64` (base64-encoded CSV,
+    /// see `ReplayDataset::from_csv_str`) must be set.
     ReplaySetDataset {
-        dataset: ReplayDataset,
+        #[serde(default)]
+        dataset: Option<ReplayDataset>,
+        #[serde(default)]
+        csv_base64: Option<String>,
     },
 }
 
+impl Request {
+    /// Requests the daemon rejects while the simulation is running.
+    fn requires_stopped(&self) -> bool {
+        matches!(
+            self,
+            Request::Trial { .. }
+                | Request::ReplaySetDataset { .. }
+                | Request::SetGame { .. }
+                | Request::ExportWeights { .. }
+        )
+    }
+
+    /// Requests served under a shared read lock (see `handle_read_request`).
+    fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            Request::GetState { .. }
+                | Request::DiagGet
+                | Request::CfgGet
+                | Request::ComputeMeaningMatrix { .. }
+                | Request::ProfileStep { .. }
+                | Request::ScoreExpert { .. }
+                | Request::GetCausalEventLog { .. }
+                | Request::GetCausalPredecessors { .. }
+                | Request::GetCausalChainScore { .. }
+                | Request::GetCausalTemporalOrder { .. }
+                | Request::GetCausalGraph { .. }
+                | Request::GetAllContextScores { .. }
+                | Request::ExportUnitMetadata
+                | Request::GetMetrics
+                | Request::ExportWeights { .. }
+                | Request::GetGraph { .. }
+                | Request::AdvisorGet
+                | Request::AdvisorContext { .. }
+                | Request::InferActionScores { .. }
+                | Request::SyncGetInfo
+                | Request::ReplayGetDataset
+                | Request::GatesGetModules
+        )
+    }
+}
+
+/// Wire form of a request line: the tagged `Request` plus an optional client-chosen id.
+///
+/// Read-only requests carrying a `request_id` are handled concurrently and answered
+/// with the same id, so a client may pipeline several of them on one connection.
+/// Writes, and requests without an id, are applied one at a time in arrival order.
+#[derive(Debug, Deserialize)]
+struct RequestEnvelope {
+    #[serde(default)]
+    request_id: Option<u64>,
+    #[serde(flatten)]
+    request: Request,
+}
+
+/// Wire form of a response line; `request_id` echoes the request's id when it had one.
+#[derive(Debug, Serialize)]
+struct ResponseEnvelope {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<u64>,
+    #[serde(flatten)]
+    response: Response,
+}
+
+impl ResponseEnvelope {
+    fn to_line(&self) -> serde_json::Result<String> {
+        let mut line = serde_json::to_string(self)?;
+        line.push('\n');
+        Ok(line)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[allow(clippy::large_enum_variant)]
@@ -546,6 +959,12 @@ enum Response {
         target_fps: u32,
         trial_period_ms: u32,
         max_units_limit: u32,
+        #[serde(default)]
+        intrinsic_reward_scale: f32,
+        #[serde(default)]
+        causal_window_frames: u32,
+        #[serde(default)]
+        experimental_flags: HashMap<String, f32>,
     },
     GatesModules {
         #[serde(default)]
@@ -568,6 +987,64 @@ enum Response {
         context_key: String,
         #[serde(default)]
         action_scores: Vec<ActionScoreBreakdown>,
+        /// Present only for `verbose` requests.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        action_debug: Vec<ActionDebugInfo>,
+    },
+    /// `rows[i][j]` is the pair meaning of `stimuli[i]` × `actions[j]`.
+    MeaningMatrix {
+        stimuli: Vec<String>,
+        actions: Vec<String>,
+        rows: Vec<Vec<f32>>,
+    },
+    StepProfile {
+        num_steps: u32,
+        profile: StepProfile,
+    },
+    /// Oldest first.
+    CausalEventLog {
+        events: Vec<CausalEventInfo>,
+    },
+    /// Strongest first.
+    CausalPredecessors {
+        symbol: String,
+        predecessors: Vec<CausalLink>,
+    },
+    /// In `[0, 1]`; 0 if any link is missing.
+    CausalChainScore {
+        score: f32,
+    },
+    /// Indices into the request's `symbols`, earliest first.
+    CausalTemporalOrder {
+        order: Vec<usize>,
+        symbols: Vec<String>,
+    },
+    /// Symbol-level causal graph (unlike `Graph`, whose causal kind only carries labels).
+    CausalGraph {
+        nodes: Vec<CausalNode>,
+        edges: Vec<CausalEdge>,
+    },
+    /// Keyed by sensor (context) name.
+    AllContextScores {
+        scores: HashMap<String, Vec<ActionScoreBreakdown>>,
+    },
+    /// JSON document from `Brain::export_unit_metadata_json`.
+    UnitMetadata {
+        json: String,
+    },
+    /// Keyed by dot-separated metric name (e.g. `learning.plasticity_l1`).
+    Metrics {
+        metrics: HashMap<String, f32>,
+    },
+    /// Serialized weights from `ExportWeights` (see `export_weights`).
+    WeightsExport {
+        format: String,
+        data: String,
+        unit_count: u32,
+    },
+    ExpertScores {
+        id: u32,
+        outputs: Vec<BrainOutput>,
     },
     TrialResult {
         action: String,
@@ -579,6 +1056,10 @@ enum Response {
         learned: bool,
     },
     State(Box<StateSnapshot>),
+    BatchResults {
+        results: Vec<Response>,
+        batch_had_errors: bool,
+    },
     GameParams {
         game: String,
         params: Vec<GameParamDef>,
@@ -671,6 +1152,25 @@ struct GraphNode {
     label: String,
     #[serde(default)]
     value: f32,
+    /// IO group the unit belongs to (substrate graphs only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+}
+
+/// Unit id → IO group name. Action groups shadow sensor groups on overlap.
+fn unit_group_labels(brain: &Brain) -> HashMap<u32, String> {
+    let mut out = HashMap::new();
+    for name in brain.sensor_group_names() {
+        for id in brain.get_sensor_group_units(name) {
+            out.insert(id as u32, name.to_string());
+        }
+    }
+    for name in brain.action_group_names() {
+        for id in brain.get_action_group_units(name) {
+            out.insert(id as u32, name.to_string());
+        }
+    }
+    out
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -746,23 +1246,51 @@ struct StorageInfo {
     /// Daemon state wrapper version for the canonical brain file.
     ///
     /// - 0: unknown / missing
-    /// - 3: BRSTATE3 (current)
+    /// - 3: BRSTATE3
+    /// - 4: BRSTATE4 (current)
     #[serde(default)]
     state_wrapper_version: u32,
+    /// True if the last save ran out of capacity; the file on disk is incomplete.
+    #[serde(default)]
+    storage_capacity_overflow: bool,
     #[serde(default)]
-    snapshots: Vec<SnapshotEntry>,
+    snapshots: Vec<storage::SnapshotEntry>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-struct SnapshotEntry {
-    #[serde(default)]
-    stem: String,
-    #[serde(default)]
-    brain_bytes: u64,
-    #[serde(default)]
-    runtime_bytes: u64,
-    #[serde(default)]
-    modified_unix: u64,
+/// `CausalEvent` with symbol ids resolved to names.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CausalEventInfo {
+    step: u64,
+    from: String,
+    to: String,
+    old_weight: f32,
+    new_weight: f32,
+    is_directed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CausalLink {
+    symbol: String,
+    strength: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CausalNode {
+    symbol: String,
+    /// `sensor`, `action`, `pair`, `reward` or `symbol` (see `causal_symbol_type`).
+    symbol_type: String,
+    /// Decayed observation count, rounded.
+    activation_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CausalEdge {
+    from_symbol: String,
+    to_symbol: String,
+    /// Decayed count of `to_symbol` following `from_symbol` on the next commit.
+    directed_weight: f32,
+    /// Decayed count of both symbols committed together.
+    cooccur_weight: f32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -824,6 +1352,9 @@ struct PersistedGameStats {
     learned_at_trial: Option<u32>,
     #[serde(default)]
     mastered_at_trial: Option<u32>,
+    /// `(name, trial)` of every milestone reached, standard ones included.
+    #[serde(default)]
+    milestones: Vec<(String, u32)>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -851,8 +1382,13 @@ struct RewardScales {
     spot_reversal: f32,
     spotxy: f32,
     maze: f32,
+    gridworld: f32,
     pong: f32,
     text: f32,
+    n_back: f32,
+    conditional_discrimination: f32,
+    temporal_xor: f32,
+    match_to_sample: f32,
     replay: f32,
 }
 
@@ -866,8 +1402,13 @@ impl Default for RewardScales {
             // Maze/Pong use small dense shaping terms; scale up so typical per-step
             // rewards exceed the learning deadband and actually commit plasticity.
             maze: 5.0,
+            gridworld: 1.0,
             pong: 5.0,
             text: 1.0,
+            n_back: 1.0,
+            conditional_discrimination: 1.0,
+            temporal_xor: 1.0,
+            match_to_sample: 1.0,
             replay: 1.0,
         }
     }
@@ -881,8 +1422,13 @@ impl RewardScales {
             "spot_reversal" => self.spot_reversal,
             "spotxy" => self.spotxy,
             "maze" => self.maze,
+            "gridworld" => self.gridworld,
             "pong" => self.pong,
             "text" => self.text,
+            "n_back" => self.n_back,
+            "conditional_discrimination" => self.conditional_discrimination,
+            "temporal_xor" => self.temporal_xor,
+            "match_to_sample" => self.match_to_sample,
             "replay" => self.replay,
             // Any unknown/new games default to neutral scaling.
             _ => 1.0,
@@ -897,8 +1443,13 @@ impl RewardScales {
             "spot_reversal" => self.spot_reversal = v,
             "spotxy" => self.spotxy = v,
             "maze" => self.maze = v,
+            "gridworld" => self.gridworld = v,
             "pong" => self.pong = v,
             "text" => self.text = v,
+            "n_back" => self.n_back = v,
+            "conditional_discrimination" => self.conditional_discrimination = v,
+            "temporal_xor" => self.temporal_xor = v,
+            "match_to_sample" => self.match_to_sample = v,
             "replay" => self.replay = v,
             _ => {}
         }
@@ -966,6 +1517,29 @@ enum GameState {
         #[serde(default)]
         maze_event: String,
     },
+    #[serde(rename = "gridworld")]
+    GridWorld {
+        #[serde(flatten)]
+        common: GameCommon,
+        #[serde(default)]
+        gridworld_size: u32,
+        #[serde(default)]
+        gridworld_wall_density: f32,
+        #[serde(default)]
+        gridworld_player_x: u32,
+        #[serde(default)]
+        gridworld_player_y: u32,
+        #[serde(default)]
+        gridworld_goal_x: u32,
+        #[serde(default)]
+        gridworld_goal_y: u32,
+        #[serde(default)]
+        gridworld_steps: u32,
+        #[serde(default)]
+        gridworld_max_steps: u32,
+        #[serde(default)]
+        gridworld_event: String,
+    },
     #[serde(rename = "pong")]
     Pong {
         #[serde(flatten)]
@@ -1012,6 +1586,83 @@ enum GameState {
         text_vocab_size: u32,
     },
 
+    #[serde(rename = "n_back")]
+    NBack {
+        #[serde(flatten)]
+        common: GameCommon,
+        #[serde(default)]
+        n_back_n: u32,
+        #[serde(default)]
+        n_back_vocab_size: u32,
+        #[serde(default)]
+        n_back_token: String,
+        #[serde(default)]
+        n_back_hits: u32,
+        #[serde(default)]
+        n_back_misses: u32,
+        #[serde(default)]
+        n_back_false_alarms: u32,
+        #[serde(default)]
+        n_back_correct_rejections: u32,
+        #[serde(default)]
+        n_back_d_prime: f32,
+    },
+
+    #[serde(rename = "conditional_discrimination")]
+    ConditionalDiscrimination {
+        #[serde(flatten)]
+        common: GameCommon,
+        #[serde(default)]
+        cd_context: String,
+        #[serde(default)]
+        cd_stimulus: String,
+        #[serde(default)]
+        cd_switch_every: u32,
+        #[serde(default)]
+        cd_context_switches: u32,
+    },
+
+    #[serde(rename = "temporal_xor")]
+    TemporalXor {
+        #[serde(flatten)]
+        common: GameCommon,
+        #[serde(default)]
+        txor_stimulus: String,
+        #[serde(default)]
+        txor_prev_stimulus: String,
+        #[serde(default)]
+        txor_iti_steps: u32,
+        #[serde(default)]
+        txor_in_iti: bool,
+        #[serde(default)]
+        txor_d_prime: f32,
+    },
+
+    #[serde(rename = "match_to_sample")]
+    MatchToSample {
+        #[serde(flatten)]
+        common: GameCommon,
+        #[serde(default)]
+        dms_phase: String,
+        #[serde(default)]
+        dms_sample: String,
+        #[serde(default)]
+        dms_test: String,
+        #[serde(default)]
+        dms_sample_steps: u32,
+        #[serde(default)]
+        dms_delay_steps: u32,
+        #[serde(default)]
+        dms_test_steps: u32,
+        #[serde(default)]
+        dms_vocab_size: u32,
+        #[serde(default)]
+        dms_d_prime: f32,
+        /// Accuracy per delay length played so far (accuracy-vs-delay curve).
+        #[serde(default)]
+        dms_delay_curve: Vec<DelayAccuracy>,
+    },
+
     #[serde(rename = "replay")]
     Replay {
         #[serde(flatten)]
@@ -1027,6 +1678,12 @@ enum GameState {
     },
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MilestoneHit {
+    name: String,
+    trial: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct HudData {
     trials: u32,
@@ -1042,6 +1699,24 @@ struct HudData {
     learned_at_trial: i32,
     #[serde(default)]
     mastered_at_trial: i32,
+    /// Every milestone reached in this game, in order, including game-specific
+    /// ones (e.g. `first_reversal_recovered`).
+    #[serde(default)]
+    milestones: Vec<MilestoneHit>,
+    /// Accuracy-per-trial trend over the last `HUD_TREND_WINDOW` trials.
+    #[serde(default)]
+    learning_curve_slope: f32,
+    #[serde(default)]
+    plateau: bool,
+    /// Normalized score gap between the top two actions at the last exploit decision.
+    #[serde(default)]
+    action_confidence: f32,
+    /// Mean clamped amplitude per sensor group (`GetState { include_group_activities: true }`).
+    #[serde(default)]
+    sensor_activities: HashMap<String, f32>,
+    /// Mean clamped amplitude per action group (same gate).
+    #[serde(default)]
+    action_activities: HashMap<String, f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1051,8 +1726,16 @@ struct BrainStats {
     max_units_limit: usize,
     #[serde(default)]
     execution_tier: String,
+    /// Tier set by `BRAINE_EXEC_TIER`/`SetExecutionTier` or auto-selection.
+    #[serde(default)]
+    execution_tier_requested: String,
+    /// Same as `execution_tier_requested`; kept for older clients.
     #[serde(default)]
     execution_tier_selected: String,
+    /// Tiers usable in this build/machine, fastest first (probed at startup).
+    #[serde(default)]
+    execution_tier_available: Vec<String>,
+    /// Tier `step()` actually runs on.
     #[serde(default)]
     execution_tier_effective: String,
     connection_count: usize,
@@ -1067,6 +1750,12 @@ struct BrainStats {
     osc_y: f32,
     #[serde(default)]
     osc_mag: f32,
+    /// Fraction of (sensor unit, action unit) pairs within `phase_lock_threshold`.
+    #[serde(default)]
+    phase_locking_ratio: f32,
+    /// Recent `(osc_x, osc_y, osc_mag)` samples, oldest first.
+    #[serde(default)]
+    osc_trace: Vec<(f32, f32, f32)>,
     memory_bytes: usize,
     causal_base_symbols: usize,
     causal_edges: usize,
@@ -1093,12 +1782,51 @@ struct BrainStats {
     homeostasis_rate: f32,
     #[serde(default)]
     homeostasis_bias_l1: f32,
+    /// EMA of committed |Δw| per edge per step.
+    #[serde(default)]
+    plasticity_rate: f32,
+    /// `plasticity_rate` is below `PLASTICITY_CONVERGED_THRESHOLD` after at least
+    /// `PLASTICITY_CONVERGED_MIN_STEPS` learning steps (false on a fresh brain).
+    #[serde(default)]
+    learning_converged: bool,
+    /// EMA of |reward prediction error| (1.0 until TD rewards are used).
+    #[serde(default)]
+    td_prediction_error_ema: f32,
 
     // Manual gates (freeze/paralyze)
     #[serde(default)]
     frozen_units: u32,
     #[serde(default)]
     paralyzed_units: u32,
+
+    // Connectivity shape
+    /// `1 - connection_count / unit_count²`.
+    #[serde(default)]
+    sparsity: f32,
+    /// Out-degree quartiles over all units.
+    #[serde(default)]
+    degree_p25: u32,
+    #[serde(default)]
+    degree_p50: u32,
+    #[serde(default)]
+    degree_p75: u32,
+}
+
+/// Smallest degree `k` such that at least `q` of the units have degree `<= k`.
+fn degree_percentile(histogram: &[u32], q: f32) -> u32 {
+    let total: u64 = histogram.iter().map(|&c| c as u64).sum();
+    if total == 0 {
+        return 0;
+    }
+    let target = ((total as f64) * q.clamp(0.0, 1.0) as f64).ceil().max(1.0) as u64;
+    let mut seen = 0u64;
+    for (k, &c) in histogram.iter().enumerate() {
+        seen += c as u64;
+        if seen >= target {
+            return k as u32;
+        }
+    }
+    histogram.len().saturating_sub(1) as u32
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1116,21 +1844,44 @@ struct DaemonState {
     last_reward: f32,
     paths: AppPaths,
     exploration_eps: f32,
+    /// Confidence of the last exploit decision (see `HudData::action_confidence`).
+    action_confidence: f32,
+    /// Slow EMA of `action_confidence`; high values speed up exploration annealing.
+    action_confidence_ema: f32,
     meaning_alpha: f32,
+    /// Weight of the brain's novelty signal added to the learning reward.
+    intrinsic_reward_scale: f32,
     rng_state: u64,
     last_autosave_trial: u32,
     target_fps: u32,
     trial_period_ms: u32,
     pending_neuromod: f32,
 
+    /// `SetAutoDreamOnFlip`: dream when the SpotReversal rule flips, then imprint
+    /// the reversed mapping on the first rewarded trial after it.
+    auto_dream_on_flip: bool,
+    /// A flip was seen and its imprint has not run yet.
+    flip_imprint_pending: bool,
+
     reward_scales: RewardScales,
 
     max_units_limit: usize,
+    neurogenesis_policy: NeurogenesisPolicy,
+    /// `Brain::available_tiers()` at startup; cached to avoid re-probing the GPU.
+    available_tiers: Vec<braine::substrate::ExecutionTier>,
 
     loaded_snapshot_stem: Option<String>,
+    /// `--snapshot-keep`: snapshots `SaveSnapshot` keeps (0 = never evict).
+    snapshot_keep: usize,
 
     persist_state_version: u32,
 
+    /// Config for fresh brains (startup and `ResetBrain`).
+    initial_config: BrainConfig,
+
+    /// Set when the last brain save hit `BRAIN_FILE_MAX_BYTES`.
+    storage_capacity_overflow: AtomicBool,
+
     view_mode: BrainViewMode,
 
     meaning_last: MeaningSnapshot,
@@ -1138,6 +1889,30 @@ struct DaemonState {
     meaning_global_gap_history: Vec<f32>,
 
     advisor: advisor::AdvisorRuntime,
+
+    /// Frame numbers announced by the game loop to `WatchDiag` subscribers, which
+    /// gather diagnostics themselves for the frames on their stride.
+    diag_tx: broadcast::Sender<u64>,
+}
+
+/// Payload of a `Response::Diagnostics` (`DiagGet` and `WatchDiag`).
+#[derive(Debug, Clone)]
+struct DiagUpdate {
+    running: bool,
+    frame: u64,
+    brain_stats: BrainStats,
+    storage: StorageInfo,
+}
+
+impl From<DiagUpdate> for Response {
+    fn from(d: DiagUpdate) -> Self {
+        Response::Diagnostics {
+            running: d.running,
+            frame: d.frame,
+            brain_stats: d.brain_stats,
+            storage: d.storage,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1157,8 +1932,15 @@ impl BrainViewMode {
 }
 
 impl DaemonState {
-    fn new(paths: AppPaths) -> Self {
-        let mut brain = Brain::new(BrainConfig {
+    /// Substrate config used when no `--config` file is given.
+    ///
+    /// Starts from the server preset; swap in
+    /// `BrainConfig::with_defaults_for_embedded()` for a constrained host. The
+    /// daemon keeps its own size and dynamics tuning on top (the unit cap
+    /// defaults to 256), and pins the preset's causal window, fast weights and
+    /// growth policy to the values daemon brains have always used.
+    fn default_brain_config() -> BrainConfig {
+        BrainConfig {
             unit_count: 160,
             connectivity_per_unit: 8,
             dt: 0.05,
@@ -1174,8 +1956,15 @@ impl DaemonState {
             imprint_rate: 0.6,
             seed: Some(123),
             causal_decay: 0.01,
-            ..Default::default()
-        });
+            causal_window_frames: 4,
+            enable_fast_weights: false,
+            growth_policy_mode: 0,
+            ..BrainConfig::with_defaults_for_server()
+        }
+    }
+
+    fn new(paths: AppPaths, initial_config: BrainConfig) -> Self {
+        let mut brain = Brain::new(initial_config.clone());
 
         brain.define_sensor("spot_left", 4);
         brain.define_sensor("spot_right", 4);
@@ -1190,6 +1979,7 @@ impl DaemonState {
         // - If BRAINE_EXEC_TIER is set, honor it as a request and warn if unavailable.
         // - Otherwise, auto-select GPU (if compiled+available) with safe fallbacks.
         // Values: scalar|simd|parallel|gpu
+        let available_tiers = brain.available_tiers();
         if let Ok(v) = std::env::var("BRAINE_EXEC_TIER") {
             let vv = v.trim().to_ascii_lowercase();
             let requested = match vv.as_str() {
@@ -1208,14 +1998,14 @@ impl DaemonState {
                 let effective = brain.effective_execution_tier();
                 if effective != t {
                     warn!(
-                        "Requested execution tier {:?} but using {:?} (feature/runtime unavailable)",
+                        "Requested execution tier {} but using {} (feature/runtime unavailable)",
                         t, effective
                     );
                 }
             }
         } else {
             let selected = brain.auto_select_execution_tier();
-            tracing::info!("Auto-selected execution tier: {:?}", selected);
+            tracing::info!("Auto-selected execution tier: {}", selected);
         }
 
         Self {
@@ -1230,21 +2020,35 @@ impl DaemonState {
             paths,
             // Exploration controls *random action rate* (epsilon-greedy).
             exploration_eps: 0.2,
+            action_confidence: 0.0,
+            action_confidence_ema: 0.0,
             // Meaning weight in action selection; keep stable over time.
             meaning_alpha: 0.2,
+            // Curiosity bonus is opt-in.
+            intrinsic_reward_scale: 0.0,
             rng_state: 0x9E37_79B9_7F4A_7C15u64 ^ 123u64,
             last_autosave_trial: 0,
             target_fps: 60,
             trial_period_ms: 250,
             pending_neuromod: 0.0,
 
+            auto_dream_on_flip: false,
+            flip_imprint_pending: false,
+
             reward_scales: RewardScales::default(),
 
             max_units_limit: 256,
+            neurogenesis_policy: NeurogenesisPolicy::default(),
+            available_tiers,
 
             loaded_snapshot_stem: None,
+            snapshot_keep: SNAPSHOT_KEEP_DEFAULT,
+
+            persist_state_version: state_image::VERSION_V4,
 
-            persist_state_version: state_image::VERSION_V3,
+            initial_config,
+
+            storage_capacity_overflow: AtomicBool::new(false),
 
             meaning_last: MeaningSnapshot::default(),
             meaning_pair_gap_history: Vec::with_capacity(96),
@@ -1252,6 +2056,8 @@ impl DaemonState {
             view_mode: BrainViewMode::Parent,
 
             advisor: advisor::AdvisorRuntime::new_from_env(),
+
+            diag_tx: broadcast::channel(DIAG_BROADCAST_CAPACITY).0,
         }
     }
 
@@ -1289,25 +2095,26 @@ impl DaemonState {
         wrong_or_chosen_action: &str,
     ) -> MeaningSnapshot {
         let a = correct_action;
+        let ranked;
         let b = if !wrong_or_chosen_action.is_empty() && wrong_or_chosen_action != correct_action {
             wrong_or_chosen_action
         } else {
-            // Pick a second action deterministically.
-            // Prefer symmetric pairs for known action sets.
-            match correct_action {
-                "left" => "right",
-                "right" => "left",
-                "up" => "down",
-                "down" => "up",
-                "stay" => "up",
-                _ => self
-                    .game
-                    .allowed_actions()
-                    .iter()
-                    .find(|x| x.as_str() != correct_action)
-                    .map(|s| s.as_str())
-                    .unwrap_or("left"),
-            }
+            // Compare against the strongest competitor: the highest-meaning
+            // action for this stimulus that is not the correct one.
+            ranked = brain.pair_reward_edges_all_actions(stimulus);
+            let allowed = self.game.allowed_actions();
+            ranked
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .filter(|name| *name != correct_action)
+                .find(|name| allowed.is_empty() || allowed.iter().any(|x| x == name))
+                .or_else(|| {
+                    allowed
+                        .iter()
+                        .map(|s| s.as_str())
+                        .find(|x| *x != correct_action)
+                })
+                .unwrap_or("left")
         };
 
         let pair_a = brain.pair_reward_edges(stimulus, a);
@@ -1339,7 +2146,7 @@ impl DaemonState {
         }
     }
 
-    fn set_game(&mut self, game: &str) -> Result<(), String> {
+    fn set_game(&mut self, game: &str, reset_causality: bool) -> Result<(), String> {
         let g = game.trim().to_ascii_lowercase();
         match g.as_str() {
             "spot" => self.game = ActiveGame::Spot(SpotGame::new()),
@@ -1355,6 +2162,11 @@ impl DaemonState {
                 self.ensure_maze_io();
                 self.game = ActiveGame::Maze(MazeGame::new());
             }
+            "gridworld" | "grid_world" | "grid-world" => {
+                let gg = GridWorldGame::new();
+                self.ensure_gridworld_io(&gg);
+                self.game = ActiveGame::GridWorld(gg);
+            }
             "pong" => {
                 self.ensure_pong_io();
                 self.game = ActiveGame::Pong(PongGame::new());
@@ -1364,6 +2176,26 @@ impl DaemonState {
                 self.ensure_text_io(&gg);
                 self.game = ActiveGame::Text(gg);
             }
+            "n_back" | "nback" | "n-back" => {
+                let gg = NBackGame::default();
+                self.ensure_n_back_io(&gg);
+                self.game = ActiveGame::NBack(gg);
+            }
+            "conditional_discrimination" | "cond_disc" | "conditional-discrimination" => {
+                let gg = ConditionalDiscriminationGame::default();
+                self.ensure_conditional_discrimination_io(&gg);
+                self.game = ActiveGame::ConditionalDiscrimination(gg);
+            }
+            "temporal_xor" | "txor" | "temporal-xor" => {
+                let gg = TemporalXORGame::default();
+                self.ensure_temporal_xor_io(&gg);
+                self.game = ActiveGame::TemporalXor(gg);
+            }
+            "match_to_sample" | "dms" | "match-to-sample" => {
+                let gg = MatchToSampleGame::default();
+                self.ensure_match_to_sample_io(&gg);
+                self.game = ActiveGame::MatchToSample(gg);
+            }
             "replay" => {
                 let gg = ReplayGame::new(self.replay_dataset.clone());
                 self.ensure_replay_io();
@@ -1371,16 +2203,22 @@ impl DaemonState {
             }
             _ => {
                 return Err(format!(
-                "Unknown game '{game}'. Use spot|bandit|spot_reversal|spotxy|maze|pong|text|replay"
+                "Unknown game '{game}'. Use spot|bandit|spot_reversal|spotxy|maze|gridworld|pong|text|n_back|conditional_discrimination|temporal_xor|match_to_sample|replay"
             ))
             }
         }
 
+        if reset_causality {
+            self.reset_causality();
+        }
+
         // New task => reset meaning history so plots represent the current game.
         self.meaning_last = MeaningSnapshot::default();
         self.meaning_pair_gap_history.clear();
         self.meaning_global_gap_history.clear();
         self.last_reward = 0.0;
+        self.action_confidence = 0.0;
+        self.action_confidence_ema = 0.0;
         // Prevent autosave underflow if the new game's trial counter resets.
         self.last_autosave_trial = self.game.stats().trials;
         Ok(())
@@ -1389,10 +2227,8 @@ impl DaemonState {
     fn ensure_spotxy_io(&mut self) {
         let k = 16usize;
         for i in 0..k {
-            self.brain
-                .ensure_sensor_min_width(&format!("pos_x_{i:02}"), 3);
-            self.brain
-                .ensure_sensor_min_width(&format!("pos_y_{i:02}"), 3);
+            ensure_sensor_logged(&mut self.brain, &format!("pos_x_{i:02}"), 3);
+            ensure_sensor_logged(&mut self.brain, &format!("pos_y_{i:02}"), 3);
         }
         self.brain.ensure_action_min_width("left", 6);
         self.brain.ensure_action_min_width("right", 6);
@@ -1408,38 +2244,32 @@ impl DaemonState {
         // Bin sensors (must match PongGame constants).
         let bins = 8u32;
         for i in 0..bins {
-            self.brain
-                .ensure_sensor_min_width(&format!("pong_ball_x_{i:02}"), 3);
-            self.brain
-                .ensure_sensor_min_width(&format!("pong_ball_y_{i:02}"), 3);
-            self.brain
-                .ensure_sensor_min_width(&format!("pong_ball2_x_{i:02}"), 3);
-            self.brain
-                .ensure_sensor_min_width(&format!("pong_ball2_y_{i:02}"), 3);
-            self.brain
-                .ensure_sensor_min_width(&format!("pong_paddle_y_{i:02}"), 3);
-
-            self.brain
-                .ensure_sensor_min_width(&format!("pong_target_y_{i:02}"), 3);
-        }
-
-        self.brain.ensure_sensor_min_width("pong_target_na", 2);
-        self.brain.ensure_sensor_min_width("pong_evt_hit", 2);
-        self.brain.ensure_sensor_min_width("pong_evt_miss", 2);
-        self.brain.ensure_sensor_min_width("pong_ball_visible", 2);
-        self.brain.ensure_sensor_min_width("pong_ball_hidden", 2);
-        self.brain.ensure_sensor_min_width("pong_ball2_visible", 2);
-        self.brain.ensure_sensor_min_width("pong_ball2_hidden", 2);
-
-        self.brain.ensure_sensor_min_width("pong_vx_pos", 2);
-        self.brain.ensure_sensor_min_width("pong_vx_neg", 2);
-        self.brain.ensure_sensor_min_width("pong_vy_pos", 2);
-        self.brain.ensure_sensor_min_width("pong_vy_neg", 2);
-
-        self.brain.ensure_sensor_min_width("pong_ball2_vx_pos", 2);
-        self.brain.ensure_sensor_min_width("pong_ball2_vx_neg", 2);
-        self.brain.ensure_sensor_min_width("pong_ball2_vy_pos", 2);
-        self.brain.ensure_sensor_min_width("pong_ball2_vy_neg", 2);
+            ensure_sensor_logged(&mut self.brain, &format!("pong_ball_x_{i:02}"), 3);
+            ensure_sensor_logged(&mut self.brain, &format!("pong_ball_y_{i:02}"), 3);
+            ensure_sensor_logged(&mut self.brain, &format!("pong_ball2_x_{i:02}"), 3);
+            ensure_sensor_logged(&mut self.brain, &format!("pong_ball2_y_{i:02}"), 3);
+            ensure_sensor_logged(&mut self.brain, &format!("pong_paddle_y_{i:02}"), 3);
+
+            ensure_sensor_logged(&mut self.brain, &format!("pong_target_y_{i:02}"), 3);
+        }
+
+        ensure_sensor_logged(&mut self.brain, "pong_target_na", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_evt_hit", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_evt_miss", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_ball_visible", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_ball_hidden", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_ball2_visible", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_ball2_hidden", 2);
+
+        ensure_sensor_logged(&mut self.brain, "pong_vx_pos", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_vx_neg", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_vy_pos", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_vy_neg", 2);
+
+        ensure_sensor_logged(&mut self.brain, "pong_ball2_vx_pos", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_ball2_vx_neg", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_ball2_vy_pos", 2);
+        ensure_sensor_logged(&mut self.brain, "pong_ball2_vy_neg", 2);
 
         self.brain.ensure_action_min_width("up", 6);
         self.brain.ensure_action_min_width("down", 6);
@@ -1478,7 +2308,7 @@ impl DaemonState {
             "maze_visit_b1",
             "maze_visit_b2",
         ] {
-            self.brain.ensure_sensor_min_width(name, 2);
+            ensure_sensor_logged(&mut self.brain, name, 2);
         }
 
         for action in ["up", "right", "down", "left"] {
@@ -1486,17 +2316,67 @@ impl DaemonState {
         }
     }
 
+    fn ensure_gridworld_io(&mut self, g: &GridWorldGame) {
+        // Sensors used by `braine_games::gridworld::GridWorldGame::apply_stimuli`.
+        for name in g.x_sensor_names().iter().chain(g.y_sensor_names()) {
+            ensure_sensor_logged(&mut self.brain, name, 3);
+        }
+        ensure_sensor_logged(&mut self.brain, "gridworld_bump", 2);
+
+        for action in g.allowed_actions() {
+            self.brain.ensure_action_min_width(action, 6);
+        }
+    }
+
     fn ensure_text_io(&mut self, g: &TextNextTokenGame) {
-        self.brain.ensure_sensor_min_width("txt_regime_0", 3);
-        self.brain.ensure_sensor_min_width("txt_regime_1", 3);
+        ensure_sensor_logged(&mut self.brain, "txt_regime_0", 3);
+        ensure_sensor_logged(&mut self.brain, "txt_regime_1", 3);
         for name in g.token_sensor_names() {
-            self.brain.ensure_sensor_min_width(name, 3);
+            ensure_sensor_logged(&mut self.brain, name, 3);
         }
         for name in g.all_action_names() {
             self.brain.ensure_action_min_width(name, 6);
         }
     }
 
+    fn ensure_n_back_io(&mut self, g: &NBackGame) {
+        // Tokens share the `txt_tok_XX` sensors with the text task.
+        for name in g.token_sensor_names() {
+            ensure_sensor_logged(&mut self.brain, name, 3);
+        }
+        for name in g.allowed_actions() {
+            self.brain.ensure_action_min_width(name, 6);
+        }
+    }
+
+    fn ensure_conditional_discrimination_io(&mut self, g: &ConditionalDiscriminationGame) {
+        for name in ConditionalDiscriminationGame::sensor_names() {
+            ensure_sensor_logged(&mut self.brain, name, 3);
+        }
+        for name in g.allowed_actions() {
+            self.brain.ensure_action_min_width(name, 6);
+        }
+    }
+
+    fn ensure_temporal_xor_io(&mut self, g: &TemporalXORGame) {
+        for name in TemporalXORGame::sensor_names() {
+            ensure_sensor_logged(&mut self.brain, name, 3);
+        }
+        for name in g.allowed_actions() {
+            self.brain.ensure_action_min_width(name, 6);
+        }
+    }
+
+    fn ensure_match_to_sample_io(&mut self, g: &MatchToSampleGame) {
+        // Tokens share the `txt_tok_XX` sensors with the text task.
+        for name in g.token_sensor_names() {
+            ensure_sensor_logged(&mut self.brain, name, 3);
+        }
+        for name in g.allowed_actions() {
+            self.brain.ensure_action_min_width(name, 6);
+        }
+    }
+
     fn ensure_replay_io(&mut self) {
         use std::collections::BTreeSet;
 
@@ -1516,7 +2396,7 @@ impl DaemonState {
         }
 
         for name in sensors {
-            self.brain.ensure_sensor_min_width(&name, 3);
+            ensure_sensor_logged(&mut self.brain, &name, 3);
         }
         for name in actions {
             self.brain.ensure_action_min_width(&name, 6);
@@ -1583,6 +2463,31 @@ impl DaemonState {
             (false, 0usize)
         };
 
+        // Some tasks are meaning-heavy: the same action interface is reused across
+        // many contexts, so action selection needs enough meaning weight to break
+        // ties early.
+        let effective_meaning_alpha = match self.game {
+            ActiveGame::Text(_) | ActiveGame::Replay(_) => self.meaning_alpha.max(5.0),
+            ActiveGame::Maze(_) | ActiveGame::GridWorld(_) | ActiveGame::Pong(_) => {
+                self.meaning_alpha.max(5.0)
+            }
+            _ => self.meaning_alpha,
+        };
+
+        // Ensemble mode: rank actions by the experts' weighted vote. The vote needs
+        // every expert, so it runs before the controller is borrowed (members score
+        // their state from the previous tick). Falls back to the controller's own
+        // ranking when no expert is active for this context.
+        let mut ensemble_ranked: Option<Vec<(String, f32)>> =
+            if need_action && !explore && self.experts.ensemble_enabled() {
+                let v = self
+                    .experts
+                    .ensemble_vote(context_key, effective_meaning_alpha);
+                (!v.is_empty()).then_some(v)
+            } else {
+                None
+            };
+
         // Run the stimulus → step → action-select → reward/commit loop on the controller brain.
         let mut completed = false;
         let mut scored_reward: Option<f32> = None;
@@ -1594,6 +2499,8 @@ impl DaemonState {
             // selected/scored or when we apply delayed credit (e.g. Pong hit/miss) to an earlier
             // held action. Committing every tick dilutes reward associations and can stall learning.
             let mut should_commit_boundary: bool = false;
+            // Set when this tick's trial flipped the SpotReversal rule.
+            let mut reversal_flipped = false;
 
             // Choose controller brain (supports nested experts).
             let mut ctrl_opt: Option<experts::ControllerBorrow<'_>> = if self.experts.enabled() {
@@ -1666,6 +2573,10 @@ impl DaemonState {
                     g.apply_stimuli(brain);
                     brain.note_compound_symbol(&[stimulus_key]);
                 }
+                ActiveGame::GridWorld(g) => {
+                    g.apply_stimuli(brain);
+                    brain.note_compound_symbol(&[stimulus_key]);
+                }
                 ActiveGame::Pong(g) => {
                     g.apply_stimuli(brain);
                     brain.note_compound_symbol(&[stimulus_key]);
@@ -1678,6 +2589,38 @@ impl DaemonState {
                     }
                     brain.note_compound_symbol(&[stimulus_key]);
                 }
+                ActiveGame::NBack(g) => {
+                    if need_action {
+                        g.apply_stimuli(brain);
+                    } else {
+                        g.apply_stimuli_inference(brain);
+                    }
+                    brain.note_compound_symbol(&[stimulus_key]);
+                }
+                ActiveGame::ConditionalDiscrimination(g) => {
+                    if need_action {
+                        g.apply_stimuli(brain);
+                    } else {
+                        g.apply_stimuli_inference(brain);
+                    }
+                    brain.note_compound_symbol(&[stimulus_key]);
+                }
+                ActiveGame::TemporalXor(g) => {
+                    if need_action {
+                        g.apply_stimuli(brain);
+                    } else {
+                        g.apply_stimuli_inference(brain);
+                    }
+                    brain.note_compound_symbol(&[stimulus_key]);
+                }
+                ActiveGame::MatchToSample(g) => {
+                    if need_action {
+                        g.apply_stimuli(brain);
+                    } else {
+                        g.apply_stimuli_inference(brain);
+                    }
+                    brain.note_compound_symbol(&[stimulus_key]);
+                }
                 ActiveGame::Replay(g) => {
                     if need_action {
                         g.apply_stimuli(brain);
@@ -1741,20 +2684,13 @@ impl DaemonState {
                         allowed[rand_idx % allowed.len()].clone()
                     }
                 } else {
-                    // Some tasks are meaning-heavy: the same action interface is reused across
-                    // many contexts, so action selection needs enough meaning weight to break
-                    // ties early.
-                    let effective_meaning_alpha = match self.game {
-                        ActiveGame::Text(_) | ActiveGame::Replay(_) => self.meaning_alpha.max(5.0),
-                        ActiveGame::Maze(_) | ActiveGame::Pong(_) => self.meaning_alpha.max(5.0),
-                        _ => self.meaning_alpha,
-                    };
-
-                    let ranked =
-                        brain.ranked_actions_with_meaning(context_key, effective_meaning_alpha);
+                    let ranked = ensemble_ranked.take().unwrap_or_else(|| {
+                        brain.ranked_actions_with_meaning(context_key, effective_meaning_alpha)
+                    });
                     let allowed = self.game.allowed_actions();
 
                     let mut top1: Option<(String, f32)> = None;
+                    let mut top2: Option<f32> = None;
                     for (name, score) in ranked {
                         if !allowed.iter().any(|a| a == &name) {
                             continue;
@@ -1762,10 +2698,18 @@ impl DaemonState {
                         if top1.is_none() {
                             top1 = Some((name, score));
                         } else {
+                            top2 = Some(score);
                             break;
                         }
                     }
 
+                    if let Some((_, s1)) = top1 {
+                        let confidence = top2.map_or(1.0, |s2| action_confidence(s1, s2));
+                        self.action_confidence = confidence;
+                        self.action_confidence_ema +=
+                            ACTION_CONFIDENCE_EMA_ALPHA * (confidence - self.action_confidence_ema);
+                    }
+
                     let picked = top1
                         .as_ref()
                         .map(|(n, _s)| n.clone())
@@ -1776,6 +2720,7 @@ impl DaemonState {
                 };
 
                 // Score once per trial.
+                let was_reversed = self.game.reversal_active();
                 if let Some((reward, done)) = self
                     .game
                     .score_action(action_name.as_str(), self.trial_period_ms)
@@ -1787,12 +2732,18 @@ impl DaemonState {
 
                     // This tick is a trial decision boundary.
                     should_commit_boundary = true;
+                    reversal_flipped = !was_reversed && self.game.reversal_active();
 
                     if allow_learning {
+                        // Curiosity: blend the novelty signal into the learning reward only;
+                        // HUD/expert statistics keep tracking the external reward.
+                        let effective_reward = (reward
+                            + self.intrinsic_reward_scale * brain.intrinsic_reward_estimate())
+                        .clamp(-1.0, 1.0);
                         let learn_reward = if controller_is_expert {
-                            (reward * controller_scale).clamp(-1.0, 1.0)
+                            (effective_reward * controller_scale).clamp(-1.0, 1.0)
                         } else {
-                            reward
+                            effective_reward
                         };
 
                         brain.note_action(action_name.as_str());
@@ -1819,17 +2770,41 @@ impl DaemonState {
             // Commit or discard perception/action/reward symbols on the controller.
             // We discard non-boundary ticks so they don't flood causality/meaning memory.
             if allow_learning && should_commit_boundary {
-                brain.commit_observation();
+                let priority = commit_priority(self.last_reward, brain.intrinsic_reward_estimate());
+                brain.commit_observation_with_priority(priority);
             } else {
                 brain.discard_observation();
             }
+
+            if self.auto_dream_on_flip && allow_learning {
+                if reversal_flipped {
+                    brain.dream_replay_with_source(
+                        5,
+                        1.5,
+                        &braine::substrate::DreamSource::Internal,
+                    );
+                    self.flip_imprint_pending = true;
+                } else if self.flip_imprint_pending
+                    && should_commit_boundary
+                    && self.last_reward > 0.0
+                    && self.game.kind() == "spot_reversal"
+                {
+                    // The cue and the newly correct action are co-active on this rewarded trial.
+                    brain.imprint_current_context_biased(0.0, 0.0, FLIP_IMPRINT_PAIR_RATE);
+                    self.flip_imprint_pending = false;
+                }
+            }
         }
 
         // Experts may only spawn on explicit novelty/shift/collapse/saturation signals.
         // Evaluate only on trial completion and only when learning is enabled.
         if self.experts.enabled() && completed && allow_learning {
             if let Some(r) = scored_reward {
-                let trials = self.game.stats().trials;
+                let stats = self.game.stats();
+                let trials = stats.trials;
+                // Only a stall below the "learned" milestone warrants a new expert.
+                let plateaued = stats.plateau_detector(HUD_TREND_WINDOW, PLATEAU_SLOPE_THRESHOLD)
+                    && stats.last_100_rate() < 0.85;
                 self.experts.note_trial_for_spawn_target_under_path(
                     context_key,
                     &controller_path,
@@ -1841,6 +2816,7 @@ impl DaemonState {
                     &controller_path,
                     trials,
                     &self.brain,
+                    plateaued,
                 );
             }
         }
@@ -1895,7 +2871,14 @@ impl DaemonState {
                         last_reward: self.last_reward,
                         exploration_eps: self.exploration_eps,
                         meaning_alpha: self.meaning_alpha,
+                        td_prediction_error_ema: self
+                            .brain
+                            .learning_stats()
+                            .td_prediction_error_ema,
                         text_regime,
+                        causal_graph_json: None,
+                        action_meaning: Vec::new(),
+                        pair_meaning: Vec::new(),
                     };
 
                     // Auto-invocation always applies.
@@ -1904,8 +2887,14 @@ impl DaemonState {
                 }
             }
 
-            // Anneal exploration but keep a small floor for on-policy correction
-            self.exploration_eps = (self.exploration_eps * 0.99).max(0.02);
+            // Anneal exploration but keep a small floor for on-policy correction.
+            // Once exploit decisions are consistently confident, anneal faster.
+            let anneal = if self.action_confidence_ema > ACTION_CONFIDENCE_HIGH {
+                0.95
+            } else {
+                0.99
+            };
+            self.exploration_eps = (self.exploration_eps * anneal).max(0.02);
 
             if allow_learning {
                 // Automatic, bounded neurogenesis: add capacity if the network is saturating.
@@ -1916,14 +2905,17 @@ impl DaemonState {
                 // When experts are enabled, we avoid topology-changing growth until the merge
                 // story is more robust.
                 if !self.experts.enabled() {
-                    let _grown = self.brain.maybe_neurogenesis(0.35, 1, self.max_units_limit);
+                    let _grown = self.brain.maybe_neurogenesis_with_policy(
+                        &self.neurogenesis_policy,
+                        self.max_units_limit,
+                    );
                 }
 
                 // Auto-save frequently so short sessions still persist.
                 let trials = self.game.stats().trials;
                 let trials_since_save = trials.saturating_sub(self.last_autosave_trial);
                 if trials_since_save >= 10 {
-                    match self.save_brain() {
+                    match self.save_causal() {
                         Ok(_) => {
                             self.last_autosave_trial = trials;
                         }
@@ -1949,13 +2941,159 @@ impl DaemonState {
         }
     }
 
-    fn get_snapshot(&self) -> StateSnapshot {
+    /// Prometheus text exposition served by `--metrics-port` on `GET /metrics`.
+    fn prometheus_metrics(&self) -> String {
+        use std::fmt::Write as _;
+
+        let m = self.brain.metrics();
+        let stats = self.game.stats();
+        let game = self.game.kind();
+        let metric = |name: &str| m.get(name).copied().unwrap_or(0.0);
+
+        let mut out = String::new();
+        let mut emit = |name: &str, kind: &str, help: &str, labels: &str, value: f32| {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} {kind}");
+            let _ = writeln!(out, "{name}{labels} {value}");
+        };
+        let game_label = format!("{{game=\"{game}\"}}");
+        emit(
+            "braine_trials_total",
+            "counter",
+            "Trials completed in the active game.",
+            &game_label,
+            stats.trials as f32,
+        );
+        emit(
+            "braine_accuracy",
+            "gauge",
+            "Fraction of correct trials in the active game.",
+            &game_label,
+            stats.accuracy(),
+        );
+        emit(
+            "braine_unit_count",
+            "gauge",
+            "Units in the substrate.",
+            "",
+            metric("brain.unit_count"),
+        );
+        emit(
+            "braine_connection_count",
+            "gauge",
+            "Live connections in the substrate.",
+            "",
+            metric("brain.connection_count"),
+        );
+        emit(
+            "braine_plasticity_l1",
+            "gauge",
+            "Sum of absolute weight changes in the last learning step.",
+            "",
+            metric("learning.plasticity_l1"),
+        );
+        emit(
+            "braine_causal_edges",
+            "gauge",
+            "Edges in causal memory.",
+            "",
+            metric("causal.edge_count"),
+        );
+        emit(
+            "braine_experts_active",
+            "gauge",
+            "Active expert brains, including nested ones.",
+            "",
+            self.experts.total_active_count_recursive() as f32,
+        );
+        emit(
+            "braine_age_steps",
+            "counter",
+            "Substrate steps since the brain was created.",
+            "",
+            metric("brain.age_steps"),
+        );
+        out
+    }
+
+    fn diag_update(&self) -> DiagUpdate {
+        DiagUpdate {
+            running: self.running,
+            frame: self.frame,
+            brain_stats: self.brain_stats(),
+            storage: self.storage_info(),
+        }
+    }
+
+    /// Substrate statistics for the brain currently in view (`DiagGet`, `WatchDiag`).
+    fn brain_stats(&self) -> BrainStats {
+        let stimulus_key = self.current_stimulus_key();
+        let view_brain = self.view_brain_for_context(stimulus_key.as_ref());
+        let diag = view_brain.diagnostics();
+        let causal = view_brain.causal_stats();
+        let (osc_x, osc_y, osc_mag) = view_brain.oscillation_sample(512);
+        let selected = view_brain.execution_tier();
+        let effective = view_brain.effective_execution_tier();
+        let learning = view_brain.learning_stats();
+        let cfg = view_brain.config();
+        let (frozen_units, paralyzed_units) = view_brain.gate_counts();
+        let degrees = view_brain.unit_degree_histogram();
+
+        BrainStats {
+            unit_count: diag.unit_count,
+            max_units_limit: self.max_units_limit,
+            execution_tier: effective.to_string(),
+            execution_tier_requested: selected.to_string(),
+            execution_tier_selected: selected.to_string(),
+            execution_tier_available: self.available_tiers.iter().map(|t| t.to_string()).collect(),
+            execution_tier_effective: effective.to_string(),
+            connection_count: diag.connection_count,
+            pruned_last_step: diag.pruned_last_step,
+            births_last_step: diag.births_last_step,
+            saturated: view_brain.is_saturated(0.35),
+            avg_amp: diag.avg_amp,
+            avg_weight: diag.avg_weight,
+            osc_x,
+            osc_y,
+            osc_mag,
+            phase_locking_ratio: view_brain.phase_locking_ratio(),
+            osc_trace: view_brain.oscillation_sample_windowed(OSC_TRACE_STEPS, OSC_TRACE_STEPS),
+            memory_bytes: diag.memory_bytes,
+            causal_base_symbols: causal.base_symbols,
+            causal_edges: causal.edges,
+            causal_last_directed_edge_updates: causal.last_directed_edge_updates,
+            causal_last_cooccur_edge_updates: causal.last_cooccur_edge_updates,
+            age_steps: view_brain.age_steps(),
+
+            plasticity_committed: learning.plasticity_committed,
+            plasticity_l1: learning.plasticity_l1,
+            plasticity_edges: learning.plasticity_edges,
+            plasticity_budget: learning.plasticity_budget,
+            plasticity_budget_used: learning.plasticity_budget_used,
+            eligibility_l1: learning.eligibility_l1,
+            learning_deadband: cfg.learning_deadband,
+            homeostasis_rate: cfg.homeostasis_rate,
+            homeostasis_bias_l1: learning.homeostasis_bias_l1,
+            plasticity_rate: learning.plasticity_rate,
+            learning_converged: learning.plasticity_rate_steps >= PLASTICITY_CONVERGED_MIN_STEPS
+                && learning.plasticity_rate < PLASTICITY_CONVERGED_THRESHOLD,
+            td_prediction_error_ema: learning.td_prediction_error_ema,
+
+            frozen_units,
+            paralyzed_units,
+
+            sparsity: view_brain.connection_sparsity(),
+            degree_p25: degree_percentile(&degrees, 0.25),
+            degree_p50: degree_percentile(&degrees, 0.50),
+            degree_p75: degree_percentile(&degrees, 0.75),
+        }
+    }
+
+    fn get_snapshot(&self, include_group_activities: bool) -> StateSnapshot {
         let stimulus_key = self.current_stimulus_key();
         let stimulus = stimulus_key.as_ref();
 
         let view_brain = self.view_brain_for_context(stimulus);
-        let diag = view_brain.diagnostics();
-        let causal = view_brain.causal_stats();
         let active_expert = if self.experts.enabled() {
             self.experts.active_expert_summary(stimulus)
         } else {
@@ -2008,6 +3146,18 @@ impl DaemonState {
                 maze_steps: g.steps_in_episode,
                 maze_event: g.last_event.as_str().to_string(),
             },
+            ActiveGame::GridWorld(g) => GameState::GridWorld {
+                common: common(),
+                gridworld_size: g.grid_size(),
+                gridworld_wall_density: g.wall_density(),
+                gridworld_player_x: g.player_x,
+                gridworld_player_y: g.player_y,
+                gridworld_goal_x: g.goal_x,
+                gridworld_goal_y: g.goal_y,
+                gridworld_steps: g.steps_in_episode,
+                gridworld_max_steps: g.max_steps_per_episode(),
+                gridworld_event: g.last_event.as_str().to_string(),
+            },
             ActiveGame::Pong(g) => GameState::Pong {
                 common: common(),
                 pong_ball_x: g.sim.state.ball_x,
@@ -2033,6 +3183,52 @@ impl DaemonState {
                 text_shift_every: g.shift_every_outcomes(),
                 text_vocab_size: g.vocab_size() as u32,
             },
+            ActiveGame::NBack(g) => GameState::NBack {
+                common: common(),
+                n_back_n: g.n() as u32,
+                n_back_vocab_size: g.vocab_size() as u32,
+                n_back_token: g.current_token().display(),
+                n_back_hits: g.counts.hits,
+                n_back_misses: g.counts.misses,
+                n_back_false_alarms: g.counts.false_alarms,
+                n_back_correct_rejections: g.counts.correct_rejections,
+                n_back_d_prime: g.d_prime(),
+            },
+            ActiveGame::ConditionalDiscrimination(g) => GameState::ConditionalDiscrimination {
+                common: common(),
+                cd_context: g.context_name().to_string(),
+                cd_stimulus: g.cue_name().to_string(),
+                cd_switch_every: g.switch_every_trials,
+                cd_context_switches: g.context_switches,
+            },
+            ActiveGame::TemporalXor(g) => GameState::TemporalXor {
+                common: common(),
+                txor_stimulus: if g.in_iti() {
+                    String::new()
+                } else {
+                    g.cue_name().to_string()
+                },
+                txor_prev_stimulus: match g.prev_is_a {
+                    Some(true) => "stim_A".to_string(),
+                    Some(false) => "stim_B".to_string(),
+                    None => String::new(),
+                },
+                txor_iti_steps: g.iti_steps,
+                txor_in_iti: g.in_iti(),
+                txor_d_prime: g.d_prime(),
+            },
+            ActiveGame::MatchToSample(g) => GameState::MatchToSample {
+                common: common(),
+                dms_phase: g.phase().name().to_string(),
+                dms_sample: g.sample_token().display(),
+                dms_test: g.test_token().display(),
+                dms_sample_steps: g.sample_steps(),
+                dms_delay_steps: g.delay_steps(),
+                dms_test_steps: g.test_steps(),
+                dms_vocab_size: g.vocab_size() as u32,
+                dms_d_prime: g.d_prime(),
+                dms_delay_curve: g.delay_curve(),
+            },
             ActiveGame::Replay(g) => GameState::Replay {
                 common: common(),
                 replay_dataset: g.dataset_name().to_string(),
@@ -2042,8 +3238,6 @@ impl DaemonState {
             },
         };
 
-        let (osc_x, osc_y, osc_mag) = view_brain.oscillation_sample(512);
-
         StateSnapshot {
             running: self.running,
             mode: "braine".to_string(),
@@ -2062,68 +3256,36 @@ impl DaemonState {
                 learning_at_trial: stats.learning_at_trial.map(|v| v as i32).unwrap_or(-1),
                 learned_at_trial: stats.learned_at_trial.map(|v| v as i32).unwrap_or(-1),
                 mastered_at_trial: stats.mastered_at_trial.map(|v| v as i32).unwrap_or(-1),
+                milestones: stats
+                    .learning_milestone_detector()
+                    .reached()
+                    .iter()
+                    .map(|(name, trial)| MilestoneHit {
+                        name: name.clone(),
+                        trial: *trial,
+                    })
+                    .collect(),
+                learning_curve_slope: stats.learning_curve_slope(HUD_TREND_WINDOW),
+                plateau: stats.plateau_detector(HUD_TREND_WINDOW, PLATEAU_SLOPE_THRESHOLD),
+                action_confidence: self.action_confidence,
+                sensor_activities: if include_group_activities {
+                    view_brain
+                        .sensor_group_names()
+                        .map(|name| (name.to_string(), view_brain.get_sensor_group_activity(name)))
+                        .collect()
+                } else {
+                    HashMap::new()
+                },
+                action_activities: if include_group_activities {
+                    view_brain
+                        .action_group_names()
+                        .map(|name| (name.to_string(), view_brain.get_action_group_activity(name)))
+                        .collect()
+                } else {
+                    HashMap::new()
+                },
             },
-            brain_stats: {
-                let selected = view_brain.execution_tier();
-                let effective = view_brain.effective_execution_tier();
-                let learning = view_brain.learning_stats();
-                let cfg = view_brain.config();
-                let (frozen_units, paralyzed_units) = view_brain.gate_counts();
-
-                BrainStats {
-                    unit_count: diag.unit_count,
-                    max_units_limit: self.max_units_limit,
-                    execution_tier: match effective {
-                        braine::substrate::ExecutionTier::Scalar => "Scalar",
-                        braine::substrate::ExecutionTier::Simd => "SIMD",
-                        braine::substrate::ExecutionTier::Parallel => "Parallel",
-                        braine::substrate::ExecutionTier::Gpu => "GPU",
-                    }
-                    .to_string(),
-                    execution_tier_selected: match selected {
-                        braine::substrate::ExecutionTier::Scalar => "Scalar",
-                        braine::substrate::ExecutionTier::Simd => "SIMD",
-                        braine::substrate::ExecutionTier::Parallel => "Parallel",
-                        braine::substrate::ExecutionTier::Gpu => "GPU",
-                    }
-                    .to_string(),
-                    execution_tier_effective: match effective {
-                        braine::substrate::ExecutionTier::Scalar => "Scalar",
-                        braine::substrate::ExecutionTier::Simd => "SIMD",
-                        braine::substrate::ExecutionTier::Parallel => "Parallel",
-                        braine::substrate::ExecutionTier::Gpu => "GPU",
-                    }
-                    .to_string(),
-                    connection_count: diag.connection_count,
-                    pruned_last_step: diag.pruned_last_step,
-                    births_last_step: diag.births_last_step,
-                    saturated: view_brain.should_grow(0.35),
-                    avg_amp: diag.avg_amp,
-                    avg_weight: diag.avg_weight,
-                    osc_x,
-                    osc_y,
-                    osc_mag,
-                    memory_bytes: diag.memory_bytes,
-                    causal_base_symbols: causal.base_symbols,
-                    causal_edges: causal.edges,
-                    causal_last_directed_edge_updates: causal.last_directed_edge_updates,
-                    causal_last_cooccur_edge_updates: causal.last_cooccur_edge_updates,
-                    age_steps: view_brain.age_steps(),
-
-                    plasticity_committed: learning.plasticity_committed,
-                    plasticity_l1: learning.plasticity_l1,
-                    plasticity_edges: learning.plasticity_edges,
-                    plasticity_budget: learning.plasticity_budget,
-                    plasticity_budget_used: learning.plasticity_budget_used,
-                    eligibility_l1: learning.eligibility_l1,
-                    learning_deadband: cfg.learning_deadband,
-                    homeostasis_rate: cfg.homeostasis_rate,
-                    homeostasis_bias_l1: learning.homeostasis_bias_l1,
-
-                    frozen_units,
-                    paralyzed_units,
-                }
-            },
+            brain_stats: self.brain_stats(),
             unit_plot: view_brain.unit_plot_points(128),
             action_scores: view_brain.action_score_breakdown(stimulus, self.meaning_alpha),
             meaning: {
@@ -2168,153 +3330,78 @@ impl DaemonState {
             Ok(f) => f,
             Err(_) => return 0,
         };
-        let mut magic = [0u8; 8];
-        if file.read_exact(&mut magic).is_err() {
-            return 0;
-        }
-        if !state_image::is_state_magic(&magic) {
-            return 0;
-        }
-        storage::read_u32_le(&mut file).unwrap_or_default()
-    }
-
-    fn snapshots_dir(&self) -> PathBuf {
-        self.paths.data_dir().join("snapshots")
+        state_image::peek_state_version(&mut file)
     }
 
-    fn brain_snapshot_path(dir: &Path, stem: &str) -> PathBuf {
-        dir.join(format!("brain_{stem}.bbi"))
-    }
-
-    fn runtime_snapshot_path(dir: &Path, stem: &str) -> PathBuf {
-        dir.join(format!("runtime_{stem}.json"))
+    fn snapshot_ring(&self) -> storage::SnapshotRing {
+        let keep = if self.snapshot_keep > 0 {
+            self.snapshot_keep
+        } else {
+            usize::MAX
+        };
+        storage::SnapshotRing::new(self.paths.data_dir().join("snapshots"), keep)
     }
 
     fn file_size_bytes(path: &Path) -> u64 {
         std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
     }
 
-    fn list_snapshots(&self, limit: usize) -> Vec<SnapshotEntry> {
-        let dir = self.snapshots_dir();
-        let rd = match std::fs::read_dir(&dir) {
-            Ok(rd) => rd,
-            Err(_) => return Vec::new(),
-        };
-
-        // Collect stems by scanning brain_*.bbi files.
-        let mut stems: Vec<(String, u64)> = Vec::new();
-        for ent in rd.flatten() {
-            let path = ent.path();
-            if path.extension().and_then(|s| s.to_str()) != Some("bbi") {
-                continue;
-            }
-            let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
-            let Some(stem) = file_name
-                .strip_prefix("brain_")
-                .and_then(|s| s.strip_suffix(".bbi"))
-            else {
-                continue;
-            };
-
-            let modified_unix = std::fs::metadata(&path)
-                .ok()
-                .and_then(|m| m.modified().ok())
-                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-                .map(|d| d.as_secs())
-                .unwrap_or(0);
-
-            stems.push((stem.to_string(), modified_unix));
-        }
-
-        // Newest first.
-        stems.sort_by(|a, b| b.1.cmp(&a.1));
-        stems.truncate(limit);
+    fn storage_info(&self) -> StorageInfo {
+        let (mut info, ring) = self.storage_info_unprobed();
+        Self::probe_storage(&mut info, &ring);
+        info
+    }
 
-        stems
-            .into_iter()
-            .map(|(stem, modified_unix)| {
-                let b = Self::brain_snapshot_path(&dir, &stem);
-                let r = Self::runtime_snapshot_path(&dir, &stem);
-                SnapshotEntry {
-                    stem,
-                    brain_bytes: Self::file_size_bytes(&b),
-                    runtime_bytes: Self::file_size_bytes(&r),
-                    modified_unix,
-                }
-            })
-            .collect()
+    /// Storage paths and flags only; the fields that need the filesystem are left
+    /// for `probe_storage`, so callers can fill them in after releasing the lock.
+    fn storage_info_unprobed(&self) -> (StorageInfo, storage::SnapshotRing) {
+        let info = StorageInfo {
+            data_dir: self.paths.data_dir().to_string_lossy().to_string(),
+            brain_file: self.paths.brain_file().to_string_lossy().to_string(),
+            runtime_file: self
+                .paths
+                .runtime_state_file()
+                .to_string_lossy()
+                .to_string(),
+            loaded_snapshot: self.loaded_snapshot_stem.clone().unwrap_or_default(),
+            storage_capacity_overflow: self.storage_capacity_overflow.load(Ordering::Relaxed),
+            ..StorageInfo::default()
+        };
+        (info, self.snapshot_ring())
     }
 
-    fn storage_info(&self) -> StorageInfo {
-        let data_dir = self.paths.data_dir().to_string_lossy().to_string();
-        let brain_file = self.paths.brain_file().to_string_lossy().to_string();
-        let runtime_file = self
-            .paths
-            .runtime_state_file()
-            .to_string_lossy()
-            .to_string();
-        let loaded_snapshot = self.loaded_snapshot_stem.clone().unwrap_or_default();
-        let state_wrapper_version = Self::detect_state_wrapper_version(Path::new(&brain_file));
-        StorageInfo {
-            data_dir,
-            brain_file: brain_file.clone(),
-            runtime_file: runtime_file.clone(),
-            loaded_snapshot,
-            brain_bytes: Self::file_size_bytes(Path::new(&brain_file)),
-            runtime_bytes: Self::file_size_bytes(Path::new(&runtime_file)),
-            state_wrapper_version,
-            snapshots: self.list_snapshots(24),
-        }
+    /// Fill in file sizes, the state wrapper version and the snapshot list.
+    fn probe_storage(info: &mut StorageInfo, ring: &storage::SnapshotRing) {
+        let brain_file = Path::new(&info.brain_file);
+        info.state_wrapper_version = Self::detect_state_wrapper_version(brain_file);
+        info.brain_bytes = Self::file_size_bytes(brain_file);
+        info.runtime_bytes = Self::file_size_bytes(Path::new(&info.runtime_file));
+        info.snapshots = ring.list();
     }
 
     fn save_snapshot(&self) -> Result<String, String> {
-        // Ensure the canonical files are current, then copy them into snapshots/.
+        // Ensure the canonical files are current, then copy them into the ring.
         self.save_brain()?;
 
-        let dir = self.snapshots_dir();
-        std::fs::create_dir_all(&dir)
-            .map_err(|e| format!("Failed to create snapshots dir {:?}: {e}", dir))?;
-
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map_err(|e| format!("System time error: {e}"))?;
-        let stem = format!("{}_{}", now.as_secs(), now.subsec_millis());
-
         let src_brain = self.paths.brain_file();
         let src_rt = self.paths.runtime_state_file();
+        let runtime = src_rt.exists().then_some(src_rt.as_path());
 
-        let dst_brain = Self::brain_snapshot_path(&dir, &stem);
-        let dst_rt = Self::runtime_snapshot_path(&dir, &stem);
-
-        std::fs::copy(&src_brain, &dst_brain).map_err(|e| {
-            format!(
-                "Failed to copy brain snapshot {:?} -> {:?}: {e}",
-                src_brain, dst_brain
-            )
-        })?;
-
-        if src_rt.exists() {
-            std::fs::copy(&src_rt, &dst_rt).map_err(|e| {
-                format!(
-                    "Failed to copy runtime snapshot {:?} -> {:?}: {e}",
-                    src_rt, dst_rt
-                )
-            })?;
-        }
-
-        Ok(stem)
+        let ring = self.snapshot_ring();
+        ring.push_file(&src_brain, runtime)
+            .map_err(|e| format!("Failed to write snapshot in {:?}: {e}", ring.dir()))
     }
 
     fn load_snapshot(&mut self, stem: &str) -> Result<(), String> {
-        let dir = self.snapshots_dir();
-        let src_brain = Self::brain_snapshot_path(&dir, stem);
+        let ring = self.snapshot_ring();
+        let src_brain = ring.brain_path(stem);
         if !src_brain.exists() {
             return Err(format!("Snapshot not found: {}", stem));
         }
-        let src_rt = Self::runtime_snapshot_path(&dir, stem);
+        let src_rt = ring.runtime_path(stem);
 
         // Copy snapshot files into the canonical locations, then load.
-        std::fs::copy(&src_brain, self.paths.brain_file()).map_err(|e| {
+        storage::copy_file_atomic(&src_brain, self.paths.brain_file()).map_err(|e| {
             format!(
                 "Failed to restore snapshot brain {:?} -> {:?}: {e}",
                 src_brain,
@@ -2323,7 +3410,7 @@ impl DaemonState {
         })?;
 
         if src_rt.exists() {
-            std::fs::copy(&src_rt, self.paths.runtime_state_file()).map_err(|e| {
+            storage::copy_file_atomic(&src_rt, self.paths.runtime_state_file()).map_err(|e| {
                 format!(
                     "Failed to restore snapshot runtime {:?} -> {:?}: {e}",
                     src_rt,
@@ -2332,6 +3419,9 @@ impl DaemonState {
             })?;
         }
 
+        // A causal checkpoint belongs to the replaced brain, not the snapshot.
+        let _ = std::fs::remove_file(self.paths.causal_file());
+
         self.load_brain()?;
         self.loaded_snapshot_stem = Some(stem.to_string());
         Ok(())
@@ -2343,6 +3433,7 @@ impl DaemonState {
         max_nodes: usize,
         max_edges: usize,
         include_isolated: bool,
+        group_pair: Option<(&str, &str)>,
     ) -> GraphSnapshot {
         let stimulus_key = self.current_stimulus_key();
         let context_key = stimulus_key.as_ref();
@@ -2357,6 +3448,7 @@ impl DaemonState {
                 max_nodes,
                 max_edges,
                 include_isolated,
+                group_pair,
             ),
         }
     }
@@ -2367,6 +3459,7 @@ impl DaemonState {
         max_nodes: usize,
         max_edges: usize,
         include_isolated: bool,
+        group_pair: Option<(&str, &str)>,
     ) -> GraphSnapshot {
         use std::collections::{HashMap, HashSet};
 
@@ -2379,9 +3472,11 @@ impl DaemonState {
                 edges: Vec::new(),
             };
         }
+        let groups = unit_group_labels(brain);
 
         // Build a connected view deterministically:
-        // 1) collect strong candidate edges across all units (no node sampling)
+        // 1) collect strong candidate edges across all units (no node sampling),
+        //    or every edge between the requested groups
         // 2) keep a pool of strongest edges
         // 3) pick up to max_nodes endpoints by incident strength
         // 4) filter to edges within kept nodes, then truncate to max_edges
@@ -2395,25 +3490,31 @@ impl DaemonState {
 
         let per_node_top = 8usize;
         let mut candidates: Vec<GraphEdge> = Vec::new();
-        for from in 0..n {
-            let mut local: Vec<(u32, f32)> = Vec::new();
-            for (to, w) in brain.neighbors(from) {
-                if to == from {
-                    continue;
+        if let Some((from_group, to_group)) = group_pair {
+            candidates.extend(
+                brain
+                    .connections_by_group_pair(from_group, to_group)
+                    .into_iter()
+                    .filter(|&(from, to, _)| from != to)
+                    .map(|(from, to, w)| GraphEdge {
+                        from: from as u32,
+                        to: to as u32,
+                        weight: w,
+                    }),
+            );
+        } else {
+            for from in 0..n {
+                let strongest = brain
+                    .neighbors_sorted(from)
+                    .filter(|&(to, _)| to != from)
+                    .take(per_node_top);
+                for (to, w) in strongest {
+                    candidates.push(GraphEdge {
+                        from: from as u32,
+                        to: to as u32,
+                        weight: w,
+                    });
                 }
-                local.push((to as u32, w));
-            }
-
-            local.sort_by(|(to_a, w_a), (to_b, w_b)| {
-                w_b.abs().total_cmp(&w_a.abs()).then_with(|| to_a.cmp(to_b))
-            });
-
-            for (to, w) in local.into_iter().take(per_node_top) {
-                candidates.push(GraphEdge {
-                    from: from as u32,
-                    to,
-                    weight: w,
-                });
             }
         }
 
@@ -2456,6 +3557,7 @@ impl DaemonState {
                     id,
                     label: format!("u{}", id),
                     value: amp,
+                    group: groups.get(&id).cloned(),
                 })
                 .collect();
             nodes.sort_by_key(|n| n.id);
@@ -2519,6 +3621,7 @@ impl DaemonState {
                     id,
                     label: format!("u{}", id),
                     value: amp,
+                    group: groups.get(&id).cloned(),
                 }
             })
             .collect();
@@ -2610,6 +3713,7 @@ impl DaemonState {
                 id,
                 label: label.clone(),
                 value: 0.0,
+                group: None,
             })
             .collect();
         nodes.sort_by_key(|n| n.id);
@@ -2645,6 +3749,65 @@ impl DaemonState {
         }
     }
 
+    /// Breadth-first walk of the parent brain's causal memory for `GetCausalGraph`.
+    fn get_causal_graph(
+        &self,
+        context_key: Option<&str>,
+        max_depth: u32,
+        min_weight: f32,
+    ) -> (Vec<CausalNode>, Vec<CausalEdge>) {
+        use std::collections::VecDeque;
+
+        let brain = &self.brain;
+        let root =
+            context_key.map_or_else(|| self.current_stimulus_key().into_owned(), str::to_string);
+
+        let mut depth_of: HashMap<String, u32> = HashMap::new();
+        depth_of.insert(root.clone(), 0);
+        let mut order = vec![root.clone()];
+        let mut queue = VecDeque::from([root]);
+        let mut edges: Vec<CausalEdge> = Vec::new();
+
+        'walk: while let Some(from) = queue.pop_front() {
+            let depth = depth_of[&from];
+            if depth >= max_depth {
+                continue;
+            }
+            for (to, directed, cooccur) in brain.causal_edges_from(&from) {
+                if directed.max(cooccur) < min_weight {
+                    continue;
+                }
+                if !depth_of.contains_key(&to) {
+                    if depth_of.len() >= CAUSAL_GRAPH_MAX_NODES {
+                        continue;
+                    }
+                    depth_of.insert(to.clone(), depth + 1);
+                    order.push(to.clone());
+                    queue.push_back(to.clone());
+                }
+                edges.push(CausalEdge {
+                    from_symbol: from.clone(),
+                    to_symbol: to,
+                    directed_weight: directed,
+                    cooccur_weight: cooccur,
+                });
+                if edges.len() >= CAUSAL_GRAPH_MAX_EDGES {
+                    break 'walk;
+                }
+            }
+        }
+
+        let nodes = order
+            .into_iter()
+            .map(|symbol| CausalNode {
+                symbol_type: causal_symbol_type(brain, &symbol).to_string(),
+                activation_count: brain.causal_symbol_count(&symbol).round() as u32,
+                symbol,
+            })
+            .collect();
+        (nodes, edges)
+    }
+
     fn save_brain(&self) -> Result<(), String> {
         let path = self.paths.brain_file();
         info!("Saving brain (braine.bbi)...");
@@ -2669,6 +3832,7 @@ impl DaemonState {
             learning_at_trial: stats.learning_at_trial,
             learned_at_trial: stats.learned_at_trial,
             mastered_at_trial: stats.mastered_at_trial,
+            milestones: stats.learning_milestone_detector().reached().to_vec(),
         };
         let runtime = PersistedRuntime {
             game_kind: cur_kind.clone(),
@@ -2688,7 +3852,7 @@ impl DaemonState {
         let json = serde_json::to_vec_pretty(&runtime)
             .map_err(|e| format!("Failed to encode runtime state: {e}"))?;
 
-        let mut file = File::create(&path).map_err(|e| {
+        let file = storage::AtomicWrite::new(&path).map_err(|e| {
             let msg = format!(
                 "Failed to create file at {:?}: {} (errno: {})",
                 path,
@@ -2704,28 +3868,75 @@ impl DaemonState {
             .save_state_bytes()
             .map_err(|e| format!("Failed to serialize experts state: {e}"))?;
 
-        state_image::save_state_to_with_version(
-            &mut file,
+        let mut cw = storage::CapacityWriter::new(file, BRAIN_FILE_MAX_BYTES);
+        let saved = state_image::save_state_to_with_version(
+            &mut cw,
             &self.brain,
             &experts_state,
             Some(&json),
             self.persist_state_version,
-        )
-        .map_err(|e| {
+        );
+        let overflow = cw.overflow_attempted();
+        self.storage_capacity_overflow
+            .store(overflow, Ordering::Relaxed);
+        if overflow {
+            warn!(
+                "Brain image exceeded {} bytes after {} bytes written; keeping the previous image",
+                BRAIN_FILE_MAX_BYTES,
+                cw.bytes_written()
+            );
+        }
+        saved.map_err(|e| {
             let msg = format!("Failed to serialize daemon state: {}", e);
             error!("{}", msg);
             msg
         })?;
+        cw.into_inner().commit().map_err(|e| {
+            let msg = format!("Failed to commit brain file {:?}: {e}", path);
+            error!("{}", msg);
+            msg
+        })?;
 
-        let mut rt = File::create(&rt_path)
-            .map_err(|e| format!("Failed to create runtime state file {:?}: {e}", rt_path))?;
-        rt.write_all(&json)
+        storage::write_file_atomic(&rt_path, &json)
             .map_err(|e| format!("Failed to write runtime state file {:?}: {e}", rt_path))?;
 
+        // The full image supersedes any causal checkpoint taken since the last save.
+        let causal_path = self.paths.causal_file();
+        if causal_path.exists() {
+            if let Err(e) = std::fs::remove_file(&causal_path) {
+                warn!("Failed to remove stale {:?}: {e}", causal_path);
+            }
+        }
+
         info!("✓ Brain saved successfully (braine.bbi)");
         Ok(())
     }
 
+    /// Checkpoint only the parent brain's causal memory (causal.bcs).
+    ///
+    /// Used by the trial autosave; weights are persisted by full saves.
+    fn save_causal(&self) -> Result<(), String> {
+        let path = self.paths.causal_file();
+        let mut file = storage::AtomicWrite::new(&path)
+            .map_err(|e| format!("Failed to create causal file {:?}: {e}", path))?;
+        self.brain
+            .save_causal_only(&mut file)
+            .and_then(|()| file.commit())
+            .map_err(|e| format!("Failed to write causal file {:?}: {e}", path))
+    }
+
+    /// Apply the causal checkpoint taken since the last full save, if any.
+    fn load_causal_checkpoint(&mut self) {
+        let path = self.paths.causal_file();
+        let Ok(mut file) = File::open(&path) else {
+            return;
+        };
+        match self.brain.load_causal_only_from(&mut file) {
+            Ok(()) => info!("Causal memory restored from {:?}", path),
+            Err(e) => warn!("Ignoring causal checkpoint {:?}: {e}", path),
+        }
+    }
+
     fn load_brain(&mut self) -> Result<(), String> {
         self.loaded_snapshot_stem = None;
         let path = self.paths.brain_file();
@@ -2734,52 +3945,61 @@ impl DaemonState {
         }
 
         let mut file = File::open(&path).map_err(|e| format!("Failed to open file: {}", e))?;
-        let mut magic = [0u8; 8];
-        file.read_exact(&mut magic)
-            .map_err(|e| format!("Failed to read brain file header: {e}"))?;
-        file.seek(SeekFrom::Start(0))
-            .map_err(|e| format!("Failed to seek brain file: {e}"))?;
-
-        if state_image::is_state_magic(&magic) {
-            let loaded = state_image::load_state_from(&mut file)
-                .map_err(|e| format!("Failed to load daemon state: {}", e))?;
-            self.brain = loaded.brain;
-            if let Some(ex_bytes) = loaded.experts_state {
-                self.experts
-                    .load_state_bytes(&ex_bytes)
-                    .map_err(|e| format!("Failed to load experts state: {e}"))?;
-            } else {
+        let (brain, format) = state_image::load_auto(&mut file)
+            .map_err(|e| format!("Failed to load brain file: {e}"))?;
+        self.brain = brain;
+
+        // Track state wrapper version for subsequent saves; bare images are
+        // rewritten as the current wrapper on the next save.
+        self.persist_state_version = match format.state_version() {
+            0 => state_image::VERSION_V4,
+            v => v,
+        };
+
+        match format {
+            state_image::LoadedFormat::Legacy => {
+                info!("Loaded bare brain image {:?}; experts disabled", path);
                 self.experts.set_enabled(false);
             }
-
-            // Mirror embedded runtime state back out to runtime.json for existing tooling.
-            if let Some(rt_bytes) = loaded.runtime_state {
-                let rt_path = self.paths.runtime_state_file();
-                if let Some(parent) = rt_path.parent() {
-                    let _ = std::fs::create_dir_all(parent);
+            state_image::LoadedFormat::StateV3 {
+                experts_state,
+                runtime_state,
+            }
+            | state_image::LoadedFormat::StateV4 {
+                experts_state,
+                runtime_state,
+            } => {
+                if let Some(ex_bytes) = experts_state {
+                    self.experts
+                        .load_state_bytes(&ex_bytes)
+                        .map_err(|e| format!("Failed to load experts state: {e}"))?;
+                } else {
+                    self.experts.set_enabled(false);
                 }
-                if let Err(e) = std::fs::write(&rt_path, &rt_bytes) {
-                    warn!(
-                        "Failed to write embedded runtime state to {:?}: {e}",
-                        rt_path
-                    );
+
+                // Mirror embedded runtime state back out to runtime.json for existing tooling.
+                if let Some(rt_bytes) = runtime_state {
+                    let rt_path = self.paths.runtime_state_file();
+                    if let Some(parent) = rt_path.parent() {
+                        let _ = std::fs::create_dir_all(parent);
+                    }
+                    if let Err(e) = storage::write_file_atomic(&rt_path, &rt_bytes) {
+                        warn!(
+                            "Failed to write embedded runtime state to {:?}: {e}",
+                            rt_path
+                        );
+                    }
                 }
             }
-
-            // Track state wrapper version for subsequent saves.
-            self.persist_state_version = Self::detect_state_wrapper_version(&path);
-        } else {
-            return Err(
-                "Incompatible brain file format (expected BRSTATE3). Delete braine.bbi and restart."
-                    .to_string(),
-            );
         }
 
+        self.load_causal_checkpoint();
+
         // Ensure required IO groups exist.
-        self.brain.ensure_sensor_min_width("spot_left", 4);
-        self.brain.ensure_sensor_min_width("spot_right", 4);
-        self.brain.ensure_sensor_min_width("spot_rev_ctx", 2);
-        self.brain.ensure_sensor_min_width("bandit", 4);
+        ensure_sensor_logged(&mut self.brain, "spot_left", 4);
+        ensure_sensor_logged(&mut self.brain, "spot_right", 4);
+        ensure_sensor_logged(&mut self.brain, "spot_rev_ctx", 2);
+        ensure_sensor_logged(&mut self.brain, "bandit", 4);
         self.ensure_spotxy_io();
         if self.game.kind() == "pong" {
             self.ensure_pong_io();
@@ -2796,16 +4016,16 @@ impl DaemonState {
             None
         };
         self.experts.for_each_brain_mut(&mut |b: &mut Brain| {
-            b.ensure_sensor_min_width("spot_left", 4);
-            b.ensure_sensor_min_width("spot_right", 4);
-            b.ensure_sensor_min_width("spot_rev_ctx", 2);
-            b.ensure_sensor_min_width("bandit", 4);
+            ensure_sensor_logged(b, "spot_left", 4);
+            ensure_sensor_logged(b, "spot_right", 4);
+            ensure_sensor_logged(b, "spot_rev_ctx", 2);
+            ensure_sensor_logged(b, "bandit", 4);
             // SpotXY IO is derived from current daemon game.
             if game_kind == "spotxy" {
                 let k = 16usize;
                 for i in 0..k {
-                    b.ensure_sensor_min_width(&format!("pos_x_{i:02}"), 3);
-                    b.ensure_sensor_min_width(&format!("pos_y_{i:02}"), 3);
+                    ensure_sensor_logged(b, &format!("pos_x_{i:02}"), 3);
+                    ensure_sensor_logged(b, &format!("pos_y_{i:02}"), 3);
                 }
                 if let Some(names) = spotxy_allowed {
                     for name in names {
@@ -2816,14 +4036,14 @@ impl DaemonState {
             if game_kind == "pong" {
                 let bins = 8u32;
                 for i in 0..bins {
-                    b.ensure_sensor_min_width(&format!("pong_ball_x_{i:02}"), 3);
-                    b.ensure_sensor_min_width(&format!("pong_ball_y_{i:02}"), 3);
-                    b.ensure_sensor_min_width(&format!("pong_paddle_y_{i:02}"), 3);
+                    ensure_sensor_logged(b, &format!("pong_ball_x_{i:02}"), 3);
+                    ensure_sensor_logged(b, &format!("pong_ball_y_{i:02}"), 3);
+                    ensure_sensor_logged(b, &format!("pong_paddle_y_{i:02}"), 3);
                 }
-                b.ensure_sensor_min_width("pong_vx_pos", 2);
-                b.ensure_sensor_min_width("pong_vx_neg", 2);
-                b.ensure_sensor_min_width("pong_vy_pos", 2);
-                b.ensure_sensor_min_width("pong_vy_neg", 2);
+                ensure_sensor_logged(b, "pong_vx_pos", 2);
+                ensure_sensor_logged(b, "pong_vx_neg", 2);
+                ensure_sensor_logged(b, "pong_vy_pos", 2);
+                ensure_sensor_logged(b, "pong_vy_neg", 2);
                 b.ensure_action_min_width("up", 6);
                 b.ensure_action_min_width("down", 6);
                 b.ensure_action_min_width("stay", 6);
@@ -2857,6 +4077,25 @@ impl DaemonState {
                         s.correct = p.correct;
                         s.incorrect = p.incorrect;
                         s.trials = p.trials;
+                        let mut milestones = p.milestones.clone();
+                        if milestones.is_empty() {
+                            // Older runtime files only have the standard three.
+                            let legacy = [
+                                (braine_games::stats::MILESTONE_LEARNING, p.learning_at_trial),
+                                (braine_games::stats::MILESTONE_LEARNED, p.learned_at_trial),
+                                (braine_games::stats::MILESTONE_MASTERED, p.mastered_at_trial),
+                            ];
+                            milestones.extend(
+                                legacy
+                                    .into_iter()
+                                    .filter_map(|(name, t)| Some((name.to_string(), t?))),
+                            );
+                        }
+                        s.learning_milestone_detector_mut().restore(
+                            p.trials,
+                            &p.recent,
+                            &milestones,
+                        );
                         s.recent = p.recent;
                         s.learning_at_trial = p.learning_at_trial;
                         s.learned_at_trial = p.learned_at_trial;
@@ -2873,12 +4112,25 @@ impl DaemonState {
         Ok(())
     }
 
+    fn reset_causality(&mut self) {
+        self.brain.reset_causality();
+        self.experts
+            .for_each_brain_mut(&mut |b: &mut Brain| b.reset_causality());
+        info!("Causal memory cleared");
+    }
+
     fn reset_brain(&mut self) {
-        *self = Self::new(self.paths.clone());
+        *self = Self::new(self.paths.clone(), self.initial_config.clone());
         info!("Brain reset to initial state");
     }
 }
 
+/// Importance weight for `commit_observation_with_priority`: rewarded and
+/// novel boundaries learn (and are replayed) more strongly.
+fn commit_priority(reward: f32, intrinsic_reward: f32) -> f32 {
+    1.0 + reward.abs() * 0.5 + intrinsic_reward * 0.3
+}
+
 fn parse_execution_tier(s: &str) -> Option<braine::substrate::ExecutionTier> {
     match s.trim().to_ascii_lowercase().as_str() {
         "scalar" | "cpu" => Some(braine::substrate::ExecutionTier::Scalar),
@@ -2889,1292 +4141,2803 @@ fn parse_execution_tier(s: &str) -> Option<braine::substrate::ExecutionTier> {
     }
 }
 
-// ═══════════════════════════════════════════════════════════════════════════
-// Client Handler
-// ═══════════════════════════════════════════════════════════════════════════
+/// Decode a base64-encoded CSV replay dataset (`ReplaySetDataset { csv_base64 }`).
+fn decode_csv_dataset(b64: &str) -> Result<ReplayDataset, String> {
+    use base64::Engine as _;
+
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(b64.trim())
+        .map_err(|e| format!("csv_base64 is not valid base64: {e}"))?;
+    let text = String::from_utf8(bytes).map_err(|_| "csv_base64 is not UTF-8".to_string())?;
+    let mut dataset =
+        ReplayDataset::from_csv_str(&text).map_err(|e| format!("invalid replay CSV: {e}"))?;
+    dataset.name = "csv".to_string();
+    Ok(dataset)
+}
 
-async fn handle_client(
-    stream: TcpStream,
-    state: Arc<RwLock<DaemonState>>,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let (reader, mut writer) = stream.into_split();
-    let mut lines = BufReader::new(reader).lines();
+/// `Brain::ensure_sensor_min_width`, logging at debug level when the group
+/// was created or grown (e.g. an older brain image missing newer IO).
+fn ensure_sensor_logged(brain: &mut Brain, name: &str, min_width: usize) {
+    let r = brain.ensure_sensor_min_width(name, min_width);
+    if r.was_created {
+        debug!("Sensor group '{}' created (width {})", name, r.new_width);
+    } else if r.added() > 0 {
+        debug!(
+            "Sensor group '{}' expanded from {} to {} units",
+            name, r.old_width, r.new_width
+        );
+    }
+}
 
-    while let Some(line) = lines.next_line().await? {
-        let request: Request = match serde_json::from_str(&line) {
-            Ok(req) => req,
-            Err(e) => {
-                let resp = Response::Error {
-                    message: format!("Invalid request: {}", e),
+/// Coarse role of a causal symbol, from the daemon's naming conventions.
+fn causal_symbol_type(brain: &Brain, symbol: &str) -> &'static str {
+    if symbol == "reward_pos" || symbol == "reward_neg" {
+        "reward"
+    } else if symbol.starts_with("pair::") {
+        "pair"
+    } else if brain.has_action(symbol) {
+        "action"
+    } else if brain.has_sensor(symbol) {
+        "sensor"
+    } else {
+        "symbol"
+    }
+}
+
+/// Serialize every connection as `(from, to, weight)` for `ExportWeights`.
+///
+/// - `csv`: `from,to,weight` header, one connection per line.
+/// - `json_sparse`: COO arrays `{"rows":[..],"cols":[..],"weights":[..]}`.
+/// - `binary_f32`: base64 of little-endian f32 triplets (unit ids cast to f32).
+fn export_weights(brain: &Brain, format: &str) -> Result<String, String> {
+    use base64::Engine as _;
+    use std::fmt::Write as _;
+
+    match format {
+        "csv" => {
+            let mut out = String::from("from,to,weight\n");
+            for (from, to, w) in brain.connection_triplets() {
+                let _ = writeln!(out, "{from},{to},{w}");
+            }
+            Ok(out)
+        }
+        "json_sparse" => {
+            let (mut rows, mut cols, mut weights) = (Vec::new(), Vec::new(), Vec::new());
+            for (from, to, w) in brain.connection_triplets() {
+                rows.push(from);
+                cols.push(to);
+                weights.push(w);
+            }
+            Ok(serde_json::json!({ "rows": rows, "cols": cols, "weights": weights }).to_string())
+        }
+        "binary_f32" => {
+            let mut bytes = Vec::with_capacity(brain.connection_count() * 12);
+            for (from, to, w) in brain.connection_triplets() {
+                for v in [from as f32, to as f32, w] {
+                    bytes.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+            Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+        }
+        other => Err(format!(
+            "unknown weights format '{other}' (expected csv, json_sparse or binary_f32)"
+        )),
+    }
+}
+
+/// Build a `NeurogenesisPolicy` from `SetNeurogenesisPolicy` arguments.
+///
+/// `custom` is rejected: a function pointer cannot travel over the protocol.
+fn parse_neurogenesis_policy(
+    name: &str,
+    params: serde_json::Value,
+) -> Result<NeurogenesisPolicy, String> {
+    #[derive(Deserialize)]
+    struct Saturation {
+        threshold: f32,
+        amount: u32,
+    }
+    #[derive(Deserialize)]
+    struct Periodic {
+        every_steps: u64,
+        amount: u32,
+    }
+    #[derive(Deserialize)]
+    struct RewardDriven {
+        min_reward: f32,
+        amount: u32,
+    }
+    #[derive(Deserialize)]
+    struct Gradient {
+        target_group: String,
+        threshold: f32,
+        amount: u32,
+    }
+
+    let bad = |e: serde_json::Error| format!("Invalid params for neurogenesis policy {name}: {e}");
+    let policy = match name.trim().to_ascii_lowercase().as_str() {
+        "saturation" => {
+            let p: Saturation = serde_json::from_value(params).map_err(bad)?;
+            NeurogenesisPolicy::Saturation {
+                threshold: p.threshold,
+                amount: p.amount,
+            }
+        }
+        "periodic" => {
+            let p: Periodic = serde_json::from_value(params).map_err(bad)?;
+            NeurogenesisPolicy::Periodic {
+                every_steps: p.every_steps,
+                amount: p.amount,
+            }
+        }
+        "reward_driven" => {
+            let p: RewardDriven = serde_json::from_value(params).map_err(bad)?;
+            NeurogenesisPolicy::RewardDriven {
+                min_reward: p.min_reward,
+                amount: p.amount,
+            }
+        }
+        "gradient" => {
+            let p: Gradient = serde_json::from_value(params).map_err(bad)?;
+            NeurogenesisPolicy::Gradient {
+                target_group: p.target_group,
+                threshold: p.threshold,
+                amount: p.amount,
+            }
+        }
+        "custom" => {
+            return Err("Custom neurogenesis policies can only be set from Rust".to_string())
+        }
+        other => {
+            return Err(format!(
+                "Unknown neurogenesis policy: {other} (expected saturation/periodic/reward_driven/gradient)"
+            ))
+        }
+    };
+
+    match policy {
+        NeurogenesisPolicy::Saturation { threshold: x, .. }
+        | NeurogenesisPolicy::RewardDriven { min_reward: x, .. }
+        | NeurogenesisPolicy::Gradient { threshold: x, .. }
+            if !x.is_finite() =>
+        {
+            Err(format!(
+                "Neurogenesis policy {name} needs a finite threshold"
+            ))
+        }
+        _ => Ok(policy),
+    }
+}
+
+/// Build a `PruningPolicy` from `SetPruningPolicy` arguments.
+///
+/// Range checks are left to `Brain::set_pruning_policy`.
+fn parse_pruning_policy(name: &str, params: serde_json::Value) -> Result<PruningPolicy, String> {
+    #[derive(Deserialize)]
+    struct Threshold {
+        below: f32,
+    }
+    #[derive(Deserialize)]
+    struct TopK {
+        k_per_unit: usize,
+    }
+    #[derive(Deserialize)]
+    struct Probabilistic {
+        rate: f32,
+        temperature: f32,
+    }
+    #[derive(Deserialize)]
+    struct Age {
+        max_age_steps: u64,
+    }
+
+    let bad = |e: serde_json::Error| format!("Invalid params for pruning policy {name}: {e}");
+    Ok(match name.trim().to_ascii_lowercase().as_str() {
+        "threshold" => {
+            let p: Threshold = serde_json::from_value(params).map_err(bad)?;
+            PruningPolicy::Threshold { below: p.below }
+        }
+        "top_k" | "topk" => {
+            let p: TopK = serde_json::from_value(params).map_err(bad)?;
+            PruningPolicy::TopK {
+                k_per_unit: p.k_per_unit,
+            }
+        }
+        "probabilistic" => {
+            let p: Probabilistic = serde_json::from_value(params).map_err(bad)?;
+            PruningPolicy::Probabilistic {
+                rate: p.rate,
+                temperature: p.temperature,
+            }
+        }
+        "age" => {
+            let p: Age = serde_json::from_value(params).map_err(bad)?;
+            PruningPolicy::Age {
+                max_age_steps: p.max_age_steps,
+            }
+        }
+        other => {
+            return Err(format!(
+                "Unknown pruning policy: {other} (expected threshold/top_k/probabilistic/age)"
+            ))
+        }
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Client Handler
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Handle a request that only reads daemon state (`Request::is_read_only`).
+fn handle_read_request(request: &Request, s: &DaemonState) -> Response {
+    match request {
+        Request::GetState {
+            include_group_activities,
+        } => Response::State(Box::new(s.get_snapshot(*include_group_activities))),
+        Request::DiagGet => s.diag_update().into(),
+        Request::CfgGet => {
+            let cfg = s.brain.config();
+            Response::Config {
+                exploration_eps: s.exploration_eps,
+                meaning_alpha: s.meaning_alpha,
+                reward_symbol_threshold: cfg.reward_symbol_threshold,
+                concept_validate_threshold: cfg.concept_validate_threshold,
+                target_fps: s.target_fps,
+                trial_period_ms: s.trial_period_ms,
+                max_units_limit: s.max_units_limit as u32,
+                intrinsic_reward_scale: s.intrinsic_reward_scale,
+                causal_window_frames: cfg.causal_window_frames,
+                experimental_flags: cfg.experimental_flags.clone(),
+            }
+        }
+        Request::ComputeMeaningMatrix { stimuli, actions } => {
+            let stimuli: Vec<String> = stimuli.iter().take(MEANING_MATRIX_MAX).cloned().collect();
+            let actions: Vec<String> = actions.iter().take(MEANING_MATRIX_MAX).cloned().collect();
+            let rows = stimuli
+                .iter()
+                .map(|st| {
+                    actions
+                        .iter()
+                        .map(|a| s.brain.pair_reward_edges(st, a).meaning)
+                        .collect()
+                })
+                .collect();
+            Response::MeaningMatrix {
+                stimuli,
+                actions,
+                rows,
+            }
+        }
+        // Profiling here would run under the lock; `handle_client` runs it off-lock.
+        Request::ProfileStep { .. } => Response::Error {
+            message: "ProfileStep must be sent as a top-level request".to_string(),
+        },
+        Request::GetCausalEventLog { n } => {
+            let n = (*n).min(CAUSAL_EVENTS_MAX) as usize;
+            let name = |id| s.brain.symbol_name(id).unwrap_or("?").to_string();
+            let events = s
+                .brain
+                .causal_event_log()
+                .last(n)
+                .map(|e| CausalEventInfo {
+                    step: e.step,
+                    from: name(e.from),
+                    to: name(e.to),
+                    old_weight: e.old_weight,
+                    new_weight: e.new_weight,
+                    is_directed: e.is_directed,
+                })
+                .collect();
+            Response::CausalEventLog { events }
+        }
+        Request::GetCausalPredecessors { symbol, k } => {
+            let k = (*k).min(CAUSAL_PREDECESSORS_MAX) as usize;
+            let predecessors = s
+                .brain
+                .causal_predecessor_symbols(symbol, k)
+                .into_iter()
+                .map(|(symbol, strength)| CausalLink { symbol, strength })
+                .collect();
+            Response::CausalPredecessors {
+                symbol: symbol.clone(),
+                predecessors,
+            }
+        }
+        Request::GetCausalChainScore { symbols } => {
+            let symbols: Vec<&str> = symbols.iter().map(String::as_str).collect();
+            Response::CausalChainScore {
+                score: s.brain.causal_chain_score(&symbols),
+            }
+        }
+        Request::GetCausalTemporalOrder { symbols } => {
+            let symbols = &symbols[..symbols.len().min(CAUSAL_TEMPORAL_ORDER_MAX)];
+            let names: Vec<&str> = symbols.iter().map(String::as_str).collect();
+            let order = s.brain.causal_temporal_order(&names);
+            let symbols = order.iter().map(|&i| symbols[i].clone()).collect();
+            Response::CausalTemporalOrder { order, symbols }
+        }
+        Request::GetCausalGraph {
+            context_key,
+            max_depth,
+            min_weight,
+        } => {
+            let (nodes, edges) = s.get_causal_graph(
+                context_key.as_deref(),
+                (*max_depth).min(CAUSAL_GRAPH_MAX_DEPTH),
+                *min_weight,
+            );
+            Response::CausalGraph { nodes, edges }
+        }
+        Request::GetAllContextScores { meaning_alpha } => {
+            let alpha = meaning_alpha.unwrap_or(s.meaning_alpha);
+            Response::AllContextScores {
+                scores: s.brain.action_score_breakdown_all_contexts(alpha),
+            }
+        }
+        Request::ExportUnitMetadata => Response::UnitMetadata {
+            json: s.brain.export_unit_metadata_json(),
+        },
+        Request::GetMetrics => Response::Metrics {
+            metrics: s.brain.metrics(),
+        },
+        Request::ExportWeights { format } => {
+            if s.running {
+                Response::Error {
+                    message: "ExportWeights requires the daemon to be stopped (running=false)"
+                        .to_string(),
+                }
+            } else {
+                match export_weights(&s.brain, format) {
+                    Ok(data) => Response::WeightsExport {
+                        format: format.clone(),
+                        data,
+                        unit_count: s.brain.unit_count() as u32,
+                    },
+                    Err(message) => Response::Error { message },
+                }
+            }
+        }
+        Request::ScoreExpert { id, inputs } => {
+            if inputs.len() > SCORE_EXPERT_MAX_INPUTS {
+                return Response::Error {
+                    message: format!(
+                        "ScoreExpert takes at most {SCORE_EXPERT_MAX_INPUTS} inputs, got {}",
+                        inputs.len()
+                    ),
                 };
-                writer
-                    .write_all(serde_json::to_string(&resp)?.as_bytes())
-                    .await?;
-                writer.write_all(b"\n").await?;
-                continue;
             }
-        };
+            // Same step bound as InferActionScores.
+            let inputs: Vec<BrainInput> = inputs
+                .iter()
+                .map(|i| BrainInput {
+                    steps: i.steps.clamp(1, 64),
+                    meaning_alpha: i.meaning_alpha.clamp(0.0, 50.0),
+                    ..i.clone()
+                })
+                .collect();
+            match s.experts.score_child(*id, &inputs) {
+                Some(outputs) => Response::ExpertScores { id: *id, outputs },
+                None => Response::Error {
+                    message: format!("No active expert with id={id}"),
+                },
+            }
+        }
+        Request::GatesGetModules => Response::GatesModules {
+            modules: s.brain.routing_modules_summary(),
+        },
+        Request::SyncGetInfo => Response::SyncInfo {
+            age_steps: s.brain.age_steps(),
+            unit_count: s.brain.unit_count() as u32,
+            weights_len: s.brain.weights_len() as u32,
+            fingerprint: s.brain.connections_fingerprint(),
+        },
+        Request::InferActionScores {
+            context_key,
+            stimuli,
+            steps,
+            meaning_alpha,
+            verbose,
+        } => {
+            let context_key = context_key
+                .clone()
+                .unwrap_or_else(|| s.current_stimulus_key().into_owned());
+            let alpha = meaning_alpha.unwrap_or(s.meaning_alpha).clamp(0.0, 50.0);
+
+            let input = BrainInput {
+                stimuli: stimuli.clone(),
+                steps: (*steps).clamp(1, 64),
+                context_key: context_key.clone(),
+                meaning_alpha: alpha,
+                verbose: *verbose,
+            };
+            let (action_scores, action_debug) = s
+                .view_brain_for_context(&context_key)
+                .step_batch(std::slice::from_ref(&input))
+                .pop()
+                .map(|out| (out.action_scores, out.action_debug))
+                .unwrap_or_default();
+
+            Response::InferActionScores {
+                context_key,
+                action_scores,
+                action_debug,
+            }
+        }
+        Request::AdvisorGet => Response::AdvisorStatus {
+            config: s.advisor.status(),
+            last_report: s.advisor.last_report.clone(),
+        },
+        Request::AdvisorContext {
+            include_action_scores,
+            include_causal_graph,
+        } => {
+            let trials = s.game.stats().trials;
+            let context_key = s.current_stimulus_key();
+            let text_regime = match &s.game {
+                ActiveGame::Text(g) => Some(g.regime()),
+                _ => None,
+            };
 
-        let response = match request {
-            Request::ApiCatalog => Response::ApiCatalog {
-                categories: vec![
-                    ApiCategory {
-                        name: "Diagnostics".to_string(),
-                        endpoints: vec![ApiEndpoint {
-                            request: "DiagGet".to_string(),
-                            input: "{}".to_string(),
-                            output: "{ type: Diagnostics, ... }".to_string(),
-                            description: "Read-only daemon/brain diagnostics snapshot.".to_string(),
-                        }],
+            let mut ctx = advisor::AdvisorContext {
+                game: s.game.kind().to_string(),
+                context_key: context_key.clone().into_owned(),
+                trials,
+                accuracy: s.game.stats().accuracy(),
+                recent_rate: s.game.stats().recent_rate(),
+                last_reward: s.last_reward,
+                exploration_eps: s.exploration_eps,
+                meaning_alpha: s.meaning_alpha,
+                td_prediction_error_ema: s.brain.learning_stats().td_prediction_error_ema,
+                text_regime,
+                causal_graph_json: include_causal_graph.then(|| {
+                    String::from_utf8_lossy(&s.brain.serialize_causal_graph()).into_owned()
+                }),
+                action_meaning: Vec::new(),
+                pair_meaning: Vec::new(),
+            };
+
+            let action_scores = if *include_action_scores {
+                let brain = s.view_brain_for_context(&context_key);
+                ctx.action_meaning = brain.action_reward_edges_all();
+                ctx.pair_meaning = brain.pair_reward_edges_all_actions(&context_key);
+                brain.action_score_breakdown(&context_key, s.meaning_alpha)
+            } else {
+                Vec::new()
+            };
+
+            Response::AdvisorContext {
+                context: ctx,
+                action_scores,
+            }
+        }
+        Request::ReplayGetDataset => Response::ReplayDataset {
+            dataset: s.replay_dataset.clone(),
+        },
+        Request::GetGraph {
+            kind,
+            max_nodes,
+            max_edges,
+            include_isolated,
+            from_group,
+            to_group,
+        } => {
+            let max_nodes = (*max_nodes as usize).clamp(1, 256);
+            let max_edges = (*max_edges as usize).clamp(0, 1024);
+            let group_pair = (from_group.is_some() || to_group.is_some()).then(|| {
+                (
+                    from_group.clone().unwrap_or_default(),
+                    to_group.clone().unwrap_or_default(),
+                )
+            });
+            Response::Graph(Box::new(s.get_graph_snapshot(
+                kind,
+                max_nodes,
+                max_edges,
+                *include_isolated,
+                group_pair.as_ref().map(|(f, t)| (f.as_str(), t.as_str())),
+            )))
+        }
+        _ => Response::Error {
+            message: "Not a read-only request".to_string(),
+        },
+    }
+}
+
+/// Average `Brain::profile_cycle` over `num_steps` cycles of `brain` (a clone of the live one).
+///
+/// Each cycle stimulates the next sensor group in turn, so imprinting and causal
+/// updates are timed along with the step itself.
+fn profile_brain(mut brain: Brain, num_steps: u32) -> Response {
+    let num_steps = num_steps.clamp(1, PROFILE_STEPS_MAX);
+    let sensors: Vec<String> = brain.sensor_group_names().map(str::to_string).collect();
+    let samples: Vec<StepProfile> = (0..num_steps as usize)
+        .map(|i| match sensors.get(i % sensors.len().max(1)) {
+            Some(name) => brain.profile_cycle(&[Stimulus::new(name, 1.0)]),
+            None => brain.profile_cycle(&[]),
+        })
+        .collect();
+    Response::StepProfile {
+        num_steps,
+        profile: StepProfile::mean(&samples),
+    }
+}
+
+/// Execute one decoded request against the daemon state.
+///
+/// Connection-scoped requests (`StreamState`, `StopStream`, `WatchDiag`,
+/// `UnwatchDiag`) and `ProfileStep` are handled by `handle_client`; here they
+/// only produce an error.
+fn handle_request(request: Request, s: &mut DaemonState) -> Response {
+    match request {
+        r if r.is_read_only() => handle_read_request(&r, s),
+        Request::StreamState { .. }
+        | Request::StopStream
+        | Request::WatchDiag { .. }
+        | Request::UnwatchDiag => Response::Error {
+            message:
+                "StreamState/StopStream/WatchDiag/UnwatchDiag must be sent as top-level requests"
+                    .to_string(),
+        },
+        Request::BatchRequests { requests } => {
+            // Validate running-gated requests against the state each one will see.
+            let mut running = s.running;
+            for (i, r) in requests.iter().enumerate() {
+                match r {
+                    Request::Start => running = true,
+                    Request::Stop => running = false,
+                    r if r.requires_stopped() && running => {
+                        return Response::Error {
+                            message: format!(
+                                "Batch rejected: request {i} requires the daemon to be stopped (running=false)"
+                            ),
+                        };
+                    }
+                    _ => {}
+                }
+            }
+
+            let results: Vec<Response> = requests
+                .into_iter()
+                .map(|r| match r {
+                    Request::BatchRequests { .. } => Response::Error {
+                        message: "BatchRequests cannot be nested".to_string(),
                     },
-                    ApiCategory {
-                        name: "Configuration".to_string(),
-                        endpoints: vec![
-                            ApiEndpoint {
-                                request: "CfgGet".to_string(),
-                                input: "{}".to_string(),
-                                output: "{ type: Config, ... }".to_string(),
-                                description: "Read current runtime configuration knobs.".to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "CfgSet".to_string(),
-                                input: "{ exploration_eps?, meaning_alpha?, reward_symbol_threshold?, concept_validate_threshold?, target_fps?, trial_period_ms?, max_units? }"
+                    r => handle_request(r, s),
+                })
+                .collect();
+            let batch_had_errors = results
+                .iter()
+                .any(|r| matches!(r, Response::Error { .. }));
+            Response::BatchResults {
+                results,
+                batch_had_errors,
+            }
+        }
+        Request::ApiCatalog => Response::ApiCatalog {
+            categories: vec![
+                ApiCategory {
+                    name: "Diagnostics".to_string(),
+                    endpoints: vec![ApiEndpoint {
+                        request: "DiagGet".to_string(),
+                        input: "{}".to_string(),
+                        output: "{ type: Diagnostics, ... }".to_string(),
+                        description: "Read-only daemon/brain diagnostics snapshot.".to_string(),
+                    }],
+                },
+                ApiCategory {
+                    name: "Configuration".to_string(),
+                    endpoints: vec![
+                        ApiEndpoint {
+                            request: "CfgGet".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: Config, ... }".to_string(),
+                            description: "Read current runtime configuration knobs.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "CfgSet".to_string(),
+                            input: "{ exploration_eps?, meaning_alpha?, reward_symbol_threshold?, concept_validate_threshold?, target_fps?, trial_period_ms?, max_units?, intrinsic_reward_scale?, causal_window_frames? }"
+                                .to_string(),
+                            output: "{ type: Success|Error }".to_string(),
+                            description: "Update runtime knobs (safe clamped).".to_string(),
+                        },
+                    ],
+                },
+                ApiCategory {
+                    name: "Gates".to_string(),
+                    endpoints: vec![
+                        ApiEndpoint {
+                            request: "GatesGetModules".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: GatesModules, modules: [...] }".to_string(),
+                            description: "List routing modules + unit counts (to target module ids for gates).".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GatesSet".to_string(),
+                            input: "{ target: unit|module, ids: [...], gate: freeze|paralyze, enabled: bool }".to_string(),
+                            output: "{ type: Success|Error }".to_string(),
+                            description: "Set freeze/paralyze gates for specific units or routing modules.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GatesClear".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: Success|Error }".to_string(),
+                            description: "Clear all freeze/paralyze gates.".to_string(),
+                        },
+                    ],
+                },
+                ApiCategory {
+                    name: "Reward".to_string(),
+                    endpoints: vec![ApiEndpoint {
+                        request: "Trial".to_string(),
+                        input: "{ context_key, stimuli: [...], allowed_actions: [...], forced_action?, reward, predicted_reward?, learn?, steps?, meaning_alpha? }".to_string(),
+                        output: "{ type: TrialResult, action, score, reward, learned }".to_string(),
+                        description: "Run one externally-defined trial with a caller-provided reward (daemon must be stopped).".to_string(),
+                    }],
+                },
+                ApiCategory {
+                    name: "Synchronization".to_string(),
+                    endpoints: vec![
+                        ApiEndpoint {
+                            request: "SyncGetInfo".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: SyncInfo, ... }".to_string(),
+                            description:
+                                "Get sync metadata for edge/child brains (fingerprint + sizes)."
                                     .to_string(),
-                                output: "{ type: Success|Error }".to_string(),
-                                description: "Update runtime knobs (safe clamped).".to_string(),
-                            },
-                        ],
-                    },
-                    ApiCategory {
-                        name: "Gates".to_string(),
-                        endpoints: vec![
-                            ApiEndpoint {
-                                request: "GatesGetModules".to_string(),
-                                input: "{}".to_string(),
-                                output: "{ type: GatesModules, modules: [...] }".to_string(),
-                                description: "List routing modules + unit counts (to target module ids for gates).".to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "GatesSet".to_string(),
-                                input: "{ target: unit|module, ids: [...], gate: freeze|paralyze, enabled: bool }".to_string(),
-                                output: "{ type: Success|Error }".to_string(),
-                                description: "Set freeze/paralyze gates for specific units or routing modules.".to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "GatesClear".to_string(),
-                                input: "{}".to_string(),
-                                output: "{ type: Success|Error }".to_string(),
-                                description: "Clear all freeze/paralyze gates.".to_string(),
-                            },
-                        ],
-                    },
-                    ApiCategory {
-                        name: "Reward".to_string(),
-                        endpoints: vec![ApiEndpoint {
-                            request: "Trial".to_string(),
-                            input: "{ context_key, stimuli: [...], allowed_actions: [...], forced_action?, reward, learn?, steps?, meaning_alpha? }".to_string(),
-                            output: "{ type: TrialResult, action, score, reward, learned }".to_string(),
-                            description: "Run one externally-defined trial with a caller-provided reward (daemon must be stopped).".to_string(),
-                        }],
-                    },
-                    ApiCategory {
-                        name: "Synchronization".to_string(),
-                        endpoints: vec![
-                            ApiEndpoint {
-                                request: "SyncGetInfo".to_string(),
-                                input: "{}".to_string(),
-                                output: "{ type: SyncInfo, ... }".to_string(),
-                                description:
-                                    "Get sync metadata for edge/child brains (fingerprint + sizes)."
-                                        .to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "SyncApplyDelta".to_string(),
-                                input: "{ delta, delta_max, expected_weights_len, expected_fingerprint, autosave }"
+                        },
+                        ApiEndpoint {
+                            request: "SyncApplyDelta".to_string(),
+                            input: "{ delta, delta_max, expected_weights_len, expected_fingerprint, autosave, mask_kind? }"
+                                .to_string(),
+                            output: "{ type: SyncApplied|Error }".to_string(),
+                            description:
+                                "Apply a sparse BrainDelta (top-K edge weight deltas) from an edge/child brain and optionally persist immediately."
                                     .to_string(),
-                                output: "{ type: SyncApplied|Error }".to_string(),
-                                description:
-                                    "Apply a sparse BrainDelta (top-K edge weight deltas) from an edge/child brain and optionally persist immediately."
-                                        .to_string(),
-                            },
-                        ],
-                    },
-                    ApiCategory {
-                        name: "Inference".to_string(),
-                        endpoints: vec![ApiEndpoint {
-                            request: "InferActionScores".to_string(),
-                            input: "{ context_key?, stimuli?, steps?, meaning_alpha? }".to_string(),
-                            output: "{ type: InferActionScores, context_key, action_scores: [...] }".to_string(),
-                            description: "Read-only inference on a cloned brain: apply stimuli (no imprint), advance dynamics (no learning/forget), return action score breakdowns.".to_string(),
-                        }],
-                    },
-                    ApiCategory {
-                        name: "Advisor".to_string(),
-                        endpoints: vec![
-                            ApiEndpoint {
-                                request: "AdvisorGet".to_string(),
-                                input: "{}".to_string(),
-                                output: "{ type: AdvisorStatus, config, last_report? }".to_string(),
-                                description: "Get advisor runtime config + last report (if any).".to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "AdvisorSet".to_string(),
-                                input: "{ enabled, every_trials?, mode? }".to_string(),
-                                output: "{ type: Success|Error }".to_string(),
-                                description: "Update advisor runtime knobs.".to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "AdvisorOnce".to_string(),
-                                input: "{ apply }".to_string(),
-                                output: "{ type: AdvisorReport, report, applied }".to_string(),
-                                description: "Invoke built-in advisor once (stub) and optionally apply.".to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "AdvisorContext".to_string(),
-                                input: "{ include_action_scores }".to_string(),
-                                output: "{ type: AdvisorContext, context, action_scores: [...] }".to_string(),
-                                description: "LLM boundary: get structured context for an external advisor.".to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "AdvisorApply".to_string(),
-                                input: "{ advice: { exploration_eps?, meaning_alpha?, ttl_trials, rationale } }".to_string(),
-                                output: "{ type: Success|Error }".to_string(),
-                                description: "LLM boundary: apply external advisor advice (daemon clamps + records).".to_string(),
-                            },
-                        ],
-                    },
-                    ApiCategory {
-                        name: "Replay".to_string(),
-                        endpoints: vec![
-                            ApiEndpoint {
-                                request: "ReplayGetDataset".to_string(),
-                                input: "{}".to_string(),
-                                output: "{ type: ReplayDataset, dataset: { name, trials: [...] } }".to_string(),
-                                description: "Get current replay dataset (for dataset-driven evaluation).".to_string(),
-                            },
-                            ApiEndpoint {
-                                request: "ReplaySetDataset".to_string(),
-                                input: "{ dataset: { name, trials: [...] } }".to_string(),
-                                output: "{ type: Success|Error }".to_string(),
-                                description: "Set replay dataset (must be stopped); resizes sensors/actions as needed.".to_string(),
-                            },
-                        ],
-                    },
-                    ApiCategory {
-                        name: "General".to_string(),
-                        endpoints: vec![ApiEndpoint {
-                            request: "GetState".to_string(),
+                        },
+                    ],
+                },
+                ApiCategory {
+                    name: "Inference".to_string(),
+                    endpoints: vec![ApiEndpoint {
+                        request: "InferActionScores".to_string(),
+                        input: "{ context_key?, stimuli?, steps?, meaning_alpha?, verbose? }".to_string(),
+                        output: "{ type: InferActionScores, context_key, action_scores: [...], action_debug?: [...] }".to_string(),
+                        description: "Read-only inference on a cloned brain: apply stimuli (no imprint), advance dynamics (no learning/forget), return action score breakdowns.".to_string(),
+                    }],
+                },
+                ApiCategory {
+                    name: "Analysis".to_string(),
+                    endpoints: vec![
+                        ApiEndpoint {
+                            request: "ComputeMeaningMatrix".to_string(),
+                            input: "{ stimuli: [String], actions: [String] } (max 32 each)".to_string(),
+                            output: "{ type: MeaningMatrix, stimuli, actions, rows: [[f32]] }".to_string(),
+                            description: "Pair meaning (reward_pos - reward_neg causal strength) for every stimulus × action, computed under a read lock.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "ScoreExpert".to_string(),
+                            input: "{ id, inputs: [{ stimuli, steps, context_key, meaning_alpha }] } (max 64)".to_string(),
+                            output: "{ type: ExpertScores, id, outputs: [{ action_scores, diagnostics }] }".to_string(),
+                            description: "Evaluate an active expert on several inputs, each on its own clone (parallel with the `parallel` feature).".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GetCausalEventLog".to_string(),
+                            input: "{ n } (max 1024)".to_string(),
+                            output: "{ type: CausalEventLog, events: [{ step, from, to, old_weight, new_weight, is_directed }] }".to_string(),
+                            description: "Most recent causal edge updates (transition or co-occurrence count before/after), oldest first.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GetCausalPredecessors".to_string(),
+                            input: "{ symbol, k } (max 64)".to_string(),
+                            output: "{ type: CausalPredecessors, symbol, predecessors: [{ symbol, strength }] }".to_string(),
+                            description: "Symbols with the strongest directed causal edges into `symbol` (e.g. what preceded reward_pos), strongest first.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GetCausalChainScore".to_string(),
+                            input: "{ symbols: [string] }".to_string(),
+                            output: "{ type: CausalChainScore, score }".to_string(),
+                            description: "Product of directed causal strengths along the chain symbols[0] -> symbols[1] -> ..., in [0, 1]; 0 if any link is missing.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GetCausalTemporalOrder".to_string(),
+                            input: "{ symbols: [string] }".to_string(),
+                            output: "{ type: CausalTemporalOrder, order: [index], symbols: [string] }".to_string(),
+                            description: "Most likely temporal order of the symbols (topological sort over their directed causal edges), earliest first.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GetCausalGraph".to_string(),
+                            input: "{ context_key?, max_depth (max 8), min_weight? }".to_string(),
+                            output: "{ type: CausalGraph, nodes: [{ symbol, symbol_type, activation_count }], edges: [{ from_symbol, to_symbol, directed_weight, cooccur_weight }] }".to_string(),
+                            description: "Symbol-level causal graph around a context (default: current stimulus), with raw directed and co-occurrence counts per edge.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GetAllContextScores".to_string(),
+                            input: "{ meaning_alpha? }".to_string(),
+                            output: "{ type: AllContextScores, scores: { <sensor>: [ActionScoreBreakdown] } }".to_string(),
+                            description: "Action score breakdown for every registered sensor context in one call (parallel with the `parallel` feature).".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "ExportUnitMetadata".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: UnitMetadata, json }".to_string(),
+                            description: "Sensor/action group membership of every unit as a JSON string, for interpreting exported weights.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "GetMetrics".to_string(),
                             input: "{}".to_string(),
+                            output: "{ type: Metrics, metrics: { <name>: f32 } }".to_string(),
+                            description: "Flat scalar metrics (brain.*, learning.*, causal.*, config.*, oscillation.*) for monitoring exporters.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "ExportWeights".to_string(),
+                            input: "{ format: csv|json_sparse|binary_f32 } (requires running=false)".to_string(),
+                            output: "{ type: WeightsExport, format, data, unit_count }".to_string(),
+                            description: "All connection weights as (from, to, weight) for analysis in Python/Julia; binary_f32 is base64 of little-endian f32 triplets.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "ProfileStep".to_string(),
+                            input: "{ num_steps } (1..1000)".to_string(),
+                            output: "{ type: StepProfile, num_steps, profile: { oscillator_ns, hebbian_ns, pruning_ns, sorted_adjacency_ns, neurogenesis_ns, causal_ns, imprint_ns, total_ns } }".to_string(),
+                            description: "Average per-phase wall time of Brain::step, measured on a clone of the live brain.".to_string(),
+                        },
+                    ],
+                },
+                ApiCategory {
+                    name: "Advisor".to_string(),
+                    endpoints: vec![
+                        ApiEndpoint {
+                            request: "AdvisorGet".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: AdvisorStatus, config, last_report? }".to_string(),
+                            description: "Get advisor runtime config + last report (if any).".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "AdvisorSet".to_string(),
+                            input: "{ enabled, every_trials?, mode? }".to_string(),
+                            output: "{ type: Success|Error }".to_string(),
+                            description: "Update advisor runtime knobs.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "AdvisorOnce".to_string(),
+                            input: "{ apply }".to_string(),
+                            output: "{ type: AdvisorReport, report, applied }".to_string(),
+                            description: "Invoke built-in advisor once (stub) and optionally apply.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "AdvisorContext".to_string(),
+                            input: "{ include_action_scores, include_causal_graph? }".to_string(),
+                            output: "{ type: AdvisorContext, context, action_scores: [...] }".to_string(),
+                            description: "LLM boundary: get structured context for an external advisor.".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "AdvisorApply".to_string(),
+                            input: "{ advice: { exploration_eps?, meaning_alpha?, ttl_trials, rationale } }".to_string(),
+                            output: "{ type: Success|Error }".to_string(),
+                            description: "LLM boundary: apply external advisor advice (daemon clamps + records).".to_string(),
+                        },
+                    ],
+                },
+                ApiCategory {
+                    name: "Replay".to_string(),
+                    endpoints: vec![
+                        ApiEndpoint {
+                            request: "ReplayGetDataset".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: ReplayDataset, dataset: { name, trials: [...] } }".to_string(),
+                            description: "Get current replay dataset (for dataset-driven evaluation).".to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "ReplaySetDataset".to_string(),
+                            input: "{ dataset: { name, trials: [...] } } | { csv_base64 }".to_string(),
+                            output: "{ type: Success|Error }".to_string(),
+                            description: "Set replay dataset (must be stopped); resizes sensors/actions as needed.".to_string(),
+                        },
+                    ],
+                },
+                ApiCategory {
+                    name: "General".to_string(),
+                    endpoints: vec![
+                        ApiEndpoint {
+                            request: "GetState".to_string(),
+                            input: "{ include_group_activities? }".to_string(),
                             output: "{ type: State, ... }".to_string(),
                             description: "Full UI snapshot (stats, HUD, plots, experts, storage)."
                                 .to_string(),
-                        }],
-                    },
-                ],
-            },
-            Request::DiagGet => {
-                let s = state.read().await;
-                let snap = s.get_snapshot();
-                Response::Diagnostics {
-                    running: snap.running,
-                    frame: snap.frame,
-                    brain_stats: snap.brain_stats,
-                    storage: snap.storage,
-                }
+                        },
+                        ApiEndpoint {
+                            request: "StreamState".to_string(),
+                            input: "{ interval_ms }".to_string(),
+                            output: "{ type: Success } then { type: State, ... } every interval_ms"
+                                .to_string(),
+                            description: "Push state snapshots on this connection until StopStream or disconnect (interval clamped to 16..60000 ms)."
+                                .to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "BatchRequests".to_string(),
+                            input: "{ requests: [Request, ...] }".to_string(),
+                            output: "{ type: BatchResults, results: [Response, ...], batch_had_errors }"
+                                .to_string(),
+                            description: "Run several requests in order under one state lock; failures do not abort the batch."
+                                .to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "StopStream".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: Success }".to_string(),
+                            description: "Stop a StreamState subscription on this connection."
+                                .to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "WatchDiag".to_string(),
+                            input: "{ every_frames }".to_string(),
+                            output: "{ type: Success } then { type: Diagnostics, running, frame, brain_stats, storage } every every_frames frames"
+                                .to_string(),
+                            description: "Push diagnostics on this connection from the game loop until UnwatchDiag or disconnect (every_frames is at least 1)."
+                                .to_string(),
+                        },
+                        ApiEndpoint {
+                            request: "UnwatchDiag".to_string(),
+                            input: "{}".to_string(),
+                            output: "{ type: Success }".to_string(),
+                            description: "Stop a WatchDiag subscription on this connection."
+                                .to_string(),
+                        },
+                    ],
+                },
+            ],
+        },
+        Request::CfgSet {
+            exploration_eps,
+            meaning_alpha,
+            reward_symbol_threshold,
+            concept_validate_threshold,
+            target_fps,
+            trial_period_ms,
+            max_units,
+            intrinsic_reward_scale,
+            causal_window_frames,
+            experimental_flags,
+        } => {
+            if let Some(v) = exploration_eps {
+                s.exploration_eps = v.clamp(0.0, 1.0);
             }
-            Request::CfgGet => {
-                let s = state.read().await;
-                let cfg = s.brain.config();
-                Response::Config {
-                    exploration_eps: s.exploration_eps,
-                    meaning_alpha: s.meaning_alpha,
-                    reward_symbol_threshold: cfg.reward_symbol_threshold,
-                    concept_validate_threshold: cfg.concept_validate_threshold,
-                    target_fps: s.target_fps,
-                    trial_period_ms: s.trial_period_ms,
-                    max_units_limit: s.max_units_limit as u32,
-                }
+            if let Some(v) = meaning_alpha {
+                s.meaning_alpha = v.clamp(0.0, 50.0);
             }
-            Request::CfgSet {
-                exploration_eps,
-                meaning_alpha,
-                reward_symbol_threshold,
-                concept_validate_threshold,
-                target_fps,
-                trial_period_ms,
-                max_units,
-            } => {
-                let mut s = state.write().await;
+            if reward_symbol_threshold.is_some() || concept_validate_threshold.is_some() {
+                let r = reward_symbol_threshold;
+                let c = concept_validate_threshold;
+                let _ = s.brain.update_config(|cfg| {
+                    if let Some(v) = r {
+                        cfg.reward_symbol_threshold = v.clamp(0.0, 1.0);
+                    }
+                    if let Some(v) = c {
+                        cfg.concept_validate_threshold = v.clamp(0.0, 1.0);
+                    }
+                });
+            }
+            if let Some(v) = target_fps {
+                s.target_fps = v.clamp(1, 240);
+            }
+            if let Some(v) = trial_period_ms {
+                let ms = v.clamp(10, 10_000);
+                s.trial_period_ms = ms;
+                s.game.update_timing(ms);
+            }
+            if let Some(max_units) = max_units {
+                let requested = max_units as usize;
+                let current_units = s.brain.unit_count();
+                s.max_units_limit = requested.clamp(current_units, 4096);
+            }
+            if let Some(v) = intrinsic_reward_scale {
+                s.intrinsic_reward_scale = v.clamp(0.0, 1.0);
+            }
+            if let Some(v) = causal_window_frames {
+                s.brain.set_causal_window(v);
+            }
+            let flags_result = match experimental_flags {
+                Some(flags) => s
+                    .brain
+                    .update_config(|cfg| cfg.experimental_flags = flags),
+                None => Ok(()),
+            };
 
-                if let Some(v) = exploration_eps {
-                    s.exploration_eps = v.clamp(0.0, 1.0);
+            match flags_result {
+                Ok(()) => Response::Success {
+                    message: "Config updated".to_string(),
+                },
+                Err(e) => Response::Error {
+                    message: e.to_string(),
+                },
+            }
+        }
+
+        Request::GatesClear => {
+            s.brain.clear_gates();
+            Response::Success {
+                message: "Gates cleared".to_string(),
+            }
+        }
+        Request::GatesSet {
+            target,
+            ids,
+            gate,
+            enabled,
+        } => {
+            let target = target.to_ascii_lowercase();
+            let gate = gate.to_ascii_lowercase();
+
+            let is_unit = target == "unit" || target == "units";
+            let is_module = target == "module" || target == "modules";
+
+            let is_freeze = gate == "freeze" || gate == "frozen";
+            let is_paralyze = gate == "paralyze" || gate == "paralyzed";
+
+            if !(is_unit || is_module) {
+                Response::Error {
+                    message: "GatesSet.target must be 'unit' or 'module'".to_string(),
                 }
-                if let Some(v) = meaning_alpha {
-                    s.meaning_alpha = v.clamp(0.0, 50.0);
+            } else if !(is_freeze || is_paralyze) {
+                Response::Error {
+                    message: "GatesSet.gate must be 'freeze' or 'paralyze'".to_string(),
                 }
-                if reward_symbol_threshold.is_some() || concept_validate_threshold.is_some() {
-                    let r = reward_symbol_threshold;
-                    let c = concept_validate_threshold;
-                    let _ = s.brain.update_config(|cfg| {
-                        if let Some(v) = r {
-                            cfg.reward_symbol_threshold = v.clamp(0.0, 1.0);
-                        }
-                        if let Some(v) = c {
-                            cfg.concept_validate_threshold = v.clamp(0.0, 1.0);
-                        }
-                    });
+            } else if is_unit {
+                for id in ids {
+                    let u = id as usize;
+                    if is_freeze {
+                        s.brain.set_unit_frozen(u, enabled);
+                    } else {
+                        s.brain.set_unit_paralyzed(u, enabled);
+                    }
                 }
-                if let Some(v) = target_fps {
-                    s.target_fps = v.clamp(1, 240);
+                Response::Success {
+                    message: "Gates updated".to_string(),
                 }
-                if let Some(v) = trial_period_ms {
-                    let ms = v.clamp(10, 10_000);
-                    s.trial_period_ms = ms;
-                    s.game.update_timing(ms);
+            } else if ids.iter().any(|&id| id > u16::MAX as u32) {
+                Response::Error {
+                    message: "module id out of range (must fit u16)".to_string(),
                 }
-                if let Some(max_units) = max_units {
-                    let requested = max_units as usize;
-                    let current_units = s.brain.diagnostics().unit_count;
-                    s.max_units_limit = requested.clamp(current_units, 4096);
+            } else {
+                for id in ids {
+                    let mid = id as u16;
+                    if is_freeze {
+                        s.brain.set_module_frozen(mid, enabled);
+                    } else {
+                        s.brain.set_module_paralyzed(mid, enabled);
+                    }
                 }
-
                 Response::Success {
-                    message: "Config updated".to_string(),
+                    message: "Gates updated".to_string(),
                 }
             }
-
-            Request::GatesGetModules => {
-                let mut s = state.write().await;
-                Response::GatesModules {
-                    modules: s.brain.routing_modules_summary(),
-                }
-            }
-            Request::GatesClear => {
-                let mut s = state.write().await;
-                s.brain.clear_gates();
-                Response::Success {
-                    message: "Gates cleared".to_string(),
+        }
+        Request::SyncApplyDelta {
+            delta,
+            delta_max,
+            expected_weights_len,
+            expected_fingerprint,
+            autosave,
+            mask_kind,
+        } => {
+            let mask = match mask_kind.as_deref() {
+                None => Ok(None),
+                Some("action") => Ok(Some(s.brain.action_unit_mask())),
+                Some("sensor") => Ok(Some(s.brain.sensor_unit_mask())),
+                Some("not_action") => Ok(Some(s.brain.non_action_unit_mask())),
+                Some("not_sensor") => Ok(Some(s.brain.non_sensor_unit_mask())),
+                Some(other) => Err(format!("Sync rejected: unknown mask_kind '{other}'")),
+            };
+            let weights_len = s.brain.weights_len() as u32;
+            if let Err(message) = mask {
+                Response::Error { message }
+            } else if weights_len != expected_weights_len {
+                Response::Error {
+                    message: format!(
+                        "Sync rejected: weights_len mismatch (local={weights_len}, expected={expected_weights_len})"
+                    ),
                 }
-            }
-            Request::GatesSet {
-                target,
-                ids,
-                gate,
-                enabled,
-            } => {
-                let mut s = state.write().await;
-
-                let target = target.to_ascii_lowercase();
-                let gate = gate.to_ascii_lowercase();
-
-                let is_unit = target == "unit" || target == "units";
-                let is_module = target == "module" || target == "modules";
-
-                let is_freeze = gate == "freeze" || gate == "frozen";
-                let is_paralyze = gate == "paralyze" || gate == "paralyzed";
-
-                if !(is_unit || is_module) {
-                    Response::Error {
-                        message: "GatesSet.target must be 'unit' or 'module'".to_string(),
-                    }
-                } else if !(is_freeze || is_paralyze) {
-                    Response::Error {
-                        message: "GatesSet.gate must be 'freeze' or 'paralyze'".to_string(),
-                    }
-                } else if is_unit {
-                    for id in ids {
-                        let u = id as usize;
-                        if is_freeze {
-                            s.brain.set_unit_frozen(u, enabled);
-                        } else {
-                            s.brain.set_unit_paralyzed(u, enabled);
-                        }
-                    }
-                    Response::Success {
-                        message: "Gates updated".to_string(),
-                    }
-                } else if ids.iter().any(|&id| id > u16::MAX as u32) {
+            } else {
+                let fp = s.brain.connections_fingerprint();
+                if fp != expected_fingerprint {
                     Response::Error {
-                        message: "module id out of range (must fit u16)".to_string(),
+                        message: "Sync rejected: fingerprint mismatch (topology differs)".to_string(),
                     }
                 } else {
-                    for id in ids {
-                        let mid = id as u16;
-                        if is_freeze {
-                            s.brain.set_module_frozen(mid, enabled);
-                        } else {
-                            s.brain.set_module_paralyzed(mid, enabled);
+                    let dm = delta_max.clamp(1.0e-6, 0.5);
+                    let mask = mask.ok().flatten();
+                    let applied_edges =
+                        s.brain.apply_weight_delta_masked(&delta, dm, mask.as_deref()) as u32;
+
+                    let mut saved = false;
+                    let mut save_error: Option<String> = None;
+                    if autosave {
+                        match s.save_brain() {
+                            Ok(()) => saved = true,
+                            Err(e) => {
+                                save_error = Some(e);
+                            }
                         }
                     }
-                    Response::Success {
-                        message: "Gates updated".to_string(),
+
+                    Response::SyncApplied {
+                        applied_edges,
+                        saved,
+                        save_error,
                     }
                 }
             }
-            Request::SyncGetInfo => {
-                let s = state.read().await;
-                Response::SyncInfo {
-                    age_steps: s.brain.age_steps(),
-                    unit_count: s.brain.diagnostics().unit_count as u32,
-                    weights_len: s.brain.weights_len() as u32,
-                    fingerprint: s.brain.connections_fingerprint(),
-                }
-            }
-            Request::SyncApplyDelta {
-                delta,
-                delta_max,
-                expected_weights_len,
-                expected_fingerprint,
-                autosave,
-            } => {
-                let mut s = state.write().await;
+        }
 
-                let weights_len = s.brain.weights_len() as u32;
-                if weights_len != expected_weights_len {
-                    Response::Error {
-                        message: format!(
-                            "Sync rejected: weights_len mismatch (local={weights_len}, expected={expected_weights_len})"
-                        ),
-                    }
-                } else {
-                    let fp = s.brain.connections_fingerprint();
-                    if fp != expected_fingerprint {
-                        Response::Error {
-                            message: "Sync rejected: fingerprint mismatch (topology differs)".to_string(),
-                        }
-                    } else {
-                        let applied_edges = delta.weight_deltas.len() as u32;
-                        let dm = delta_max.clamp(1.0e-6, 0.5);
-                        s.brain.apply_weight_delta(&delta, dm);
-
-                        let mut saved = false;
-                        let mut save_error: Option<String> = None;
-                        if autosave {
-                            match s.save_brain() {
-                                Ok(()) => saved = true,
-                                Err(e) => {
-                                    save_error = Some(e);
-                                }
-                            }
-                        }
+        Request::Trial {
+            context_key,
+            stimuli,
+            allowed_actions,
+            forced_action,
+            reward,
+            predicted_reward,
+            learn,
+            steps,
+            meaning_alpha,
+        } => {
+            if s.running {
+                Response::Error {
+                    message: "Trial requires the daemon to be stopped (running=false)".to_string(),
+                }
+            } else {
+                // Apply stimuli for this trial.
+                for st in &stimuli {
+                    s.brain
+                        .apply_stimulus(Stimulus::new(st.name.as_str(), st.strength));
+                }
 
-                        Response::SyncApplied {
-                            applied_edges,
-                            saved,
-                            save_error,
-                        }
-                    }
+                // Advance dynamics a bit (no learning occurs inside step; learning happens on commit_observation).
+                let steps = steps.clamp(0, 64);
+                for _ in 0..steps {
+                    s.brain.step();
                 }
-            }
 
-            Request::Trial {
-                context_key,
-                stimuli,
-                allowed_actions,
-                forced_action,
-                reward,
-                learn,
-                steps,
-                meaning_alpha,
-            } => {
-                let mut s = state.write().await;
+                let alpha = meaning_alpha.unwrap_or(s.meaning_alpha).clamp(0.0, 20.0);
 
-                if s.running {
-                    Response::Error {
-                        message: "Trial requires the daemon to be stopped (running=false)".to_string(),
-                    }
-                } else {
-                    // Apply stimuli for this trial.
-                    for st in &stimuli {
-                        s.brain
-                            .apply_stimulus(Stimulus::new(st.name.as_str(), st.strength));
-                    }
+                let breakdown = s.brain.action_score_breakdown(&context_key, alpha);
 
-                    // Advance dynamics a bit (no learning occurs inside step; learning happens on commit_observation).
-                    let steps = steps.clamp(0, 64);
-                    for _ in 0..steps {
-                        s.brain.step();
+                let pick_best_allowed = |allowed: &[String], all: &[ActionScoreBreakdown]| {
+                    if allowed.is_empty() {
+                        return all
+                            .iter()
+                            .max_by(|a, b| {
+                                a.score
+                                    .partial_cmp(&b.score)
+                                    .unwrap_or(core::cmp::Ordering::Equal)
+                            })
+                            .map(|b| (b.name.clone(), b.score));
                     }
 
-                    let alpha = meaning_alpha.unwrap_or(s.meaning_alpha).clamp(0.0, 20.0);
-
-                    let breakdown = s.brain.action_score_breakdown(&context_key, alpha);
-
-                    let pick_best_allowed = |allowed: &[String], all: &[ActionScoreBreakdown]| {
-                        if allowed.is_empty() {
-                            return all
-                                .iter()
-                                .max_by(|a, b| {
-                                    a.score
-                                        .partial_cmp(&b.score)
-                                        .unwrap_or(core::cmp::Ordering::Equal)
-                                })
-                                .map(|b| (b.name.clone(), b.score));
-                        }
-
-                        let mut best: Option<(String, f32)> = None;
-                        for a in allowed {
-                            if let Some(b) = all.iter().find(|x| x.name == *a) {
-                                let cand = (b.name.clone(), b.score);
-                                if best
-                                    .as_ref()
-                                    .map(|bb| cand.1 > bb.1)
-                                    .unwrap_or(true)
-                                {
-                                    best = Some(cand);
-                                }
+                    let mut best: Option<(String, f32)> = None;
+                    for a in allowed {
+                        if let Some(b) = all.iter().find(|x| x.name == *a) {
+                            let cand = (b.name.clone(), b.score);
+                            if best
+                                .as_ref()
+                                .map(|bb| cand.1 > bb.1)
+                                .unwrap_or(true)
+                            {
+                                best = Some(cand);
                             }
                         }
-                        best
-                    };
-
-                    let (action, score) = if let Some(forced) = forced_action {
-                        let sc = breakdown
-                            .iter()
-                            .find(|b| b.name == forced)
-                            .map(|b| b.score)
-                            .unwrap_or(0.0);
-                        (forced, sc)
-                    } else if let Some((a, sc)) = pick_best_allowed(&allowed_actions, &breakdown) {
-                        (a, sc)
-                    } else {
-                        ("idle".to_string(), 0.0)
-                    };
-
-                    // Record boundary symbols and apply reward.
-                    s.brain.note_action(action.as_str());
-                    s.brain
-                        .note_compound_symbol(&["pair", context_key.as_str(), action.as_str()]);
-                    s.brain.set_neuromodulator(reward);
-
-                    if learn {
-                        s.brain.reinforce_action(action.as_str(), reward);
-                        s.brain.commit_observation();
-                    } else {
-                        s.brain.discard_observation();
                     }
+                    best
+                };
 
-                    Response::TrialResult {
-                        action,
-                        score,
-                        reward,
-                        learned: learn,
+                let (action, score) = if let Some(forced) = forced_action {
+                    let sc = breakdown
+                        .iter()
+                        .find(|b| b.name == forced)
+                        .map(|b| b.score)
+                        .unwrap_or(0.0);
+                    (forced, sc)
+                } else if let Some((a, sc)) = pick_best_allowed(&allowed_actions, &breakdown) {
+                    (a, sc)
+                } else {
+                    ("idle".to_string(), 0.0)
+                };
+
+                // Record boundary symbols and apply reward.
+                s.brain.note_action(action.as_str());
+                s.brain
+                    .note_compound_symbol(&["pair", context_key.as_str(), action.as_str()]);
+                let learn_signal = match predicted_reward {
+                    Some(p) => s.brain.reward_prediction_error(p, reward),
+                    None => {
+                        s.brain.set_neuromodulator(reward);
+                        reward
                     }
-                }
-            }
-            Request::InferActionScores {
-                context_key,
-                stimuli,
-                steps,
-                meaning_alpha,
-            } => {
-                let s = state.read().await;
-                let context_key = context_key.unwrap_or_else(|| s.current_stimulus_key().into_owned());
-                let alpha = meaning_alpha.unwrap_or(s.meaning_alpha).clamp(0.0, 50.0);
+                };
 
-                let mut brain = s.view_brain_for_context(&context_key).clone();
-                for stim in &stimuli {
-                    brain.apply_stimulus_inference(stim.as_stimulus());
-                }
-                let steps = steps.clamp(1, 64);
-                for _ in 0..steps {
-                    brain.step_inference();
+                if learn {
+                    s.brain.reinforce_action(action.as_str(), learn_signal);
+                    let priority = commit_priority(reward, s.brain.intrinsic_reward_estimate());
+                    s.brain.commit_observation_with_priority(priority);
+                } else {
+                    s.brain.discard_observation();
                 }
 
-                let action_scores = brain.action_score_breakdown(&context_key, alpha);
-
-                Response::InferActionScores {
-                    context_key,
-                    action_scores,
+                Response::TrialResult {
+                    action,
+                    score,
+                    reward,
+                    learned: learn,
                 }
             }
-            Request::GetState => {
-                let s = state.read().await;
-                Response::State(Box::new(s.get_snapshot()))
+        }
+        Request::AdvisorSet {
+            enabled,
+            every_trials,
+            mode,
+        } => {
+            s.advisor.set_enabled(enabled);
+            if let Some(n) = every_trials {
+                s.advisor.set_every_trials(n);
             }
-
-            Request::AdvisorGet => {
-                let s = state.read().await;
-                Response::AdvisorStatus {
-                    config: s.advisor.status(),
-                    last_report: s.advisor.last_report.clone(),
-                }
+            if let Some(m) = mode {
+                s.advisor.set_mode(m);
             }
-
-            Request::AdvisorSet {
-                enabled,
-                every_trials,
-                mode,
-            } => {
-                let mut s = state.write().await;
-                s.advisor.set_enabled(enabled);
-                if let Some(n) = every_trials {
-                    s.advisor.set_every_trials(n);
-                }
-                if let Some(m) = mode {
-                    s.advisor.set_mode(m);
-                }
-                Response::Success {
-                    message: "Advisor config updated".to_string(),
-                }
+            Response::Success {
+                message: "Advisor config updated".to_string(),
             }
+        }
 
-            Request::AdvisorOnce { apply } => {
-                let mut s = state.write().await;
-                let trials = s.game.stats().trials;
-                let context_key = s.current_stimulus_key();
-                let text_regime = match &s.game {
-                    ActiveGame::Text(g) => Some(g.regime()),
-                    _ => None,
-                };
+        Request::AdvisorOnce { apply } => {
+            let trials = s.game.stats().trials;
+            let context_key = s.current_stimulus_key();
+            let text_regime = match &s.game {
+                ActiveGame::Text(g) => Some(g.regime()),
+                _ => None,
+            };
 
-                let ctx = advisor::AdvisorContext {
-                    game: s.game.kind().to_string(),
-                    context_key: context_key.into_owned(),
-                    trials,
-                    accuracy: s.game.stats().accuracy(),
-                    recent_rate: s.game.stats().recent_rate(),
-                    last_reward: s.last_reward,
-                    exploration_eps: s.exploration_eps,
-                    meaning_alpha: s.meaning_alpha,
-                    text_regime,
-                };
+            let ctx = advisor::AdvisorContext {
+                game: s.game.kind().to_string(),
+                context_key: context_key.into_owned(),
+                trials,
+                accuracy: s.game.stats().accuracy(),
+                recent_rate: s.game.stats().recent_rate(),
+                last_reward: s.last_reward,
+                exploration_eps: s.exploration_eps,
+                meaning_alpha: s.meaning_alpha,
+                td_prediction_error_ema: s.brain.learning_stats().td_prediction_error_ema,
+                text_regime,
+                causal_graph_json: None,
+                action_meaning: Vec::new(),
+                pair_meaning: Vec::new(),
+            };
 
-                let report = s.advisor.invoke(ctx, trials, apply);
-                if apply {
-                    s.apply_advice(&report.advice);
-                }
-                Response::AdvisorReport {
-                    report,
-                    applied: apply,
-                }
+            let report = s.advisor.invoke(ctx, trials, apply);
+            if apply {
+                s.apply_advice(&report.advice);
             }
+            Response::AdvisorReport {
+                report,
+                applied: apply,
+            }
+        }
 
-            Request::AdvisorContext {
-                include_action_scores,
-            } => {
-                let s = state.read().await;
-                let trials = s.game.stats().trials;
-                let context_key = s.current_stimulus_key();
-                let text_regime = match &s.game {
-                    ActiveGame::Text(g) => Some(g.regime()),
-                    _ => None,
-                };
+        Request::AdvisorApply { advice } => {
+            let trials = s.game.stats().trials;
+            let context_key = s.current_stimulus_key();
+            let text_regime = match &s.game {
+                ActiveGame::Text(g) => Some(g.regime()),
+                _ => None,
+            };
 
-                let ctx = advisor::AdvisorContext {
-                    game: s.game.kind().to_string(),
-                    context_key: context_key.clone().into_owned(),
-                    trials,
-                    accuracy: s.game.stats().accuracy(),
-                    recent_rate: s.game.stats().recent_rate(),
-                    last_reward: s.last_reward,
-                    exploration_eps: s.exploration_eps,
-                    meaning_alpha: s.meaning_alpha,
-                    text_regime,
-                };
+            let ctx = advisor::AdvisorContext {
+                game: s.game.kind().to_string(),
+                context_key: context_key.into_owned(),
+                trials,
+                accuracy: s.game.stats().accuracy(),
+                recent_rate: s.game.stats().recent_rate(),
+                last_reward: s.last_reward,
+                exploration_eps: s.exploration_eps,
+                meaning_alpha: s.meaning_alpha,
+                td_prediction_error_ema: s.brain.learning_stats().td_prediction_error_ema,
+                text_regime,
+                causal_graph_json: None,
+                action_meaning: Vec::new(),
+                pair_meaning: Vec::new(),
+            };
 
-                let action_scores = if include_action_scores {
-                    let brain = s.view_brain_for_context(&context_key);
-                    brain.action_score_breakdown(&context_key, s.meaning_alpha)
-                } else {
-                    Vec::new()
-                };
+            // Clamp + apply. This is the explicit LLM boundary.
+            s.apply_advice(&advice);
 
-                Response::AdvisorContext {
-                    context: ctx,
-                    action_scores,
-                }
-            }
+            // Record for visibility, even when advice did not originate from the built-in stub.
+            s.advisor.last_report = Some(advisor::AdvisorReport {
+                at_trials: trials,
+                applied: true,
+                context: ctx,
+                advice,
+            });
 
-            Request::AdvisorApply { advice } => {
-                let mut s = state.write().await;
-                let trials = s.game.stats().trials;
-                let context_key = s.current_stimulus_key();
-                let text_regime = match &s.game {
-                    ActiveGame::Text(g) => Some(g.regime()),
-                    _ => None,
-                };
+            Response::Success {
+                message: "Advisor advice applied".to_string(),
+            }
+        }
 
-                let ctx = advisor::AdvisorContext {
-                    game: s.game.kind().to_string(),
-                    context_key: context_key.into_owned(),
-                    trials,
-                    accuracy: s.game.stats().accuracy(),
-                    recent_rate: s.game.stats().recent_rate(),
-                    last_reward: s.last_reward,
-                    exploration_eps: s.exploration_eps,
-                    meaning_alpha: s.meaning_alpha,
-                    text_regime,
+        Request::ReplaySetDataset {
+            dataset,
+            csv_base64,
+        } => {
+            if s.running {
+                return Response::Error {
+                    message: "Stop the simulation before setting replay dataset".to_string(),
                 };
-
-                // Clamp + apply. This is the explicit LLM boundary.
-                s.apply_advice(&advice);
-
-                // Record for visibility, even when advice did not originate from the built-in stub.
-                s.advisor.last_report = Some(advisor::AdvisorReport {
-                    at_trials: trials,
-                    applied: true,
-                    context: ctx,
-                    advice,
-                });
-
-                Response::Success {
-                    message: "Advisor advice applied".to_string(),
+            }
+            let dataset = match (dataset, csv_base64) {
+                (Some(d), None) => d,
+                (None, Some(b64)) => match decode_csv_dataset(&b64) {
+                    Ok(d) => d,
+                    Err(message) => return Response::Error { message },
+                },
+                _ => {
+                    return Response::Error {
+                        message: "ReplaySetDataset needs exactly one of dataset or csv_base64"
+                            .to_string(),
+                    }
                 }
+            };
+            s.replay_dataset = dataset;
+
+            // Keep I/O sizes in sync, and reset replay game if currently active.
+            s.ensure_replay_io();
+            if matches!(s.game, ActiveGame::Replay(_)) {
+                let gg = ReplayGame::new(s.replay_dataset.clone());
+                s.game = ActiveGame::Replay(gg);
+                s.pending_neuromod = 0.0;
+                s.last_reward = 0.0;
             }
 
-            Request::ReplayGetDataset => {
-                let s = state.read().await;
-                Response::ReplayDataset {
-                    dataset: s.replay_dataset.clone(),
+            Response::Success {
+                message: "Replay dataset updated".to_string(),
+            }
+        }
+        Request::SetView { view } => {
+            match BrainViewMode::parse(&view) {
+                Some(m) => {
+                    s.view_mode = m;
+                    Response::Success {
+                        message: format!("View set to {}", view.trim()),
+                    }
                 }
+                None => Response::Error {
+                    message: format!(
+                        "Unknown view '{}'. Use parent|active_expert",
+                        view.trim()
+                    ),
+                },
             }
+        }
 
-            Request::ReplaySetDataset { dataset } => {
-                let mut s = state.write().await;
-                if s.running {
-                    Response::Error {
-                        message: "Stop the simulation before setting replay dataset".to_string(),
+        Request::SetMaxUnits { max_units } => {
+            let requested = max_units as usize;
+            // Clamp to a sane range; cannot be set below current parent unit count.
+            let current_units = s.brain.unit_count();
+            let clamped = requested.clamp(current_units, 4096);
+            s.max_units_limit = clamped;
+            Response::Success {
+                message: format!("Max units limit set to {}", clamped),
+            }
+        }
+        Request::SetNeurogenesisPolicy {
+            policy_name,
+            params,
+        } => match parse_neurogenesis_policy(&policy_name, params) {
+            Ok(policy) => {
+                let message = format!("Neurogenesis policy set to {policy:?}");
+                s.neurogenesis_policy = policy;
+                Response::Success { message }
+            }
+            Err(message) => Response::Error { message },
+        },
+        Request::SetPruningPolicy {
+            policy_name,
+            params,
+        } => match parse_pruning_policy(&policy_name, params)
+            .and_then(|policy| s.brain.set_pruning_policy(policy).map_err(str::to_string))
+        {
+            Ok(()) => Response::Success {
+                message: format!("Pruning policy set to {:?}", s.brain.pruning_policy()),
+            },
+            Err(message) => Response::Error { message },
+        },
+        Request::InjectNoise { pattern } => match s.brain.apply_structured_noise(&pattern) {
+            Ok(n) => Response::Success {
+                message: format!("Noise injected into {n} units"),
+            },
+            Err(message) => Response::Error { message },
+        },
+
+        Request::SaveSnapshot => {
+            match s.save_snapshot() {
+                Ok(stem) => Response::Success {
+                    message: format!("Snapshot saved ({stem})"),
+                },
+                Err(e) => Response::Error { message: e },
+            }
+        }
+
+        Request::LoadSnapshot { stem } => {
+            match s.load_snapshot(stem.trim()) {
+                Ok(_) => Response::Success {
+                    message: format!("Snapshot loaded ({})", stem.trim()),
+                },
+                Err(e) => Response::Error { message: e },
+            }
+        }
+        Request::Start => {
+            // Prime weight storage before the first tick after a resume.
+            s.brain.warm_cache();
+            s.running = true;
+            Response::Success {
+                message: "Started".to_string(),
+            }
+        }
+        Request::Stop => {
+            s.running = false;
+            // Persist on explicit stop to keep online-learned memory
+            match s.save_brain() {
+                Ok(_) => Response::Success {
+                    message: "Stopped and saved".to_string(),
+                },
+                Err(e) => Response::Error {
+                    message: format!("Stopped but save failed: {}", e),
+                },
+            }
+        }
+        Request::GetGameParams { game } => {
+            let game = game.trim();
+
+            // Shared reward knob for all games.
+            // Applies as: reward = clamp(raw_reward * reward_scale, -1..1).
+            let reward_scale_def = || GameParamDef {
+                key: "reward_scale".to_string(),
+                label: "Reward scale".to_string(),
+                description: "Scales reward before applying neuromodulation/learning (0..10)."
+                    .to_string(),
+                min: 0.0,
+                max: 10.0,
+                default: 1.0,
+            };
+
+            // The daemon is the source of truth for knob definitions.
+            match game {
+                "pong" => {
+                    // Keep these in sync with `PongGame::set_param` clamping.
+                    const PONG_PADDLE_SPEED_MIN: f32 = 0.1;
+                    const PONG_PADDLE_SPEED_MAX: f32 = 5.0;
+
+                    const PONG_BALL_SPEED_MIN: f32 = 0.1;
+                    const PONG_BALL_SPEED_MAX: f32 = 3.0;
+
+                    const PONG_PADDLE_HALF_HEIGHT_MIN: f32 = 0.05;
+                    const PONG_PADDLE_HALF_HEIGHT_MAX: f32 = 0.9;
+
+                    let defaults = braine_games::pong::PongParams::default();
+
+                    Response::GameParams {
+                        game: "pong".to_string(),
+                        params: vec![
+                            reward_scale_def(),
+                            GameParamDef {
+                                key: "paddle_speed".to_string(),
+                                label: "Paddle speed".to_string(),
+                                description: "Paddle movement speed (units per second)."
+                                    .to_string(),
+                                min: PONG_PADDLE_SPEED_MIN,
+                                max: PONG_PADDLE_SPEED_MAX,
+                                default: defaults.paddle_speed,
+                            },
+                            GameParamDef {
+                                key: "ball_speed".to_string(),
+                                label: "Ball speed".to_string(),
+                                description: "Ball movement speed multiplier.".to_string(),
+                                min: PONG_BALL_SPEED_MIN,
+                                max: PONG_BALL_SPEED_MAX,
+                                default: defaults.ball_speed,
+                            },
+                            GameParamDef {
+                                key: "paddle_half_height".to_string(),
+                                label: "Paddle height".to_string(),
+                                description:
+                                    "Half-height of paddle as a fraction of playfield height."
+                                        .to_string(),
+                                min: PONG_PADDLE_HALF_HEIGHT_MIN,
+                                max: PONG_PADDLE_HALF_HEIGHT_MAX,
+                                default: defaults.paddle_half_height,
+                            },
+                        ],
                     }
-                } else {
-                    s.replay_dataset = dataset;
-
-                    // Keep I/O sizes in sync, and reset replay game if currently active.
-                    s.ensure_replay_io();
-                    if matches!(s.game, ActiveGame::Replay(_)) {
-                        let gg = ReplayGame::new(s.replay_dataset.clone());
-                        s.game = ActiveGame::Replay(gg);
-                        s.pending_neuromod = 0.0;
-                        s.last_reward = 0.0;
+                }
+                "maze" => {
+                    Response::GameParams {
+                        game: "maze".to_string(),
+                        params: vec![
+                            reward_scale_def(),
+                            GameParamDef {
+                                key: "difficulty".to_string(),
+                                label: "Difficulty".to_string(),
+                                description: "0=easy, 1=medium, 2=hard".to_string(),
+                                min: 0.0,
+                                max: 2.0,
+                                default: 0.0,
+                            },
+                            GameParamDef {
+                                key: "episodes_per_maze".to_string(),
+                                label: "Episodes per maze".to_string(),
+                                description: "How many episodes to run before regenerating a new maze layout. Higher values reduce non-stationarity and usually learn faster.".to_string(),
+                                min: 1.0,
+                                max: 1000.0,
+                                default: 8.0,
+                            },
+                        ],
                     }
-
-                    Response::Success {
-                        message: "Replay dataset updated".to_string(),
+                }
+                "gridworld" => {
+                    // Keep these in sync with `GridWorldGame` clamping.
+                    use braine_games::gridworld::GridWorldParams;
+                    let defaults = GridWorldParams::default();
+
+                    Response::GameParams {
+                        game: "gridworld".to_string(),
+                        params: vec![
+                            reward_scale_def(),
+                            GameParamDef {
+                                key: "grid_size".to_string(),
+                                label: "Grid size".to_string(),
+                                description: "Grid dimension (NxN cells). Changing it regenerates the layout."
+                                    .to_string(),
+                                min: GridWorldParams::MIN_GRID_SIZE as f32,
+                                max: GridWorldParams::MAX_GRID_SIZE as f32,
+                                default: defaults.grid_size as f32,
+                            },
+                            GameParamDef {
+                                key: "wall_density".to_string(),
+                                label: "Wall density".to_string(),
+                                description: "Fraction of cells that are obstacles. Changing it regenerates the layout."
+                                    .to_string(),
+                                min: 0.0,
+                                max: GridWorldParams::MAX_WALL_DENSITY,
+                                default: defaults.wall_density,
+                            },
+                        ],
                     }
                 }
-            }
-            Request::SetView { view } => {
-                let mut s = state.write().await;
-                match BrainViewMode::parse(&view) {
-                    Some(m) => {
-                        s.view_mode = m;
-                        Response::Success {
-                            message: format!("View set to {}", view.trim()),
-                        }
+                "n_back" => {
+                    // Keep these in sync with `NBackGame` clamping.
+                    Response::GameParams {
+                        game: "n_back".to_string(),
+                        params: vec![
+                            reward_scale_def(),
+                            GameParamDef {
+                                key: "n".to_string(),
+                                label: "N".to_string(),
+                                description: "How many trials back the current token is compared against."
+                                    .to_string(),
+                                min: NBackGame::MIN_N as f32,
+                                max: NBackGame::MAX_N as f32,
+                                default: 2.0,
+                            },
+                            GameParamDef {
+                                key: "vocab_size".to_string(),
+                                label: "Vocabulary size".to_string(),
+                                description: "Number of distinct tokens in the stream.".to_string(),
+                                min: NBackGame::MIN_VOCAB as f32,
+                                max: NBackGame::MAX_VOCAB as f32,
+                                default: 4.0,
+                            },
+                        ],
+                    }
+                }
+                "conditional_discrimination" => {
+                    // Keep these in sync with `ConditionalDiscriminationGame` clamping.
+                    Response::GameParams {
+                        game: "conditional_discrimination".to_string(),
+                        params: vec![
+                            reward_scale_def(),
+                            GameParamDef {
+                                key: "switch_every".to_string(),
+                                label: "Context switch every (trials)".to_string(),
+                                description: "Trials before the active context flips between ctx_A and ctx_B."
+                                    .to_string(),
+                                min: ConditionalDiscriminationGame::MIN_SWITCH_EVERY as f32,
+                                max: ConditionalDiscriminationGame::MAX_SWITCH_EVERY as f32,
+                                default: 50.0,
+                            },
+                        ],
+                    }
+                }
+                "temporal_xor" => {
+                    // Keep these in sync with `TemporalXORGame` clamping.
+                    Response::GameParams {
+                        game: "temporal_xor".to_string(),
+                        params: vec![
+                            reward_scale_def(),
+                            GameParamDef {
+                                key: "iti_steps".to_string(),
+                                label: "Inter-trial interval (ticks)".to_string(),
+                                description: "Blank ticks between trials; the previous stimulus must be held across them."
+                                    .to_string(),
+                                min: TemporalXORGame::MIN_ITI_STEPS as f32,
+                                max: TemporalXORGame::MAX_ITI_STEPS as f32,
+                                default: 3.0,
+                            },
+                        ],
+                    }
+                }
+                "match_to_sample" => {
+                    // Keep these in sync with `MatchToSampleGame` clamping.
+                    Response::GameParams {
+                        game: "match_to_sample".to_string(),
+                        params: vec![
+                            reward_scale_def(),
+                            GameParamDef {
+                                key: "sample_steps".to_string(),
+                                label: "Sample (ticks)".to_string(),
+                                description: "Ticks the sample token is shown.".to_string(),
+                                min: MatchToSampleGame::MIN_SAMPLE_STEPS as f32,
+                                max: MatchToSampleGame::MAX_SAMPLE_STEPS as f32,
+                                default: 3.0,
+                            },
+                            GameParamDef {
+                                key: "delay_steps".to_string(),
+                                label: "Delay (ticks)".to_string(),
+                                description: "Blank ticks between sample and test; accuracy is tracked per value."
+                                    .to_string(),
+                                min: MatchToSampleGame::MIN_DELAY_STEPS as f32,
+                                max: MatchToSampleGame::MAX_DELAY_STEPS as f32,
+                                default: 5.0,
+                            },
+                            GameParamDef {
+                                key: "test_steps".to_string(),
+                                label: "Test window (ticks)".to_string(),
+                                description: "Ticks the test token stays up waiting for match/no_match."
+                                    .to_string(),
+                                min: MatchToSampleGame::MIN_TEST_STEPS as f32,
+                                max: MatchToSampleGame::MAX_TEST_STEPS as f32,
+                                default: 5.0,
+                            },
+                            GameParamDef {
+                                key: "vocab_size".to_string(),
+                                label: "Vocabulary size".to_string(),
+                                description: "Distinct tokens (A, B, ...). Changing it resets stats."
+                                    .to_string(),
+                                min: MatchToSampleGame::MIN_VOCAB as f32,
+                                max: MatchToSampleGame::MAX_VOCAB as f32,
+                                default: 4.0,
+                            },
+                        ],
                     }
-                    None => Response::Error {
-                        message: format!(
-                            "Unknown view '{}'. Use parent|active_expert",
-                            view.trim()
-                        ),
-                    },
                 }
-            }
-
-            Request::SetMaxUnits { max_units } => {
-                let mut s = state.write().await;
-                let requested = max_units as usize;
-                // Clamp to a sane range; cannot be set below current parent unit count.
-                let current_units = s.brain.diagnostics().unit_count;
-                let clamped = requested.clamp(current_units, 4096);
-                s.max_units_limit = clamped;
-                Response::Success {
-                    message: format!("Max units limit set to {}", clamped),
+                "spotxy" => {
+                    // SpotXY grid range: 0 (binary mode) or 2..=8 grid.
+                    Response::GameParams {
+                        game: "spotxy".to_string(),
+                        params: vec![
+                            reward_scale_def(),
+                            GameParamDef {
+                                key: "grid_n".to_string(),
+                                label: "Grid size".to_string(),
+                                description:
+                                    "Grid dimension (0 = binary left/right; 2-8 = NxN grid)."
+                                        .to_string(),
+                                min: 0.0,
+                                max: 8.0,
+                                default: 0.0,
+                            },
+                            GameParamDef {
+                                key: "eval".to_string(),
+                                label: "Eval mode".to_string(),
+                                description: "Holdout/evaluation mode (0=train, 1=eval)."
+                                    .to_string(),
+                                min: 0.0,
+                                max: 1.0,
+                                default: 0.0,
+                            },
+                        ],
+                    }
                 }
+                _ => Response::GameParams {
+                    game: game.to_string(),
+                    params: vec![reward_scale_def()],
+                },
             }
-
-            Request::SaveSnapshot => {
-                let s = state.read().await;
-                match s.save_snapshot() {
-                    Ok(stem) => Response::Success {
-                        message: format!("Snapshot saved ({stem})"),
-                    },
-                    Err(e) => Response::Error { message: e },
+        }
+        Request::SetGame {
+            game,
+            reset_causality,
+        } => {
+            if s.running {
+                Response::Error {
+                    message: "Stop the simulation before switching game".to_string(),
                 }
-            }
-
-            Request::LoadSnapshot { stem } => {
-                let mut s = state.write().await;
-                match s.load_snapshot(stem.trim()) {
+            } else {
+                match s.set_game(&game, reset_causality) {
                     Ok(_) => Response::Success {
-                        message: format!("Snapshot loaded ({})", stem.trim()),
+                        message: format!("Game set to {}", game),
                     },
                     Err(e) => Response::Error { message: e },
                 }
             }
-            Request::GetGraph {
-                kind,
-                max_nodes,
-                max_edges,
-                include_isolated,
-            } => {
-                let s = state.read().await;
-                let max_nodes = (max_nodes as usize).clamp(1, 256);
-                let max_edges = (max_edges as usize).clamp(0, 1024);
-                Response::Graph(Box::new(s.get_graph_snapshot(
-                    &kind,
-                    max_nodes,
-                    max_edges,
-                    include_isolated,
-                )))
-            }
-            Request::Start => {
-                let mut s = state.write().await;
-                s.running = true;
-                Response::Success {
-                    message: "Started".to_string(),
-                }
-            }
-            Request::Stop => {
-                let mut s = state.write().await;
-                s.running = false;
-                // Persist on explicit stop to keep online-learned memory
-                match s.save_brain() {
-                    Ok(_) => Response::Success {
-                        message: "Stopped and saved".to_string(),
-                    },
-                    Err(e) => Response::Error {
-                        message: format!("Stopped but save failed: {}", e),
-                    },
-                }
-            }
-            Request::GetGameParams { game } => {
-                let game = game.trim();
-
-                // Shared reward knob for all games.
-                // Applies as: reward = clamp(raw_reward * reward_scale, -1..1).
-                let reward_scale_def = || GameParamDef {
-                    key: "reward_scale".to_string(),
-                    label: "Reward scale".to_string(),
-                    description: "Scales reward before applying neuromodulation/learning (0..10)."
-                        .to_string(),
-                    min: 0.0,
-                    max: 10.0,
-                    default: 1.0,
-                };
+        }
+        Request::SetGameParam { game, key, value } => {
+            let game = game.trim();
+            let key = key.trim();
 
-                // The daemon is the source of truth for knob definitions.
-                match game {
-                    "pong" => {
-                        // Keep these in sync with `PongGame::set_param` clamping.
-                        const PONG_PADDLE_SPEED_MIN: f32 = 0.1;
-                        const PONG_PADDLE_SPEED_MAX: f32 = 5.0;
-
-                        const PONG_BALL_SPEED_MIN: f32 = 0.1;
-                        const PONG_BALL_SPEED_MAX: f32 = 3.0;
-
-                        const PONG_PADDLE_HALF_HEIGHT_MIN: f32 = 0.05;
-                        const PONG_PADDLE_HALF_HEIGHT_MAX: f32 = 0.9;
-
-                        let defaults = braine_games::pong::PongParams::default();
-
-                        Response::GameParams {
-                            game: "pong".to_string(),
-                            params: vec![
-                                reward_scale_def(),
-                                GameParamDef {
-                                    key: "paddle_speed".to_string(),
-                                    label: "Paddle speed".to_string(),
-                                    description: "Paddle movement speed (units per second)."
-                                        .to_string(),
-                                    min: PONG_PADDLE_SPEED_MIN,
-                                    max: PONG_PADDLE_SPEED_MAX,
-                                    default: defaults.paddle_speed,
-                                },
-                                GameParamDef {
-                                    key: "ball_speed".to_string(),
-                                    label: "Ball speed".to_string(),
-                                    description: "Ball movement speed multiplier.".to_string(),
-                                    min: PONG_BALL_SPEED_MIN,
-                                    max: PONG_BALL_SPEED_MAX,
-                                    default: defaults.ball_speed,
-                                },
-                                GameParamDef {
-                                    key: "paddle_half_height".to_string(),
-                                    label: "Paddle height".to_string(),
-                                    description:
-                                        "Half-height of paddle as a fraction of playfield height."
-                                            .to_string(),
-                                    min: PONG_PADDLE_HALF_HEIGHT_MIN,
-                                    max: PONG_PADDLE_HALF_HEIGHT_MAX,
-                                    default: defaults.paddle_half_height,
-                                },
-                            ],
-                        }
-                    }
-                    "maze" => {
-                        Response::GameParams {
-                            game: "maze".to_string(),
-                            params: vec![
-                                reward_scale_def(),
-                                GameParamDef {
-                                    key: "difficulty".to_string(),
-                                    label: "Difficulty".to_string(),
-                                    description: "0=easy, 1=medium, 2=hard".to_string(),
-                                    min: 0.0,
-                                    max: 2.0,
-                                    default: 0.0,
-                                },
-                                GameParamDef {
-                                    key: "episodes_per_maze".to_string(),
-                                    label: "Episodes per maze".to_string(),
-                                    description: "How many episodes to run before regenerating a new maze layout. Higher values reduce non-stationarity and usually learn faster.".to_string(),
-                                    min: 1.0,
-                                    max: 1000.0,
-                                    default: 8.0,
-                                },
-                            ],
-                        }
-                    }
-                    "spotxy" => {
-                        // SpotXY grid range: 0 (binary mode) or 2..=8 grid.
-                        Response::GameParams {
-                            game: "spotxy".to_string(),
-                            params: vec![
-                                reward_scale_def(),
-                                GameParamDef {
-                                    key: "grid_n".to_string(),
-                                    label: "Grid size".to_string(),
-                                    description:
-                                        "Grid dimension (0 = binary left/right; 2-8 = NxN grid)."
-                                            .to_string(),
-                                    min: 0.0,
-                                    max: 8.0,
-                                    default: 0.0,
-                                },
-                                GameParamDef {
-                                    key: "eval".to_string(),
-                                    label: "Eval mode".to_string(),
-                                    description: "Holdout/evaluation mode (0=train, 1=eval)."
-                                        .to_string(),
-                                    min: 0.0,
-                                    max: 1.0,
-                                    default: 0.0,
-                                },
-                            ],
-                        }
-                    }
-                    _ => Response::GameParams {
-                        game: game.to_string(),
-                        params: vec![reward_scale_def()],
-                    },
-                }
-            }
-            Request::SetGame { game } => {
-                let mut s = state.write().await;
-                if s.running {
-                    Response::Error {
-                        message: "Stop the simulation before switching game".to_string(),
-                    }
-                } else {
-                    match s.set_game(&game) {
-                        Ok(_) => Response::Success {
-                            message: format!("Game set to {}", game),
-                        },
-                        Err(e) => Response::Error { message: e },
-                    }
+            if s.game.kind() != game {
+                Response::Error {
+                    message: format!(
+                        "Game param applies to active game only (active={}, requested={})",
+                        s.game.kind(),
+                        game
+                    ),
                 }
-            }
-            Request::SetGameParam { game, key, value } => {
-                let mut s = state.write().await;
-                let game = game.trim();
-                let key = key.trim();
-
-                if s.game.kind() != game {
-                    Response::Error {
+            } else if key == "reward_scale" {
+                    s.reward_scales.set(game, value);
+                    s.pending_neuromod = 0.0;
+                    s.last_reward = 0.0;
+                    Response::Success {
                         message: format!(
-                            "Game param applies to active game only (active={}, requested={})",
-                            s.game.kind(),
-                            game
+                            "Set {game}.{key} = {:.3}",
+                            s.reward_scales.get(game)
                         ),
                     }
-                } else if key == "reward_scale" {
-                        s.reward_scales.set(game, value);
-                        s.pending_neuromod = 0.0;
-                        s.last_reward = 0.0;
-                        Response::Success {
-                            message: format!(
-                                "Set {game}.{key} = {:.3}",
-                                s.reward_scales.get(game)
-                            ),
-                        }
-                } else {
-                    match &mut s.game {
-                            ActiveGame::Pong(g) => match g.set_param(key, value) {
-                                Ok(_) => Response::Success {
+            } else {
+                match &mut s.game {
+                        ActiveGame::Pong(g) => match g.set_param(key, value) {
+                            Ok(_) => Response::Success {
+                                message: format!("Set {game}.{key} = {value}"),
+                            },
+                            Err(e) => Response::Error { message: e },
+                        },
+                        ActiveGame::Maze(g) => match key {
+                            "difficulty" => {
+                                let d = braine_games::maze::MazeDifficulty::from_param(value);
+                                g.set_difficulty(d);
+                                s.ensure_maze_io();
+                                s.pending_neuromod = 0.0;
+                                s.last_reward = 0.0;
+                                Response::Success {
+                                    message: format!("Set {game}.{key} = {}", d.name()),
+                                }
+                            }
+                            "episodes_per_maze" => {
+                                let n = value.round().clamp(1.0, 1000.0) as u32;
+                                g.set_episodes_per_maze(n);
+                                s.pending_neuromod = 0.0;
+                                s.last_reward = 0.0;
+                                Response::Success {
+                                    message: format!("Set {game}.{key} = {n}"),
+                                }
+                            }
+                            _ => Response::Error {
+                                message: format!(
+                                    "Unknown Maze param '{key}'. Use difficulty (0=easy,1=medium,2=hard) | episodes_per_maze (1..1000)"
+                                ),
+                            },
+                        },
+                        ActiveGame::GridWorld(g) => match g.set_param(key, value) {
+                            Ok(_) => {
+                                let gg = std::mem::take(g);
+                                s.ensure_gridworld_io(&gg);
+                                s.game = ActiveGame::GridWorld(gg);
+                                s.pending_neuromod = 0.0;
+                                s.last_reward = 0.0;
+                                Response::Success {
                                     message: format!("Set {game}.{key} = {value}"),
-                                },
-                                Err(e) => Response::Error { message: e },
+                                }
+                            }
+                            Err(e) => Response::Error { message: e },
+                        },
+                        ActiveGame::NBack(g) => match g.set_param(key, value) {
+                            Ok(_) => {
+                                let gg = std::mem::take(g);
+                                s.ensure_n_back_io(&gg);
+                                s.game = ActiveGame::NBack(gg);
+                                s.pending_neuromod = 0.0;
+                                s.last_reward = 0.0;
+                                Response::Success {
+                                    message: format!("Set {game}.{key} = {value}"),
+                                }
+                            }
+                            Err(e) => Response::Error { message: e },
+                        },
+                        ActiveGame::ConditionalDiscrimination(g) => match g.set_param(key, value) {
+                            Ok(_) => Response::Success {
+                                message: format!("Set {game}.{key} = {value}"),
+                            },
+                            Err(e) => Response::Error { message: e },
+                        },
+                        ActiveGame::TemporalXor(g) => match g.set_param(key, value) {
+                            Ok(_) => Response::Success {
+                                message: format!("Set {game}.{key} = {value}"),
                             },
-                            ActiveGame::Maze(g) => match key {
-                                "difficulty" => {
-                                    let d = braine_games::maze::MazeDifficulty::from_param(value);
-                                    g.set_difficulty(d);
-                                    s.ensure_maze_io();
+                            Err(e) => Response::Error { message: e },
+                        },
+                        ActiveGame::MatchToSample(g) => match g.set_param(key, value) {
+                            Ok(_) => {
+                                let gg = std::mem::take(g);
+                                s.ensure_match_to_sample_io(&gg);
+                                s.game = ActiveGame::MatchToSample(gg);
+                                Response::Success {
+                                    message: format!("Set {game}.{key} = {value}"),
+                                }
+                            }
+                            Err(e) => Response::Error { message: e },
+                        },
+                        ActiveGame::SpotXY(g) => {
+                            // SpotXY tunable params: grid_n, eval.
+                            match key {
+                                "grid_n" => {
+                                    let n = value.round().clamp(0.0, 8.0) as u32;
+                                    // Adapt existing grid state to target size.
+                                    while g.grid_n() < n {
+                                        g.increase_grid();
+                                    }
+                                    while g.grid_n() > n {
+                                        g.decrease_grid();
+                                    }
+                                    s.ensure_spotxy_io();
                                     s.pending_neuromod = 0.0;
                                     s.last_reward = 0.0;
                                     Response::Success {
-                                        message: format!("Set {game}.{key} = {}", d.name()),
+                                        message: format!("Set {game}.{key} = {n}"),
                                     }
                                 }
-                                "episodes_per_maze" => {
-                                    let n = value.round().clamp(1.0, 1000.0) as u32;
-                                    g.set_episodes_per_maze(n);
+                                "eval" => {
+                                    let eval = value >= 0.5;
+                                    g.set_eval_mode(eval);
                                     s.pending_neuromod = 0.0;
                                     s.last_reward = 0.0;
                                     Response::Success {
-                                        message: format!("Set {game}.{key} = {n}"),
+                                        message: format!("Set {game}.{key} = {eval}"),
                                     }
                                 }
                                 _ => Response::Error {
                                     message: format!(
-                                        "Unknown Maze param '{key}'. Use difficulty (0=easy,1=medium,2=hard) | episodes_per_maze (1..1000)"
+                                        "Unknown SpotXY param '{key}'. Use grid_n | eval"
                                     ),
                                 },
-                            },
-                            ActiveGame::SpotXY(g) => {
-                                // SpotXY tunable params: grid_n, eval.
-                                match key {
-                                    "grid_n" => {
-                                        let n = value.round().clamp(0.0, 8.0) as u32;
-                                        // Adapt existing grid state to target size.
-                                        while g.grid_n() < n {
-                                            g.increase_grid();
-                                        }
-                                        while g.grid_n() > n {
-                                            g.decrease_grid();
-                                        }
-                                        s.ensure_spotxy_io();
-                                        s.pending_neuromod = 0.0;
-                                        s.last_reward = 0.0;
-                                        Response::Success {
-                                            message: format!("Set {game}.{key} = {n}"),
-                                        }
-                                    }
-                                    "eval" => {
-                                        let eval = value >= 0.5;
-                                        g.set_eval_mode(eval);
-                                        s.pending_neuromod = 0.0;
-                                        s.last_reward = 0.0;
-                                        Response::Success {
-                                            message: format!("Set {game}.{key} = {eval}"),
-                                        }
-                                    }
-                                    _ => Response::Error {
-                                        message: format!(
-                                            "Unknown SpotXY param '{key}'. Use grid_n | eval"
-                                        ),
-                                    },
-                                }
                             }
-                            _ => Response::Error {
-                                message: format!("No tunable params implemented for game '{game}'"),
-                            },
-                    }
+                        }
+                        _ => Response::Error {
+                            message: format!("No tunable params implemented for game '{game}'"),
+                        },
                 }
             }
-            Request::SetSpotXYEval { eval } => {
-                let mut s = state.write().await;
-                match &mut s.game {
-                    ActiveGame::SpotXY(g) => {
-                        g.set_eval_mode(eval);
-                        s.pending_neuromod = 0.0;
-                        s.last_reward = 0.0;
-                        Response::Success {
-                            message: format!("SpotXY eval mode set to {}", eval),
-                        }
+        }
+        Request::SetSpotXYEval { eval } => {
+            match &mut s.game {
+                ActiveGame::SpotXY(g) => {
+                    g.set_eval_mode(eval);
+                    s.pending_neuromod = 0.0;
+                    s.last_reward = 0.0;
+                    Response::Success {
+                        message: format!("SpotXY eval mode set to {}", eval),
                     }
-                    _ => Response::Error {
-                        message: "SpotXY eval mode is only available in the spotxy game"
-                            .to_string(),
-                    },
                 }
+                _ => Response::Error {
+                    message: "SpotXY eval mode is only available in the spotxy game"
+                        .to_string(),
+                },
+            }
+        }
+        Request::SpotXYIncreaseGrid => {
+            let mode_and_grid = match &mut s.game {
+                ActiveGame::SpotXY(g) => {
+                    g.increase_grid();
+                    Some((g.mode_name().to_string(), g.grid_n()))
+                }
+                _ => None,
+            };
+
+            if let Some((mode, grid_n)) = mode_and_grid {
+                s.ensure_spotxy_io();
+                s.pending_neuromod = 0.0;
+                s.last_reward = 0.0;
+                Response::Success {
+                    message: format!(
+                        "SpotXY grid increased (mode={}, grid_n={})",
+                        mode, grid_n
+                    ),
+                }
+            } else {
+                Response::Error {
+                    message: "SpotXY grid increase is only available in the spotxy game"
+                        .to_string(),
+                }
+            }
+        }
+        Request::SpotXYDecreaseGrid => {
+            let mode_and_grid = match &mut s.game {
+                ActiveGame::SpotXY(g) => {
+                    g.decrease_grid();
+                    Some((g.mode_name().to_string(), g.grid_n()))
+                }
+                _ => None,
+            };
+
+            if let Some((mode, grid_n)) = mode_and_grid {
+                s.ensure_spotxy_io();
+                s.pending_neuromod = 0.0;
+                s.last_reward = 0.0;
+                Response::Success {
+                    message: format!(
+                        "SpotXY grid decreased (mode={}, grid_n={})",
+                        mode, grid_n
+                    ),
+                }
+            } else {
+                Response::Error {
+                    message: "SpotXY grid decrease is only available in the spotxy game"
+                        .to_string(),
+                }
+            }
+        }
+        Request::SetMode { .. } => {
+            // Spot is Braine-only; treat mode switching as a no-op.
+            // The UI may emit a SetMode on startup; returning Success avoids noisy errors.
+            Response::Success {
+                message: "Spot is Braine-only; mode unchanged".to_string(),
+            }
+        }
+        Request::HumanAction { .. } => {
+            // Not supported in Spot game; ignore (no-op) to avoid UI log spam.
+            Response::Success {
+                message: "Spot ignores human actions".to_string(),
+            }
+        }
+        Request::TriggerDream { source } => {
+            let source = source.unwrap_or_default();
+            let activity = s.brain.dream_replay_with_source(5, 1.5, &source);
+            if source != braine::substrate::DreamSource::Internal && activity == 0.0 {
+                Response::Success {
+                    message: format!("No remembered observations match {source:?}; dream skipped"),
+                }
+            } else {
+                Response::Success {
+                    message: "Dream triggered".to_string(),
+                }
+            }
+        }
+        Request::TriggerBurst => {
+            s.brain.set_burst_mode(true, 2.5);
+            Response::Success {
+                message: "Burst mode activated".to_string(),
+            }
+        }
+        Request::TriggerSync => {
+            s.brain.force_synchronize_sensors();
+            Response::Success {
+                message: "Sensors synchronized".to_string(),
+            }
+        }
+        Request::TriggerImprint {
+            group: None,
+            sensor_rate,
+            action_rate,
+            pair_rate,
+        } => {
+            s.brain.imprint_current_context_biased(
+                sensor_rate.unwrap_or(0.6),
+                action_rate.unwrap_or(0.0),
+                pair_rate.unwrap_or(0.0),
+            );
+            Response::Success {
+                message: "Context imprinted".to_string(),
+            }
+        }
+        Request::TriggerImprint {
+            group: Some(_),
+            sensor_rate: Some(_),
+            ..
+        }
+        | Request::TriggerImprint {
+            group: Some(_),
+            action_rate: Some(_),
+            ..
+        } => Response::Error {
+            message: "TriggerImprint with `group` only accepts `pair_rate`".to_string(),
+        },
+        Request::TriggerImprint {
+            group: Some(group),
+            pair_rate,
+            ..
+        } => {
+            if s.brain.imprint_sensor_group(&group, pair_rate.unwrap_or(0.6)) {
+                Response::Success {
+                    message: format!("Sensor group '{group}' imprinted"),
+                }
+            } else {
+                Response::Error {
+                    message: format!("No active units to imprint for sensor group '{group}'"),
+                }
+            }
+        }
+        Request::SetAutoDreamOnFlip { enabled } => {
+            s.auto_dream_on_flip = enabled;
+            s.flip_imprint_pending = false;
+            Response::Success {
+                message: format!("Auto dream on flip {}", if enabled { "on" } else { "off" }),
+            }
+        }
+        Request::SaveBrain => {
+            match s.save_brain() {
+                Ok(_) => Response::Success {
+                    message: "Brain saved".to_string(),
+                },
+                Err(e) => Response::Error { message: e },
+            }
+        }
+        Request::LoadBrain => {
+            match s.load_brain() {
+                Ok(_) => Response::Success {
+                    message: "Brain loaded".to_string(),
+                },
+                Err(e) => Response::Error { message: e },
+            }
+        }
+        Request::ResetBrain => {
+            s.reset_brain();
+            Response::Success {
+                message: "Brain reset".to_string(),
+            }
+        }
+        Request::ResetCausality => {
+            s.reset_causality();
+            Response::Success {
+                message: "Causal memory reset".to_string(),
             }
-            Request::SpotXYIncreaseGrid => {
-                let mut s = state.write().await;
-                let mode_and_grid = match &mut s.game {
-                    ActiveGame::SpotXY(g) => {
-                        g.increase_grid();
-                        Some((g.mode_name().to_string(), g.grid_n()))
+        }
+        Request::Shutdown => {
+            match s.save_brain() {
+                Ok(_) => {
+                    info!("Shutdown requested; brain saved");
+                    tokio::spawn(async {
+                        // Give the response a moment to flush before exiting.
+                        time::sleep(Duration::from_millis(50)).await;
+                        std::process::exit(0);
+                    });
+                    Response::Success {
+                        message: "Shutting down".to_string(),
                     }
-                    _ => None,
-                };
+                }
+                Err(e) => Response::Error {
+                    message: format!("Save failed, aborting shutdown: {}", e),
+                },
+            }
+        }
+        Request::SetFramerate { fps } => {
+            let clamped = fps.clamp(1, 1000);
+            s.target_fps = clamped;
+            info!("Framerate set to {} FPS", clamped);
+            Response::Success {
+                message: format!("Framerate set to {} FPS", clamped),
+            }
+        }
+        Request::SetTrialPeriodMs { ms } => {
+            let clamped = ms.clamp(10, 60_000);
+            s.trial_period_ms = clamped;
+            info!("Trial period set to {} ms", clamped);
+            Response::Success {
+                message: format!("Trial period set to {} ms", clamped),
+            }
+        }
 
-                if let Some((mode, grid_n)) = mode_and_grid {
-                    s.ensure_spotxy_io();
-                    s.pending_neuromod = 0.0;
-                    s.last_reward = 0.0;
+        Request::SetExecutionTier { tier } => {
+            match parse_execution_tier(&tier) {
+                None => Response::Error {
+                    message: format!(
+                        "Invalid execution tier: {} (expected scalar|simd|parallel|gpu)",
+                        tier
+                    ),
+                },
+                Some(t) => {
+                    s.brain.set_execution_tier(t);
+                    let eff = s.brain.effective_execution_tier();
+                    info!("Execution tier set to {:?} (effective {:?})", t, eff);
                     Response::Success {
                         message: format!(
-                            "SpotXY grid increased (mode={}, grid_n={})",
-                            mode, grid_n
+                            "Execution tier set to {:?} (effective {:?})",
+                            t, eff
                         ),
                     }
-                } else {
-                    Response::Error {
-                        message: "SpotXY grid increase is only available in the spotxy game"
-                            .to_string(),
-                    }
                 }
             }
-            Request::SpotXYDecreaseGrid => {
-                let mut s = state.write().await;
-                let mode_and_grid = match &mut s.game {
-                    ActiveGame::SpotXY(g) => {
-                        g.decrease_grid();
-                        Some((g.mode_name().to_string(), g.grid_n()))
-                    }
-                    _ => None,
-                };
+        }
+
+        Request::SetExpertsEnabled { enabled } => {
+            s.experts.set_enabled(enabled);
+            Response::Success {
+                message: format!("Experts enabled = {}", enabled),
+            }
+        }
+        Request::SetExpertNesting {
+            allow_nested,
+            max_depth,
+        } => {
+            let mut p = s.experts.policy().clone();
+            p.allow_nested = allow_nested;
+            p.max_depth = max_depth.max(1);
+            s.experts.set_policy(p);
+
+            Response::Success {
+                message: format!(
+                    "Expert nesting updated (allow_nested={}, max_depth={})",
+                    allow_nested,
+                    max_depth.max(1)
+                ),
+            }
+        }
+        Request::SetExpertPolicy {
+            parent_learning,
+            max_children,
+            child_reward_scale,
+            episode_trials,
+            consolidate_topk,
+            reward_shift_ema_delta_threshold,
+            performance_collapse_drop_threshold,
+            performance_collapse_baseline_min,
+            allow_nested,
+            max_depth,
+            persistence_mode,
+        } => {
+            let pm = ExpertsPersistenceMode::parse(&persistence_mode)
+                .unwrap_or(ExpertsPersistenceMode::Full);
+            s.experts.set_persistence_mode(pm);
+
+            let parent_learning =
+                ParentLearningPolicy::parse(&parent_learning).ok_or_else(|| Response::Error {
+                    message: "parent_learning must be one of: normal|reduced|holdout"
+                        .to_string(),
+                });
+
+            match parent_learning {
+                Ok(parent_learning) => {
+                    let mut p = s.experts.policy().clone();
+                    p.parent_learning = parent_learning;
+                    p.max_children = (max_children as usize).clamp(0, 8);
+                    p.child_reward_scale = child_reward_scale.clamp(0.0, 4.0);
+                    p.episode_trials = episode_trials.clamp(1, 10_000);
+                    p.consolidate_topk = (consolidate_topk as usize).clamp(0, 10_000);
+                    p.reward_shift_ema_delta_threshold =
+                        reward_shift_ema_delta_threshold.clamp(0.0, 5.0);
+                    p.performance_collapse_drop_threshold =
+                        performance_collapse_drop_threshold.clamp(0.0, 5.0);
+                    p.performance_collapse_baseline_min =
+                        performance_collapse_baseline_min.clamp(-1.0, 1.0);
+                    p.allow_nested = allow_nested;
+                    p.max_depth = max_depth.max(1);
+                    s.experts.set_policy(p);
 
-                if let Some((mode, grid_n)) = mode_and_grid {
-                    s.ensure_spotxy_io();
-                    s.pending_neuromod = 0.0;
-                    s.last_reward = 0.0;
                     Response::Success {
                         message: format!(
-                            "SpotXY grid decreased (mode={}, grid_n={})",
-                            mode, grid_n
+                            "Expert policy set (parent_learning={}, max_children={}, child_reward_scale={:.2}, episode_trials={}, consolidate_topk={}, persist={}, allow_nested={}, max_depth={})",
+                            parent_learning.as_str(),
+                            max_children,
+                            child_reward_scale,
+                            episode_trials,
+                            consolidate_topk,
+                            pm.as_str(),
+                            allow_nested,
+                            max_depth.max(1)
                         ),
                     }
-                } else {
-                    Response::Error {
-                        message: "SpotXY grid decrease is only available in the spotxy game"
-                            .to_string(),
-                    }
                 }
+                Err(resp) => resp,
             }
-            Request::SetMode { .. } => {
-                // Spot is Braine-only; treat mode switching as a no-op.
-                // The UI may emit a SetMode on startup; returning Success avoids noisy errors.
-                Response::Success {
-                    message: "Spot is Braine-only; mode unchanged".to_string(),
-                }
+        }
+        Request::CullExperts => {
+            s.experts.cull_all_recursive();
+            Response::Success {
+                message: "Experts culled".to_string(),
             }
-            Request::HumanAction { .. } => {
-                // Not supported in Spot game; ignore (no-op) to avoid UI log spam.
-                Response::Success {
-                    message: "Spot ignores human actions".to_string(),
-                }
+        }
+        Request::SetExpertSimilarityRouting { enabled, threshold } => {
+            if enabled {
+                s.experts.context_similarity_routing(threshold);
+            } else {
+                s.experts.disable_context_similarity_routing();
             }
-            Request::TriggerDream => {
-                let mut s = state.write().await;
-                s.brain.dream_replay(5, 1.5);
-                Response::Success {
-                    message: "Dream triggered".to_string(),
+            Response::Success {
+                message: format!(
+                    "Expert similarity routing = {} (threshold={:.2})",
+                    enabled,
+                    threshold.clamp(0.0, 1.0)
+                ),
+            }
+        }
+        Request::SetExpertEnsembleMode { enabled, top_k } => {
+            let top_k = top_k.min(EXPERT_ENSEMBLE_TOP_K_MAX);
+            s.experts.set_ensemble_mode(enabled, top_k as usize);
+            Response::Success {
+                message: format!("Expert ensemble mode = {} (top_k={})", enabled, top_k),
+            }
+        }
+        // Everything else only reads state; `handle_read_request` owns those arms.
+        _ => handle_read_request(&request, s),
+    }
+}
+
+async fn handle_client<R, W>(
+    reader: R,
+    mut writer: W,
+    state: Arc<RwLock<DaemonState>>,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut lines = BufReader::new(reader).lines();
+    // Active `StreamState` subscription (pushes `State` until `StopStream` or disconnect).
+    let mut stream_interval: Option<time::Interval> = None;
+    // Active `WatchDiag` subscription: game-loop receiver and frame stride.
+    let mut diag_watch: Option<(broadcast::Receiver<u64>, u64)> = None;
+    // Responses from requests that carry a `request_id` (handled on their own tasks).
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let mut tx = Some(tx);
+
+    loop {
+        let line = tokio::select! {
+            out = rx.recv() => match out {
+                Some(out) => {
+                    writer.write_all(out.as_bytes()).await?;
+                    continue;
                 }
+                // Input closed and every pipelined request has been answered.
+                None => break,
+            },
+            _ = next_tick(&mut stream_interval) => {
+                let snapshot = {
+                    let s = state.read().await;
+                    Response::State(Box::new(s.get_snapshot(false)))
+                };
+                let out = ResponseEnvelope {
+                    request_id: None,
+                    response: snapshot,
+                };
+                writer.write_all(out.to_line()?.as_bytes()).await?;
+                continue;
+            }
+            update = next_diag(&mut diag_watch, &state) => {
+                let out = ResponseEnvelope {
+                    request_id: None,
+                    response: update.into(),
+                };
+                writer.write_all(out.to_line()?.as_bytes()).await?;
+                continue;
+            }
+            line = lines.next_line(), if tx.is_some() => line?,
+        };
+        let Some(line) = line else {
+            // Stop reading, but keep writing until in-flight requests have replied.
+            tx = None;
+            stream_interval = None;
+            diag_watch = None;
+            continue;
+        };
+
+        let RequestEnvelope {
+            request_id,
+            request,
+        } = match serde_json::from_str(&line) {
+            Ok(req) => req,
+            Err(e) => {
+                let out = ResponseEnvelope {
+                    request_id: serde_json::from_str::<serde_json::Value>(&line)
+                        .ok()
+                        .and_then(|v| v.get("request_id")?.as_u64()),
+                    response: Response::Error {
+                        message: format!("Invalid request: {}", e),
+                    },
+                };
+                writer.write_all(out.to_line()?.as_bytes()).await?;
+                continue;
             }
-            Request::TriggerBurst => {
-                let mut s = state.write().await;
-                s.brain.set_burst_mode(true, 2.5);
+        };
+
+        let response = match request {
+            Request::StreamState { interval_ms } => {
+                let ms = interval_ms.clamp(16, 60_000);
+                let mut iv = time::interval(Duration::from_millis(ms as u64));
+                iv.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+                stream_interval = Some(iv);
                 Response::Success {
-                    message: "Burst mode activated".to_string(),
+                    message: format!("Streaming state every {ms} ms"),
                 }
             }
-            Request::TriggerSync => {
-                let mut s = state.write().await;
-                s.brain.force_synchronize_sensors();
+            Request::StopStream => {
+                stream_interval = None;
                 Response::Success {
-                    message: "Sensors synchronized".to_string(),
+                    message: "State stream stopped".to_string(),
                 }
             }
-            Request::TriggerImprint => {
-                let mut s = state.write().await;
-                s.brain.imprint_current_context(0.6);
+            Request::WatchDiag { every_frames } => {
+                let every = every_frames.max(1);
+                let rx = state.read().await.diag_tx.subscribe();
+                diag_watch = Some((rx, every as u64));
                 Response::Success {
-                    message: "Context imprinted".to_string(),
-                }
-            }
-            Request::SaveBrain => {
-                let s = state.read().await;
-                match s.save_brain() {
-                    Ok(_) => Response::Success {
-                        message: "Brain saved".to_string(),
-                    },
-                    Err(e) => Response::Error { message: e },
-                }
-            }
-            Request::LoadBrain => {
-                let mut s = state.write().await;
-                match s.load_brain() {
-                    Ok(_) => Response::Success {
-                        message: "Brain loaded".to_string(),
-                    },
-                    Err(e) => Response::Error { message: e },
+                    message: format!("Watching diagnostics every {every} frames"),
                 }
             }
-            Request::ResetBrain => {
-                let mut s = state.write().await;
-                s.reset_brain();
+            Request::UnwatchDiag => {
+                diag_watch = None;
                 Response::Success {
-                    message: "Brain reset".to_string(),
+                    message: "Diagnostics watch stopped".to_string(),
                 }
             }
-            Request::Shutdown => {
-                let s = state.read().await;
-                match s.save_brain() {
-                    Ok(_) => {
-                        info!("Shutdown requested; brain saved");
-                        tokio::spawn(async {
-                            // Give the response a moment to flush before exiting.
-                            time::sleep(Duration::from_millis(50)).await;
-                            std::process::exit(0);
-                        });
-                        Response::Success {
-                            message: "Shutting down".to_string(),
+            Request::ProfileStep { num_steps } => {
+                // Profile a clone on a blocking thread: holding the lock (or a runtime
+                // worker) for up to `PROFILE_STEPS_MAX` cycles would stall the game loop.
+                let brain = state.read().await.brain.clone();
+                let job = tokio::task::spawn_blocking(move || profile_brain(brain, num_steps));
+                if let (Some(id), Some(tx)) = (request_id, tx.as_ref()) {
+                    let tx = tx.clone();
+                    tokio::spawn(async move {
+                        let out = ResponseEnvelope {
+                            request_id: Some(id),
+                            response: profile_join(job.await),
+                        };
+                        match out.to_line() {
+                            Ok(line) => {
+                                let _ = tx.send(line);
+                            }
+                            Err(e) => error!("Failed to encode response: {e}"),
                         }
-                    }
-                    Err(e) => Response::Error {
-                        message: format!("Save failed, aborting shutdown: {}", e),
-                    },
-                }
-            }
-            Request::SetFramerate { fps } => {
-                let mut s = state.write().await;
-                let clamped = fps.clamp(1, 1000);
-                s.target_fps = clamped;
-                info!("Framerate set to {} FPS", clamped);
-                Response::Success {
-                    message: format!("Framerate set to {} FPS", clamped),
-                }
-            }
-            Request::SetTrialPeriodMs { ms } => {
-                let mut s = state.write().await;
-                let clamped = ms.clamp(10, 60_000);
-                s.trial_period_ms = clamped;
-                info!("Trial period set to {} ms", clamped);
-                Response::Success {
-                    message: format!("Trial period set to {} ms", clamped),
+                    });
+                    continue;
                 }
+                profile_join(job.await)
             }
-
-            Request::SetExecutionTier { tier } => {
-                match parse_execution_tier(&tier) {
-                    None => Response::Error {
-                        message: format!(
-                            "Invalid execution tier: {} (expected scalar|simd|parallel|gpu)",
-                            tier
-                        ),
-                    },
-                    Some(t) => {
-                        let mut s = state.write().await;
-                        s.brain.set_execution_tier(t);
-                        let eff = s.brain.effective_execution_tier();
-                        info!("Execution tier set to {:?} (effective {:?})", t, eff);
-                        Response::Success {
-                            message: format!(
-                                "Execution tier set to {:?} (effective {:?})",
-                                t, eff
-                            ),
+            other => {
+                // Only reads run on their own tasks; writes are applied here, in order.
+                // The read lock is taken before spawning so a read never observes a
+                // write that was sent after it on this connection.
+                if let (Some(id), Some(tx), true) = (request_id, tx.as_ref(), other.is_read_only())
+                {
+                    let s = Arc::clone(&state).read_owned().await;
+                    let tx = tx.clone();
+                    tokio::spawn(async move {
+                        let out = ResponseEnvelope {
+                            request_id: Some(id),
+                            response: handle_read_request(&other, &s),
+                        };
+                        match out.to_line() {
+                            Ok(line) => {
+                                let _ = tx.send(line);
+                            }
+                            Err(e) => error!("Failed to encode response: {e}"),
                         }
-                    }
+                    });
+                    continue;
                 }
+                dispatch_request(other, &state).await
             }
+        };
 
-            Request::SetExpertsEnabled { enabled } => {
-                let mut s = state.write().await;
-                s.experts.set_enabled(enabled);
-                Response::Success {
-                    message: format!("Experts enabled = {}", enabled),
-                }
+        let out = ResponseEnvelope {
+            request_id,
+            response,
+        };
+        writer.write_all(out.to_line()?.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+/// Unwrap the result of a `profile_brain` blocking task.
+fn profile_join(result: Result<Response, tokio::task::JoinError>) -> Response {
+    result.unwrap_or_else(|e| Response::Error {
+        message: format!("Profiling failed: {e}"),
+    })
+}
+
+/// Run `request` under a read lock when it is read-only, otherwise under the write lock.
+async fn dispatch_request(request: Request, state: &RwLock<DaemonState>) -> Response {
+    if request.is_read_only() {
+        let s = state.read().await;
+        handle_read_request(&request, &s)
+    } else {
+        let mut s = state.write().await;
+        handle_request(request, &mut s)
+    }
+}
+
+/// Wait for the next stream tick; never resolves without an active subscription.
+async fn next_tick(interval: &mut Option<time::Interval>) {
+    match interval {
+        Some(iv) => {
+            iv.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Wait for the next watched diagnostics frame; never resolves without a subscription.
+///
+/// Frames off the `every_frames` stride are skipped, as are frames dropped
+/// because this connection lagged behind the game loop. Diagnostics are only
+/// gathered for frames on the stride.
+async fn next_diag(
+    watch: &mut Option<(broadcast::Receiver<u64>, u64)>,
+    state: &RwLock<DaemonState>,
+) -> DiagUpdate {
+    let Some((rx, every)) = watch else {
+        return std::future::pending().await;
+    };
+    loop {
+        match rx.recv().await {
+            Ok(frame) if frame % *every == 0 => return watched_diag_update(state).await,
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+            Err(broadcast::error::RecvError::Closed) => return std::future::pending().await,
+        }
+    }
+}
+
+/// Gather a `WatchDiag` update under a read lock, then probe storage without it.
+///
+/// The update reflects the state when the lock was taken, which may already be a
+/// frame past the announced one.
+async fn watched_diag_update(state: &RwLock<DaemonState>) -> DiagUpdate {
+    let (mut update, ring) = {
+        let s = state.read().await;
+        let (storage, ring) = s.storage_info_unprobed();
+        let update = DiagUpdate {
+            running: s.running,
+            frame: s.frame,
+            brain_stats: s.brain_stats(),
+            storage,
+        };
+        (update, ring)
+    };
+    DaemonState::probe_storage(&mut update.storage, &ring);
+    update
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Transport
+// ═══════════════════════════════════════════════════════════════════════════
+
+const TCP_ADDR: &str = "127.0.0.1:9876";
+
+/// Default WebSocket port: off. The browser UI uses `--ws-port 9877`.
+const WS_PORT_DEFAULT: u16 = 0;
+
+/// Snapshots kept by default; `--snapshot-keep 0` keeps all of them.
+const SNAPSHOT_KEEP_DEFAULT: usize = 24;
+
+/// Upper bound on an HTTP request head read by the metrics server.
+const METRICS_REQUEST_MAX_BYTES: usize = 8 * 1024;
+
+/// Buffer size of the in-memory pipe between a WebSocket and `handle_client`.
+const WS_PIPE_BYTES: usize = 64 * 1024;
+
+/// Fallback socket path when the data-dir path is unusable (e.g. too long for `sun_path`).
+#[cfg(unix)]
+const UNIX_SOCKET_FALLBACK: &str = "/tmp/brained.sock";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Transport {
+    Tcp,
+    Unix,
+    Both,
+}
+
+impl Transport {
+    fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "tcp" => Some(Self::Tcp),
+            "unix" => Some(Self::Unix),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+
+    fn tcp(self) -> bool {
+        matches!(self, Self::Tcp | Self::Both)
+    }
+
+    fn unix(self) -> bool {
+        matches!(self, Self::Unix | Self::Both)
+    }
+}
+
+const USAGE: &str =
+    "Usage: brained [--transport tcp|unix|both] [--ws-port PORT] [--metrics-port PORT] [--snapshot-keep N] [--config FILE] [--print-config] [--print-env]";
+
+/// Command-line options.
+struct CliArgs {
+    transport: Transport,
+    /// WebSocket port on 127.0.0.1 (0 = disabled, the default).
+    ws_port: u16,
+    /// Prometheus `GET /metrics` port on 127.0.0.1 (0 = disabled).
+    metrics_port: u16,
+    /// Snapshots `SaveSnapshot` keeps, oldest evicted first (0 = keep all).
+    snapshot_keep: usize,
+    /// TOML `BrainConfig` used when no saved brain exists.
+    config: Option<PathBuf>,
+    /// Print the effective config as TOML and exit.
+    print_config: bool,
+    /// Print the effective config as `BRAINE_*` environment variables and exit.
+    print_env: bool,
+}
+
+/// Parse `--transport tcp|unix|both` (default: tcp), `--ws-port PORT` (default: disabled),
+/// `--metrics-port PORT` (default: disabled), `--snapshot-keep N` (default: 24, 0 = keep all),
+/// `--config FILE`, `--print-config` and `--print-env`.
+fn parse_args() -> Result<CliArgs, String> {
+    let mut cli = CliArgs {
+        transport: Transport::Tcp,
+        ws_port: WS_PORT_DEFAULT,
+        metrics_port: 0,
+        snapshot_keep: SNAPSHOT_KEEP_DEFAULT,
+        config: None,
+        print_config: false,
+        print_env: false,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if let Some(v) = arg.strip_prefix("--config=") {
+            cli.config = Some(PathBuf::from(v));
+        } else if arg == "--config" {
+            cli.config = Some(PathBuf::from(
+                args.next().ok_or("--config requires a file path")?,
+            ));
+        } else if arg == "--print-config" {
+            cli.print_config = true;
+        } else if arg == "--print-env" {
+            cli.print_env = true;
+        } else if arg == "--ws-port" || arg.starts_with("--ws-port=") {
+            let value = match arg.strip_prefix("--ws-port=") {
+                Some(v) => v.to_string(),
+                None => args.next().ok_or("--ws-port requires a port number")?,
+            };
+            cli.ws_port = value
+                .parse()
+                .map_err(|_| format!("Invalid --ws-port '{value}'. Use 0-65535"))?;
+        } else if arg == "--snapshot-keep" || arg.starts_with("--snapshot-keep=") {
+            let value = match arg.strip_prefix("--snapshot-keep=") {
+                Some(v) => v.to_string(),
+                None => args.next().ok_or("--snapshot-keep requires a count")?,
+            };
+            cli.snapshot_keep = value.parse().map_err(|_| {
+                format!("Invalid --snapshot-keep '{value}'. Use a count (0 = keep all)")
+            })?;
+        } else if arg == "--metrics-port" || arg.starts_with("--metrics-port=") {
+            let value = match arg.strip_prefix("--metrics-port=") {
+                Some(v) => v.to_string(),
+                None => args.next().ok_or("--metrics-port requires a port number")?,
+            };
+            cli.metrics_port = value
+                .parse()
+                .map_err(|_| format!("Invalid --metrics-port '{value}'. Use 0-65535"))?;
+        } else {
+            let value = if let Some(v) = arg.strip_prefix("--transport=") {
+                v.to_string()
+            } else if arg == "--transport" {
+                args.next()
+                    .ok_or("--transport requires a value: tcp|unix|both")?
+            } else {
+                return Err(format!("Unknown argument '{arg}'. {USAGE}"));
+            };
+            cli.transport = Transport::parse(&value)
+                .ok_or_else(|| format!("Invalid transport '{value}'. Use tcp|unix|both"))?;
+        }
+    }
+    Ok(cli)
+}
+
+/// Load the substrate config: the `--config` file at `path` (or the daemon
+/// defaults) with `BRAINE_*` environment overrides applied on top.
+fn load_brain_config(path: Option<&Path>) -> Result<BrainConfig, String> {
+    let cfg = match path {
+        Some(path) => {
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read config {}: {e}", path.display()))?;
+            BrainConfig::from_toml(&text).map_err(|e| format!("{}: {e}", path.display()))?
+        }
+        None => DaemonState::default_brain_config(),
+    };
+    let cfg = cfg.with_env_overrides()?;
+    cfg.validate()
+        .map_err(|e| format!("Invalid config after environment overrides: {e}"))?;
+    Ok(cfg)
+}
+
+async fn serve_tcp(listener: TcpListener, state: Arc<RwLock<DaemonState>>) -> std::io::Result<()> {
+    loop {
+        let (stream, addr) = listener.accept().await?;
+        info!("Client connected: {}", addr);
+        let state_clone = Arc::clone(&state);
+
+        tokio::spawn(async move {
+            let (reader, writer) = stream.into_split();
+            if let Err(e) = handle_client(reader, writer, state_clone).await {
+                error!("Client handler error: {}", e);
             }
-            Request::SetExpertNesting {
-                allow_nested,
-                max_depth,
-            } => {
-                let mut s = state.write().await;
+        });
+    }
+}
 
-                let mut p = s.experts.policy().clone();
-                p.allow_nested = allow_nested;
-                p.max_depth = max_depth.max(1);
-                s.experts.set_policy(p);
+/// Bind the Unix listener at `primary`, falling back to `UNIX_SOCKET_FALLBACK`.
+///
+/// A socket file left by a previous run is only removed once nothing answers
+/// on it, and the socket is never reachable by other users (see
+/// `bind_unix_private`).
+#[cfg(unix)]
+fn bind_unix(primary: PathBuf) -> std::io::Result<(UnixListener, PathBuf)> {
+    // `sun_path` is 104 bytes on macOS, 108 on Linux.
+    let mut candidates = Vec::with_capacity(2);
+    if primary.as_os_str().len() < 100 {
+        candidates.push(primary);
+    }
+    candidates.push(PathBuf::from(UNIX_SOCKET_FALLBACK));
 
-                Response::Success {
-                    message: format!(
-                        "Expert nesting updated (allow_nested={}, max_depth={})",
-                        allow_nested,
-                        max_depth.max(1)
-                    ),
-                }
+    let mut last_err = None;
+    for path in candidates {
+        match remove_stale_socket(&path).and_then(|()| bind_unix_private(&path)) {
+            Ok(listener) => return Ok((listener, path)),
+            Err(e) => {
+                warn!("Could not bind Unix socket {}: {}", path.display(), e);
+                last_err = Some(e);
             }
-            Request::SetExpertPolicy {
-                parent_learning,
-                max_children,
-                child_reward_scale,
-                episode_trials,
-                consolidate_topk,
-                reward_shift_ema_delta_threshold,
-                performance_collapse_drop_threshold,
-                performance_collapse_baseline_min,
-                allow_nested,
-                max_depth,
-                persistence_mode,
-            } => {
-                let mut s = state.write().await;
+        }
+    }
+    Err(last_err.unwrap_or_else(|| std::io::Error::other("no Unix socket path available")))
+}
 
-                let pm = ExpertsPersistenceMode::parse(&persistence_mode)
-                    .unwrap_or(ExpertsPersistenceMode::Full);
-                s.experts.set_persistence_mode(pm);
+/// Remove a leftover socket at `path`. Fails without touching it when another
+/// process still accepts connections there or the file is not a socket.
+#[cfg(unix)]
+fn remove_stale_socket(path: &Path) -> std::io::Result<()> {
+    use std::io::{Error, ErrorKind};
+    use std::os::unix::fs::FileTypeExt;
+
+    let meta = match std::fs::symlink_metadata(path) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    if !meta.file_type().is_socket() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            "path exists and is not a socket",
+        ));
+    }
+    match std::os::unix::net::UnixStream::connect(path) {
+        Ok(_) => Err(Error::new(
+            ErrorKind::AddrInUse,
+            "another daemon is listening on this socket",
+        )),
+        Err(e) if e.kind() == ErrorKind::ConnectionRefused => std::fs::remove_file(path),
+        Err(e) => Err(e),
+    }
+}
 
-                let parent_learning =
-                    ParentLearningPolicy::parse(&parent_learning).ok_or_else(|| Response::Error {
-                        message: "parent_learning must be one of: normal|reduced|holdout"
-                            .to_string(),
-                    });
+/// Bind a listener at `path` that only the current user can ever connect to.
+///
+/// The socket is created inside a fresh `0700` directory next to `path`,
+/// restricted to `0600` there, and then renamed into place, so there is no
+/// moment where it is reachable with the process umask's permissions.
+#[cfg(unix)]
+fn bind_unix_private(path: &Path) -> std::io::Result<UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    let parent = path.parent().unwrap_or(Path::new("."));
+    let staging = parent.join(format!(".brained-bind-{}", std::process::id()));
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+    let staged = staging.join("sock");
+    let bound = UnixListener::bind(&staged).and_then(|listener| {
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = std::fs::remove_file(&staged);
+    let _ = std::fs::remove_dir(&staging);
+    bound
+}
 
-                match parent_learning {
-                    Ok(parent_learning) => {
-                        let mut p = s.experts.policy().clone();
-                        p.parent_learning = parent_learning;
-                        p.max_children = (max_children as usize).clamp(0, 8);
-                        p.child_reward_scale = child_reward_scale.clamp(0.0, 4.0);
-                        p.episode_trials = episode_trials.clamp(1, 10_000);
-                        p.consolidate_topk = (consolidate_topk as usize).clamp(0, 10_000);
-                        p.reward_shift_ema_delta_threshold =
-                            reward_shift_ema_delta_threshold.clamp(0.0, 5.0);
-                        p.performance_collapse_drop_threshold =
-                            performance_collapse_drop_threshold.clamp(0.0, 5.0);
-                        p.performance_collapse_baseline_min =
-                            performance_collapse_baseline_min.clamp(-1.0, 1.0);
-                        p.allow_nested = allow_nested;
-                        p.max_depth = max_depth.max(1);
-                        s.experts.set_policy(p);
-
-                        Response::Success {
-                            message: format!(
-                                "Expert policy set (parent_learning={}, max_children={}, child_reward_scale={:.2}, episode_trials={}, consolidate_topk={}, persist={}, allow_nested={}, max_depth={})",
-                                parent_learning.as_str(),
-                                max_children,
-                                child_reward_scale,
-                                episode_trials,
-                                consolidate_topk,
-                                pm.as_str(),
-                                allow_nested,
-                                max_depth.max(1)
-                            ),
-                        }
-                    }
-                    Err(resp) => resp,
+/// Whether a WebSocket handshake may proceed given its `Origin` header.
+///
+/// Browsers always send `Origin`, so only pages served from localhost
+/// (`http(s)://localhost|127.0.0.1|[::1][:port]`) are accepted; this blocks
+/// cross-site WebSocket hijacking by arbitrary web pages. Clients without an
+/// `Origin` (CLI tools, scripts) are not browsers and are allowed.
+fn ws_origin_allowed(origin: Option<&str>) -> bool {
+    let Some(origin) = origin else {
+        return true;
+    };
+    let Some(rest) = origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+    else {
+        return false;
+    };
+    let (host, port) = match rest.strip_prefix("[::1]") {
+        Some(tail) => ("[::1]", tail),
+        None => rest.find(':').map_or((rest, ""), |i| rest.split_at(i)),
+    };
+    let port_ok = port.is_empty()
+        || port
+            .strip_prefix(':')
+            .is_some_and(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()));
+    port_ok
+        && matches!(
+            host.to_ascii_lowercase().as_str(),
+            "localhost" | "127.0.0.1" | "[::1]"
+        )
+}
+
+async fn serve_ws(listener: TcpListener, state: Arc<RwLock<DaemonState>>) -> std::io::Result<()> {
+    loop {
+        let (stream, addr) = listener.accept().await?;
+        let state_clone = Arc::clone(&state);
+
+        tokio::spawn(async move {
+            // The callback signature (and its large `Err`) is fixed by tungstenite.
+            #[allow(clippy::result_large_err)]
+            let check_origin = |req: &WsRequest, resp: WsResponse| {
+                let origin = req
+                    .headers()
+                    .get("origin")
+                    .map(|v| v.to_str().unwrap_or(""));
+                if ws_origin_allowed(origin) {
+                    Ok(resp)
+                } else {
+                    let mut err = WsErrorResponse::new(Some("origin not allowed".to_string()));
+                    *err.status_mut() = tokio_tungstenite::tungstenite::http::StatusCode::FORBIDDEN;
+                    Err(err)
                 }
-            }
-            Request::CullExperts => {
-                let mut s = state.write().await;
-                s.experts.cull_all_recursive();
-                Response::Success {
-                    message: "Experts culled".to_string(),
+            };
+            let ws = match tokio_tungstenite::accept_hdr_async(stream, check_origin).await {
+                Ok(ws) => ws,
+                Err(e) => {
+                    warn!("WebSocket handshake with {} failed: {}", addr, e);
+                    return;
                 }
+            };
+            info!("WebSocket client connected: {}", addr);
+            if let Err(e) = handle_ws_client(ws, state_clone).await {
+                error!("WebSocket client error: {}", e);
             }
-        };
+        });
+    }
+}
+
+async fn serve_metrics(
+    listener: TcpListener,
+    state: Arc<RwLock<DaemonState>>,
+) -> std::io::Result<()> {
+    loop {
+        let (stream, addr) = listener.accept().await?;
+        let state_clone = Arc::clone(&state);
+
+        tokio::spawn(async move {
+            if let Err(e) = handle_metrics_client(stream, state_clone).await {
+                warn!("Metrics request from {} failed: {}", addr, e);
+            }
+        });
+    }
+}
 
-        writer
-            .write_all(serde_json::to_string(&response)?.as_bytes())
-            .await?;
-        writer.write_all(b"\n").await?;
+/// Answer one HTTP/1.1 request: `GET /metrics` gets the Prometheus text,
+/// anything else a 404. The connection is closed after the response.
+async fn handle_metrics_client(
+    mut stream: TcpStream,
+    state: Arc<RwLock<DaemonState>>,
+) -> std::io::Result<()> {
+    use tokio::io::AsyncReadExt as _;
+
+    let mut head = Vec::with_capacity(512);
+    let mut buf = [0u8; 512];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 || head.len() + n > METRICS_REQUEST_MAX_BYTES {
+            return Ok(());
+        }
+        head.extend_from_slice(&buf[..n]);
     }
 
+    let request_line = head.split(|&b| b == b'\r').next().unwrap_or_default();
+    let mut parts = request_line.split(|&b| b == b' ');
+    let (status, content_type, body) = match (parts.next(), parts.next()) {
+        (Some(b"GET"), Some(b"/metrics")) => {
+            let body = state.read().await.prometheus_metrics();
+            ("200 OK", "text/plain; version=0.0.4; charset=utf-8", body)
+        }
+        _ => (
+            "404 Not Found",
+            "text/plain; charset=utf-8",
+            "not found\n".to_string(),
+        ),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Bridge one WebSocket to [`handle_client`] over an in-memory pipe.
+///
+/// Each text message is one JSON request and each response line goes back as
+/// one text message, so requests, `request_id` pipelining and state streaming
+/// behave exactly as on TCP.
+async fn handle_ws_client(
+    ws: WebSocketStream<TcpStream>,
+    state: Arc<RwLock<DaemonState>>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let (client, server) = tokio::io::duplex(WS_PIPE_BYTES);
+    let (server_r, server_w) = tokio::io::split(server);
+    tokio::spawn(async move {
+        if let Err(e) = handle_client(server_r, server_w, state).await {
+            error!("Client handler error: {}", e);
+        }
+    });
+
+    let (client_r, mut client_w) = tokio::io::split(client);
+    let mut lines = BufReader::new(client_r).lines();
+    let (mut ws_tx, mut ws_rx) = ws.split();
+    let mut input_open = true;
+
+    loop {
+        tokio::select! {
+            msg = ws_rx.next(), if input_open => match msg {
+                Some(Ok(Message::Text(text))) => {
+                    // Line framing on the pipe: a pretty-printed request must stay one line.
+                    let line = text.as_str().replace(['\r', '\n'], " ");
+                    client_w.write_all(line.as_bytes()).await?;
+                    client_w.write_all(b"\n").await?;
+                }
+                // Closed (or dropped without a closing handshake, e.g. a killed tab):
+                // let in-flight requests finish; the handler ends once they reply.
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                    input_open = false;
+                    client_w.shutdown().await?;
+                }
+                // Pings are answered by tungstenite; binary frames are not part of the protocol.
+                Some(Ok(_)) => {}
+            },
+            line = lines.next_line() => match line? {
+                Some(line) => {
+                    if ws_tx.send(Message::text(line)).await.is_err() {
+                        break;
+                    }
+                }
+                None => break,
+            },
+        }
+    }
+    let _ = ws_tx.close().await;
     Ok(())
 }
 
+#[cfg(unix)]
+async fn serve_unix(
+    listener: UnixListener,
+    state: Arc<RwLock<DaemonState>>,
+) -> std::io::Result<()> {
+    loop {
+        let (stream, _addr) = listener.accept().await?;
+        info!("Client connected: unix socket");
+        let state_clone = Arc::clone(&state);
+
+        tokio::spawn(async move {
+            let (reader, writer) = stream.into_split();
+            if let Err(e) = handle_client(reader, writer, state_clone).await {
+                error!("Client handler error: {}", e);
+            }
+        });
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Main
 // ═══════════════════════════════════════════════════════════════════════════
@@ -4184,16 +6947,96 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize logging
     tracing_subscriber::fmt::init();
 
+    let cli = parse_args()?;
+    let brain_cfg = load_brain_config(cli.config.as_deref())?;
+    if cli.print_config {
+        print!("{}", brain_cfg.to_toml()?);
+        return Ok(());
+    }
+    if cli.print_env {
+        brain_cfg.print_env_template();
+        return Ok(());
+    }
+    if let Some(path) = &cli.config {
+        info!("Using brain config from {}", path.display());
+    }
+    let transport = cli.transport;
+    #[cfg(not(unix))]
+    if transport.unix() {
+        return Err(
+            "Unix domain sockets are not supported on this platform; use --transport tcp".into(),
+        );
+    }
+
     // Setup application paths
     let paths = AppPaths::new()?;
+    #[cfg(unix)]
+    let socket_path = paths.socket_file();
     info!("Persistence initialized (OS data dir; braine.bbi)");
 
     // Initialize daemon state
-    let state = Arc::new(RwLock::new(DaemonState::new(paths)));
+    let mut daemon_state = DaemonState::new(paths, brain_cfg);
+    daemon_state.snapshot_keep = cli.snapshot_keep;
+    let state = Arc::new(RwLock::new(daemon_state));
+
+    // Start IPC listeners before anything else so clients see a bound endpoint early.
+    let tcp_listener = if transport.tcp() {
+        let listener = TcpListener::bind(TCP_ADDR).await?;
+        info!("Braine daemon listening on {}", TCP_ADDR);
+        Some(listener)
+    } else {
+        None
+    };
+    #[cfg(unix)]
+    let unix_listener = if transport.unix() {
+        let (listener, path) = bind_unix(socket_path)?;
+        info!("Braine daemon listening on unix:{}", path.display());
+        Some((listener, path))
+    } else {
+        None
+    };
+    #[cfg(unix)]
+    let bound_socket = unix_listener.as_ref().map(|(_, p)| p.clone());
+    // The WebSocket server is an add-on: failing to bind it must not stop the daemon.
+    let ws_listener = if cli.ws_port == 0 {
+        None
+    } else {
+        match TcpListener::bind(("127.0.0.1", cli.ws_port)).await {
+            Ok(listener) => {
+                info!("Braine daemon WebSocket on ws://127.0.0.1:{}", cli.ws_port);
+                Some(listener)
+            }
+            Err(e) => {
+                warn!("Could not bind WebSocket port {}: {}", cli.ws_port, e);
+                None
+            }
+        }
+    };
+
+    // Metrics are optional too: a busy port only costs the scrape endpoint.
+    let metrics_listener = if cli.metrics_port == 0 {
+        None
+    } else {
+        match TcpListener::bind(("127.0.0.1", cli.metrics_port)).await {
+            Ok(listener) => {
+                info!(
+                    "Braine daemon metrics on http://127.0.0.1:{}/metrics",
+                    cli.metrics_port
+                );
+                Some(listener)
+            }
+            Err(e) => {
+                warn!("Could not bind metrics port {}: {}", cli.metrics_port, e);
+                None
+            }
+        }
+    };
 
     // Save on Ctrl-C so state persists even if the daemon is stopped abruptly.
     {
         let state = Arc::clone(&state);
+        #[cfg(unix)]
+        let bound_socket = bound_socket.clone();
         tokio::spawn(async move {
             if tokio::signal::ctrl_c().await.is_ok() {
                 let s = state.read().await;
@@ -4202,6 +7045,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 } else {
                     info!("Ctrl-C: brain saved");
                 }
+                #[cfg(unix)]
+                if let Some(p) = &bound_socket {
+                    let _ = std::fs::remove_file(p);
+                }
                 std::process::exit(0);
             }
         });
@@ -4216,10 +7063,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    // Start IPC server
-    let listener = TcpListener::bind("127.0.0.1:9876").await?;
-    info!("Braine daemon listening on 127.0.0.1:9876");
-
     // Game loop task
     let state_clone = Arc::clone(&state);
     tokio::spawn(async move {
@@ -4236,20 +7079,129 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             // Execute game tick
             let mut s = state_clone.write().await;
+            let frame = s.frame;
             s.tick();
+            // Wake `WatchDiag` subscribers whenever a frame actually ran.
+            if s.frame != frame && s.diag_tx.receiver_count() > 0 {
+                let _ = s.diag_tx.send(s.frame);
+            }
         }
     });
 
-    // Accept client connections
-    loop {
-        let (stream, addr) = listener.accept().await?;
-        info!("Client connected: {}", addr);
-        let state_clone = Arc::clone(&state);
+    // Browser clients: same JSON messages, one per WebSocket text frame.
+    if let Some(listener) = ws_listener {
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            if let Err(e) = serve_ws(listener, state).await {
+                error!("WebSocket server error: {}", e);
+            }
+        });
+    }
 
+    // Prometheus scrapes: plain HTTP/1.1, `GET /metrics` only.
+    if let Some(listener) = metrics_listener {
+        let state = Arc::clone(&state);
         tokio::spawn(async move {
-            if let Err(e) = handle_client(stream, state_clone).await {
-                error!("Client handler error: {}", e);
+            if let Err(e) = serve_metrics(listener, state).await {
+                error!("Metrics server error: {}", e);
             }
         });
     }
+
+    // Accept client connections on every enabled transport (same JSON-newline framing).
+    #[cfg(unix)]
+    if let Some((listener, _)) = unix_listener {
+        let unix_task = serve_unix(listener, Arc::clone(&state));
+        match tcp_listener {
+            Some(tcp) => {
+                tokio::try_join!(unix_task, serve_tcp(tcp, state))?;
+            }
+            None => unix_task.await?,
+        }
+        return Ok(());
+    }
+
+    if let Some(listener) = tcp_listener {
+        serve_tcp(listener, state).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_state() -> DaemonState {
+        let dir = std::env::temp_dir().join(format!("brained-test-{}", std::process::id()));
+        DaemonState::new(AppPaths::in_dir(dir), DaemonState::default_brain_config())
+    }
+
+    #[test]
+    fn batch_of_reads_runs_while_running() {
+        let mut s = test_state();
+        s.running = true;
+        let batch: Request = serde_json::from_value(serde_json::json!({
+            "type": "BatchRequests",
+            "requests": [
+                {"type": "GetGraph", "kind": "substrate", "max_nodes": 16, "max_edges": 16},
+                {"type": "AdvisorGet"},
+                {"type": "AdvisorContext"},
+                {"type": "InferActionScores"},
+                {"type": "SyncGetInfo"},
+                {"type": "ReplayGetDataset"},
+                {"type": "GatesGetModules"},
+            ],
+        }))
+        .unwrap();
+        let Request::BatchRequests { requests } = &batch else {
+            unreachable!()
+        };
+        assert!(requests
+            .iter()
+            .all(|r| r.is_read_only() && !r.requires_stopped()));
+
+        match handle_request(batch, &mut s) {
+            Response::BatchResults { results, .. } => assert_eq!(results.len(), 7),
+            other => panic!("batch rejected: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn score_expert_rejects_oversized_batches() {
+        let s = test_state();
+        let input =
+            serde_json::json!({"stimuli": [], "steps": 1, "context_key": "", "meaning_alpha": 0.0});
+        let request: Request = serde_json::from_value(serde_json::json!({
+            "type": "ScoreExpert",
+            "id": 1,
+            "inputs": vec![input; SCORE_EXPERT_MAX_INPUTS + 1],
+        }))
+        .unwrap();
+        match handle_read_request(&request, &s) {
+            Response::Error { message } => assert!(message.contains("at most 64"), "{message}"),
+            other => panic!("oversized batch accepted: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn profile_step_is_rejected_inside_batches() {
+        let mut s = test_state();
+        let batch: Request = serde_json::from_value(serde_json::json!({
+            "type": "BatchRequests",
+            "requests": [{"type": "ProfileStep", "num_steps": 1}],
+        }))
+        .unwrap();
+        match handle_request(batch, &mut s) {
+            Response::BatchResults {
+                results,
+                batch_had_errors,
+            } => {
+                assert!(batch_had_errors);
+                assert!(
+                    matches!(&results[0], Response::Error { message } if message.contains("top-level"))
+                );
+            }
+            other => panic!("unexpected response: {other:?}"),
+        }
+    }
 }
@@ -19,6 +19,12 @@ impl AppPaths {
         Ok(Self { data_dir })
     }
 
+    /// Paths rooted at `data_dir`, which is not created.
+    #[cfg(test)]
+    pub fn in_dir(data_dir: PathBuf) -> Self {
+        Self { data_dir }
+    }
+
     fn get_data_dir() -> Result<PathBuf, String> {
         let base = dirs::data_dir().ok_or("Could not determine data directory")?;
         Ok(base.join("braine"))
@@ -33,6 +39,11 @@ impl AppPaths {
         self.data_dir.join("braine.bbi")
     }
 
+    /// Causal-memory checkpoint written between full saves.
+    pub fn causal_file(&self) -> PathBuf {
+        self.data_dir.join("causal.bcs")
+    }
+
     pub fn runtime_state_file(&self) -> PathBuf {
         self.data_dir.join("runtime.json")
     }
@@ -42,6 +53,11 @@ impl AppPaths {
         self.data_dir.join("config.json")
     }
 
+    /// Unix domain socket for the daemon's local IPC listener.
+    pub fn socket_file(&self) -> PathBuf {
+        self.data_dir.join("brained.sock")
+    }
+
     #[allow(dead_code)]
     pub fn log_file(&self) -> PathBuf {
         self.data_dir.join("brained.log")
@@ -1,9 +1,13 @@
 use braine::{storage, substrate::Brain};
-use std::io::{self, Read, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 
 pub const MAGIC_V3: &[u8; 8] = b"BRSTATE3";
+pub const MAGIC_V4: &[u8; 8] = b"BRSTATE4";
 
 pub const VERSION_V3: u32 = 3;
+/// Stores the brain image uncompressed (its own chunks are already LZ4 +
+/// CRC32), so loading streams it straight from the file.
+pub const VERSION_V4: u32 = 4;
 
 /// Tags for chunks in the persisted daemon state file.
 const TAG_BRAIN_IMAGE: [u8; 4] = *b"BIMG";
@@ -12,12 +16,78 @@ const TAG_RUNTIME_STATE: [u8; 4] = *b"RTST";
 
 pub struct LoadedState {
     pub brain: Brain,
+    pub version: u32,
     pub experts_state: Option<Vec<u8>>,
     pub runtime_state: Option<Vec<u8>>,
 }
 
+/// What [`load_auto`] found at the start of the file.
+pub enum LoadedFormat {
+    /// A bare brain image (`BRAINE01`), as written by `Brain::save_image_to`.
+    Legacy,
+    StateV3 {
+        experts_state: Option<Vec<u8>>,
+        runtime_state: Option<Vec<u8>>,
+    },
+    StateV4 {
+        experts_state: Option<Vec<u8>>,
+        runtime_state: Option<Vec<u8>>,
+    },
+}
+
+impl LoadedFormat {
+    /// State wrapper version, or 0 for a bare brain image.
+    pub fn state_version(&self) -> u32 {
+        match self {
+            LoadedFormat::Legacy => 0,
+            LoadedFormat::StateV3 { .. } => VERSION_V3,
+            LoadedFormat::StateV4 { .. } => VERSION_V4,
+        }
+    }
+}
+
 pub fn is_state_magic(magic: &[u8; 8]) -> bool {
-    magic == MAGIC_V3
+    magic == MAGIC_V3 || magic == MAGIC_V4
+}
+
+/// State wrapper version of the file at the reader's position, or 0 if it is
+/// not a state file (including unreadable or truncated headers).
+pub fn peek_state_version<R: Read>(r: &mut R) -> u32 {
+    match storage::read_exact::<8, _>(r) {
+        Ok(magic) if is_state_magic(&magic) => storage::read_u32_le(r).unwrap_or_default(),
+        _ => 0,
+    }
+}
+
+/// Load either a daemon state file or a bare brain image, picked by magic bytes.
+pub fn load_auto<R: Read + Seek>(r: &mut R) -> io::Result<(Brain, LoadedFormat)> {
+    let start = r.stream_position()?;
+    let magic = storage::read_exact::<8, _>(r)?;
+    r.seek(SeekFrom::Start(start))?;
+
+    if is_state_magic(&magic) {
+        let loaded = load_state_from(r)?;
+        let (experts_state, runtime_state) = (loaded.experts_state, loaded.runtime_state);
+        let format = if loaded.version == VERSION_V3 {
+            LoadedFormat::StateV3 {
+                experts_state,
+                runtime_state,
+            }
+        } else {
+            LoadedFormat::StateV4 {
+                experts_state,
+                runtime_state,
+            }
+        };
+        Ok((loaded.brain, format))
+    } else if &magic == storage::MAGIC {
+        Ok((Brain::load_image_from(r)?, LoadedFormat::Legacy))
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unrecognised brain file (expected BRSTATE3, BRSTATE4 or BRAINE01)",
+        ))
+    }
 }
 
 pub fn save_state_to_with_version<W: Write>(
@@ -27,23 +97,37 @@ pub fn save_state_to_with_version<W: Write>(
     runtime_state: Option<&[u8]>,
     version: u32,
 ) -> io::Result<()> {
-    if version != VERSION_V3 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            "unsupported state version",
-        ));
-    }
-
-    w.write_all(MAGIC_V3)?;
-    storage::write_u32_le(w, VERSION_V3)?;
-
     let mut brain_bytes: Vec<u8> = Vec::new();
-    // State wrapper stores the current compressed brain image bytes (which are already chunked).
     brain.save_image_to(&mut brain_bytes)?;
-    storage::write_chunk_v2_lz4(w, TAG_BRAIN_IMAGE, &brain_bytes)?;
-    storage::write_chunk_v2_lz4(w, TAG_EXPERTS_STATE, experts_state)?;
-    if let Some(rt) = runtime_state {
-        storage::write_chunk_v2_lz4(w, TAG_RUNTIME_STATE, rt)?;
+
+    match version {
+        VERSION_V3 => {
+            w.write_all(MAGIC_V3)?;
+            storage::write_u32_le(w, VERSION_V3)?;
+
+            // State wrapper stores the current compressed brain image bytes (which are already chunked).
+            storage::write_chunk_v2_lz4(w, TAG_BRAIN_IMAGE, &brain_bytes)?;
+            storage::write_chunk_v2_lz4(w, TAG_EXPERTS_STATE, experts_state)?;
+            if let Some(rt) = runtime_state {
+                storage::write_chunk_v2_lz4(w, TAG_RUNTIME_STATE, rt)?;
+            }
+        }
+        VERSION_V4 => {
+            w.write_all(MAGIC_V4)?;
+            storage::write_u32_le(w, VERSION_V4)?;
+
+            storage::write_chunk(w, TAG_BRAIN_IMAGE, &brain_bytes)?;
+            storage::write_chunk_v4_lz4(w, TAG_EXPERTS_STATE, experts_state)?;
+            if let Some(rt) = runtime_state {
+                storage::write_chunk_v4_lz4(w, TAG_RUNTIME_STATE, rt)?;
+            }
+        }
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "unsupported state version",
+            ));
+        }
     }
     Ok(())
 }
@@ -58,14 +142,24 @@ pub fn load_state_from<R: Read>(r: &mut R) -> io::Result<LoadedState> {
     }
 
     let version = storage::read_u32_le(r)?;
-    if version != VERSION_V3 {
+    let expected_magic = match version {
+        VERSION_V3 => MAGIC_V3,
+        VERSION_V4 => MAGIC_V4,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unsupported state version",
+            ))
+        }
+    };
+    if &magic != expected_magic {
         return Err(io::Error::new(
             io::ErrorKind::InvalidData,
-            "unsupported state version",
+            "state magic does not match version",
         ));
     }
 
-    let mut brain_img: Option<Vec<u8>> = None;
+    let mut brain: Option<Brain> = None;
     let mut experts_state: Option<Vec<u8>> = None;
     let mut runtime_state: Option<Vec<u8>> = None;
 
@@ -76,18 +170,24 @@ pub fn load_state_from<R: Read>(r: &mut R) -> io::Result<LoadedState> {
             Err(e) => return Err(e),
         };
 
-        let buf = {
+        if version == VERSION_V4 && tag == TAG_BRAIN_IMAGE {
+            // Decode the image chunk by chunk instead of buffering it whole.
             let mut take = r.take(len as u64);
-            let uncompressed_len = storage::read_u32_le(&mut take)? as usize;
-            let mut compressed = Vec::with_capacity((len as usize).saturating_sub(4));
-            take.read_to_end(&mut compressed)?;
-            let decompressed = storage::decompress_lz4(&compressed, uncompressed_len)?;
+            brain = Some(Brain::load_image_from(&mut take)?);
             io::copy(&mut take, &mut io::sink())?;
-            decompressed
+            continue;
+        }
+
+        let crc = if version == VERSION_V4 {
+            Some(storage::read_u32_le(r)?)
+        } else {
+            None
         };
+        let buf = storage::ChunkFrame { tag, len, crc }.read_payload(r)?;
 
         if tag == TAG_BRAIN_IMAGE {
-            brain_img = Some(buf);
+            let mut cursor = std::io::Cursor::new(buf);
+            brain = Some(Brain::load_image_from(&mut cursor)?);
         } else if tag == TAG_EXPERTS_STATE {
             experts_state = Some(buf);
         } else if tag == TAG_RUNTIME_STATE {
@@ -95,14 +195,56 @@ pub fn load_state_from<R: Read>(r: &mut R) -> io::Result<LoadedState> {
         }
     }
 
-    let brain_img = brain_img
+    let brain = brain
         .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing brain image chunk"))?;
-    let mut cursor = std::io::Cursor::new(brain_img);
-    let brain = Brain::load_image_from(&mut cursor)?;
 
     Ok(LoadedState {
         brain,
+        version,
         experts_state,
         runtime_state,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use braine::substrate::BrainConfig;
+
+    #[test]
+    fn state_round_trips_in_v3_and_v4() {
+        let mut brain = Brain::new(BrainConfig::with_size(32, 4).with_seed(3));
+        brain.define_sensor("s", 4);
+        for version in [VERSION_V3, VERSION_V4] {
+            let mut buf = Vec::new();
+            save_state_to_with_version(&mut buf, &brain, b"experts", Some(b"{}"), version).unwrap();
+            let loaded = load_state_from(&mut buf.as_slice()).unwrap();
+            assert_eq!(loaded.brain.unit_count(), brain.unit_count());
+            assert_eq!(loaded.experts_state.as_deref(), Some(&b"experts"[..]));
+            assert_eq!(loaded.runtime_state.as_deref(), Some(&b"{}"[..]));
+        }
+    }
+
+    #[test]
+    fn load_auto_detects_state_and_bare_images() {
+        let brain = Brain::new(BrainConfig::with_size(16, 2).with_seed(5));
+
+        for version in [VERSION_V3, VERSION_V4] {
+            let mut buf = Vec::new();
+            save_state_to_with_version(&mut buf, &brain, b"experts", None, version).unwrap();
+            assert_eq!(peek_state_version(&mut buf.as_slice()), version);
+            let (loaded, format) = load_auto(&mut io::Cursor::new(buf)).unwrap();
+            assert_eq!(loaded.unit_count(), brain.unit_count());
+            assert_eq!(format.state_version(), version);
+        }
+
+        let mut bare = Vec::new();
+        brain.save_image_to(&mut bare).unwrap();
+        assert_eq!(peek_state_version(&mut bare.as_slice()), 0);
+        let (loaded, format) = load_auto(&mut io::Cursor::new(bare)).unwrap();
+        assert_eq!(loaded.unit_count(), brain.unit_count());
+        assert!(matches!(format, LoadedFormat::Legacy));
+
+        assert!(load_auto(&mut io::Cursor::new(b"NOTABRAIN".to_vec())).is_err());
+    }
+}
@@ -3,11 +3,15 @@
 //! Run with:
 //!   cargo bench
 //!   cargo bench --features parallel
+//!   RUSTFLAGS="-C target-cpu=native" cargo bench --features simd -- hebbian_phase
 //!
 //! Results are saved to target/criterion/
 
+use std::time::Duration;
+
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 
+use braine::causality::{CausalMemory, SymbolId};
 use braine::substrate::{Brain, BrainConfig, ExecutionTier, Stimulus};
 
 fn make_brain(unit_count: usize, connectivity: usize, seed: u64) -> Brain {
@@ -128,6 +132,42 @@ fn bench_step_tiers(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark only the Hebbian (plasticity) phase of step(), scalar vs SIMD.
+///
+/// Uses `Brain::profile_step` so dynamics/pruning time is excluded.
+fn bench_hebbian_phase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hebbian_phase");
+
+    let size = 256;
+    let connectivity = 64;
+    group.throughput(Throughput::Elements((size * connectivity) as u64));
+
+    for (name, tier) in [
+        ("scalar_256", ExecutionTier::Scalar),
+        ("simd_256", ExecutionTier::Simd),
+    ] {
+        group.bench_function(name, |b| {
+            let mut brain = make_brain(size, connectivity, 42);
+            brain.set_execution_tier(tier);
+            brain.define_sensor("stim", 32);
+            brain.define_action("act", 6);
+
+            b.iter_custom(|iters| {
+                let mut total = Duration::ZERO;
+                for _ in 0..iters {
+                    brain.apply_stimulus(Stimulus::new("stim", 1.0));
+                    brain.set_neuromodulator(0.5);
+                    let profile = brain.profile_step();
+                    total += Duration::from_nanos(profile.hebbian_ns);
+                }
+                total
+            });
+        });
+    }
+
+    group.finish();
+}
+
 /// Benchmark learning (Hebbian updates) separately.
 fn bench_learning(c: &mut Criterion) {
     let mut group = c.benchmark_group("learning");
@@ -152,7 +192,7 @@ fn bench_learning(c: &mut Criterion) {
                 brain.apply_stimulus(Stimulus::new("stim", 1.0));
                 brain.set_neuromodulator(0.7);
                 brain.step();
-                black_box(brain.diagnostics().connection_count)
+                black_box(brain.connection_count())
             });
         });
     }
@@ -160,6 +200,39 @@ fn bench_learning(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark causal memory updates with a large symbol table.
+///
+/// Compare `cargo bench -- causal_observe` with and without `--features parallel`.
+fn bench_causal_observe(c: &mut Criterion) {
+    let mut group = c.benchmark_group("causal_observe");
+
+    let symbols: SymbolId = 600;
+    let active = 120;
+    group.throughput(Throughput::Elements((active * active) as u64));
+
+    group.bench_function("600_symbols_120_active", |b| {
+        let mut mem = CausalMemory::new(0.01);
+        // Rotate through overlapping windows so the edge map fills up.
+        let window = |t: u32| -> Vec<SymbolId> {
+            (0..active as u32)
+                .map(|k| (t * 37 + k * 5) % symbols)
+                .collect()
+        };
+        for t in 0..200 {
+            mem.observe(&window(t));
+        }
+
+        let mut t = 200;
+        b.iter(|| {
+            mem.observe(&window(t));
+            t = t.wrapping_add(1);
+            black_box(mem.stats().edges)
+        });
+    });
+
+    group.finish();
+}
+
 /// Benchmark serialization round-trip.
 fn bench_serialization(c: &mut Criterion) {
     let mut group = c.benchmark_group("serialization");
@@ -186,7 +259,7 @@ fn bench_serialization(c: &mut Criterion) {
             b.iter(|| {
                 let mut cursor = std::io::Cursor::new(&buf);
                 let loaded = Brain::load_image_from(&mut cursor).unwrap();
-                black_box(loaded.diagnostics().unit_count)
+                black_box(loaded.unit_count())
             });
         });
     }
@@ -228,7 +301,9 @@ criterion_group!(
     benches,
     bench_step_sizes,
     bench_step_tiers,
+    bench_hebbian_phase,
     bench_learning,
+    bench_causal_observe,
     bench_serialization,
     bench_csr_ops,
 );
@@ -9,11 +9,16 @@
 //!   braine-cli action left
 //!   braine-cli save
 //!
-//! By default it talks to 127.0.0.1:9876; override with `--addr host:port`.
+//! By default it tries the daemon's Unix socket (on Unix) and falls back to
+//! 127.0.0.1:9876; force TCP with `--addr host:port` or a socket with `--socket path`.
 
 use serde::{Deserialize, Serialize};
-use std::io::{BufRead, BufReader, Write};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
 use std::net::TcpStream;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
 use std::process;
 use std::time::Duration;
 
@@ -80,6 +85,14 @@ enum Request {
         persistence_mode: String,
     },
     CullExperts,
+    SetExpertEnsembleMode {
+        enabled: bool,
+        top_k: u32,
+    },
+    SetExpertSimilarityRouting {
+        enabled: bool,
+        threshold: f32,
+    },
 
     // Advisor / LLM integration
     AdvisorGet,
@@ -126,6 +139,13 @@ enum Request {
         trial_period_ms: Option<u32>,
         #[serde(default)]
         max_units: Option<u32>,
+        #[serde(default)]
+        intrinsic_reward_scale: Option<f32>,
+        #[serde(default)]
+        causal_window_frames: Option<u32>,
+        /// Replaces `BrainConfig::experimental_flags`.
+        #[serde(default)]
+        experimental_flags: Option<HashMap<String, f32>>,
     },
 
     // Manual gates (freeze/paralyze)
@@ -149,6 +169,8 @@ enum Request {
         forced_action: Option<String>,
         reward: f32,
         #[serde(default)]
+        predicted_reward: Option<f32>,
+        #[serde(default)]
         learn: bool,
         #[serde(default)]
         steps: u32,
@@ -204,6 +226,12 @@ enum Response {
         target_fps: u32,
         trial_period_ms: u32,
         max_units_limit: u32,
+        #[serde(default)]
+        intrinsic_reward_scale: f32,
+        #[serde(default)]
+        causal_window_frames: u32,
+        #[serde(default)]
+        experimental_flags: HashMap<String, f32>,
     },
 
     GatesModules {
@@ -648,13 +676,13 @@ struct BrainStats {
 }
 
 fn usage() -> ! {
-    eprintln!("braine-cli (talks to brained @ 127.0.0.1:9876 by default)");
-    eprintln!("Usage: braine-cli [--addr host:port] <command> [args]\n");
+    eprintln!("braine-cli (talks to brained via its Unix socket, else 127.0.0.1:9876)");
+    eprintln!("Usage: braine-cli [--addr host:port | --socket path] <command> [args]\n");
     eprintln!("Commands:");
     eprintln!("  status                      Show daemon state");
     eprintln!("  start | stop                Control run loop");
     eprintln!(
-        "  game <spot|bandit|spot_reversal|spotxy|maze|pong|text|replay>  Switch task/game (stop first)"
+        "  game <spot|bandit|spot_reversal|spotxy|maze|gridworld|pong|text|n_back|conditional_discrimination|temporal_xor|match_to_sample|replay>  Switch task/game (stop first)"
     );
     eprintln!("  mode <braine|human>         Switch control mode");
     eprintln!("  action <left|right|up|down|stay>  Send human action");
@@ -665,6 +693,10 @@ fn usage() -> ! {
     eprintln!("  trialms <10-60000>          Set trial period in milliseconds");
     eprintln!("  tier <scalar|simd|parallel|gpu>  Set execution tier (effective may fall back)");
     eprintln!("  experts <on|off|cull>        Control expert (child brain) mechanism");
+    eprintln!("  experts ensemble <on|off> [top_k]  Weighted vote across active experts");
+    eprintln!(
+        "  experts similarity <on|off> [threshold]  Route unseen contexts to similar experts"
+    );
     eprintln!("  experts policy <parent_learning> <max_children> <child_reward_scale> <episode_trials> <consolidate_topk> [allow_nested] [max_depth] [persist_mode]");
     eprintln!("                               allow_nested: true|false (default false)");
     eprintln!("                               max_depth: >=1 (default 1)");
@@ -684,7 +716,7 @@ fn usage() -> ! {
     eprintln!(
         "  trial <ctx> <reward> [k=v...] <stim...>  Run external trial (daemon must be stopped)"
     );
-    eprintln!("                             opts: learn=true|false steps=N alpha=A action=NAME allowed=a,b,c");
+    eprintln!("                             opts: learn=true|false steps=N alpha=A predicted=P action=NAME allowed=a,b,c");
     eprintln!("                             stimuli: name or name=strength");
     eprintln!("  demo text <trials> [advisor] Run a quick text task demo and print summary");
     eprintln!("  demo replay <trials> [dataset.json] [mock_llm]  Run replay demo; optionally mimic LLM via context/apply");
@@ -752,15 +784,28 @@ fn parse_bool(s: &str) -> Option<bool> {
     }
 }
 
-fn parse_args() -> (String, Vec<String>) {
+const DEFAULT_TCP_ADDR: &str = "127.0.0.1:9876";
+
+/// Where to reach the daemon.
+enum Endpoint {
+    /// Try the daemon's Unix socket first, then fall back to TCP.
+    Auto,
+    Tcp(String),
+    Unix(PathBuf),
+}
+
+fn parse_args() -> (Endpoint, Vec<String>) {
     let mut args: Vec<String> = std::env::args().skip(1).collect();
     if args.is_empty() {
         usage();
     }
 
-    let mut addr = "127.0.0.1:9876".to_string();
+    let mut endpoint = Endpoint::Auto;
     if args.len() >= 2 && args[0] == "--addr" {
-        addr = args[1].clone();
+        endpoint = Endpoint::Tcp(args[1].clone());
+        args.drain(0..2);
+    } else if args.len() >= 2 && args[0] == "--socket" {
+        endpoint = Endpoint::Unix(PathBuf::from(&args[1]));
         args.drain(0..2);
     }
 
@@ -768,16 +813,70 @@ fn parse_args() -> (String, Vec<String>) {
         usage();
     }
 
-    (addr, args)
+    (endpoint, args)
+}
+
+/// Socket paths the daemon may bind, in the order it tries them.
+///
+/// Mirrors `brained`'s data directory (`dirs::data_dir()/braine`) with the
+/// `/tmp/brained.sock` fallback.
+#[cfg(unix)]
+fn unix_socket_candidates() -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let data_dir = if cfg!(target_os = "macos") {
+        home.map(|h| h.join("Library/Application Support"))
+    } else {
+        std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .filter(|p| p.is_absolute())
+            .or_else(|| home.map(|h| h.join(".local/share")))
+    };
+    if let Some(d) = data_dir {
+        out.push(d.join("braine").join("brained.sock"));
+    }
+    out.push(PathBuf::from("/tmp/brained.sock"));
+    out
 }
 
-fn send_request(addr: &str, req: &Request) -> Result<Response, String> {
-    let mut stream = TcpStream::connect(addr).map_err(|e| format!("connect: {e}"))?;
+fn send_request(endpoint: &Endpoint, req: &Request) -> Result<Response, String> {
+    match endpoint {
+        Endpoint::Tcp(addr) => send_tcp(addr, req),
+        #[cfg(unix)]
+        Endpoint::Unix(path) => {
+            let stream = UnixStream::connect(path).map_err(|e| format!("connect: {e}"))?;
+            stream
+                .set_read_timeout(Some(Duration::from_secs(5)))
+                .map_err(|e| format!("set_read_timeout: {e}"))?;
+            exchange(stream, req)
+        }
+        #[cfg(not(unix))]
+        Endpoint::Unix(_) => Err("Unix sockets are not supported on this platform".to_string()),
+        Endpoint::Auto => {
+            #[cfg(unix)]
+            for path in unix_socket_candidates() {
+                if let Ok(stream) = UnixStream::connect(&path) {
+                    stream
+                        .set_read_timeout(Some(Duration::from_secs(5)))
+                        .map_err(|e| format!("set_read_timeout: {e}"))?;
+                    return exchange(stream, req);
+                }
+            }
+            send_tcp(DEFAULT_TCP_ADDR, req)
+        }
+    }
+}
+
+fn send_tcp(addr: &str, req: &Request) -> Result<Response, String> {
+    let stream = TcpStream::connect(addr).map_err(|e| format!("connect: {e}"))?;
     stream
         .set_read_timeout(Some(Duration::from_secs(5)))
         .map_err(|e| format!("set_read_timeout: {e}"))?;
-    let mut reader = BufReader::new(stream.try_clone().map_err(|e| format!("clone: {e}"))?);
+    exchange(stream, req)
+}
 
+/// One request/response round trip using newline-delimited JSON.
+fn exchange<S: Read + Write>(mut stream: S, req: &Request) -> Result<Response, String> {
     let line = serde_json::to_string(req).map_err(|e| format!("serialize: {e}"))?;
     stream
         .write_all(line.as_bytes())
@@ -785,7 +884,7 @@ fn send_request(addr: &str, req: &Request) -> Result<Response, String> {
         .map_err(|e| format!("send: {e}"))?;
 
     let mut resp_line = String::new();
-    reader
+    BufReader::new(&mut stream)
         .read_line(&mut resp_line)
         .map_err(|e| format!("recv: {e}"))?;
     serde_json::from_str(&resp_line).map_err(|e| format!("parse response: {e}"))
@@ -966,6 +1065,7 @@ fn main() {
             let mut learn: bool = true;
             let mut steps: u32 = 1;
             let mut meaning_alpha: Option<f32> = None;
+            let mut predicted_reward: Option<f32> = None;
             let mut forced_action: Option<String> = None;
             let mut allowed_actions: Vec<String> = Vec::new();
             let mut stimuli: Vec<OwnedStimulus> = Vec::new();
@@ -989,6 +1089,13 @@ fn main() {
                     meaning_alpha = Some(a);
                     continue;
                 }
+                if let Some(v) = tok.strip_prefix("predicted=") {
+                    let p: f32 = v
+                        .parse()
+                        .unwrap_or_else(|_| make_error("predicted must be a float"));
+                    predicted_reward = Some(p);
+                    continue;
+                }
                 if let Some(v) = tok.strip_prefix("action=") {
                     forced_action = Some(v.to_string());
                     continue;
@@ -1025,6 +1132,7 @@ fn main() {
                 allowed_actions,
                 forced_action,
                 reward,
+                predicted_reward,
                 learn,
                 steps,
                 meaning_alpha,
@@ -1114,6 +1222,42 @@ fn main() {
                 "on" => Request::SetExpertsEnabled { enabled: true },
                 "off" => Request::SetExpertsEnabled { enabled: false },
                 "cull" => Request::CullExperts,
+                "ensemble" => {
+                    if args.len() < 3 {
+                        make_error("usage: experts ensemble <on|off> [top_k]");
+                    }
+                    let enabled = match args[2].as_str() {
+                        "on" => true,
+                        "off" => false,
+                        _ => make_error("usage: experts ensemble <on|off> [top_k]"),
+                    };
+                    let top_k: u32 = if args.len() >= 4 {
+                        args[3]
+                            .parse()
+                            .unwrap_or_else(|_| make_error("top_k must be a u32"))
+                    } else {
+                        0
+                    };
+                    Request::SetExpertEnsembleMode { enabled, top_k }
+                }
+                "similarity" => {
+                    if args.len() < 3 {
+                        make_error("usage: experts similarity <on|off> [threshold]");
+                    }
+                    let enabled = match args[2].as_str() {
+                        "on" => true,
+                        "off" => false,
+                        _ => make_error("usage: experts similarity <on|off> [threshold]"),
+                    };
+                    let threshold: f32 = if args.len() >= 4 {
+                        args[3]
+                            .parse()
+                            .unwrap_or_else(|_| make_error("threshold must be a float (0-1)"))
+                    } else {
+                        0.8
+                    };
+                    Request::SetExpertSimilarityRouting { enabled, threshold }
+                }
                 "policy" => {
                     if args.len() < 7 {
                         make_error(
@@ -1413,6 +1557,9 @@ fn main() {
                 target_fps: Some(120),
                 trial_period_ms: Some(40),
                 max_units: None,
+                intrinsic_reward_scale: None,
+                causal_window_frames: None,
+                experimental_flags: None,
             });
             if kind == "text" {
                 must(&Request::AdvisorSet {
@@ -1602,6 +1749,9 @@ fn main() {
             target_fps,
             trial_period_ms,
             max_units_limit,
+            intrinsic_reward_scale,
+            causal_window_frames,
+            experimental_flags,
         }) => {
             println!("exploration_eps={exploration_eps:.3}");
             println!("meaning_alpha={meaning_alpha:.3}");
@@ -1610,6 +1760,13 @@ fn main() {
             println!("target_fps={target_fps}");
             println!("trial_period_ms={trial_period_ms}");
             println!("max_units_limit={max_units_limit}");
+            println!("intrinsic_reward_scale={intrinsic_reward_scale:.3}");
+            println!("causal_window_frames={causal_window_frames}");
+            let mut flags: Vec<_> = experimental_flags.into_iter().collect();
+            flags.sort_by(|a, b| a.0.cmp(&b.0));
+            for (name, value) in flags {
+                println!("experimental_flags.{name}={value:.3}");
+            }
         }
         Ok(Response::Error { message }) => {
             eprintln!("Error: {message}");
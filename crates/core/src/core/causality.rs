@@ -10,20 +10,48 @@ use std::collections::HashMap;
 use std::io::{self, Read, Write};
 
 #[cfg(not(feature = "std"))]
-use alloc::collections::BinaryHeap;
+use alloc::collections::{BinaryHeap, VecDeque};
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
 use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
 
 use core::cmp::{Ordering, Reverse};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "std")]
 use crate::storage;
 
+/// Below this many edges the decay pass stays sequential (rayon overhead dominates).
+#[cfg(feature = "parallel")]
+const PAR_MIN_EDGES: usize = 4096;
+
+/// Below this many current symbols co-occurrence keys are generated sequentially.
+#[cfg(feature = "parallel")]
+const PAR_MIN_SYMBOLS: usize = 64;
+
+/// Below this many directed transitions per observation they are applied sequentially.
+#[cfg(feature = "parallel")]
+const PAR_MIN_TRANSITIONS: usize = 4096;
+
+/// Lock shards of the parallel transition accumulator (a power of two).
+#[cfg(feature = "parallel")]
+const PAR_ACC_SHARDS: usize = 16;
+
+/// Accumulator shard owning `key`.
+#[cfg(feature = "parallel")]
+#[inline]
+fn acc_shard(key: u64) -> usize {
+    (key.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> (64 - PAR_ACC_SHARDS.trailing_zeros())) as usize
+}
+
 pub type SymbolId = u32;
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -35,7 +63,101 @@ pub struct CausalStats {
     pub last_cooccur_edge_updates: usize,
 }
 
-#[derive(Debug, Clone, Default)]
+/// Default capacity of a [`CausalEventLog`].
+pub const CAUSAL_EVENT_LOG_DEFAULT: usize = 1024;
+
+/// One edge count change made by [`CausalMemory::observe_lagged`].
+///
+/// `old_weight`/`new_weight` are the transition count (directed) or the
+/// co-occurrence count (undirected) before and after the update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CausalEvent {
+    pub step: u64,
+    pub from: SymbolId,
+    pub to: SymbolId,
+    pub old_weight: f32,
+    pub new_weight: f32,
+    pub is_directed: bool,
+}
+
+/// Bounded ring buffer of the most recent [`CausalEvent`]s, oldest first.
+#[derive(Debug, Clone)]
+pub struct CausalEventLog {
+    events: VecDeque<CausalEvent>,
+    capacity: usize,
+    step: u64,
+}
+
+impl CausalEventLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::new(),
+            capacity: capacity.max(1),
+            step: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change the capacity (min 1), dropping the oldest events if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.events.len() > self.capacity {
+            self.events.pop_front();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &CausalEvent> {
+        self.events.iter()
+    }
+
+    /// The last `n` events, oldest first.
+    pub fn last(&self, n: usize) -> impl Iterator<Item = &CausalEvent> {
+        self.events.iter().skip(self.events.len().saturating_sub(n))
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Stamp subsequent events with `step`.
+    pub(crate) fn set_step(&mut self, step: u64) {
+        self.step = step;
+    }
+
+    fn push(&mut self, key: u64, old_weight: f32, new_weight: f32, is_directed: bool) {
+        if self.events.len() >= self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(CausalEvent {
+            step: self.step,
+            from: (key >> 32) as SymbolId,
+            to: key as SymbolId,
+            old_weight,
+            new_weight,
+            is_directed,
+        });
+    }
+}
+
+impl Default for CausalEventLog {
+    fn default() -> Self {
+        Self::new(CAUSAL_EVENT_LOG_DEFAULT)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 struct EdgeStats {
     // Exponentially decayed transition counts (directed edges from prev to current).
@@ -92,22 +214,9 @@ impl CausalMemory {
 
         self.observe_count = self.observe_count.wrapping_add(1);
 
-        // Apply decay.
-        self.base_total *= 1.0 - self.decay;
-        for v in self.base.values_mut() {
-            *v *= 1.0 - self.decay;
-        }
-        for e in self.edges.values_mut() {
-            e.transition_count *= 1.0 - self.decay;
-            e.cooccur_count *= 1.0 - self.decay;
-        }
-
-        // Keep memory bounded: occasionally remove near-zero entries.
-        // Amortized to avoid scanning large maps every tick.
-        if (self.observe_count & 0xFF) == 0 {
-            let thr = 0.001;
-            self.prune_near_zero(thr);
-        }
+        // Update directed edges from previous->current.
+        let mut prev = core::mem::take(&mut self.prev_symbols);
+        self.decay_and_add_transitions(&[(&prev, 1.0)], current_symbols, &mut None);
 
         // Update base counts.
         for &s in current_symbols {
@@ -115,33 +224,23 @@ impl CausalMemory {
             self.base_total += 1.0;
         }
 
-        // Update directed edges from previous->current.
-        for &a in &self.prev_symbols {
-            for &b in current_symbols {
-                let key = pack(a, b);
-                self.edges.entry(key).or_default().transition_count += 1.0;
-                self.last_directed_edge_updates += 1;
-            }
-        }
-
         // Also record same-tick co-occurrence as a cheap proxy for immediate meaning links
         // (e.g. stimulus present and action selected in the same control cycle).
-        // This keeps compute small because current_symbols is tiny.
-        for (i, &a) in current_symbols.iter().enumerate() {
-            for &b in current_symbols.iter().skip(i + 1) {
-                if a == b {
-                    continue;
-                }
-                let k1 = pack(a, b);
-                let k2 = pack(b, a);
-                self.edges.entry(k1).or_default().cooccur_count += 0.5;
-                self.edges.entry(k2).or_default().cooccur_count += 0.5;
-                self.last_cooccur_edge_updates += 2;
-            }
-        }
+        self.observe_cooccurrence(current_symbols, &mut None);
 
+        prev.clear();
+        prev.extend_from_slice(current_symbols);
+        self.prev_symbols = prev;
+    }
+
+    /// Drop all base counts, edges and lag-1 history; keeps the decay rate.
+    pub fn clear(&mut self) {
+        self.edges.clear();
+        self.base.clear();
+        self.base_total = 0.0;
         self.prev_symbols.clear();
-        self.prev_symbols.extend_from_slice(current_symbols);
+        self.last_directed_edge_updates = 0;
+        self.last_cooccur_edge_updates = 0;
     }
 
     #[must_use]
@@ -154,31 +253,39 @@ impl CausalMemory {
     /// - lag 1 uses the internal `prev_symbols` (persisted in brain images)
     /// - lag >=2 are provided via `lag2_plus_history[0]=lag2`, `[1]=lag3`, ...
     /// - contributions decay geometrically by `lag_decay` (0<lag_decay<1)
+    ///
+    /// Every edge count change is appended to `events` when given.
     pub fn observe_lagged(
         &mut self,
         current_symbols: &[SymbolId],
         lag2_plus_history: &[Vec<SymbolId>],
         lag_decay: f32,
+        mut events: Option<&mut CausalEventLog>,
     ) {
         self.last_directed_edge_updates = 0;
         self.last_cooccur_edge_updates = 0;
 
         self.observe_count = self.observe_count.wrapping_add(1);
 
-        // Apply decay.
-        self.base_total *= 1.0 - self.decay;
-        for v in self.base.values_mut() {
-            *v *= 1.0 - self.decay;
-        }
-        for e in self.edges.values_mut() {
-            e.transition_count *= 1.0 - self.decay;
-            e.cooccur_count *= 1.0 - self.decay;
-        }
-
-        if (self.observe_count & 0xFF) == 0 {
-            let thr = 0.001;
-            self.prune_near_zero(thr);
+        // Directed edges: lag 1 from persisted prev_symbols, lag >=2 from provided history.
+        let mut prev = core::mem::take(&mut self.prev_symbols);
+        let mut sources: Vec<(&[SymbolId], f32)> = Vec::with_capacity(1 + lag2_plus_history.len());
+        sources.push((&prev, 1.0));
+        let mut w = lag_decay;
+        if w.is_finite() {
+            for older in lag2_plus_history {
+                if older.is_empty() {
+                    w *= lag_decay;
+                    continue;
+                }
+                if w <= 0.0 {
+                    break;
+                }
+                sources.push((older, w));
+                w *= lag_decay;
+            }
         }
+        self.decay_and_add_transitions(&sources, current_symbols, &mut events);
 
         // Update base counts.
         for &s in current_symbols {
@@ -186,54 +293,276 @@ impl CausalMemory {
             self.base_total += 1.0;
         }
 
-        // Directed edges: lag 1 from persisted prev_symbols.
-        for &a in &self.prev_symbols {
+        // Same-tick co-occurrence as a cheap proxy for immediate meaning links.
+        self.observe_cooccurrence(current_symbols, &mut events);
+
+        prev.clear();
+        prev.extend_from_slice(current_symbols);
+        self.prev_symbols = prev;
+    }
+
+    /// Record one synthetic `a → b` transition of weight `amount`, as if `a` had
+    /// been observed and `b` followed it, without touching decay or the lag-1
+    /// history. Used for targeted evidence such as contrastive negative examples.
+    pub fn observe_transition(
+        &mut self,
+        a: SymbolId,
+        b: SymbolId,
+        amount: f32,
+        mut events: Option<&mut CausalEventLog>,
+    ) {
+        if !(amount.is_finite() && amount > 0.0) {
+            return;
+        }
+        self.last_directed_edge_updates = 0;
+        self.last_cooccur_edge_updates = 0;
+        for s in [a, b] {
+            *self.base.entry(s).or_default() += amount;
+            self.base_total += amount;
+        }
+        self.add_transition(pack(a, b), amount, &mut events);
+    }
+
+    /// Record co-occurrence (both directions, 0.5 each, like same-tick pairs)
+    /// between `current_symbols` and every distinct symbol of the earlier
+    /// frames in `window_history` that is not itself current.
+    ///
+    /// Call after `observe`/`observe_lagged` for the same frame; base counts and
+    /// decay are left to those.
+    pub fn observe_window(
+        &mut self,
+        current_symbols: &[SymbolId],
+        window_history: &[Vec<SymbolId>],
+        mut events: Option<&mut CausalEventLog>,
+    ) {
+        if current_symbols.is_empty() || window_history.is_empty() {
+            return;
+        }
+        let mut earlier: Vec<SymbolId> = window_history
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|s| !current_symbols.contains(s))
+            .collect();
+        earlier.sort_unstable();
+        earlier.dedup();
+
+        for &a in &earlier {
             for &b in current_symbols {
-                let key = pack(a, b);
-                self.edges.entry(key).or_default().transition_count += 1.0;
-                self.last_directed_edge_updates += 1;
+                self.add_cooccur(pack(a, b), &mut events);
+                self.add_cooccur(pack(b, a), &mut events);
             }
         }
+    }
 
-        // Directed edges: lag >=2 from provided history.
-        let mut w = lag_decay;
-        if w.is_finite() {
-            for prev in lag2_plus_history {
-                if prev.is_empty() {
-                    w *= lag_decay;
-                    continue;
+    /// Exponentially decay all base counts and edge statistics.
+    ///
+    /// With the `parallel` feature, large edge maps are decayed on the rayon pool;
+    /// each entry is independent so no locking is needed.
+    fn apply_decay(&mut self) {
+        let keep = 1.0 - self.decay;
+        self.base_total *= keep;
+
+        #[cfg(feature = "parallel")]
+        if self.edges.len() >= PAR_MIN_EDGES {
+            self.base.par_iter_mut().for_each(|(_, v)| *v *= keep);
+            self.edges.par_iter_mut().for_each(|(_, e)| {
+                e.transition_count *= keep;
+                e.cooccur_count *= keep;
+            });
+            return;
+        }
+
+        for v in self.base.values_mut() {
+            *v *= keep;
+        }
+        for e in self.edges.values_mut() {
+            e.transition_count *= keep;
+            e.cooccur_count *= keep;
+        }
+    }
+
+    /// Decay all counts (pruning near-zero entries on amortized ticks), then add
+    /// `weight` to the `a → b` transition for every `a` of each `(symbols, weight)`
+    /// source and every `b` in `current_symbols`.
+    fn decay_and_add_transitions(
+        &mut self,
+        sources: &[(&[SymbolId], f32)],
+        current_symbols: &[SymbolId],
+        events: &mut Option<&mut CausalEventLog>,
+    ) {
+        // Keep memory bounded: occasionally remove near-zero entries.
+        // Amortized to avoid scanning large maps every tick.
+        let prune = (self.observe_count & 0xFF) == 0;
+
+        // The parallel path cannot keep the per-edge event order, and pruning must
+        // sit between decay and the new counts.
+        #[cfg(feature = "parallel")]
+        if !prune
+            && events.is_none()
+            && self.par_decay_and_add_transitions(sources, current_symbols)
+        {
+            return;
+        }
+
+        self.apply_decay();
+        if prune {
+            self.prune_near_zero(0.001);
+        }
+        for &(from, weight) in sources {
+            for &a in from {
+                for &b in current_symbols {
+                    self.add_transition(pack(a, b), weight, events);
                 }
-                let weight = w;
-                if weight <= 0.0 {
-                    break;
+            }
+        }
+    }
+
+    /// Parallel form of `decay_and_add_transitions` for large updates.
+    ///
+    /// Each `(source symbol, weight)` row is summed on the rayon pool into a
+    /// segment-locked accumulator: keys are split across `PAR_ACC_SHARDS` mutexes
+    /// and a row takes each shard's lock once for its whole batch, so rows from
+    /// different sources sum concurrently. The sums are then added to existing
+    /// edges inside the parallel decay pass, so no edge is written by two threads.
+    /// Only edges seen for the first time are inserted sequentially. Returns
+    /// `false`, without touching anything, when the update is too small to pay off.
+    #[cfg(feature = "parallel")]
+    fn par_decay_and_add_transitions(
+        &mut self,
+        sources: &[(&[SymbolId], f32)],
+        current_symbols: &[SymbolId],
+    ) -> bool {
+        use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+        use std::sync::Mutex;
+
+        let transitions =
+            sources.iter().map(|(from, _)| from.len()).sum::<usize>() * current_symbols.len();
+        if self.edges.len() < PAR_MIN_EDGES || transitions < PAR_MIN_TRANSITIONS {
+            return false;
+        }
+
+        // Summed increment per edge key, plus whether the key already has an edge.
+        type Increments = HashMap<u64, (f32, AtomicBool)>;
+        let shards: Vec<Mutex<Increments>> = (0..PAR_ACC_SHARDS)
+            .map(|_| Mutex::new(Increments::new()))
+            .collect();
+        let rows: Vec<(SymbolId, f32)> = sources
+            .iter()
+            .flat_map(|&(from, weight)| from.iter().map(move |&a| (a, weight)))
+            .collect();
+        rows.par_iter().for_each_init(
+            || vec![Vec::new(); PAR_ACC_SHARDS],
+            |batches: &mut Vec<Vec<u64>>, &(a, weight)| {
+                for &b in current_symbols {
+                    let key = pack(a, b);
+                    batches[acc_shard(key)].push(key);
                 }
-                for &a in prev {
-                    for &b in current_symbols {
-                        let key = pack(a, b);
-                        self.edges.entry(key).or_default().transition_count += weight;
-                        self.last_directed_edge_updates += 1;
+                for (shard, batch) in shards.iter().zip(batches.iter_mut()) {
+                    if batch.is_empty() {
+                        continue;
+                    }
+                    let mut m = shard.lock().unwrap();
+                    for key in batch.drain(..) {
+                        m.entry(key)
+                            .or_insert_with(|| (0.0, AtomicBool::new(false)))
+                            .0 += weight;
                     }
                 }
-                w *= lag_decay;
+            },
+        );
+        let increments: Vec<Increments> = shards
+            .into_iter()
+            .map(|m| m.into_inner().unwrap())
+            .collect();
+
+        let keep = 1.0 - self.decay;
+        self.base_total *= keep;
+        self.base.par_iter_mut().for_each(|(_, v)| *v *= keep);
+        self.edges.par_iter_mut().for_each(|(key, e)| {
+            e.transition_count *= keep;
+            e.cooccur_count *= keep;
+            if let Some((w, seen)) = increments[acc_shard(*key)].get(key) {
+                e.transition_count += *w;
+                seen.store(true, AtomicOrdering::Relaxed);
+            }
+        });
+
+        for (key, (w, seen)) in increments.into_iter().flatten() {
+            if !seen.into_inner() {
+                self.edges.insert(
+                    key,
+                    EdgeStats {
+                        transition_count: w,
+                        cooccur_count: 0.0,
+                    },
+                );
             }
         }
+        self.last_directed_edge_updates += transitions;
+        true
+    }
+
+    fn add_transition(&mut self, key: u64, amount: f32, events: &mut Option<&mut CausalEventLog>) {
+        let e = self.edges.entry(key).or_default();
+        let old = e.transition_count;
+        e.transition_count += amount;
+        let new = e.transition_count;
+        self.last_directed_edge_updates += 1;
+        if let Some(log) = events.as_deref_mut() {
+            log.push(key, old, new, true);
+        }
+    }
+
+    fn add_cooccur(&mut self, key: u64, events: &mut Option<&mut CausalEventLog>) {
+        let e = self.edges.entry(key).or_default();
+        let old = e.cooccur_count;
+        e.cooccur_count += 0.5;
+        let new = e.cooccur_count;
+        self.last_cooccur_edge_updates += 1;
+        if let Some(log) = events.as_deref_mut() {
+            log.push(key, old, new, false);
+        }
+    }
+
+    /// Record same-tick co-occurrence (both directions, 0.5 each) for every pair
+    /// of distinct symbols in `current_symbols`.
+    fn observe_cooccurrence(
+        &mut self,
+        current_symbols: &[SymbolId],
+        events: &mut Option<&mut CausalEventLog>,
+    ) {
+        #[cfg(feature = "parallel")]
+        if current_symbols.len() >= PAR_MIN_SYMBOLS {
+            // Pair enumeration is O(n²) and read-only, so it runs in parallel.
+            // Map writes stay sequential: the edge map is a plain HashMap shared with
+            // the no_std build and the image format.
+            let keys: Vec<(u64, u64)> = (0..current_symbols.len())
+                .into_par_iter()
+                .flat_map_iter(|i| {
+                    let a = current_symbols[i];
+                    current_symbols[i + 1..]
+                        .iter()
+                        .filter(move |&&b| b != a)
+                        .map(move |&b| (pack(a, b), pack(b, a)))
+                })
+                .collect();
+            for (k1, k2) in keys {
+                self.add_cooccur(k1, events);
+                self.add_cooccur(k2, events);
+            }
+            return;
+        }
 
-        // Same-tick co-occurrence as a cheap proxy for immediate meaning links.
         for (i, &a) in current_symbols.iter().enumerate() {
             for &b in current_symbols.iter().skip(i + 1) {
                 if a == b {
                     continue;
                 }
-                let k1 = pack(a, b);
-                let k2 = pack(b, a);
-                self.edges.entry(k1).or_default().cooccur_count += 0.5;
-                self.edges.entry(k2).or_default().cooccur_count += 0.5;
-                self.last_cooccur_edge_updates += 2;
+                self.add_cooccur(pack(a, b), events);
+                self.add_cooccur(pack(b, a), events);
             }
         }
-
-        self.prev_symbols.clear();
-        self.prev_symbols.extend_from_slice(current_symbols);
     }
 
     fn prune_near_zero(&mut self, threshold: f32) {
@@ -369,6 +698,95 @@ impl CausalMemory {
         out
     }
 
+    /// Return the symbols with the strongest directed edges *into* `b`.
+    ///
+    /// The reverse of [`CausalMemory::top_outgoing`]: scans edges on demand and
+    /// ranks predecessors `a` (with a nonzero transition count) by `causal_strength(a,b)`.
+    pub fn top_incoming(&self, b: SymbolId, top_n: usize) -> Vec<(SymbolId, f32)> {
+        let mut out: Vec<(SymbolId, f32)> = Vec::new();
+        for (&key, stats) in self.edges.iter() {
+            if (key & 0xFFFF_FFFF) as SymbolId != b || stats.transition_count <= 0.0 {
+                continue;
+            }
+            let a = (key >> 32) as SymbolId;
+            out.push((a, self.causal_strength(a, b)));
+        }
+
+        out.sort_by(|x, y| y.1.total_cmp(&x.1).then(x.0.cmp(&y.0)));
+        out.truncate(top_n);
+        out
+    }
+
+    /// Decayed transition count of the directed edge `a -> b` (0 if there is none).
+    pub fn transition_count(&self, a: SymbolId, b: SymbolId) -> f32 {
+        self.edges
+            .get(&pack(a, b))
+            .map_or(0.0, |e| e.transition_count)
+    }
+
+    /// Decayed co-occurrence count of the edge `a -> b` (0 if there is none).
+    pub fn cooccur_count(&self, a: SymbolId, b: SymbolId) -> f32 {
+        self.edges.get(&pack(a, b)).map_or(0.0, |e| e.cooccur_count)
+    }
+
+    /// Every edge leaving `a` as `(to, transition_count, cooccur_count)`.
+    ///
+    /// Raw decayed counts, unranked, including co-occurrence-only edges;
+    /// sorted by target id.
+    pub fn edge_counts_from(&self, a: SymbolId) -> Vec<(SymbolId, f32, f32)> {
+        let mut out: Vec<(SymbolId, f32, f32)> = self
+            .edges
+            .iter()
+            .filter(|(&key, _)| (key >> 32) as SymbolId == a)
+            .map(|(&key, e)| {
+                (
+                    (key & 0xFFFF_FFFF) as SymbolId,
+                    e.transition_count,
+                    e.cooccur_count,
+                )
+            })
+            .collect();
+        out.sort_by_key(|&(b, _, _)| b);
+        out
+    }
+
+    /// Cosine similarity of the outgoing association profiles of `a` and `b`.
+    ///
+    /// Each symbol is described by its positive `association_strength` to every
+    /// successor it has an edge to (other than `a`/`b` themselves), so two
+    /// contexts followed by the same actions and outcomes score near 1.
+    /// Returns 1.0 for `a == b` and 0.0 when either profile is empty.
+    pub fn context_similarity(&self, a: SymbolId, b: SymbolId) -> f32 {
+        if a == b {
+            return 1.0;
+        }
+
+        let mut profile: HashMap<SymbolId, (f32, f32)> = HashMap::new();
+        for &key in self.edges.keys() {
+            let from = (key >> 32) as SymbolId;
+            let to = (key & 0xFFFF_FFFF) as SymbolId;
+            if to == a || to == b {
+                continue;
+            }
+            if from == a {
+                profile.entry(to).or_default().0 = self.association_strength(a, to).max(0.0);
+            } else if from == b {
+                profile.entry(to).or_default().1 = self.association_strength(b, to).max(0.0);
+            }
+        }
+
+        let (mut dot, mut na, mut nb) = (0.0f32, 0.0f32, 0.0f32);
+        for &(x, y) in profile.values() {
+            dot += x * y;
+            na += x * x;
+            nb += y * y;
+        }
+        if na <= 0.0 || nb <= 0.0 {
+            return 0.0;
+        }
+        (dot / (na.sqrt() * nb.sqrt())).clamp(0.0, 1.0)
+    }
+
     /// Return strongest outgoing edges from `a` to symbols in `candidates`.
     ///
     /// Useful for predicting next context when you have a known set of context symbol IDs.
@@ -404,6 +822,36 @@ impl CausalMemory {
         *self.base.get(&sym).unwrap_or(&0.0)
     }
 
+    /// Overwrite the base count of `sym`, keeping the cached total in sync.
+    pub fn set_base_count(&mut self, sym: SymbolId, count: f32) {
+        let count = count.max(0.0);
+        let old = self.base.insert(sym, count).unwrap_or(0.0);
+        self.base_total = (self.base_total - old + count).max(0.0);
+    }
+
+    /// Overwrite the raw counts of the edge `a -> b`.
+    pub fn set_edge_counts(&mut self, a: SymbolId, b: SymbolId, transition: f32, cooccur: f32) {
+        self.edges.insert(
+            pack(a, b),
+            EdgeStats {
+                transition_count: transition.max(0.0),
+                cooccur_count: cooccur.max(0.0),
+            },
+        );
+    }
+
+    /// Sum of all (decayed) base counts.
+    #[must_use]
+    pub fn base_total(&self) -> f32 {
+        self.base_total
+    }
+
+    /// Overwrite the cached base total, e.g. to restore a snapshot that only
+    /// kept some of the symbols.
+    pub fn set_base_total(&mut self, total: f32) {
+        self.base_total = total.max(0.0);
+    }
+
     /// Return top N strongest causal edges in the entire graph.
     ///
     /// Returns (from_symbol, to_symbol, causal_strength).
@@ -570,6 +1018,446 @@ impl CausalMemory {
             last_cooccur_edge_updates: 0,
         })
     }
+
+    /// Rewrite every symbol id `s` as `map[s]`.
+    ///
+    /// Returns false (leaving `self` unchanged) if any id is out of range.
+    #[cfg(feature = "std")]
+    pub(crate) fn remap_symbols(&mut self, map: &[SymbolId]) -> bool {
+        let get = |s: SymbolId| map.get(s as usize).copied();
+
+        let mut base = HashMap::with_capacity(self.base.len());
+        for (&s, &c) in &self.base {
+            let Some(s) = get(s) else { return false };
+            base.insert(s, c);
+        }
+        let mut edges = HashMap::with_capacity(self.edges.len());
+        for (&key, stats) in &self.edges {
+            let (Some(a), Some(b)) = (get((key >> 32) as SymbolId), get(key as SymbolId)) else {
+                return false;
+            };
+            edges.insert(pack(a, b), *stats);
+        }
+        let Some(prev) = self.prev_symbols.iter().map(|&s| get(s)).collect() else {
+            return false;
+        };
+
+        self.base = base;
+        self.edges = edges;
+        self.prev_symbols = prev;
+        true
+    }
+}
+
+/// Causal memory operations shared by the heap-backed [`CausalMemory`] and the
+/// array-backed [`CausalGraphFixed`].
+///
+/// Code written against this trait runs unchanged on targets without an
+/// allocator (e.g. a Cortex-M4 with 256 KB of RAM) by picking the fixed graph.
+pub trait CausalGraph {
+    /// Record one boundary: lag-1 directed edges from the previous call's
+    /// symbols plus same-tick co-occurrence between `current_symbols`.
+    fn observe(&mut self, current_symbols: &[SymbolId]);
+
+    /// Drop all counts and history; keeps the decay rate.
+    fn clear(&mut self);
+
+    fn stats(&self) -> CausalStats;
+
+    /// `P(B|A) - P(B)` over directed transitions (see [`CausalMemory::causal_strength`]).
+    fn causal_strength(&self, a: SymbolId, b: SymbolId) -> f32;
+
+    /// Like `causal_strength`, but counting same-tick co-occurrence too.
+    fn association_strength(&self, a: SymbolId, b: SymbolId) -> f32;
+
+    /// Current (decayed) base count for `sym`.
+    fn base_count(&self, sym: SymbolId) -> f32;
+
+    /// Fill `out` with the strongest outgoing links from `a`, strongest first.
+    ///
+    /// Returns how many entries were written (at most `out.len()`).
+    fn top_outgoing_into(&self, a: SymbolId, out: &mut [(SymbolId, f32)]) -> usize;
+}
+
+impl CausalGraph for CausalMemory {
+    fn observe(&mut self, current_symbols: &[SymbolId]) {
+        CausalMemory::observe(self, current_symbols);
+    }
+
+    fn clear(&mut self) {
+        CausalMemory::clear(self);
+    }
+
+    fn stats(&self) -> CausalStats {
+        CausalMemory::stats(self)
+    }
+
+    fn causal_strength(&self, a: SymbolId, b: SymbolId) -> f32 {
+        CausalMemory::causal_strength(self, a, b)
+    }
+
+    fn association_strength(&self, a: SymbolId, b: SymbolId) -> f32 {
+        CausalMemory::association_strength(self, a, b)
+    }
+
+    fn base_count(&self, sym: SymbolId) -> f32 {
+        CausalMemory::base_count(self, sym)
+    }
+
+    fn top_outgoing_into(&self, a: SymbolId, out: &mut [(SymbolId, f32)]) -> usize {
+        let top = self.top_outgoing(a, out.len());
+        out[..top.len()].copy_from_slice(&top);
+        top.len()
+    }
+}
+
+/// Open-addressing (linear probing) table with `N` fixed slots and no allocation.
+///
+/// Holds at most `N - 1` entries so every probe sequence ends at an empty slot.
+#[derive(Debug, Clone)]
+struct FixedTable<V: Copy + Default, const N: usize> {
+    keys: [u64; N],
+    vals: [V; N],
+    len: usize,
+}
+
+impl<V: Copy + Default, const N: usize> FixedTable<V, N> {
+    const EMPTY: u64 = u64::MAX;
+
+    /// `fill` must equal `V::default()`; it is passed in so this can be `const`.
+    const fn new(fill: V) -> Self {
+        Self {
+            keys: [Self::EMPTY; N],
+            vals: [fill; N],
+            len: 0,
+        }
+    }
+
+    fn home(key: u64) -> usize {
+        (key.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 32) as usize % N
+    }
+
+    fn find(&self, key: u64) -> Option<usize> {
+        if N == 0 || key == Self::EMPTY {
+            return None;
+        }
+        let mut i = Self::home(key);
+        loop {
+            match self.keys[i] {
+                k if k == key => return Some(i),
+                Self::EMPTY => return None,
+                _ => i = (i + 1) % N,
+            }
+        }
+    }
+
+    fn get(&self, key: u64) -> Option<&V> {
+        self.find(key).map(|i| &self.vals[i])
+    }
+
+    /// Slot for `key`, inserting a default value if absent. `None` when full.
+    fn entry(&mut self, key: u64) -> Option<&mut V> {
+        if N == 0 || key == Self::EMPTY {
+            return None;
+        }
+        let mut i = Self::home(key);
+        loop {
+            match self.keys[i] {
+                k if k == key => return Some(&mut self.vals[i]),
+                Self::EMPTY => {
+                    if self.len + 1 >= N {
+                        return None;
+                    }
+                    self.keys[i] = key;
+                    self.vals[i] = V::default();
+                    self.len += 1;
+                    return Some(&mut self.vals[i]);
+                }
+                _ => i = (i + 1) % N,
+            }
+        }
+    }
+
+    /// Remove the entry in slot `i`, shifting later probes back (no tombstones).
+    fn remove_at(&mut self, mut i: usize) {
+        let mut j = i;
+        loop {
+            j = (j + 1) % N;
+            let k = self.keys[j];
+            if k == Self::EMPTY {
+                break;
+            }
+            let h = Self::home(k);
+            // `k` may stay put if its home lies cyclically in (i, j].
+            let stays = if i <= j {
+                i < h && h <= j
+            } else {
+                i < h || h <= j
+            };
+            if !stays {
+                self.keys[i] = k;
+                self.vals[i] = self.vals[j];
+                i = j;
+            }
+        }
+        self.keys[i] = Self::EMPTY;
+        self.vals[i] = V::default();
+        self.len -= 1;
+    }
+
+    /// Keep only entries for which `keep` returns true.
+    fn retain(&mut self, mut keep: impl FnMut(&V) -> bool) {
+        let mut i = 0;
+        while i < N {
+            if self.keys[i] != Self::EMPTY && !keep(&self.vals[i]) {
+                // The shifted-in entry (if any) lands in slot `i`; check it again.
+                self.remove_at(i);
+                continue;
+            }
+            i += 1;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.keys = [Self::EMPTY; N];
+        self.vals = [V::default(); N];
+        self.len = 0;
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (u64, &V)> {
+        self.keys
+            .iter()
+            .zip(self.vals.iter())
+            .filter(|(&k, _)| k != Self::EMPTY)
+            .map(|(&k, v)| (k, v))
+    }
+
+    fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.keys
+            .iter()
+            .zip(self.vals.iter_mut())
+            .filter(|(&k, _)| k != Self::EMPTY)
+            .map(|(_, v)| v)
+    }
+}
+
+/// Array-backed causal memory for targets without an allocator.
+///
+/// Same counting rules as [`CausalMemory::observe`] (decayed base counts,
+/// lag-1 transitions, same-tick co-occurrence), but symbols and edges live in
+/// fixed hash tables of `MAX_SYMBOLS` and `MAX_EDGES` slots, and at most
+/// `MAX_SYMBOLS` symbols of the previous boundary are remembered. Updates that
+/// do not fit are dropped and counted in [`CausalGraphFixed::dropped_updates`];
+/// near-zero entries are pruned periodically to make room.
+///
+/// The footprint is roughly `13 * MAX_SYMBOLS + 16 * MAX_EDGES` bytes, so
+/// `CausalGraphFixed<256, 4096>` needs about 68 KB. That is too large for a
+/// typical embedded stack, so [`new`](Self::new) is a `const fn`: build the graph
+/// in place in a `static` (behind whatever mutex or cell the target uses) rather
+/// than in a local. Symbol id `u32::MAX` is reserved.
+///
+/// This is a standalone graph for code written against [`CausalGraph`].
+///
+/// Not done yet: [`Brain`](crate::substrate::Brain) cannot run on this graph.
+/// It still needs `alloc`, keeps a [`CausalMemory`], and uses far more of it
+/// than [`CausalGraph`] covers (event log, lagged windows, merging, image
+/// chunks). Its name-level API such as `top_causal_links_from` and
+/// `note_compound_symbol` also needs the symbol table, which lives in `Brain`,
+/// so it has no counterpart on the trait.
+#[derive(Debug, Clone)]
+pub struct CausalGraphFixed<const MAX_SYMBOLS: usize, const MAX_EDGES: usize> {
+    decay: f32,
+    base: FixedTable<f32, MAX_SYMBOLS>,
+    base_total: f32,
+    edges: FixedTable<EdgeStats, MAX_EDGES>,
+    prev_symbols: [SymbolId; MAX_SYMBOLS],
+    prev_len: usize,
+    observe_count: u64,
+    dropped_updates: u64,
+    last_directed_edge_updates: usize,
+    last_cooccur_edge_updates: usize,
+}
+
+impl<const MAX_SYMBOLS: usize, const MAX_EDGES: usize> CausalGraphFixed<MAX_SYMBOLS, MAX_EDGES> {
+    /// Empty graph with the given per-observation decay (clamped to `0..=1`).
+    pub const fn new(decay: f32) -> Self {
+        // `f32::clamp` is not `const` on every supported toolchain; NaN maps to 0.
+        let decay = if decay > 1.0 {
+            1.0
+        } else if decay >= 0.0 {
+            decay
+        } else {
+            0.0
+        };
+        Self {
+            decay,
+            base: FixedTable::new(0.0),
+            base_total: 0.0,
+            edges: FixedTable::new(EdgeStats {
+                transition_count: 0.0,
+                cooccur_count: 0.0,
+            }),
+            prev_symbols: [0; MAX_SYMBOLS],
+            prev_len: 0,
+            observe_count: 0,
+            dropped_updates: 0,
+            last_directed_edge_updates: 0,
+            last_cooccur_edge_updates: 0,
+        }
+    }
+
+    /// Base-count and edge updates discarded because a table was full.
+    #[must_use]
+    pub fn dropped_updates(&self) -> u64 {
+        self.dropped_updates
+    }
+
+    fn edge_mut(&mut self, a: SymbolId, b: SymbolId) -> Option<&mut EdgeStats> {
+        let e = self.edges.entry(pack(a, b));
+        if e.is_none() {
+            self.dropped_updates += 1;
+        }
+        e
+    }
+
+    fn strength(&self, a: SymbolId, b: SymbolId, with_cooccur: bool) -> f32 {
+        let base_a = self.base_count(a);
+        if base_a <= 0.001 {
+            return 0.0;
+        }
+        let edge = self
+            .edges
+            .get(pack(a, b))
+            .map(|e| e.transition_count + if with_cooccur { e.cooccur_count } else { 0.0 })
+            .unwrap_or(0.0);
+
+        let alpha = 1.0;
+        let p_b_given_a = ((edge + alpha) / (base_a + alpha)).clamp(0.0, 1.0);
+        let p_b = (self.base_count(b) / self.base_total.max(1.0)).clamp(0.0, 1.0);
+        (p_b_given_a - p_b).clamp(-1.0, 1.0)
+    }
+}
+
+impl<const MAX_SYMBOLS: usize, const MAX_EDGES: usize> CausalGraph
+    for CausalGraphFixed<MAX_SYMBOLS, MAX_EDGES>
+{
+    fn observe(&mut self, current_symbols: &[SymbolId]) {
+        self.last_directed_edge_updates = 0;
+        self.last_cooccur_edge_updates = 0;
+        self.observe_count = self.observe_count.wrapping_add(1);
+
+        let keep = 1.0 - self.decay;
+        self.base_total *= keep;
+        for v in self.base.values_mut() {
+            *v *= keep;
+        }
+        for e in self.edges.values_mut() {
+            e.transition_count *= keep;
+            e.cooccur_count *= keep;
+        }
+
+        if (self.observe_count & 0xFF) == 0 {
+            let thr = 0.001;
+            self.base.retain(|&v| v > thr);
+            self.base_total = self.base.iter().map(|(_, &v)| v).sum();
+            self.edges
+                .retain(|e| e.transition_count > thr || e.cooccur_count > thr);
+        }
+
+        for &s in current_symbols {
+            match self.base.entry(s as u64) {
+                Some(v) => {
+                    *v += 1.0;
+                    self.base_total += 1.0;
+                }
+                None => self.dropped_updates += 1,
+            }
+        }
+
+        for i in 0..self.prev_len {
+            let a = self.prev_symbols[i];
+            for &b in current_symbols {
+                if let Some(e) = self.edge_mut(a, b) {
+                    e.transition_count += 1.0;
+                    self.last_directed_edge_updates += 1;
+                }
+            }
+        }
+
+        for (i, &a) in current_symbols.iter().enumerate() {
+            for &b in current_symbols.iter().skip(i + 1) {
+                if a == b {
+                    continue;
+                }
+                for (x, y) in [(a, b), (b, a)] {
+                    if let Some(e) = self.edge_mut(x, y) {
+                        e.cooccur_count += 0.5;
+                        self.last_cooccur_edge_updates += 1;
+                    }
+                }
+            }
+        }
+
+        self.prev_len = current_symbols.len().min(MAX_SYMBOLS);
+        self.prev_symbols[..self.prev_len].copy_from_slice(&current_symbols[..self.prev_len]);
+    }
+
+    fn clear(&mut self) {
+        self.base.clear();
+        self.base_total = 0.0;
+        self.edges.clear();
+        self.prev_len = 0;
+        self.last_directed_edge_updates = 0;
+        self.last_cooccur_edge_updates = 0;
+    }
+
+    fn stats(&self) -> CausalStats {
+        CausalStats {
+            base_symbols: self.base.len,
+            edges: self.edges.len,
+            last_directed_edge_updates: self.last_directed_edge_updates,
+            last_cooccur_edge_updates: self.last_cooccur_edge_updates,
+        }
+    }
+
+    fn causal_strength(&self, a: SymbolId, b: SymbolId) -> f32 {
+        self.strength(a, b, false)
+    }
+
+    fn association_strength(&self, a: SymbolId, b: SymbolId) -> f32 {
+        self.strength(a, b, true)
+    }
+
+    fn base_count(&self, sym: SymbolId) -> f32 {
+        self.base.get(sym as u64).copied().unwrap_or(0.0)
+    }
+
+    fn top_outgoing_into(&self, a: SymbolId, out: &mut [(SymbolId, f32)]) -> usize {
+        let mut n = 0;
+        for (key, _) in self.edges.iter() {
+            if (key >> 32) as SymbolId != a {
+                continue;
+            }
+            let b = key as SymbolId;
+            let s = self.causal_strength(a, b);
+            // Bounded insertion sort: keep `out[..n]` sorted strongest first.
+            let mut i = n.min(out.len());
+            if i == out.len() && (i == 0 || out[i - 1].1 >= s) {
+                continue;
+            }
+            if n < out.len() {
+                n += 1;
+            }
+            while i > 0 && out[i - 1].1 < s {
+                if i < out.len() {
+                    out[i] = out[i - 1];
+                }
+                i -= 1;
+            }
+            out[i] = (b, s);
+        }
+        n
+    }
 }
 
 fn pack(a: SymbolId, b: SymbolId) -> u64 {
@@ -612,6 +1500,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn event_log_records_each_edge_update() {
+        let mut mem = CausalMemory::new(0.0);
+        let mut log = CausalEventLog::new(8);
+        log.set_step(1);
+        mem.observe_lagged(&[1], &[], 0.5, Some(&mut log));
+        assert!(log.is_empty());
+
+        log.set_step(2);
+        mem.observe_lagged(&[2, 3], &[], 0.5, Some(&mut log));
+        // Two directed (1->2, 1->3) plus two co-occurrence directions (2<->3).
+        assert_eq!(log.len(), 4);
+        let directed: Vec<_> = log.iter().filter(|e| e.is_directed).collect();
+        assert_eq!(directed.len(), 2);
+        assert!(directed.iter().all(|e| e.step == 2 && e.from == 1));
+        assert_eq!((directed[0].old_weight, directed[0].new_weight), (0.0, 1.0));
+        assert!(log
+            .iter()
+            .any(|e| !e.is_directed && e.from == 3 && e.to == 2 && e.new_weight == 0.5));
+
+        log.set_step(3);
+        mem.observe_lagged(&[2, 3], &[], 0.5, Some(&mut log));
+        mem.observe_lagged(&[2, 3], &[], 0.5, Some(&mut log));
+        assert_eq!(log.len(), 8);
+        assert_eq!(log.last(2).count(), 2);
+        assert!(log.iter().all(|e| e.step == 3));
+    }
+
     #[test]
     fn causal_memory_lagged_directed_edges_decay_with_lag() {
         let mut mem = CausalMemory::new(0.0);
@@ -622,7 +1538,7 @@ mod tests {
 
         // Now observe 20 with lag 1 = [10] (internal prev_symbols)
         // and lag 2 = [30] (provided history) with decay 0.5.
-        mem.observe_lagged(&[20], &[vec![30]], 0.5);
+        mem.observe_lagged(&[20], &[vec![30]], 0.5, None);
 
         let stats = mem.stats();
         assert_eq!(
@@ -694,6 +1610,64 @@ mod tests {
         assert!(!top.is_empty(), "Should have outgoing edges");
     }
 
+    #[test]
+    fn causal_memory_top_incoming_ranks_predecessors() {
+        let mut mem = CausalMemory::new(0.0);
+
+        // 1 precedes 9 three times, 2 precedes 9 once; 3 only co-occurs with 9.
+        for _ in 0..3 {
+            mem.observe(&[1]);
+            mem.observe(&[9]);
+        }
+        mem.observe(&[2]);
+        mem.observe(&[9, 3]);
+        mem.observe(&[4]);
+
+        let top = mem.top_incoming(9, 5);
+        let ids: Vec<SymbolId> = top.iter().map(|&(a, _)| a).collect();
+        assert_eq!(ids[0], 1);
+        assert!(ids.contains(&2));
+        assert!(!ids.contains(&3), "co-occurrence is not a directed edge");
+        assert!(top.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert_eq!(mem.top_incoming(9, 1).len(), 1);
+    }
+
+    #[test]
+    fn edge_counts_from_reports_directed_and_cooccur_counts() {
+        let mut mem = CausalMemory::new(0.0);
+        mem.observe(&[1]);
+        mem.observe(&[2, 3]);
+        mem.observe(&[1]);
+        mem.observe(&[2]);
+
+        let from1 = mem.edge_counts_from(1);
+        assert_eq!(from1, vec![(2, 2.0, 0.0), (3, 1.0, 0.0)]);
+        // 2 -> 3 only co-occurred; 2 -> 1 is a transition.
+        let from2 = mem.edge_counts_from(2);
+        assert_eq!(from2[0], (1, 1.0, 0.0));
+        assert!(from2[1].0 == 3 && from2[1].1 == 0.0 && from2[1].2 > 0.0);
+        assert!(mem.edge_counts_from(7).is_empty());
+    }
+
+    #[test]
+    fn context_similarity_compares_successor_profiles() {
+        let mut mem = CausalMemory::new(0.0);
+
+        // Contexts 1 and 2 both lead to 5; context 3 leads to 6.
+        for _ in 0..4 {
+            for (ctx, next) in [(1, 5), (2, 5), (3, 6)] {
+                mem.observe(&[ctx]);
+                mem.observe(&[next]);
+                mem.observe(&[9]);
+            }
+        }
+
+        assert!(mem.context_similarity(1, 2) > 0.9);
+        assert!(mem.context_similarity(1, 3) < 0.5);
+        assert_eq!(mem.context_similarity(1, 1), 1.0);
+        assert_eq!(mem.context_similarity(1, 42), 0.0);
+    }
+
     #[test]
     fn causal_memory_serialization_roundtrip() {
         let mut mem = CausalMemory::new(0.1);
@@ -725,6 +1699,62 @@ mod tests {
         assert_eq!(strength, 0.0);
     }
 
+    #[test]
+    fn large_observation_matches_pairwise_counts() {
+        // Large enough to take the parallel paths when `parallel` is enabled.
+        let mut mem = CausalMemory::new(0.1);
+        let a: Vec<SymbolId> = (0..100).collect();
+        let b: Vec<SymbolId> = (50..150).collect();
+        mem.observe(&a);
+        mem.observe(&b);
+
+        let stats = mem.stats();
+        assert_eq!(stats.last_directed_edge_updates, 100 * 100);
+        assert_eq!(stats.last_cooccur_edge_updates, 100 * 99);
+
+        // 60 -> 120: decayed co-occurrence from tick 1 is absent (120 not in a);
+        // one transition this tick.
+        let e = mem.edges.get(&pack(60, 120)).expect("edge");
+        assert!((e.transition_count - 1.0).abs() < 1e-6);
+        assert!((e.cooccur_count - 0.5).abs() < 1e-6);
+        // 60 -> 70 co-occurred on both ticks and transitioned on tick 2.
+        let e = mem.edges.get(&pack(60, 70)).expect("edge");
+        assert!((e.transition_count - 1.0).abs() < 1e-6);
+        assert!((e.cooccur_count - 0.95).abs() < 1e-6);
+        // 10 <-> 20 co-occurred only on tick 1, then decayed once.
+        let e = mem.edges.get(&pack(10, 20)).expect("edge");
+        assert!((e.cooccur_count - 0.45).abs() < 1e-6);
+    }
+
+    #[test]
+    fn large_lagged_observation_matches_logged_path() {
+        // An event log forces the sequential path; without one, `parallel` builds
+        // take the parallel path. Both must agree.
+        let mut fast = CausalMemory::new(0.05);
+        let mut logged = CausalMemory::new(0.05);
+        let mut log = CausalEventLog::new(16);
+        let frame = |t: u32| -> Vec<SymbolId> { (0..90).map(|k| (t * 13 + k * 3) % 400).collect() };
+        let mut history: Vec<Vec<SymbolId>> = Vec::new();
+        for t in 0..6 {
+            let cur = frame(t);
+            fast.observe_lagged(&cur, &history, 0.5, None);
+            logged.observe_lagged(&cur, &history, 0.5, Some(&mut log));
+            assert_eq!(
+                fast.stats().last_directed_edge_updates,
+                logged.stats().last_directed_edge_updates
+            );
+            history.insert(0, cur);
+            history.truncate(2);
+        }
+
+        assert_eq!(fast.edges.len(), logged.edges.len());
+        for (key, e) in &logged.edges {
+            let f = fast.edges.get(key).expect("edge");
+            assert!((f.transition_count - e.transition_count).abs() < 1e-4);
+            assert!((f.cooccur_count - e.cooccur_count).abs() < 1e-4);
+        }
+    }
+
     #[test]
     fn pack_unpack_roundtrip() {
         let a: SymbolId = 12345;
@@ -759,4 +1789,92 @@ mod tests {
         let expected = mem.base.values().sum::<f32>();
         assert!((mem.base_total - expected).abs() < 1e-6);
     }
+
+    #[test]
+    fn fixed_graph_matches_causal_memory() {
+        let mut heap = CausalMemory::new(0.01);
+        let mut fixed: CausalGraphFixed<64, 1024> = CausalGraphFixed::new(0.01);
+        let boundaries: [&[SymbolId]; 4] = [&[1, 10], &[2, 20], &[1, 10, 30], &[3]];
+        for t in 0..300 {
+            let syms = boundaries[t % boundaries.len()];
+            CausalGraph::observe(&mut heap, syms);
+            fixed.observe(syms);
+        }
+
+        assert_eq!(fixed.stats().base_symbols, heap.stats().base_symbols);
+        assert_eq!(fixed.stats().edges, heap.stats().edges);
+        assert_eq!(fixed.dropped_updates(), 0);
+        for (a, b) in [(1, 2), (10, 20), (2, 1), (30, 3), (1, 10), (3, 1)] {
+            assert!((fixed.causal_strength(a, b) - heap.causal_strength(a, b)).abs() < 1e-4);
+            assert!(
+                (fixed.association_strength(a, b) - heap.association_strength(a, b)).abs() < 1e-4
+            );
+        }
+
+        let mut top_heap = [(0, 0.0); 3];
+        let mut top_fixed = [(0, 0.0); 3];
+        let n = heap.top_outgoing_into(1, &mut top_heap);
+        assert_eq!(fixed.top_outgoing_into(1, &mut top_fixed), n);
+        for (h, f) in top_heap[..n].iter().zip(&top_fixed[..n]) {
+            assert!((h.1 - f.1).abs() < 1e-4);
+        }
+        assert!(top_fixed[..n].windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+
+    #[test]
+    fn fixed_graph_drops_updates_when_full_and_prunes() {
+        let mut g: CausalGraphFixed<4, 8> = CausalGraphFixed::new(0.5);
+        g.observe(&[1, 2, 3, 4, 5]);
+        // Three symbol slots usable (one stays empty); edges cap at 7.
+        assert_eq!(g.stats().base_symbols, 3);
+        assert!(g.dropped_updates() > 0);
+        assert!(g.stats().edges <= 7);
+
+        // Strong decay drives everything near zero; the periodic prune empties the tables.
+        for _ in 0..256 {
+            g.observe(&[]);
+        }
+        assert_eq!(g.stats().base_symbols, 0);
+        assert_eq!(g.stats().edges, 0);
+        g.observe(&[7, 8]);
+        assert_eq!(g.base_count(7), 1.0);
+        assert!(g.association_strength(7, 8) > 0.0);
+    }
+
+    #[test]
+    fn fixed_table_remove_keeps_colliding_keys_reachable() {
+        let mut t: FixedTable<f32, 16> = FixedTable::new(0.0);
+        for k in 0..12u64 {
+            *t.entry(k).unwrap() = k as f32;
+        }
+        t.retain(|&v| !(v as u64).is_multiple_of(3));
+        assert_eq!(t.len, 8);
+        for k in 0..12u64 {
+            assert_eq!(t.get(k).is_some(), !k.is_multiple_of(3), "key {k}");
+        }
+    }
+
+    #[test]
+    fn fixed_graph_fits_small_ram_budget() {
+        assert!(core::mem::size_of::<CausalGraphFixed<256, 4096>>() < 96 * 1024);
+    }
+
+    #[test]
+    fn fixed_graph_builds_in_place_in_a_static() {
+        static GRAPH: std::sync::Mutex<CausalGraphFixed<256, 4096>> =
+            std::sync::Mutex::new(CausalGraphFixed::new(0.01));
+
+        // A stack far smaller than the graph: it must never be copied onto it.
+        std::thread::Builder::new()
+            .stack_size(32 * 1024)
+            .spawn(|| {
+                let mut g = GRAPH.lock().unwrap();
+                g.observe(&[1, 2]);
+                g.observe(&[3]);
+                assert!(g.causal_strength(1, 3) > 0.0);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
 }
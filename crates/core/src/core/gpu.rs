@@ -8,6 +8,7 @@
 
 use bytemuck::{Pod, Zeroable};
 use std::borrow::Cow;
+#[cfg(target_arch = "wasm32")]
 use wgpu::util::DeviceExt;
 
 thread_local! {
@@ -198,6 +199,22 @@ impl std::fmt::Display for GpuError {
 
 impl std::error::Error for GpuError {}
 
+/// Device buffers reused across native `step_dynamics` calls.
+///
+/// Sized for `capacity` units and reallocated only when the substrate outgrows
+/// them, so a steady-state step is upload + dispatch + readback with no allocation.
+#[cfg(not(target_arch = "wasm32"))]
+struct DynamicsBuffers {
+    capacity: usize,
+    units_in: wgpu::Buffer,
+    units_out: wgpu::Buffer,
+    influences: wgpu::Buffer,
+    inputs: wgpu::Buffer,
+    params: wgpu::Buffer,
+    staging: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
 /// GPU compute context for accelerating Brain dynamics.
 pub struct GpuContext {
     device: wgpu::Device,
@@ -205,6 +222,8 @@ pub struct GpuContext {
     pipeline: wgpu::ComputePipeline,
     bind_group_layout: wgpu::BindGroupLayout,
     max_units: usize,
+    #[cfg(not(target_arch = "wasm32"))]
+    buffers: std::cell::RefCell<Option<DynamicsBuffers>>,
 }
 
 impl GpuContext {
@@ -257,9 +276,91 @@ impl GpuContext {
             pipeline,
             bind_group_layout,
             max_units,
+            buffers: std::cell::RefCell::new(None),
         })
     }
 
+    /// Allocate persistent dynamics buffers for up to `capacity` units.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn create_dynamics_buffers(&self, capacity: usize) -> DynamicsBuffers {
+        let units_bytes = (capacity * std::mem::size_of::<GpuUnit>()) as u64;
+        let storage = |label: &str, size: u64, extra: wgpu::BufferUsages| {
+            self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size,
+                usage: wgpu::BufferUsages::STORAGE | extra,
+                mapped_at_creation: false,
+            })
+        };
+
+        let units_in = storage("Units In Buffer", units_bytes, wgpu::BufferUsages::COPY_DST);
+        let units_out = storage(
+            "Units Out Buffer",
+            units_bytes,
+            wgpu::BufferUsages::COPY_SRC,
+        );
+        let influences = storage(
+            "Influences Buffer",
+            (capacity * std::mem::size_of::<GpuInfluence>()) as u64,
+            wgpu::BufferUsages::COPY_DST,
+        );
+        let inputs = storage(
+            "Inputs Buffer",
+            (capacity * std::mem::size_of::<GpuInput>()) as u64,
+            wgpu::BufferUsages::COPY_DST,
+        );
+        let params = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Params Buffer"),
+            size: std::mem::size_of::<GpuParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Staging Buffer"),
+            size: units_bytes,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Dynamics Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: units_in.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: influences.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: inputs.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: units_out.as_entire_binding(),
+                },
+            ],
+        });
+
+        DynamicsBuffers {
+            capacity,
+            units_in,
+            units_out,
+            influences,
+            inputs,
+            params,
+            staging,
+            bind_group,
+        }
+    }
+
     /// Create a new GPU context asynchronously (WebGPU).
     #[cfg(target_arch = "wasm32")]
     pub async fn new_async(max_units: usize) -> Result<Self, String> {
@@ -391,6 +492,11 @@ impl GpuContext {
 
     /// Execute dynamics update on GPU.
     ///
+    /// Uploads this step's unit state, influences (including fresh noise), and
+    /// inputs into persistent device buffers, dispatches the shader, and reads
+    /// the updated units back. The full unit readback is required because
+    /// eligibility and plasticity run on the CPU.
+    ///
     /// # Arguments
     /// * `units` - Mutable slice of unit state (amp, phase, bias, decay)
     /// * `influences` - Pre-computed influences from sparse graph traversal
@@ -399,6 +505,7 @@ impl GpuContext {
     ///
     /// # Errors
     /// Returns `GpuError` if the operation fails or size exceeds limits.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn step_dynamics(
         &self,
         units: &mut [GpuUnit],
@@ -417,29 +524,18 @@ impl GpuContext {
             });
         }
 
-        // Create buffers
-        let units_in_buffer = self
-            .device
-            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Units In Buffer"),
-                contents: bytemuck::cast_slice(units),
-                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
-            });
-
-        let units_out_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Units Out Buffer"),
-            size: std::mem::size_of_val(units) as u64,
-            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
-            mapped_at_creation: false,
-        });
-
-        let influences_buffer = self
-            .device
-            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Influences Buffer"),
-                contents: bytemuck::cast_slice(influences),
-                usage: wgpu::BufferUsages::STORAGE,
-            });
+        let mut slot = self.buffers.borrow_mut();
+        let grow = match slot.as_ref() {
+            Some(b) => b.capacity < n,
+            None => true,
+        };
+        if grow {
+            // Round up so neurogenesis does not reallocate on every new unit.
+            *slot = Some(self.create_dynamics_buffers(n.next_power_of_two().min(self.max_units)));
+        }
+        let Some(bufs) = slot.as_ref() else {
+            return Err(GpuError::ReceiveError);
+        };
 
         // Pack inputs with padding
         let inputs_padded: Vec<GpuInput> = inputs
@@ -449,56 +545,15 @@ impl GpuContext {
                 _padding: [0.0; 3],
             })
             .collect();
-        let inputs_buffer = self
-            .device
-            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Inputs Buffer"),
-                contents: bytemuck::cast_slice(&inputs_padded),
-                usage: wgpu::BufferUsages::STORAGE,
-            });
-
-        let params_buffer = self
-            .device
-            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Params Buffer"),
-                contents: bytemuck::bytes_of(&params),
-                usage: wgpu::BufferUsages::UNIFORM,
-            });
 
-        // Staging buffer for readback
-        let staging_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Staging Buffer"),
-            size: std::mem::size_of_val(units) as u64,
-            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
-        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Dynamics Bind Group"),
-            layout: &self.bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: units_in_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: influences_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: inputs_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 3,
-                    resource: params_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 4,
-                    resource: units_out_buffer.as_entire_binding(),
-                },
-            ],
-        });
+        self.queue
+            .write_buffer(&bufs.units_in, 0, bytemuck::cast_slice(units));
+        self.queue
+            .write_buffer(&bufs.influences, 0, bytemuck::cast_slice(influences));
+        self.queue
+            .write_buffer(&bufs.inputs, 0, bytemuck::cast_slice(&inputs_padded));
+        self.queue
+            .write_buffer(&bufs.params, 0, bytemuck::bytes_of(&params));
 
         let mut encoder = self
             .device
@@ -512,24 +567,19 @@ impl GpuContext {
                 timestamp_writes: None,
             });
             pass.set_pipeline(&self.pipeline);
-            pass.set_bind_group(0, &bind_group, &[]);
+            pass.set_bind_group(0, &bufs.bind_group, &[]);
             // Dispatch workgroups (64 threads per group)
             pass.dispatch_workgroups(n.div_ceil(64) as u32, 1, 1);
         }
 
         // Copy results back
-        encoder.copy_buffer_to_buffer(
-            &units_out_buffer,
-            0,
-            &staging_buffer,
-            0,
-            std::mem::size_of_val(units) as u64,
-        );
+        let bytes = std::mem::size_of_val(units) as u64;
+        encoder.copy_buffer_to_buffer(&bufs.units_out, 0, &bufs.staging, 0, bytes);
 
         self.queue.submit(std::iter::once(encoder.finish()));
 
         // Read back results
-        let buffer_slice = staging_buffer.slice(..);
+        let buffer_slice = bufs.staging.slice(..bytes);
         let (tx, rx) = std::sync::mpsc::channel();
         buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
             let _ = tx.send(result);
@@ -539,9 +589,13 @@ impl GpuContext {
         let map_result = rx.recv().map_err(|_| GpuError::ReceiveError)?;
         map_result.map_err(GpuError::MapError)?;
 
-        let data = buffer_slice.get_mapped_range();
-        let result: &[GpuUnit] = bytemuck::cast_slice(&data);
-        units.copy_from_slice(result);
+        {
+            let data = buffer_slice.get_mapped_range();
+            let result: &[GpuUnit] = bytemuck::cast_slice(&data);
+            units.copy_from_slice(result);
+        }
+        // The staging buffer is reused next step, so it must be unmapped now.
+        bufs.staging.unmap();
         Ok(())
     }
 
@@ -852,6 +906,45 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn step_dynamics_reuses_and_grows_buffers() {
+        let Some(ctx) = GpuContext::new(1024) else {
+            println!("No GPU available (expected in some CI environments)");
+            return;
+        };
+
+        let params = |n: usize| GpuParams {
+            dt: 0.1,
+            base_freq: 1.0,
+            inhibition: 0.0,
+            unit_count: n as u32,
+            amp_saturation_beta: 0.0,
+        };
+        let unit = GpuUnit {
+            amp: 0.0,
+            phase: 0.0,
+            bias: 1.0,
+            decay: 0.0,
+        };
+        let influence = GpuInfluence {
+            amp: 0.0,
+            phase: 0.0,
+            noise_amp: 0.0,
+            noise_phase: 0.0,
+        };
+
+        for n in [10usize, 10, 100] {
+            let mut units = vec![unit; n];
+            ctx.step_dynamics(&mut units, &vec![influence; n], &vec![0.0; n], params(n))
+                .expect("gpu step");
+            // d_amp = bias * dt, d_phase = base_freq * dt.
+            assert!(units.iter().all(|u| (u.amp - 0.1).abs() < 1e-6));
+            assert!(units.iter().all(|u| (u.phase - 0.1).abs() < 1e-6));
+        }
+        let capacity = ctx.buffers.borrow().as_ref().map(|b| b.capacity);
+        assert_eq!(capacity, Some(128));
+    }
+
     #[test]
     fn gpu_context_creation() {
         // This test may fail on systems without GPU support
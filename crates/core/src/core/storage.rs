@@ -1,8 +1,46 @@
-use std::io::{self, Read, Write};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
 
 pub const MAGIC: &[u8; 8] = b"BRAINE01";
+/// Magic for causal-only images (symbol table + causal memory).
+pub const CAUSAL_MAGIC: &[u8; 8] = b"BRCAUS01";
+/// Chunks framed by [`write_chunk_v2_lz4`].
 pub const VERSION_V3: u32 = 3;
-pub const VERSION_CURRENT: u32 = VERSION_V3;
+/// Chunks framed by [`write_chunk_v4_lz4`] (adds a CRC32 per chunk).
+pub const VERSION_V4: u32 = 4;
+pub const VERSION_CURRENT: u32 = VERSION_V4;
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+/// CRC-32 (IEEE 802.3, the zlib/PNG variant).
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut c = !0u32;
+    for &b in bytes {
+        c = CRC32_TABLE[((c ^ b as u32) & 0xFF) as usize] ^ (c >> 8);
+    }
+    !c
+}
 
 pub fn compress_lz4(input: &[u8]) -> Vec<u8> {
     lz4_flex::compress(input)
@@ -18,6 +56,305 @@ pub struct CapacityWriter<W> {
     inner: W,
     remaining: usize,
     written: usize,
+    overflowed: bool,
+}
+
+/// Write-to-temp-then-rename file writer.
+///
+/// Bytes go to a temporary file next to `target`; [`AtomicWrite::commit`]
+/// flushes, syncs and renames it over `target`, so readers only ever see the
+/// previous file or the complete new one. Dropping without `commit` (an error
+/// path or a panic) removes the temporary file and leaves `target` untouched.
+///
+/// Composes with [`CapacityWriter`]: wrap it, then `into_inner().commit()`.
+pub struct AtomicWrite {
+    target: PathBuf,
+    temp: PathBuf,
+    file: Option<BufWriter<File>>,
+}
+
+impl AtomicWrite {
+    /// Create the temporary file for `target`. The parent directory must exist.
+    pub fn new(target: impl Into<PathBuf>) -> io::Result<Self> {
+        let target = target.into();
+        let name = target.file_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "AtomicWrite: target has no file name",
+            )
+        })?;
+        let mut temp_name = std::ffi::OsString::from(".");
+        temp_name.push(name);
+        temp_name.push(format!(".{}.tmp", std::process::id()));
+        let temp = target.with_file_name(temp_name);
+
+        let file = File::create(&temp)?;
+        Ok(Self {
+            target,
+            temp,
+            file: Some(BufWriter::new(file)),
+        })
+    }
+
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+
+    /// Flush and sync the temporary file, then rename it over the target.
+    pub fn commit(mut self) -> io::Result<()> {
+        let Some(file) = self.file.take() else {
+            return Ok(());
+        };
+        let result = file
+            .into_inner()
+            .map_err(io::IntoInnerError::into_error)
+            .and_then(|f| f.sync_all())
+            .and_then(|()| fs::rename(&self.temp, &self.target));
+        if result.is_err() {
+            let _ = fs::remove_file(&self.temp);
+        }
+        result
+    }
+}
+
+impl Write for AtomicWrite {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.file.as_mut() {
+            Some(f) => f.write(buf),
+            None => Err(io::Error::other("AtomicWrite: already committed")),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.file.as_mut() {
+            Some(f) => f.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AtomicWrite {
+    fn drop(&mut self) {
+        if self.file.take().is_some() {
+            let _ = fs::remove_file(&self.temp);
+        }
+    }
+}
+
+/// Atomically replace `target` with `bytes` (see [`AtomicWrite`]).
+pub fn write_file_atomic(target: impl Into<PathBuf>, bytes: &[u8]) -> io::Result<()> {
+    let mut w = AtomicWrite::new(target)?;
+    w.write_all(bytes)?;
+    w.commit()
+}
+
+/// Atomically replace `target` with a copy of `source` (see [`AtomicWrite`]).
+pub fn copy_file_atomic(source: &Path, target: impl Into<PathBuf>) -> io::Result<u64> {
+    let mut src = File::open(source)?;
+    let mut w = AtomicWrite::new(target)?;
+    let n = io::copy(&mut src, &mut w)?;
+    w.commit()?;
+    Ok(n)
+}
+
+/// One snapshot in a [`SnapshotRing`]: `brain_{stem}.bbi` plus an optional
+/// `runtime_{stem}.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SnapshotEntry {
+    pub stem: String,
+    pub brain_bytes: u64,
+    /// 0 when the snapshot has no runtime file.
+    pub runtime_bytes: u64,
+    /// Modification time of the brain file (seconds since the Unix epoch).
+    pub modified_unix: u64,
+}
+
+/// A directory of timestamped snapshots kept in FIFO order.
+///
+/// [`SnapshotRing::push`] writes a new snapshot (atomically, see
+/// [`AtomicWrite`]) and then deletes the oldest snapshots *this ring wrote*
+/// until at most `max_count` of them remain. The ring records its own stems in
+/// `ring.manifest`, so older or hand-copied snapshots in the directory are
+/// listed but never deleted.
+#[derive(Debug, Clone)]
+pub struct SnapshotRing {
+    dir: PathBuf,
+    max_count: usize,
+}
+
+impl SnapshotRing {
+    /// File in the ring directory listing the stems the ring wrote, oldest first.
+    const MANIFEST: &'static str = "ring.manifest";
+
+    /// Ring over `dir` (created on the first push) keeping at most
+    /// `max_count` (>= 1) ring-written snapshots, evicting the oldest.
+    pub fn new(dir: PathBuf, max_count: usize) -> Self {
+        Self {
+            dir,
+            max_count: max_count.max(1),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn max_count(&self) -> usize {
+        self.max_count
+    }
+
+    pub fn brain_path(&self, stem: &str) -> PathBuf {
+        self.dir.join(format!("brain_{stem}.bbi"))
+    }
+
+    pub fn runtime_path(&self, stem: &str) -> PathBuf {
+        self.dir.join(format!("runtime_{stem}.json"))
+    }
+
+    /// Snapshot `brain` as a `BRAINE01` image, with optional runtime JSON.
+    /// Returns the new stem.
+    pub fn push(
+        &self,
+        brain: &crate::substrate::Brain,
+        runtime: Option<&[u8]>,
+    ) -> io::Result<String> {
+        self.push_with(|w| brain.save_image_to(w), runtime)
+    }
+
+    /// Like [`push`](Self::push) for an already-encoded brain file (e.g. a
+    /// state wrapper that also carries expert brains).
+    pub fn push_image(&self, image: &[u8], runtime: Option<&[u8]>) -> io::Result<String> {
+        self.push_with(|w| w.write_all(image), runtime)
+    }
+
+    /// Like [`push_image`](Self::push_image), streaming the brain and runtime
+    /// files from disk instead of holding them in memory.
+    pub fn push_file(&self, brain: &Path, runtime: Option<&Path>) -> io::Result<String> {
+        let stem = self.push_with(
+            |w| {
+                let mut src = File::open(brain)?;
+                io::copy(&mut src, w).map(|_| ())
+            },
+            None,
+        )?;
+        if let Some(runtime) = runtime {
+            copy_file_atomic(runtime, self.runtime_path(&stem))?;
+        }
+        Ok(stem)
+    }
+
+    fn push_with(
+        &self,
+        write_brain: impl FnOnce(&mut AtomicWrite) -> io::Result<()>,
+        runtime: Option<&[u8]>,
+    ) -> io::Result<String> {
+        fs::create_dir_all(&self.dir)?;
+        let stem = self.fresh_stem()?;
+
+        let mut w = AtomicWrite::new(self.brain_path(&stem))?;
+        write_brain(&mut w)?;
+        w.commit()?;
+        if let Some(runtime) = runtime {
+            write_file_atomic(self.runtime_path(&stem), runtime)?;
+        }
+
+        let mut owned = self.owned_stems();
+        owned.push(stem.clone());
+        while owned.len() > self.max_count {
+            let oldest = owned.remove(0);
+            self.remove(&oldest)?;
+        }
+        let manifest: String = owned.iter().map(|s| format!("{s}\n")).collect();
+        write_file_atomic(self.dir.join(Self::MANIFEST), manifest.as_bytes())?;
+        Ok(stem)
+    }
+
+    /// Stems this ring wrote that still exist, oldest first.
+    fn owned_stems(&self) -> Vec<String> {
+        let Ok(text) = fs::read_to_string(self.dir.join(Self::MANIFEST)) else {
+            return Vec::new();
+        };
+        text.lines()
+            .map(str::trim)
+            .filter(|s| !s.is_empty() && self.brain_path(s).exists())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// `{secs}_{millis:03}` of the current time, suffixed if that stem is taken.
+    fn fresh_stem(&self) -> io::Result<String> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(io::Error::other)?;
+        let base = format!("{}_{:03}", now.as_secs(), now.subsec_millis());
+        let mut stem = base.clone();
+        let mut n = 1;
+        while self.brain_path(&stem).exists() {
+            stem = format!("{base}_{n}");
+            n += 1;
+        }
+        Ok(stem)
+    }
+
+    /// All snapshots, newest first. A missing directory is an empty ring.
+    pub fn list(&self) -> Vec<SnapshotEntry> {
+        let Ok(rd) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+
+        let mut found: Vec<(std::time::SystemTime, SnapshotEntry)> = Vec::new();
+        for ent in rd.flatten() {
+            let path = ent.path();
+            let Some(stem) = path
+                .file_name()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.strip_prefix("brain_"))
+                .and_then(|s| s.strip_suffix(".bbi"))
+            else {
+                continue;
+            };
+            let Ok(meta) = fs::metadata(&path) else {
+                continue;
+            };
+            let modified = meta.modified().unwrap_or(std::time::UNIX_EPOCH);
+            let modified_unix = modified
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let runtime_bytes = fs::metadata(self.runtime_path(stem))
+                .map(|m| m.len())
+                .unwrap_or(0);
+            found.push((
+                modified,
+                SnapshotEntry {
+                    stem: stem.to_string(),
+                    brain_bytes: meta.len(),
+                    runtime_bytes,
+                    modified_unix,
+                },
+            ));
+        }
+
+        found.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| b.1.stem.cmp(&a.1.stem)));
+        found.into_iter().map(|(_, e)| e).collect()
+    }
+
+    /// The ring-written snapshot the next push would evict first (not deleted).
+    pub fn pop_oldest(&self) -> Option<SnapshotEntry> {
+        let oldest = self.owned_stems().into_iter().next()?;
+        self.list().into_iter().find(|e| e.stem == oldest)
+    }
+
+    /// Delete a snapshot's brain and runtime files.
+    pub fn remove(&self, stem: &str) -> io::Result<()> {
+        fs::remove_file(self.brain_path(stem))?;
+        match fs::remove_file(self.runtime_path(stem)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
 }
 
 pub struct CountingWriter {
@@ -57,6 +394,7 @@ impl<W: Write> CapacityWriter<W> {
             inner,
             remaining: capacity_bytes,
             written: 0,
+            overflowed: false,
         }
     }
 
@@ -64,10 +402,23 @@ impl<W: Write> CapacityWriter<W> {
         self.remaining
     }
 
-    pub fn written(&self) -> usize {
+    /// Total bytes accepted so far.
+    pub fn bytes_written(&self) -> usize {
         self.written
     }
 
+    #[deprecated(note = "use `bytes_written`")]
+    pub fn written(&self) -> usize {
+        self.bytes_written()
+    }
+
+    /// True if any write was rejected for lack of space.
+    ///
+    /// Once set, the output is incomplete and will not load.
+    pub fn overflow_attempted(&self) -> bool {
+        self.overflowed
+    }
+
     pub fn into_inner(self) -> W {
         self.inner
     }
@@ -76,6 +427,7 @@ impl<W: Write> CapacityWriter<W> {
 impl<W: Write> Write for CapacityWriter<W> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         if buf.len() > self.remaining {
+            self.overflowed = true;
             return Err(io::Error::new(
                 io::ErrorKind::WriteZero,
                 "CapacityWriter: out of space",
@@ -171,8 +523,245 @@ pub fn write_chunk_v2_lz4<W: Write>(w: &mut W, tag: [u8; 4], payload: &[u8]) ->
     w.write_all(&compressed)
 }
 
+/// Write a V4 chunk: a V2 chunk with the CRC32 of its body after the length.
+///
+/// Layout:
+/// - tag: [u8;4]
+/// - len: u32 (body bytes)
+/// - crc: u32 (CRC32 of the body)
+/// - body: uncompressed_len (u32) followed by the LZ4-compressed payload
+pub fn write_chunk_v4_lz4<W: Write>(w: &mut W, tag: [u8; 4], payload: &[u8]) -> io::Result<()> {
+    let compressed = compress_lz4(payload);
+    let mut body = Vec::with_capacity(4 + compressed.len());
+    write_u32_le(&mut body, payload.len() as u32)?;
+    body.extend_from_slice(&compressed);
+    let len = u32::try_from(body.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "chunk too large"))?;
+
+    w.write_all(&tag)?;
+    write_u32_le(w, len)?;
+    write_u32_le(w, crc32(&body))?;
+    w.write_all(&body)
+}
+
+/// Header of one LZ4 chunk in a V3 or V4 stream. The body follows in the
+/// reader; consume it with [`ChunkFrame::read_payload`] or [`ChunkFrame::skip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkFrame {
+    pub tag: [u8; 4],
+    /// Body length in bytes.
+    pub len: u32,
+    /// Expected CRC32 of the body (V4 only).
+    pub crc: Option<u32>,
+}
+
+impl ChunkFrame {
+    /// Read the next chunk header for a stream of the given format version.
+    ///
+    /// Returns `Ok(None)` at a clean end of stream.
+    pub fn read<R: Read>(r: &mut R, version: u32) -> io::Result<Option<Self>> {
+        let (tag, len) = match read_chunk_header(r) {
+            Ok(v) => v,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let crc = match version {
+            VERSION_V3 => None,
+            VERSION_V4 => Some(read_u32_le(r)?),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "unsupported chunk format version",
+                ))
+            }
+        };
+        Ok(Some(Self { tag, len, crc }))
+    }
+
+    /// Read the body, check its CRC (V4) and decompress it.
+    pub fn read_payload<R: Read>(&self, r: &mut R) -> io::Result<Vec<u8>> {
+        let mut body = Vec::new();
+        r.take(self.len as u64).read_to_end(&mut body)?;
+        if body.len() != self.len as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "truncated chunk",
+            ));
+        }
+        if let Some(expected) = self.crc {
+            if crc32(&body) != expected {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "chunk CRC mismatch",
+                ));
+            }
+        }
+        if body.len() < 4 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "chunk body too short",
+            ));
+        }
+        let uncompressed_len = u32::from_le_bytes([body[0], body[1], body[2], body[3]]) as usize;
+        decompress_lz4(&body[4..], uncompressed_len)
+    }
+
+    /// Discard the body without buffering or decompressing it.
+    pub fn skip<R: Read>(&self, r: &mut R) -> io::Result<()> {
+        let n = io::copy(&mut r.take(self.len as u64), &mut io::sink())?;
+        if n != self.len as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "truncated chunk",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Read and decompress the next chunk of a V3 or V4 stream.
+///
+/// Returns `Ok(None)` at a clean end of stream.
+pub fn read_chunk_lz4<R: Read>(r: &mut R, version: u32) -> io::Result<Option<([u8; 4], Vec<u8>)>> {
+    match ChunkFrame::read(r, version)? {
+        Some(frame) => Ok(Some((frame.tag, frame.read_payload(r)?))),
+        None => Ok(None),
+    }
+}
+
 pub fn read_chunk_header<R: Read>(r: &mut R) -> io::Result<([u8; 4], u32)> {
     let tag = read_exact::<4, _>(r)?;
     let len = read_u32_le(r)?;
     Ok((tag, len))
 }
+
+/// Read the next V2 chunk written by [`write_chunk_v2_lz4`] and decompress it.
+///
+/// Returns `Ok(None)` at a clean end of stream.
+pub fn read_chunk_v2_lz4<R: Read>(r: &mut R) -> io::Result<Option<([u8; 4], Vec<u8>)>> {
+    let (tag, len) = match read_chunk_header(r) {
+        Ok(v) => v,
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let mut take = r.take(len as u64);
+    let uncompressed_len = read_u32_le(&mut take)? as usize;
+    let mut compressed = Vec::with_capacity((len as usize).saturating_sub(4));
+    take.read_to_end(&mut compressed)?;
+    let payload = decompress_lz4(&compressed, uncompressed_len)?;
+    io::copy(&mut take, &mut io::sink())?;
+    Ok(Some((tag, payload)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_matches_reference_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn v4_chunks_round_trip_and_detect_corruption() {
+        let mut buf = Vec::new();
+        write_chunk_v4_lz4(&mut buf, *b"AAAA", b"hello hello hello").unwrap();
+        write_chunk_v4_lz4(&mut buf, *b"BBBB", b"world").unwrap();
+
+        let mut r = io::Cursor::new(buf.clone());
+        let first = ChunkFrame::read(&mut r, VERSION_V4).unwrap().unwrap();
+        assert_eq!(first.tag, *b"AAAA");
+        first.skip(&mut r).unwrap();
+        let (tag, payload) = read_chunk_lz4(&mut r, VERSION_V4).unwrap().unwrap();
+        assert_eq!((&tag, payload.as_slice()), (b"BBBB", &b"world"[..]));
+        assert!(read_chunk_lz4(&mut r, VERSION_V4).unwrap().is_none());
+
+        let last = buf.len() - 1;
+        buf[last] ^= 0xFF;
+        let mut r = io::Cursor::new(buf);
+        read_chunk_lz4(&mut r, VERSION_V4).unwrap();
+        let err = read_chunk_lz4(&mut r, VERSION_V4).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn snapshot_ring_keeps_the_newest_max_count() {
+        let dir = std::env::temp_dir().join(format!("braine_ring_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let ring = SnapshotRing::new(dir.clone(), 2);
+        assert!(ring.list().is_empty());
+
+        // A snapshot the ring did not write is listed but never evicted.
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(ring.brain_path("manual"), b"kept").unwrap();
+
+        let first = ring.push_image(b"one", Some(b"{}")).unwrap();
+        let (_, millis) = first.split_once('_').unwrap();
+        assert_eq!(millis.len(), 3, "millis must be zero-padded: {first}");
+        let second = ring.push_image(b"two", None).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(ring.pop_oldest().unwrap().stem, first);
+
+        let source = dir.join("source.bbi");
+        fs::write(&source, b"three").unwrap();
+        let third = ring.push_file(&source, None).unwrap();
+        let mut stems: Vec<String> = ring.list().into_iter().map(|e| e.stem).collect();
+        stems.sort();
+        let mut expected = vec![third.clone(), second.clone(), "manual".to_string()];
+        expected.sort();
+        assert_eq!(stems, expected);
+        assert!(!ring.brain_path(&first).exists());
+        assert!(!ring.runtime_path(&first).exists());
+        assert_eq!(fs::read(ring.brain_path(&third)).unwrap(), b"three");
+
+        // A larger bound over the same directory evicts nothing yet.
+        let wider = SnapshotRing::new(dir.clone(), 3);
+        wider.push_image(b"four", None).unwrap();
+        assert_eq!(wider.list().len(), 4);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn atomic_write_replaces_only_on_commit() {
+        let dir = std::env::temp_dir().join(format!("braine_atomic_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("brain.bbi");
+        fs::write(&target, b"old").unwrap();
+
+        // Abandoned write (e.g. overflow): target untouched, temp removed.
+        {
+            let mut cw = CapacityWriter::new(AtomicWrite::new(&target).unwrap(), 4);
+            assert!(cw.write_all(b"too long").is_err());
+        }
+        assert_eq!(fs::read(&target).unwrap(), b"old");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        let mut cw = CapacityWriter::new(AtomicWrite::new(&target).unwrap(), 16);
+        cw.write_all(b"new").unwrap();
+        assert_eq!(fs::read(&target).unwrap(), b"old");
+        cw.into_inner().commit().unwrap();
+        assert_eq!(fs::read(&target).unwrap(), b"new");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn capacity_writer_tracks_bytes_and_overflow() {
+        let mut w = CapacityWriter::new(Vec::new(), 8);
+        write_u32_le(&mut w, 1).unwrap();
+        assert_eq!(w.bytes_written(), 4);
+        assert!(!w.overflow_attempted());
+
+        assert!(write_u64_le(&mut w, 2).is_err());
+        assert!(w.overflow_attempted());
+        assert_eq!(w.bytes_written(), 4);
+        assert_eq!(w.remaining(), 4);
+        #[allow(deprecated)]
+        let old = w.written();
+        assert_eq!(old, 4);
+    }
+}
@@ -8,9 +8,11 @@ use std::collections::{HashMap, HashSet};
 use std::io::{self, Read, Write};
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+use alloc::{collections::VecDeque, format, string::String, string::ToString, vec, vec::Vec};
 #[cfg(not(feature = "std"))]
 use hashbrown::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
 
 use core::ops::Range;
 
@@ -18,12 +20,12 @@ use core::ops::Range;
 use rayon::prelude::*;
 
 #[cfg(feature = "simd")]
-use wide::{f32x4, CmpGt, CmpLt};
+use wide::{f32x4, f32x8, CmpGt, CmpLt, CmpNe};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::causality::{CausalMemory, SymbolId};
+use crate::causality::{CausalEventLog, CausalMemory, SymbolId};
 use crate::prng::Prng;
 #[cfg(feature = "std")]
 use crate::storage;
@@ -45,6 +47,45 @@ pub type Neuromodulator = f32;
 /// Sentinel value for pruned/invalid connections in CSR storage.
 pub const INVALID_UNIT: UnitId = UnitId::MAX;
 
+/// Default number of amplitude frames kept by observer telemetry.
+pub const ACTIVATION_HISTORY_DEFAULT: usize = 32;
+
+/// Upper bound for [`Brain::set_activation_history_window`].
+pub const ACTIVATION_HISTORY_MAX: usize = 256;
+
+/// Oscillation samples kept by observer telemetry (see
+/// [`Brain::oscillation_sample_windowed`]).
+pub const OSCILLATION_HISTORY_MAX: usize = 1024;
+
+/// Edges kept by [`Brain::serialize_causal_graph`].
+pub const CAUSAL_GRAPH_JSON_MAX_EDGES: usize = 256;
+
+/// Units sampled per step for the oscillation history.
+#[cfg(feature = "std")]
+const OSCILLATION_HISTORY_UNITS: usize = 512;
+
+/// `Brain::coactive_last_step` entry for a unit that has never been coactive.
+const NEVER_COACTIVE: u64 = u64::MAX;
+
+/// Smoothing factor for [`LearningStats::plasticity_rate`].
+const PLASTICITY_RATE_ALPHA: f32 = 0.05;
+
+/// Smoothing factor for [`LearningStats::td_prediction_error_ema`].
+const TD_ERROR_ALPHA: f32 = 0.05;
+
+/// Upper bound for [`BrainConfig::causal_window_frames`].
+pub const CAUSAL_WINDOW_MAX: u32 = 64;
+
+/// Fraction of the fast weight bank that one [`Brain::dream`] call consolidates
+/// into the slow bank (when `enable_fast_weights` is set).
+const DREAM_FAST_CONSOLIDATION_RATE: f32 = 0.5;
+
+/// Committed observations kept for [`Brain::dream_replay_with_source`].
+const DREAM_TRACE_CAP: usize = 256;
+
+/// Neighbor lists up to this length are re-sorted by insertion sort after each step.
+const SORTED_ADJACENCY_INSERTION_MAX: usize = 32;
+
 /// Execution tier for step() and learning updates.
 ///
 /// Allows seamless scaling from edge devices to servers:
@@ -66,6 +107,17 @@ pub enum ExecutionTier {
     Gpu,
 }
 
+impl core::fmt::Display for ExecutionTier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ExecutionTier::Scalar => "Scalar",
+            ExecutionTier::Simd => "SIMD",
+            ExecutionTier::Parallel => "Parallel",
+            ExecutionTier::Gpu => "GPU",
+        })
+    }
+}
+
 /// Legacy struct kept for API compatibility in some contexts.
 /// Internal storage now uses CSR format.
 #[derive(Debug, Clone)]
@@ -110,8 +162,37 @@ pub struct CsrConnections {
     pub offsets: Vec<usize>,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Error reading or writing a [`BrainConfig`] as TOML.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    /// TOML (de)serialization failed.
+    Toml(String),
+    /// The config parsed but failed [`BrainConfig::validate`].
+    Invalid(&'static str),
+}
+
+#[cfg(feature = "serde")]
+impl core::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConfigError::Toml(e) => write!(f, "invalid config TOML: {e}"),
+            ConfigError::Invalid(e) => write!(f, "invalid config: {e}"),
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl std::error::Error for ConfigError {}
+
+/// Substrate parameters.
+///
+/// With the `serde` feature, fields (de)serialize under their Rust names and
+/// missing fields take their [`Default`] values, so config files only need to
+/// list what they change.
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct BrainConfig {
     pub unit_count: usize,
     pub connectivity_per_unit: usize,
@@ -157,6 +238,14 @@ pub struct BrainConfig {
     pub forget_rate: f32,
     pub prune_below: f32,
 
+    /// Split every connection into a slow bank (learning at `hebb_rate`,
+    /// decaying at `forget_rate`) and a fast bank (learning at
+    /// `fast_hebb_rate`, additionally decaying at `fast_forget_rate`). The
+    /// effective weight is their sum. See [`Brain::consolidate_fast_to_slow`].
+    pub enable_fast_weights: bool,
+    pub fast_hebb_rate: f32,
+    pub fast_forget_rate: f32,
+
     pub coactive_threshold: f32,
 
     // If two units are active and phase-aligned, strengthen more.
@@ -204,6 +293,10 @@ pub struct BrainConfig {
     pub causal_lag_decay: f32,
     /// Cap how many symbols per tick participate in lagged updates (keeps bounded work).
     pub causal_symbol_cap: u8,
+    /// Symbols committed within this many observation frames count as co-occurring
+    /// (1 = same frame only). Short windows give tight temporal associations, long
+    /// ones looser contextual links. See [`Brain::set_causal_window`].
+    pub causal_window_frames: u32,
 
     // If set, makes behavior reproducible for evaluation.
     pub seed: Option<u64>,
@@ -226,6 +319,16 @@ pub struct BrainConfig {
     /// Eligibility trace gain (accumulation rate). Higher accumulates faster.
     pub eligibility_gain: f32,
 
+    /// Lower hard bound for connection weights, applied after every learning update.
+    ///
+    /// `-f32::INFINITY` disables the bound (the built-in ±1.5 clamp still applies).
+    pub weight_clip_min: f32,
+
+    /// Upper hard bound for connection weights, applied after every learning update.
+    ///
+    /// `f32::INFINITY` disables the bound (the built-in ±1.5 clamp still applies).
+    pub weight_clip_max: f32,
+
     /// Minimum reward magnitude required to emit discrete reward symbols into
     /// causal/meaning memory during `commit_observation()`.
     ///
@@ -352,6 +455,33 @@ pub struct BrainConfig {
 
     /// Retire only if `abs(reward_ema) < threshold`.
     pub latent_module_retire_reward_threshold: f32,
+
+    // ---------------------------------------------------------------------
+    // Novelty (intrinsic reward)
+    // ---------------------------------------------------------------------
+    /// A coactive unit counts as novel if it was not coactive in any of the
+    /// previous `novelty_window` steps. See [`Brain::intrinsic_reward_estimate`].
+    pub novelty_window: u32,
+
+    /// Momentum in `[0, 1)` for [`Brain::set_neuromodulator`]: the effective
+    /// level becomes `(1 - m) * reward + m * previous`. 0.0 applies rewards
+    /// instantly. See [`Brain::set_neuromodulator_momentum`].
+    pub neuromodulator_momentum: f32,
+
+    // ---------------------------------------------------------------------
+    // Experimental toggles
+    // ---------------------------------------------------------------------
+    /// Unstable feature toggles read by `step()`, keyed by name. A missing or
+    /// zero-valued flag leaves the feature off; unknown names are ignored so
+    /// experiments can land their flag before their code. Not stored in
+    /// brain images. Currently read:
+    ///
+    /// - `fast_weights`: enable the fast weight bank (as `enable_fast_weights`).
+    /// - `oja_units_all`: run Oja's rule on every unit at `hebb_rate * value`.
+    /// - `sorted_adjacency`: keep the [`Brain::neighbors_sorted`] order up to
+    ///   date at the end of each step.
+    #[cfg(feature = "std")]
+    pub experimental_flags: HashMap<String, f32>,
 }
 
 impl Default for BrainConfig {
@@ -378,6 +508,9 @@ impl Default for BrainConfig {
             hebb_rate: 0.08,
             forget_rate: 0.0005,
             prune_below: 0.01,
+            enable_fast_weights: false,
+            fast_hebb_rate: 0.4,
+            fast_forget_rate: 0.02,
             coactive_threshold: 0.3,
             phase_lock_threshold: 0.7,
             imprint_rate: 0.5,
@@ -395,6 +528,7 @@ impl Default for BrainConfig {
             causal_lag_steps: 1,
             causal_lag_decay: 0.7,
             causal_symbol_cap: 32,
+            causal_window_frames: 4,
             seed: None,
             causal_decay: 0.002,
 
@@ -403,6 +537,8 @@ impl Default for BrainConfig {
             learning_deadband: 0.05,
             eligibility_decay: 0.02,
             eligibility_gain: 0.35,
+            weight_clip_min: f32::NEG_INFINITY,
+            weight_clip_max: f32::INFINITY,
             reward_symbol_threshold: 0.2,
             concept_validate_threshold: 0.2,
             coactive_softness: 0.05,
@@ -434,6 +570,12 @@ impl Default for BrainConfig {
             latent_module_auto_reward_threshold: 0.2,
             latent_module_retire_after_steps: 0,
             latent_module_retire_reward_threshold: 0.05,
+
+            novelty_window: 50,
+            neuromodulator_momentum: 0.0,
+
+            #[cfg(feature = "std")]
+            experimental_flags: HashMap::new(),
         }
     }
 }
@@ -518,6 +660,12 @@ impl BrainConfig {
         if self.forget_rate < 0.0 || self.forget_rate > 1.0 {
             return Err("forget_rate must be in [0, 1]");
         }
+        if !(0.0..=1.0).contains(&self.fast_hebb_rate) {
+            return Err("fast_hebb_rate must be in [0, 1]");
+        }
+        if !(0.0..=1.0).contains(&self.fast_forget_rate) {
+            return Err("fast_forget_rate must be in [0, 1]");
+        }
         if self.causal_decay < 0.0 || self.causal_decay > 1.0 {
             return Err("causal_decay must be in [0, 1]");
         }
@@ -576,6 +724,9 @@ impl BrainConfig {
         {
             return Err("causal_lag_decay must be finite and in (0, 1)");
         }
+        if !(1..=CAUSAL_WINDOW_MAX).contains(&self.causal_window_frames) {
+            return Err("causal_window_frames must be in 1..=64");
+        }
         if self.causal_symbol_cap == 0 {
             return Err("causal_symbol_cap must be >= 1");
         }
@@ -589,6 +740,12 @@ impl BrainConfig {
         if !self.eligibility_gain.is_finite() || self.eligibility_gain < 0.0 {
             return Err("eligibility_gain must be finite and >= 0");
         }
+        if self.weight_clip_min.is_nan()
+            || self.weight_clip_max.is_nan()
+            || self.weight_clip_min > self.weight_clip_max
+        {
+            return Err("weight_clip_min must be <= weight_clip_max");
+        }
         if !self.coactive_softness.is_finite() || self.coactive_softness < 0.0 {
             return Err("coactive_softness must be finite and >= 0");
         }
@@ -654,9 +811,175 @@ impl BrainConfig {
         {
             return Err("latent_module_retire_reward_threshold must be finite and >= 0");
         }
+        if self.novelty_window == 0 {
+            return Err("novelty_window must be >= 1");
+        }
+        if !(0.0..1.0).contains(&self.neuromodulator_momentum) {
+            return Err("neuromodulator_momentum must be in [0, 1)");
+        }
+        #[cfg(feature = "std")]
+        if self.experimental_flags.values().any(|v| !v.is_finite()) {
+            return Err("experimental_flags values must be finite");
+        }
         Ok(())
     }
 
+    /// Value of an experimental flag, or `None` when it is absent or zero
+    /// (always `None` without the `std` feature).
+    #[must_use]
+    pub fn experimental_flag(&self, name: &str) -> Option<f32> {
+        #[cfg(feature = "std")]
+        {
+            self.experimental_flags
+                .get(name)
+                .copied()
+                .filter(|&v| v != 0.0)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = name;
+            None
+        }
+    }
+
+    /// Parse a config from TOML and [`validate`](Self::validate) it.
+    #[cfg(feature = "serde")]
+    pub fn from_toml(s: &str) -> Result<Self, ConfigError> {
+        let cfg: Self = toml::from_str(s).map_err(|e| ConfigError::Toml(e.to_string()))?;
+        cfg.validate().map_err(ConfigError::Invalid)?;
+        Ok(cfg)
+    }
+
+    /// Render this config as a TOML document accepted by [`Self::from_toml`].
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
+        toml::to_string(self).map_err(|e| ConfigError::Toml(e.to_string()))
+    }
+
+    /// Environment variables understood by [`Self::from_env`].
+    #[cfg(feature = "std")]
+    pub const ENV_VARS: &'static [&'static str] = &[
+        "BRAINE_UNIT_COUNT",
+        "BRAINE_CONNECTIVITY",
+        "BRAINE_DT",
+        "BRAINE_BASE_FREQ",
+        "BRAINE_NOISE_AMP",
+        "BRAINE_NOISE_PHASE",
+        "BRAINE_GLOBAL_INHIBITION",
+        "BRAINE_HEBB_RATE",
+        "BRAINE_FORGET_RATE",
+        "BRAINE_PRUNE_BELOW",
+        "BRAINE_COACTIVE_THRESHOLD",
+        "BRAINE_PHASE_LOCK_THRESHOLD",
+        "BRAINE_IMPRINT_RATE",
+        "BRAINE_SEED",
+        "BRAINE_CAUSAL_DECAY",
+    ];
+
+    /// [`BrainConfig::default`] with any [`Self::ENV_VARS`] that are set applied
+    /// on top. Values that fail to parse are ignored; use
+    /// [`Self::with_env_overrides`] to get an error instead.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_env() -> Self {
+        let mut cfg = Self::default();
+        let _ = cfg.apply_env_vars(|var| std::env::var(var).ok());
+        cfg
+    }
+
+    /// Apply the [`Self::ENV_VARS`] that are set on top of this config.
+    ///
+    /// `BRAINE_SEED` also accepts `none`. Fails on the first value that does
+    /// not parse (the result is not [`validate`](Self::validate)d).
+    #[cfg(feature = "std")]
+    pub fn with_env_overrides(mut self) -> Result<Self, String> {
+        self.apply_env_vars(|var| std::env::var(var).ok())?;
+        Ok(self)
+    }
+
+    /// One `VAR=value` line per [`Self::ENV_VARS`] entry, using this config's values.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn env_template(&self) -> String {
+        let mut out = String::new();
+        for var in Self::ENV_VARS {
+            let value = match *var {
+                "BRAINE_UNIT_COUNT" => self.unit_count.to_string(),
+                "BRAINE_CONNECTIVITY" => self.connectivity_per_unit.to_string(),
+                "BRAINE_DT" => self.dt.to_string(),
+                "BRAINE_BASE_FREQ" => self.base_freq.to_string(),
+                "BRAINE_NOISE_AMP" => self.noise_amp.to_string(),
+                "BRAINE_NOISE_PHASE" => self.noise_phase.to_string(),
+                "BRAINE_GLOBAL_INHIBITION" => self.global_inhibition.to_string(),
+                "BRAINE_HEBB_RATE" => self.hebb_rate.to_string(),
+                "BRAINE_FORGET_RATE" => self.forget_rate.to_string(),
+                "BRAINE_PRUNE_BELOW" => self.prune_below.to_string(),
+                "BRAINE_COACTIVE_THRESHOLD" => self.coactive_threshold.to_string(),
+                "BRAINE_PHASE_LOCK_THRESHOLD" => self.phase_lock_threshold.to_string(),
+                "BRAINE_IMPRINT_RATE" => self.imprint_rate.to_string(),
+                "BRAINE_SEED" => self
+                    .seed
+                    .map_or_else(|| "none".to_string(), |s| s.to_string()),
+                "BRAINE_CAUSAL_DECAY" => self.causal_decay.to_string(),
+                _ => continue,
+            };
+            out.push_str(&format!("{var}={value}\n"));
+        }
+        out
+    }
+
+    /// Print [`Self::env_template`] to stdout.
+    #[cfg(feature = "std")]
+    pub fn print_env_template(&self) {
+        print!("{}", self.env_template());
+    }
+
+    /// Apply every variable `get` returns a value for. Keeps going past bad
+    /// values and reports the first one.
+    #[cfg(feature = "std")]
+    fn apply_env_vars(&mut self, get: impl Fn(&str) -> Option<String>) -> Result<(), String> {
+        fn parse<T: core::str::FromStr>(var: &str, raw: &str) -> Result<T, String> {
+            raw.parse()
+                .map_err(|_| format!("{var}: cannot parse '{raw}'"))
+        }
+
+        let mut first_err = None;
+        for var in Self::ENV_VARS {
+            let Some(raw) = get(var) else {
+                continue;
+            };
+            let raw = raw.trim();
+            let res = match *var {
+                "BRAINE_UNIT_COUNT" => parse(var, raw).map(|v| self.unit_count = v),
+                "BRAINE_CONNECTIVITY" => parse(var, raw).map(|v| self.connectivity_per_unit = v),
+                "BRAINE_DT" => parse(var, raw).map(|v| self.dt = v),
+                "BRAINE_BASE_FREQ" => parse(var, raw).map(|v| self.base_freq = v),
+                "BRAINE_NOISE_AMP" => parse(var, raw).map(|v| self.noise_amp = v),
+                "BRAINE_NOISE_PHASE" => parse(var, raw).map(|v| self.noise_phase = v),
+                "BRAINE_GLOBAL_INHIBITION" => parse(var, raw).map(|v| self.global_inhibition = v),
+                "BRAINE_HEBB_RATE" => parse(var, raw).map(|v| self.hebb_rate = v),
+                "BRAINE_FORGET_RATE" => parse(var, raw).map(|v| self.forget_rate = v),
+                "BRAINE_PRUNE_BELOW" => parse(var, raw).map(|v| self.prune_below = v),
+                "BRAINE_COACTIVE_THRESHOLD" => parse(var, raw).map(|v| self.coactive_threshold = v),
+                "BRAINE_PHASE_LOCK_THRESHOLD" => {
+                    parse(var, raw).map(|v| self.phase_lock_threshold = v)
+                }
+                "BRAINE_IMPRINT_RATE" => parse(var, raw).map(|v| self.imprint_rate = v),
+                "BRAINE_SEED" if raw.eq_ignore_ascii_case("none") => {
+                    self.seed = None;
+                    Ok(())
+                }
+                "BRAINE_SEED" => parse(var, raw).map(|v| self.seed = Some(v)),
+                "BRAINE_CAUSAL_DECAY" => parse(var, raw).map(|v| self.causal_decay = v),
+                _ => Ok(()),
+            };
+            if let Err(e) = res {
+                first_err.get_or_insert(e);
+            }
+        }
+        first_err.map_or(Ok(()), Err)
+    }
+
     /// Estimated memory usage in bytes for a brain with this config.
     #[must_use]
     pub fn estimated_memory_bytes(&self) -> usize {
@@ -687,6 +1010,41 @@ impl BrainConfig {
         self.noise_phase = phase;
         self
     }
+
+    /// Conservative preset for embedded / `no_std` targets with tight memory.
+    ///
+    /// - 32 units with 4 connections each
+    /// - 2-frame causal window and low noise
+    /// - Fast weights off
+    ///
+    /// `BrainConfig` has no unit cap; embedded callers keep the size fixed by
+    /// not calling the neurogenesis APIs (`grow_units`, `maybe_neurogenesis`).
+    pub fn with_defaults_for_embedded() -> Self {
+        Self {
+            unit_count: 32,
+            connectivity_per_unit: 4,
+            causal_window_frames: 2,
+            noise_amp: 0.005,
+            enable_fast_weights: false,
+            ..Self::default()
+        }
+    }
+
+    /// Preset for hosts with memory and cores to spare (e.g. the daemon).
+    ///
+    /// - 512 units with 32 connections each
+    /// - Fast weights and the hybrid growth policy on
+    /// - 8-frame causal window
+    pub fn with_defaults_for_server() -> Self {
+        Self {
+            unit_count: 512,
+            connectivity_per_unit: 32,
+            causal_window_frames: 8,
+            enable_fast_weights: true,
+            growth_policy_mode: 1,
+            ..Self::default()
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -726,6 +1084,9 @@ pub struct UnitPlotPoint {
     pub is_reserved: bool,
     pub is_sensor_member: bool,
     pub is_group_member: bool,
+    /// Current homeostasis bias (0 unless observer telemetry is enabled).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub homeostasis_bias: f32,
 }
 
 /// Per-action score breakdown for UI inspection.
@@ -743,6 +1104,126 @@ pub struct ActionScoreBreakdown {
     pub score: f32,
 }
 
+/// Verbose per-action entry from [`Brain::ranked_actions_with_meaning_debug`].
+///
+/// `composite_score` is exactly the score [`Brain::ranked_actions_with_meaning`]
+/// ranks by; the other fields show where it came from.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ActionDebugInfo {
+    pub name: String,
+    /// Normalized habit readout of the action group in `[0, 1]`.
+    pub habit_score: f32,
+    /// Association-based meaning (`pair + 0.15 * global`) used in the composite.
+    pub meaning_score: f32,
+    /// The same blend from directional causal strength (the
+    /// [`Brain::action_score_breakdown`] readout); not part of the composite.
+    pub causal_score: f32,
+    /// `0.5 * habit_score + alpha * meaning_score`.
+    pub composite_score: f32,
+    pub pair_reward_edges: RewardEdges,
+    pub action_reward_edges: RewardEdges,
+    /// Decayed causal-memory count of `pair::<ctx>::<action>`.
+    pub visit_count: f32,
+}
+
+/// Wall-clock time spent in each phase of one [`Brain::step`], in nanoseconds.
+///
+/// Causal-memory updates and imprinting run outside `step()` (in
+/// `commit_observation` and `apply_stimulus`). [`Brain::profile_step`] leaves
+/// `causal_ns` and `imprint_ns` at 0; [`Brain::profile_cycle`] measures them.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StepProfile {
+    /// Unit dynamics, paralyze gate, novelty tracking, and homeostasis.
+    pub oscillator_ns: u64,
+    /// Eligibility traces, neuromodulated plasticity, and Oja updates.
+    pub hebbian_ns: u64,
+    /// Forgetting and pruning of weak connections.
+    pub pruning_ns: u64,
+    /// Growth-signal bookkeeping that drives neurogenesis.
+    pub neurogenesis_ns: u64,
+    /// Re-sorting [`Brain::neighbors_sorted`] order (0 unless the
+    /// `sorted_adjacency` experimental flag is on).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sorted_adjacency_ns: u64,
+    /// `commit_observation`: causal-memory and meaning updates.
+    pub causal_ns: u64,
+    /// `apply_stimulus`: sensor input and one-shot concept imprinting.
+    pub imprint_ns: u64,
+    pub total_ns: u64,
+}
+
+#[cfg(feature = "std")]
+impl StepProfile {
+    /// Per-field mean of `samples` (all zeros when empty).
+    pub fn mean(samples: &[StepProfile]) -> StepProfile {
+        let n = samples.len().max(1) as u64;
+        let sum = |f: fn(&StepProfile) -> u64| samples.iter().map(f).sum::<u64>() / n;
+        StepProfile {
+            oscillator_ns: sum(|p| p.oscillator_ns),
+            hebbian_ns: sum(|p| p.hebbian_ns),
+            pruning_ns: sum(|p| p.pruning_ns),
+            neurogenesis_ns: sum(|p| p.neurogenesis_ns),
+            sorted_adjacency_ns: sum(|p| p.sorted_adjacency_ns),
+            causal_ns: sum(|p| p.causal_ns),
+            imprint_ns: sum(|p| p.imprint_ns),
+            total_ns: sum(|p| p.total_ns),
+        }
+    }
+}
+
+/// Load exactly eight lanes from a slice of length 8.
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn load_f32x8(s: &[f32]) -> f32x8 {
+    f32x8::from(<[f32; 8]>::try_from(s).expect("slice of 8 lanes"))
+}
+
+/// Phases of [`Brain::step`] reported to a [`PhaseClock`].
+#[derive(Debug, Clone, Copy)]
+enum StepPhase {
+    Oscillator,
+    Hebbian,
+    Pruning,
+    SortedAdjacency,
+    Neurogenesis,
+}
+
+/// Receives a lap at the end of each step phase; `()` is the no-op clock used by `step()`.
+trait PhaseClock {
+    fn lap(&mut self, phase: StepPhase);
+}
+
+impl PhaseClock for () {
+    #[inline(always)]
+    fn lap(&mut self, _phase: StepPhase) {}
+}
+
+#[cfg(feature = "std")]
+struct ProfileClock {
+    last: std::time::Instant,
+    profile: StepProfile,
+}
+
+#[cfg(feature = "std")]
+impl PhaseClock for ProfileClock {
+    fn lap(&mut self, phase: StepPhase) {
+        let now = std::time::Instant::now();
+        let ns = now.duration_since(self.last).as_nanos() as u64;
+        self.last = now;
+        let slot = match phase {
+            StepPhase::Oscillator => &mut self.profile.oscillator_ns,
+            StepPhase::Hebbian => &mut self.profile.hebbian_ns,
+            StepPhase::Pruning => &mut self.profile.pruning_ns,
+            StepPhase::SortedAdjacency => &mut self.profile.sorted_adjacency_ns,
+            StepPhase::Neurogenesis => &mut self.profile.neurogenesis_ns,
+        };
+        *slot += ns;
+    }
+}
+
 /// Reward-edge breakdown for a symbol in causal memory.
 ///
 /// This is primarily for UI/diagnostics.
@@ -797,6 +1278,33 @@ impl<'a> Stimulus<'a> {
     }
 }
 
+impl<'a> From<(&'a str, f32)> for Stimulus<'a> {
+    fn from((name, strength): (&'a str, f32)) -> Self {
+        Self { name, strength }
+    }
+}
+
+impl<'a> From<&'a OwnedStimulus> for Stimulus<'a> {
+    fn from(s: &'a OwnedStimulus) -> Self {
+        s.as_stimulus()
+    }
+}
+
+impl From<(String, f32)> for OwnedStimulus {
+    fn from((name, strength): (String, f32)) -> Self {
+        Self { name, strength }
+    }
+}
+
+impl<'a> From<Stimulus<'a>> for OwnedStimulus {
+    fn from(s: Stimulus<'a>) -> Self {
+        Self {
+            name: s.name.to_string(),
+            strength: s.strength,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ActionPolicy {
@@ -804,6 +1312,131 @@ pub enum ActionPolicy {
     EpsilonGreedy { epsilon: f32 },
 }
 
+/// When [`Brain::maybe_neurogenesis_with_policy`] grows new units, how many,
+/// and (for `Gradient`) where they are wired in.
+#[derive(Debug, Clone)]
+pub enum NeurogenesisPolicy {
+    /// Grow `amount` units when [`Brain::is_saturated`] reports saturation
+    /// at `threshold` (the behavior of [`Brain::maybe_neurogenesis`]).
+    Saturation { threshold: f32, amount: u32 },
+    /// Grow `amount` units once at least `every_steps` brain steps have passed
+    /// since the last birth (0 disables growth).
+    Periodic { every_steps: u64, amount: u32 },
+    /// Grow `amount` units while the neuromodulator is at least `min_reward`.
+    RewardDriven { min_reward: f32, amount: u32 },
+    /// Grow as many units as `f` returns.
+    Custom { f: fn(&Brain) -> u32 },
+    /// Like `Saturation`, but wire the new units near `target_group` (a sensor
+    /// or action group) instead of to random units: their connections are
+    /// sampled from the units within two hops of the group's most active
+    /// units. Falls back to random wiring if the group is unknown.
+    Gradient {
+        target_group: String,
+        threshold: f32,
+        amount: u32,
+    },
+}
+
+/// How [`Brain::step`] removes connections after weight decay
+/// (see [`Brain::set_pruning_policy`]).
+///
+/// Engram edges (sensor↔validated concept) are never pruned by any policy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PruningPolicy {
+    /// Prune when `|w| < below` (plus `cross_module_prune_bonus` across
+    /// module boundaries). Mirrors [`BrainConfig::prune_below`].
+    Threshold { below: f32 },
+    /// Keep only the `k_per_unit` strongest outgoing connections of each unit.
+    TopK { k_per_unit: usize },
+    /// Prune each connection with probability `rate * exp(-|w| / temperature)`,
+    /// so weak connections are removed far more often than strong ones.
+    Probabilistic { rate: f32, temperature: f32 },
+    /// Prune connections whose weight has not been changed by learning for
+    /// more than `max_age_steps` steps.
+    Age { max_age_steps: u64 },
+}
+
+impl Default for NeurogenesisPolicy {
+    fn default() -> Self {
+        Self::Saturation {
+            threshold: 0.35,
+            amount: 1,
+        }
+    }
+}
+
+/// One-shot perturbation applied by [`Brain::apply_structured_noise`].
+///
+/// Unlike the per-step `noise_amp`/`noise_phase` jitter, these patterns are
+/// applied once, on demand, for robustness probes and sensory-noise studies.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NoisePattern {
+    /// Jitter every unit's amplitude and phase by a uniform sample in `±sigma`.
+    Uniform { sigma: f32 },
+    /// Like `Uniform`, but only for the units of one sensor group.
+    PerGroup { group: String, sigma: f32 },
+    /// Zero the pending input and amplitude of a random `fraction` of a
+    /// sensor group's units (apply after the stimulus, before `step()`).
+    Occlusion { group: String, fraction: f32 },
+    /// Push the amplitude of the given units by `direction`.
+    Adversarial { units: Vec<UnitId>, direction: f32 },
+}
+
+/// Which committed observations [`Brain::dream_replay_with_source`] replays.
+///
+/// The brain keeps the last 256 committed observations (symbols, reward and
+/// step); every source except `Internal` seeds dream steps from the sensor and
+/// action groups named in the matching ones.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DreamSource {
+    /// Random reactivation of unreserved units (what [`Brain::dream`] does).
+    #[default]
+    Internal,
+    /// Observations that included the symbol `name` (a stimulus, action or context tag).
+    Context(String),
+    /// Observations committed with a neuromodulator of at least `min_reward`.
+    HighReward { min_reward: f32 },
+    /// Observations from the last `window_steps` brain steps.
+    Recent { window_steps: u64 },
+    /// Observations whose rarest symbol had a causal surprise `-ln p` of at
+    /// least `min_surprise` nats, `p` being its base-rate share when committed.
+    Rare { min_surprise: f32 },
+}
+
+/// One committed observation, kept for source-selected dreams.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+struct DreamTrace {
+    step: u64,
+    reward: f32,
+    /// Base-rate share of the rarest symbol before this observation.
+    rarity: f32,
+    /// Priority passed to [`Brain::commit_observation_with_priority`].
+    priority: f32,
+    symbols: Vec<SymbolId>,
+}
+
+/// Outcome of [`Brain::ensure_sensor_min_width`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SensorEnsureResult {
+    /// The group did not exist and was defined by this call.
+    pub was_created: bool,
+    /// Width before the call (0 if the group was created).
+    pub old_width: usize,
+    /// Width after the call; below `min_width` only if free units ran out.
+    pub new_width: usize,
+}
+
+impl SensorEnsureResult {
+    /// Units added to the group by the call.
+    pub fn added(&self) -> usize {
+        self.new_width - self.old_width
+    }
+}
+
 /// Runtime diagnostics about the brain's current state.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -826,6 +1459,34 @@ pub struct Diagnostics {
     pub execution_tier: ExecutionTier,
 }
 
+/// One inference job for [`Brain::step_batch`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BrainInput {
+    /// Applied once, without imprinting, before stepping.
+    pub stimuli: Vec<OwnedStimulus>,
+    /// Inference steps to run after applying `stimuli`.
+    pub steps: u32,
+    /// Context whose action scores are reported.
+    pub context_key: String,
+    /// Meaning weight passed to [`Brain::action_score_breakdown`].
+    pub meaning_alpha: f32,
+    /// Also report [`Brain::ranked_actions_with_meaning_debug`] in `action_debug`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub verbose: bool,
+}
+
+/// Result of one [`BrainInput`] in [`Brain::step_batch`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BrainOutput {
+    pub action_scores: Vec<ActionScoreBreakdown>,
+    pub diagnostics: Diagnostics,
+    /// Filled only when [`BrainInput::verbose`] is set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub action_debug: Vec<ActionDebugInfo>,
+}
+
 /// Lightweight monitors for learning/stability.
 ///
 /// These are intended for dashboards and debugging: they summarize the most
@@ -847,6 +1508,30 @@ pub struct LearningStats {
     pub eligibility_l1: f32,
     /// Sum of absolute bias changes applied by homeostasis this step (0 if not run).
     pub homeostasis_bias_l1: f32,
+    /// EMA over learning steps of `plasticity_l1 / num_weights`.
+    ///
+    /// Near zero once the brain has stopped rewiring.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub plasticity_rate: f32,
+    /// Learning steps folded into `plasticity_rate` (saturating).
+    ///
+    /// The EMA starts at 0, so a small count means the rate is not yet meaningful.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub plasticity_rate_steps: u32,
+    /// EMA of `|actual - predicted|` over [`Brain::reward_prediction_error`] calls.
+    ///
+    /// Starts at 1.0 (uncalibrated); small values mean the caller's reward
+    /// predictions are accurate.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub td_prediction_error_ema: f32,
+    /// Sum of absolute fast-bank weights after the last learning step
+    /// (0 unless `enable_fast_weights`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fast_weight_l1: f32,
+    /// Sum of absolute slow-bank weights after the last learning step
+    /// (0 unless `enable_fast_weights`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slow_weight_l1: f32,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -857,6 +1542,8 @@ struct LearningMonitors {
     plasticity_budget_used: f32,
     eligibility_l1: f32,
     homeostasis_bias_l1: f32,
+    fast_weight_l1: f32,
+    slow_weight_l1: f32,
 }
 
 #[derive(Debug, Clone)]
@@ -865,66 +1552,276 @@ struct NamedGroup {
     units: Vec<UnitId>,
 }
 
-#[cfg(feature = "std")]
-const LATENT_MODULES_CHUNK: [u8; 4] = *b"LMOD";
-
-const NO_MODULE: u16 = u16::MAX;
+/// Kind of IO group targeted by [`Brain::assign_unit_to_group`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GroupType {
+    Sensor,
+    Action,
+}
 
+/// One decoded chunk of a brain image, yielded by [`Brain::load_image_streaming`].
+#[cfg(feature = "std")]
 #[derive(Debug, Clone)]
-struct RoutingModule {
-    // Base name without kind prefix.
-    name: String,
-    // Sparse associations between this module and boundary symbols.
-    signature: HashMap<SymbolId, f32>,
-    // Reward summary to bias routing.
-    reward_ema: f32,
-    // Last step index when this module was selected by the router.
-    last_routed_step: u64,
+pub enum BrainChunk {
+    /// Image format version; always the first item.
+    Header {
+        version: u32,
+    },
+    Config(Box<BrainConfig>),
+    Prng {
+        state: u64,
+    },
+    Stats {
+        age_steps: u64,
+    },
+    /// Unit scalar state (salience arrives in [`BrainChunk::Salience`]).
+    Units(Vec<Unit>),
+    /// Connection matrix in CSR form, without tombstones.
+    Connections(CsrConnections),
+    Masks {
+        reserved: Vec<bool>,
+        learning_enabled: Vec<bool>,
+    },
+    Salience(Vec<f32>),
+    /// Sensor and action groups as `(name, units)`.
+    Groups {
+        sensors: Vec<(String, Vec<UnitId>)>,
+        actions: Vec<(String, Vec<UnitId>)>,
+    },
+    LatentModules(Vec<(String, Vec<UnitId>)>),
+    Symbols(Vec<String>),
+    Causal(Box<CausalMemory>),
+    /// A chunk that was skipped (see [`BrainImageChunks::skip_tags`]) or that
+    /// this build does not know; its body was not decoded.
+    Skipped {
+        tag: [u8; 4],
+    },
 }
 
-/// A dynamical brain-like substrate with local learning.
-///
-/// `Brain` is the core cognitive substrate: a collection of oscillating units
-/// with sparse connections that learn through local Hebbian plasticity.
-///
-/// # Key Features
-/// - **Sparse local dynamics**: No matrices, no backprop
-/// - **Continuous learning**: Hebbian updates every step
-/// - **Meaning/causality**: Tracks temporal relationships between symbols
-/// - **Tiered execution**: Scalar, SIMD, parallel, or GPU
-///
-/// # Example
-/// ```
-/// use braine::substrate::{Brain, BrainConfig, Stimulus, ActionPolicy};
-///
-/// let cfg = BrainConfig::with_size(256, 12).with_seed(42);
-/// let mut brain = Brain::new(cfg);
+/// Chunk-by-chunk reader over a brain image (see [`Brain::load_image_streaming`]).
 ///
-/// brain.define_sensor("vision", 8);
-/// brain.define_action("move", 4);
-///
-/// brain.apply_stimulus(Stimulus::new("vision", 1.0));
-/// brain.step();
-/// let (action, score) = brain.select_action(&mut ActionPolicy::Deterministic);
-/// ```
-#[derive(Clone)]
-pub struct Brain {
-    cfg: BrainConfig,
-    units: Vec<Unit>,
+/// Only one chunk is held in memory at a time, so callers can pick out the
+/// parts they need (e.g. weights only) without materializing a full `Brain`.
+#[cfg(feature = "std")]
+pub struct BrainImageChunks<R> {
+    r: R,
+    /// Format version once the header has been read.
+    version: Option<u32>,
+    skip: Vec<[u8; 4]>,
+    /// Second half of a V3 `UNIT` chunk (connections follow the unit states).
+    pending: Option<BrainChunk>,
+    done: bool,
+}
 
-    /// Slow activity trace per unit (ephemeral; not persisted).
-    ///
-    /// Used to decouple fast `amp` from learning/salience gating.
-    activity_trace: Vec<f32>,
+#[cfg(feature = "std")]
+impl<R: Read> BrainImageChunks<R> {
+    /// Skip chunks with these tags without decompressing them (they are
+    /// yielded as [`BrainChunk::Skipped`]). Tags: `CFG0`, `PRNG`, `STAT`,
+    /// `UNST` (units), `CSR0` (connections), `MASK`, `SALI`, `GRPS`, `LMOD`,
+    /// `SYMB`, `CAUS`. Version 3 images store units and connections together
+    /// under `UNIT`.
+    #[must_use]
+    pub fn skip_tags(mut self, tags: &[[u8; 4]]) -> Self {
+        self.skip.extend_from_slice(tags);
+        self
+    }
 
-    // Growth policy signals (ephemeral; not persisted).
-    growth_eligibility_norm_ema: f32,
-    growth_commit_ema: f32,
-    growth_prune_norm_ema: f32,
+    /// Image format version, once the header has been read.
+    #[must_use]
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    fn read_header(&mut self) -> io::Result<BrainChunk> {
+        let magic = storage::read_exact::<8, _>(&mut self.r)?;
+        if &magic != storage::MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "bad brain image magic",
+            ));
+        }
+        let version = storage::read_u32_le(&mut self.r)?;
+        if version != storage::VERSION_V3 && version != storage::VERSION_V4 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unsupported brain image version",
+            ));
+        }
+        self.version = Some(version);
+        Ok(BrainChunk::Header { version })
+    }
+
+    fn read_chunk(&mut self, version: u32) -> io::Result<Option<BrainChunk>> {
+        let Some(frame) = storage::ChunkFrame::read(&mut self.r, version)? else {
+            return Ok(None);
+        };
+        let tag = frame.tag;
+        if self.skip.contains(&tag) {
+            frame.skip(&mut self.r)?;
+            return Ok(Some(BrainChunk::Skipped { tag }));
+        }
+
+        let mut c = io::Cursor::new(frame.read_payload(&mut self.r)?);
+        let named = |groups: Vec<NamedGroup>| -> Vec<(String, Vec<UnitId>)> {
+            groups.into_iter().map(|g| (g.name, g.units)).collect()
+        };
+        let chunk = match &tag {
+            b"CFG0" => BrainChunk::Config(Box::new(Brain::read_cfg_payload(&mut c)?)),
+            b"PRNG" => BrainChunk::Prng {
+                state: storage::read_u64_le(&mut c)?,
+            },
+            b"STAT" => BrainChunk::Stats {
+                age_steps: storage::read_u64_le(&mut c)?,
+            },
+            b"UNIT" => {
+                let units = Brain::read_unit_states_payload(&mut c)?;
+                let connections = Brain::read_csr_payload(&mut c, units.len())?;
+                self.pending = Some(BrainChunk::Connections(connections));
+                BrainChunk::Units(units)
+            }
+            &UNIT_STATES_CHUNK => BrainChunk::Units(Brain::read_unit_states_payload(&mut c)?),
+            &CSR_CHUNK => {
+                let unit_count = storage::read_u32_le(&mut c)? as usize;
+                BrainChunk::Connections(Brain::read_csr_payload(&mut c, unit_count)?)
+            }
+            b"MASK" => {
+                let (reserved, learning_enabled) = Brain::read_mask_payload(&mut c)?;
+                BrainChunk::Masks {
+                    reserved,
+                    learning_enabled,
+                }
+            }
+            b"SALI" => BrainChunk::Salience(Brain::read_salience_payload(&mut c)?),
+            b"GRPS" => {
+                let (sensors, actions) = Brain::read_groups_payload(&mut c)?;
+                BrainChunk::Groups {
+                    sensors: named(sensors),
+                    actions: named(actions),
+                }
+            }
+            &LATENT_MODULES_CHUNK => {
+                BrainChunk::LatentModules(named(Brain::read_latent_modules_payload(&mut c)?))
+            }
+            b"SYMB" => BrainChunk::Symbols(Brain::read_symbols_payload(&mut c)?),
+            b"CAUS" => BrainChunk::Causal(Box::new(CausalMemory::read_image_payload(&mut c)?)),
+            // Unknown chunk: skipped for forward-compatibility.
+            _ => BrainChunk::Skipped { tag },
+        };
+        Ok(Some(chunk))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Iterator for BrainImageChunks<R> {
+    type Item = io::Result<BrainChunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if let Some(chunk) = self.pending.take() {
+            return Some(Ok(chunk));
+        }
+        let next = match self.version {
+            None => self.read_header().map(Some),
+            Some(version) => self.read_chunk(version),
+        };
+        match next {
+            Ok(Some(chunk)) => Some(Ok(chunk)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+const LATENT_MODULES_CHUNK: [u8; 4] = *b"LMOD";
+/// V4 splits the V3 `UNIT` chunk into unit states and the CSR matrix.
+#[cfg(feature = "std")]
+const UNIT_STATES_CHUNK: [u8; 4] = *b"UNST";
+#[cfg(feature = "std")]
+const CSR_CHUNK: [u8; 4] = *b"CSR0";
+
+const NO_MODULE: u16 = u16::MAX;
+
+#[derive(Debug, Clone)]
+struct RoutingModule {
+    // Base name without kind prefix.
+    name: String,
+    // Sparse associations between this module and boundary symbols.
+    signature: HashMap<SymbolId, f32>,
+    // Reward summary to bias routing.
+    reward_ema: f32,
+    // Last step index when this module was selected by the router.
+    last_routed_step: u64,
+}
+
+/// A dynamical brain-like substrate with local learning.
+///
+/// `Brain` is the core cognitive substrate: a collection of oscillating units
+/// with sparse connections that learn through local Hebbian plasticity.
+///
+/// # Key Features
+/// - **Sparse local dynamics**: No matrices, no backprop
+/// - **Continuous learning**: Hebbian updates every step
+/// - **Meaning/causality**: Tracks temporal relationships between symbols
+/// - **Tiered execution**: Scalar, SIMD, parallel, or GPU
+///
+/// # Example
+/// ```
+/// use braine::substrate::{Brain, BrainConfig, Stimulus, ActionPolicy};
+///
+/// let cfg = BrainConfig::with_size(256, 12).with_seed(42);
+/// let mut brain = Brain::new(cfg);
+///
+/// brain.define_sensor("vision", 8);
+/// brain.define_action("move", 4);
+///
+/// brain.apply_stimulus(Stimulus::new("vision", 1.0));
+/// brain.step();
+/// let (action, score) = brain.select_action(&mut ActionPolicy::Deterministic);
+/// ```
+///
+/// # Cloning
+/// `Brain` implements [`Clone`] as a full deep copy; see the `Clone` impl below.
+pub struct Brain {
+    cfg: BrainConfig,
+    units: Vec<Unit>,
+
+    /// Slow activity trace per unit (ephemeral; not persisted).
+    ///
+    /// Used to decouple fast `amp` from learning/salience gating.
+    activity_trace: Vec<f32>,
+
+    /// Part of each unit's `bias` contributed by homeostasis. Action groups and
+    /// reinforcement also write `bias`. Not persisted; rebuilt on load from the
+    /// bias of units outside groups.
+    homeostasis_bias: Vec<f32>,
+
+    // Growth policy signals (ephemeral; not persisted).
+    growth_eligibility_norm_ema: f32,
+    growth_commit_ema: f32,
+    // EMA of committed |Δw| per edge (ephemeral; not persisted).
+    plasticity_rate_ema: f32,
+    // Learning steps folded into `plasticity_rate_ema` (ephemeral; not persisted).
+    plasticity_rate_steps: u32,
+    // EMA of |reward prediction error| (ephemeral; not persisted).
+    td_error_ema: f32,
+    growth_prune_norm_ema: f32,
     growth_last_birth_step: u64,
 
     // Lagged causal meaning history (ephemeral; not persisted). Stores lag>=2 symbol sets.
     causal_lag_history: Vec<Vec<SymbolId>>,
+    // Committed symbol sets of the last `causal_window_frames - 1` frames, newest first
+    // (ephemeral; not persisted).
+    causal_window_history: Vec<Vec<SymbolId>>,
 
     /// CSR-format connection storage for cache-friendly iteration.
     connections: CsrConnections,
@@ -1002,6 +1899,19 @@ pub struct Brain {
     // Not serialized; persistence compacts away tombstones.
     csr_tombstones: usize,
 
+    /// Per-unit CSR slots ordered by `|weight|` descending (ephemeral; not persisted).
+    ///
+    /// Entry `k` of unit `i`'s range holds a local offset into that range.
+    /// Repaired by `step` (with the `sorted_adjacency` experimental flag) when
+    /// `csr_generation` moved; rebuilt after a layout change.
+    sorted_adjacency: Vec<u32>,
+
+    /// Bumped on every CSR mutation: weights, targets or unit ranges (ephemeral).
+    csr_generation: u64,
+
+    /// `csr_generation` that `sorted_adjacency` matches; `None` until first built.
+    sorted_adjacency_generation: Option<u64>,
+
     /// Units born via neurogenesis in the last step.
     births_last_step: usize,
 
@@ -1010,6 +1920,42 @@ pub struct Brain {
     telemetry: Telemetry,
 
     learning_monitors: LearningMonitors,
+
+    // Per-unit `age_steps` at which the unit was last coactive
+    // (`NEVER_COACTIVE` = never). Ephemeral; not serialized.
+    coactive_last_step: Vec<u64>,
+
+    // Fraction of coactive units that were novel on the most recent step.
+    intrinsic_reward: f32,
+    // Weight of causal surprise in `intrinsic_reward_estimate()` (0 = off).
+    // Not serialized.
+    causal_novelty_weight: f32,
+    // Scales the next learning step's plasticity; set by
+    // `commit_observation_with_priority`, reset to 1 after that step.
+    learning_priority: f32,
+
+    // Units whose input weights follow Oja's rule during `step()`.
+    // Not serialized.
+    oja_units: Vec<UnitId>,
+
+    // Active pruning strategy. `Threshold` is kept in sync with `cfg.prune_below`.
+    // Not serialized.
+    pruning_policy: PruningPolicy,
+    // Per-edge bookkeeping for `PruningPolicy::Age`, aligned with the CSR arrays.
+    // Empty unless the age policy is active.
+    edge_last_update: Vec<u64>,
+    edge_weight_snapshot: Vec<f32>,
+
+    // Fast weight bank (`cfg.enable_fast_weights`), aligned with the CSR arrays.
+    // `connections.weights` holds the effective (slow + fast) weight; the
+    // snapshot is the effective weight after the previous learning step.
+    // Not serialized: a loaded brain keeps everything in the slow bank.
+    fast_weights: Vec<f32>,
+    fast_weight_snapshot: Vec<f32>,
+
+    // Recent committed observations for source-selected dreams, oldest first.
+    // Not serialized.
+    dream_traces: VecDeque<DreamTrace>,
 }
 
 /// A bounded, sparse representation of structural changes between two brains.
@@ -1036,7 +1982,7 @@ pub struct RoutingModuleSummary {
     pub paralyzed_units: u32,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 struct Telemetry {
     enabled: bool,
 
@@ -1044,6 +1990,113 @@ struct Telemetry {
     last_actions: Vec<SymbolId>,
     last_reinforced_actions: Vec<(SymbolId, f32)>,
     last_committed_symbols: Vec<SymbolId>,
+
+    /// Per-unit amplitudes after each step, oldest first.
+    activation_history: VecDeque<Vec<f32>>,
+    activation_window: usize,
+
+    /// `oscillation_sample` after each step, oldest first.
+    oscillation_history: VecDeque<(f32, f32, f32)>,
+
+    causal_events: CausalEventLog,
+}
+
+impl Default for Telemetry {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            last_stimuli: Vec::new(),
+            last_actions: Vec::new(),
+            last_reinforced_actions: Vec::new(),
+            last_committed_symbols: Vec::new(),
+            activation_history: VecDeque::new(),
+            activation_window: ACTIVATION_HISTORY_DEFAULT,
+            oscillation_history: VecDeque::new(),
+            causal_events: CausalEventLog::default(),
+        }
+    }
+}
+
+/// Deep copy of the full brain state.
+///
+/// The clone owns independent copies of everything that defines the brain:
+/// the CSR weight matrix, unit state (amplitudes, phases, homeostasis biases),
+/// eligibility traces, the causal graph and symbol table, and the IO group
+/// registry (sensor/action/latent groups and their derived caches). Mutating
+/// the clone never affects the original, and vice versa.
+///
+/// Ephemeral state (gates, routing modules, telemetry, growth signals) is
+/// copied too, so a clone continues exactly where the original left off.
+/// Use [`Brain::spawn_child`] instead when you want a sandboxed variant.
+impl Clone for Brain {
+    fn clone(&self) -> Self {
+        Self {
+            cfg: self.cfg.clone(),
+            units: self.units.clone(),
+            activity_trace: self.activity_trace.clone(),
+            homeostasis_bias: self.homeostasis_bias.clone(),
+            growth_eligibility_norm_ema: self.growth_eligibility_norm_ema,
+            growth_commit_ema: self.growth_commit_ema,
+            plasticity_rate_ema: self.plasticity_rate_ema,
+            plasticity_rate_steps: self.plasticity_rate_steps,
+            td_error_ema: self.td_error_ema,
+            growth_prune_norm_ema: self.growth_prune_norm_ema,
+            growth_last_birth_step: self.growth_last_birth_step,
+            causal_lag_history: self.causal_lag_history.clone(),
+            causal_window_history: self.causal_window_history.clone(),
+            connections: self.connections.clone(),
+            eligibility: self.eligibility.clone(),
+            tier: self.tier,
+            rng: self.rng.clone(),
+            reserved: self.reserved.clone(),
+            sensor_member: self.sensor_member.clone(),
+            group_member: self.group_member.clone(),
+            concept_validated: self.concept_validated.clone(),
+            learning_enabled: self.learning_enabled.clone(),
+            frozen_units: self.frozen_units.clone(),
+            paralyzed_units: self.paralyzed_units.clone(),
+            sensor_groups: self.sensor_groups.clone(),
+            action_groups: self.action_groups.clone(),
+            latent_groups: self.latent_groups.clone(),
+            routing_modules: self.routing_modules.clone(),
+            routing_module_index: self.routing_module_index.clone(),
+            unit_module: self.unit_module.clone(),
+            learning_route_modules: self.learning_route_modules.clone(),
+            module_unit_counts: self.module_unit_counts.clone(),
+            module_unit_counts_dirty: self.module_unit_counts_dirty,
+            latent_auto_last_create_step: self.latent_auto_last_create_step,
+            latent_auto_seq: self.latent_auto_seq,
+            sensor_group_index: self.sensor_group_index.clone(),
+            pending_input: self.pending_input.clone(),
+            neuromod: self.neuromod,
+            symbols: self.symbols.clone(),
+            symbols_rev: self.symbols_rev.clone(),
+            active_symbols: self.active_symbols.clone(),
+            causal: self.causal.clone(),
+            reward_pos_symbol: self.reward_pos_symbol,
+            reward_neg_symbol: self.reward_neg_symbol,
+            pruned_last_step: self.pruned_last_step,
+            csr_tombstones: self.csr_tombstones,
+            sorted_adjacency: self.sorted_adjacency.clone(),
+            csr_generation: self.csr_generation,
+            sorted_adjacency_generation: self.sorted_adjacency_generation,
+            births_last_step: self.births_last_step,
+            age_steps: self.age_steps,
+            telemetry: self.telemetry.clone(),
+            learning_monitors: self.learning_monitors,
+            coactive_last_step: self.coactive_last_step.clone(),
+            intrinsic_reward: self.intrinsic_reward,
+            causal_novelty_weight: self.causal_novelty_weight,
+            learning_priority: self.learning_priority,
+            oja_units: self.oja_units.clone(),
+            pruning_policy: self.pruning_policy,
+            edge_last_update: self.edge_last_update.clone(),
+            edge_weight_snapshot: self.edge_weight_snapshot.clone(),
+            fast_weights: self.fast_weights.clone(),
+            fast_weight_snapshot: self.fast_weight_snapshot.clone(),
+            dream_traces: self.dream_traces.clone(),
+        }
+    }
 }
 
 impl Brain {
@@ -1119,21 +2172,19 @@ impl Brain {
     /// This is intended for UI/daemon introspection so clients can pick module ids
     /// for freeze/paralyze gates.
     #[cfg(feature = "std")]
-    pub fn routing_modules_summary(&mut self) -> Vec<RoutingModuleSummary> {
-        self.ensure_gate_vectors();
-        self.refresh_module_unit_counts_if_dirty();
-
+    pub fn routing_modules_summary(&self) -> Vec<RoutingModuleSummary> {
         let mut out: Vec<RoutingModuleSummary> = Vec::with_capacity(self.routing_modules.len());
         for (i, m) in self.routing_modules.iter().enumerate() {
             let id = i as u16;
-            let unit_count = self.module_unit_counts.get(i).copied().unwrap_or(0);
 
+            let mut unit_count = 0u32;
             let mut frozen = 0u32;
             let mut paralyzed = 0u32;
             for (uid, &mid) in self.unit_module.iter().enumerate() {
                 if mid != id {
                     continue;
                 }
+                unit_count = unit_count.saturating_add(1);
                 if self.frozen_units.get(uid).copied().unwrap_or(false) {
                     frozen = frozen.saturating_add(1);
                 }
@@ -1200,19 +2251,7 @@ impl Brain {
         // NOTE: This must work in `no_std` builds (e.g. wasm32-unknown-unknown).
         // We don't need cryptographic security here; we just want a stable-ish
         // topology fingerprint to gate safe delta application.
-        #[inline]
-        fn mix64(mut h: u64, x: u64) -> u64 {
-            // FNV-1a-ish mixing
-            h ^= x;
-            h = h.wrapping_mul(1099511628211);
-            // extra avalanching
-            h ^= h >> 33;
-            h = h.wrapping_mul(0xff51afd7ed558ccd);
-            h ^= h >> 33;
-            h
-        }
-
-        let mut h = 14695981039346656037u64;
+        let mut h = FINGERPRINT_SEED;
         h = mix64(h, self.units.len() as u64);
         h = mix64(h, self.connections.weights.len() as u64);
         for &t in &self.connections.targets {
@@ -1221,6 +2260,30 @@ impl Brain {
         h
     }
 
+    /// A coarse fingerprint of the IO group registry: sensor and action group
+    /// names, in order, with their unit ids.
+    ///
+    /// Changes whenever a group is defined, grown, or a unit is reassigned with
+    /// [`Brain::assign_unit_to_group`], so clients caching unit↔group maps can
+    /// tell when to refresh them.
+    #[must_use]
+    pub fn io_groups_fingerprint(&self) -> u64 {
+        let mut h = FINGERPRINT_SEED;
+        for (kind, groups) in [(1u64, &self.sensor_groups), (2u64, &self.action_groups)] {
+            for g in groups {
+                h = mix64(h, kind);
+                for &b in g.name.as_bytes() {
+                    h = mix64(h, b as u64);
+                }
+                h = mix64(h, g.units.len() as u64);
+                for &id in &g.units {
+                    h = mix64(h, id as u64);
+                }
+            }
+        }
+        h
+    }
+
     /// Number of connection weights (equals number of edges).
     #[must_use]
     pub fn weights_len(&self) -> usize {
@@ -1274,21 +2337,101 @@ impl Brain {
     ///
     /// The applied delta for each edge is clamped to `[-delta_max, +delta_max]`.
     pub fn apply_weight_delta(&mut self, delta: &BrainDelta, delta_max: Weight) {
+        self.apply_weight_delta_masked(delta, delta_max, None);
+    }
+
+    /// Like [`Self::apply_weight_delta`], but only applies entries whose
+    /// weight index is `true` in `mask` (indices past the end of the mask are
+    /// skipped). `None` applies everything.
+    ///
+    /// Masks are indexed like the CSR weights; see [`Self::action_unit_mask`],
+    /// [`Self::sensor_unit_mask`] and their `non_*` complements. Returns the
+    /// number of entries applied.
+    pub fn apply_weight_delta_masked(
+        &mut self,
+        delta: &BrainDelta,
+        delta_max: Weight,
+        mask: Option<&[bool]>,
+    ) -> usize {
         if delta.weight_deltas.is_empty() {
-            return;
+            return 0;
         }
         if delta_max <= 0.0 {
-            return;
+            return 0;
         }
 
         let w = &mut self.connections.weights;
+        let mut applied = 0;
         for (idx, dw) in &delta.weight_deltas {
             if *idx >= w.len() {
                 continue;
             }
+            if let Some(mask) = mask {
+                if !mask.get(*idx).copied().unwrap_or(false) {
+                    continue;
+                }
+            }
             let clipped = dw.clamp(-delta_max, delta_max);
             w[*idx] += clipped;
+            applied += 1;
+        }
+        if applied > 0 {
+            self.touch_csr();
+        }
+        applied
+    }
+
+    /// Weight mask of edges that start or end on an action-group unit.
+    #[must_use]
+    pub fn action_unit_mask(&self) -> Vec<bool> {
+        self.edge_mask(&self.action_member_flags(), true)
+    }
+
+    /// Weight mask of live edges that touch no action-group unit.
+    ///
+    /// Accepts a delta everywhere except the action readout. Unlike negating
+    /// [`Self::action_unit_mask`], tombstoned slots stay `false`.
+    #[must_use]
+    pub fn non_action_unit_mask(&self) -> Vec<bool> {
+        self.edge_mask(&self.action_member_flags(), false)
+    }
+
+    /// Weight mask of edges that start or end on a sensor-group unit.
+    #[must_use]
+    pub fn sensor_unit_mask(&self) -> Vec<bool> {
+        self.edge_mask(&self.sensor_member, true)
+    }
+
+    /// Weight mask of live edges that touch no sensor-group unit.
+    #[must_use]
+    pub fn non_sensor_unit_mask(&self) -> Vec<bool> {
+        self.edge_mask(&self.sensor_member, false)
+    }
+
+    fn action_member_flags(&self) -> Vec<bool> {
+        let mut is_action = vec![false; self.units.len()];
+        for g in &self.action_groups {
+            for &id in &g.units {
+                if id < is_action.len() {
+                    is_action[id] = true;
+                }
+            }
+        }
+        is_action
+    }
+
+    /// Per-weight mask of live edges whose "either endpoint flagged in `units`"
+    /// test equals `touching`. Tombstoned slots are always `false`.
+    fn edge_mask(&self, units: &[bool], touching: bool) -> Vec<bool> {
+        let flagged = |id: UnitId| units.get(id).copied().unwrap_or(false);
+        let mut mask = vec![false; self.connections.weights.len()];
+        for from in 0..self.units.len() {
+            for idx in self.conn_range(from) {
+                let to = self.connections.targets[idx];
+                mask[idx] = to != INVALID_UNIT && (flagged(from) || flagged(to)) == touching;
+            }
         }
+        mask
     }
 
     pub fn new(cfg: BrainConfig) -> Self {
@@ -1364,16 +2507,24 @@ impl Brain {
         let reward_neg_symbol = intern_symbol(&mut symbols, &mut symbols_rev, "reward_neg");
 
         let causal = CausalMemory::new(cfg.causal_decay);
+        let pruning_policy = PruningPolicy::Threshold {
+            below: cfg.prune_below,
+        };
 
         Self {
             cfg,
             units,
             activity_trace,
+            homeostasis_bias: Vec::new(),
             growth_eligibility_norm_ema: 0.0,
             growth_commit_ema: 0.0,
+            plasticity_rate_ema: 0.0,
+            plasticity_rate_steps: 0,
+            td_error_ema: 1.0,
             growth_prune_norm_ema: 0.0,
             growth_last_birth_step: 0,
             causal_lag_history: Vec::new(),
+            causal_window_history: Vec::new(),
             connections,
             eligibility,
             tier: ExecutionTier::default(),
@@ -1395,6 +2546,9 @@ impl Brain {
             pruned_last_step: 0,
             births_last_step: 0,
             csr_tombstones: 0,
+            sorted_adjacency: Vec::new(),
+            csr_generation: 0,
+            sorted_adjacency_generation: None,
             rng,
             reserved,
             learning_enabled,
@@ -1416,6 +2570,17 @@ impl Brain {
             age_steps: 0,
             telemetry: Telemetry::default(),
             learning_monitors: LearningMonitors::default(),
+            coactive_last_step: Vec::new(),
+            intrinsic_reward: 0.0,
+            causal_novelty_weight: 0.0,
+            learning_priority: 1.0,
+            oja_units: Vec::new(),
+            pruning_policy,
+            edge_last_update: Vec::new(),
+            edge_weight_snapshot: Vec::new(),
+            fast_weights: Vec::new(),
+            fast_weight_snapshot: Vec::new(),
+            dream_traces: VecDeque::new(),
         }
     }
 
@@ -1769,7 +2934,6 @@ impl Brain {
         }
     }
 
-    #[allow(dead_code)]
     fn rebuild_group_membership(&mut self) {
         self.sensor_member.fill(false);
         self.group_member.fill(false);
@@ -1923,37 +3087,46 @@ impl Brain {
         }
     }
 
-    /// Select a default execution tier.
+    /// Tiers usable in this build and on this machine, fastest first.
     ///
-    /// Picks GPU when compiled+available, otherwise falls back to Parallel/Simd/Scalar.
-    /// Returns the effective tier selected.
-    pub fn auto_select_execution_tier(&mut self) -> ExecutionTier {
+    /// `Scalar` is always present. `Simd` and `Parallel` depend only on the
+    /// compiled features; `Gpu` additionally requires a usable adapter, so
+    /// this probes the GPU when the `gpu` feature is on. Callers that poll
+    /// should cache the result.
+    pub fn available_tiers(&self) -> Vec<ExecutionTier> {
+        let mut tiers = Vec::with_capacity(4);
         #[cfg(feature = "gpu")]
         {
             let max_units = self.units.len().max(65_536);
             if crate::gpu::gpu_available(max_units) {
-                self.tier = ExecutionTier::Gpu;
-                return ExecutionTier::Gpu;
+                tiers.push(ExecutionTier::Gpu);
             }
         }
-
         #[cfg(feature = "parallel")]
-        {
-            self.tier = ExecutionTier::Parallel;
-            ExecutionTier::Parallel
-        }
-
-        #[cfg(all(not(feature = "parallel"), feature = "simd"))]
-        {
-            self.tier = ExecutionTier::Simd;
-            ExecutionTier::Simd
-        }
+        tiers.push(ExecutionTier::Parallel);
+        #[cfg(feature = "simd")]
+        tiers.push(ExecutionTier::Simd);
+        tiers.push(ExecutionTier::Scalar);
+        tiers
+    }
 
-        #[cfg(all(not(feature = "parallel"), not(feature = "simd")))]
-        {
-            self.tier = ExecutionTier::Scalar;
-            ExecutionTier::Scalar
-        }
+    /// Select the fastest available execution tier and make it the requested one.
+    ///
+    /// Fallback order is `Gpu → Parallel → Simd → Scalar`:
+    ///
+    /// 1. `Gpu` if built with `gpu` and a GPU adapter is available at runtime;
+    /// 2. otherwise `Parallel` if built with `parallel`;
+    /// 3. otherwise `Simd` if built with `simd`;
+    /// 4. otherwise `Scalar`.
+    ///
+    /// Note that `effective_execution_tier` does not walk this chain: an
+    /// explicitly requested tier that is unavailable drops straight to `Scalar`.
+    /// Returns the tier selected (always equal to the effective tier right after
+    /// the call).
+    pub fn auto_select_execution_tier(&mut self) -> ExecutionTier {
+        let tier = self.available_tiers()[0];
+        self.tier = tier;
+        tier
     }
 
     // =========================================================================
@@ -1972,6 +3145,88 @@ impl Brain {
             .filter(|(t, _)| *t != INVALID_UNIT)
     }
 
+    /// Every live connection as `(from, to, weight)`, in CSR order.
+    pub fn connection_triplets(&self) -> impl Iterator<Item = (UnitId, UnitId, Weight)> + '_ {
+        (0..self.units.len()).flat_map(move |i| self.neighbors(i).map(move |(t, w)| (i, t, w)))
+    }
+
+    /// Like [`Brain::neighbors`], but ordered by `|weight|` descending (ties by target).
+    ///
+    /// With the `sorted_adjacency` experimental flag on, the order is cached and
+    /// brought up to date at the end of each [`Brain::step`] after any connection
+    /// change (learning, forgetting, growth, pruning, imprinting, weight deltas),
+    /// timed as [`StepProfile::sorted_adjacency_ns`]. Otherwise, or while the
+    /// cache is stale, the unit's neighbors are sorted on the fly instead.
+    pub fn neighbors_sorted(&self, unit: UnitId) -> impl Iterator<Item = (UnitId, Weight)> + '_ {
+        let range = self.conn_range(unit);
+        let cached = self.sorted_adjacency_generation == Some(self.csr_generation);
+        let fallback = (!cached).then(|| {
+            let mut local: Vec<(UnitId, Weight)> = self.neighbors(unit).collect();
+            local.sort_by(|(ta, wa), (tb, wb)| wb.abs().total_cmp(&wa.abs()).then(ta.cmp(tb)));
+            local
+        });
+        let sorted = cached.then(|| {
+            let start = range.start;
+            self.sorted_adjacency[range]
+                .iter()
+                .map(move |&k| {
+                    let idx = start + k as usize;
+                    (self.connections.targets[idx], self.connections.weights[idx])
+                })
+                .filter(|(t, _)| *t != INVALID_UNIT)
+        });
+        sorted
+            .into_iter()
+            .flatten()
+            .chain(fallback.into_iter().flatten())
+    }
+
+    /// Bring `sorted_adjacency` in line with the current weights.
+    ///
+    /// Weights drift a little per step, so each unit's order is usually
+    /// already nearly sorted: short lists are repaired with an insertion
+    /// sort, longer ones fall back to a full sort.
+    fn refresh_sorted_adjacency(&mut self) {
+        if self.sorted_adjacency_generation == Some(self.csr_generation) {
+            return;
+        }
+        let conns = &self.connections;
+        // Cleared by `csr_layout_changed`; start from CSR order.
+        if self.sorted_adjacency.len() != conns.targets.len() {
+            self.sorted_adjacency.clear();
+            for w in conns.offsets.windows(2) {
+                self.sorted_adjacency.extend(0..(w[1] - w[0]) as u32);
+            }
+        }
+
+        for w in conns.offsets.windows(2) {
+            let start = w[0];
+            let slots = &mut self.sorted_adjacency[start..w[1]];
+            let before = |a: u32, b: u32| {
+                let (ia, ib) = (start + a as usize, start + b as usize);
+                let (ta, tb) = (conns.targets[ia], conns.targets[ib]);
+                // Tombstones sink to the end; live edges by |w| desc, then target.
+                (ta == INVALID_UNIT)
+                    .cmp(&(tb == INVALID_UNIT))
+                    .then_with(|| conns.weights[ib].abs().total_cmp(&conns.weights[ia].abs()))
+                    .then(ta.cmp(&tb))
+                    .then(a.cmp(&b))
+            };
+            if slots.len() <= SORTED_ADJACENCY_INSERTION_MAX {
+                for k in 1..slots.len() {
+                    let mut j = k;
+                    while j > 0 && before(slots[j], slots[j - 1]).is_lt() {
+                        slots.swap(j, j - 1);
+                        j -= 1;
+                    }
+                }
+            } else {
+                slots.sort_unstable_by(|&a, &b| before(a, b));
+            }
+        }
+        self.sorted_adjacency_generation = Some(self.csr_generation);
+    }
+
     /// Returns the range of indices in the CSR arrays for unit `i`'s connections.
     #[inline]
     fn conn_range(&self, i: UnitId) -> Range<usize> {
@@ -1979,12 +3234,15 @@ impl Brain {
     }
 
     /// Count of valid (non-pruned) connections for unit `i`.
-    #[allow(dead_code)]
     fn conn_count(&self, i: UnitId) -> usize {
         self.neighbors(i).count()
     }
 
-    /// Total connection count across all units.
+    /// Total connection count across all units, by scanning the CSR targets.
+    ///
+    /// [`Brain::connection_count`] is the O(1) equivalent; this scan is kept for
+    /// serialization, where the count must match the written targets exactly.
+    #[cfg(any(test, feature = "std"))]
     fn total_connection_count(&self) -> usize {
         self.connections
             .targets
@@ -1993,52 +3251,203 @@ impl Brain {
             .count()
     }
 
-    /// Add or bump a connection from `from` to `target` by `bump`.
-    /// If connection exists, bumps weight. Otherwise appends to CSR (may require realloc).
-    fn add_or_bump_csr(&mut self, from: UnitId, target: UnitId, bump: f32) {
-        let range = self.conn_range(from);
+    /// Clamp a learned weight to the built-in ±1.5 range and the configured clipping bounds.
+    #[inline]
+    fn clip_weight(&self, w: Weight) -> Weight {
+        w.clamp(-1.5, 1.5)
+            .max(self.cfg.weight_clip_min)
+            .min(self.cfg.weight_clip_max)
+    }
 
-        // First, try to find existing connection or a tombstone slot
-        for idx in range.clone() {
-            if self.connections.targets[idx] == target {
-                // Existing connection: bump weight
-                self.connections.weights[idx] =
-                    (self.connections.weights[idx] + bump).clamp(-1.5, 1.5);
-                return;
-            }
-        }
+    /// Configure hard bounds for connection weights.
+    ///
+    /// The bounds are applied after every learning update (plasticity commits,
+    /// burst learning, imprinting) and immediately to all existing weights.
+    /// `(-1.0, 1.0)` matches the neuromodulator range; pass infinities to disable.
+    ///
+    /// # Panics
+    /// Panics if either bound is NaN or `min_weight > max_weight`.
+    pub fn set_weight_clipping(&mut self, min_weight: Weight, max_weight: Weight) {
+        assert!(
+            !min_weight.is_nan() && !max_weight.is_nan() && min_weight <= max_weight,
+            "weight clipping requires min_weight <= max_weight"
+        );
+        self.cfg.weight_clip_min = min_weight;
+        self.cfg.weight_clip_max = max_weight;
+        self.touch_csr();
 
-        // Try to reuse a tombstone slot within this unit's range
-        for idx in range {
+        for idx in 0..self.connections.weights.len() {
             if self.connections.targets[idx] == INVALID_UNIT {
-                self.connections.targets[idx] = target;
-                self.connections.weights[idx] = bump.clamp(-1.5, 1.5);
-                if idx < self.eligibility.len() {
-                    self.eligibility[idx] = 0.0;
-                }
-                self.csr_tombstones = self.csr_tombstones.saturating_sub(1);
-                return;
+                continue;
             }
+            self.connections.weights[idx] = self.clip_weight(self.connections.weights[idx]);
         }
-
-        // No slot available: must append (requires CSR rebuild).
-        // This is expensive but rare after initial wiring stabilizes.
-        self.append_connection(from, target, bump.clamp(-1.5, 1.5));
     }
 
-    /// Append a new connection (rebuilds CSR structure - expensive, use sparingly).
-    fn append_connection(&mut self, from: UnitId, target: UnitId, weight: f32) {
-        // Insert at the end of `from`'s segment, shifting later units.
-        let insert_pos = self.connections.offsets[from + 1];
-
-        self.connections.targets.insert(insert_pos, target);
-        self.connections.weights.insert(insert_pos, weight);
-        self.eligibility.insert(insert_pos, 0.0);
+    /// Current weight clipping bounds as `(min, max)`.
+    pub fn weight_clipping(&self) -> (Weight, Weight) {
+        (self.cfg.weight_clip_min, self.cfg.weight_clip_max)
+    }
+
+    /// Apply one step of Oja's rule to the input weights of `unit`.
+    ///
+    /// For each connection `unit <- j` (the CSR row that `unit`'s dynamics
+    /// integrate), `Δw_ij = rate * y_i * (x_j - y_i * w_ij)` with `y_i` the
+    /// unit's current amplitude and `x_j` the presynaptic amplitude. The decay
+    /// term keeps the weight vector near unit norm, so repeated application
+    /// converges to the first principal component of the inputs.
+    ///
+    /// Frozen or paralyzed units are skipped.
+    pub fn apply_oja_rule(&mut self, unit: UnitId, rate: f32) {
+        if unit >= self.units.len() || rate == 0.0 || self.unit_learning_blocked(unit) {
+            return;
+        }
+        self.touch_csr();
+        let y = self.units[unit].amp;
+        for idx in self.conn_range(unit) {
+            let j = self.connections.targets[idx];
+            if j == INVALID_UNIT {
+                continue;
+            }
+            let x = self.units[j].amp;
+            let w = self.connections.weights[idx];
+            self.connections.weights[idx] = self.clip_weight(w + rate * y * (x - y * w));
+        }
+    }
+
+    /// Select units whose input weights are updated with Oja's rule
+    /// (at `hebb_rate`) on every learning `step()`, in addition to regular
+    /// plasticity. Out-of-range ids are ignored. Not persisted in brain images.
+    pub fn set_oja_units(&mut self, units: &[UnitId]) {
+        self.oja_units = units
+            .iter()
+            .copied()
+            .filter(|&u| u < self.units.len())
+            .collect();
+    }
+
+    /// Units currently updated with Oja's rule during `step()`.
+    pub fn oja_units(&self) -> &[UnitId] {
+        &self.oja_units
+    }
+
+    /// Choose how `step()` prunes connections after weight decay.
+    ///
+    /// `Threshold` writes through to [`BrainConfig::prune_below`]; `Age` starts
+    /// counting from now for every existing connection. Not persisted in brain
+    /// images (a loaded brain uses `Threshold`).
+    pub fn set_pruning_policy(&mut self, policy: PruningPolicy) -> Result<(), &'static str> {
+        match policy {
+            PruningPolicy::Threshold { below } => {
+                self.update_config(|cfg| cfg.prune_below = below)?;
+            }
+            PruningPolicy::TopK { k_per_unit } => {
+                if k_per_unit == 0 {
+                    return Err("pruning k_per_unit must be >= 1");
+                }
+            }
+            PruningPolicy::Probabilistic { rate, temperature } => {
+                if !(0.0..=1.0).contains(&rate) {
+                    return Err("pruning rate must be in [0, 1]");
+                }
+                if !temperature.is_finite() || temperature <= 0.0 {
+                    return Err("pruning temperature must be finite and > 0");
+                }
+            }
+            PruningPolicy::Age { max_age_steps } => {
+                if max_age_steps == 0 {
+                    return Err("pruning max_age_steps must be >= 1");
+                }
+            }
+        }
+
+        self.edge_last_update.clear();
+        self.edge_weight_snapshot.clear();
+        if matches!(policy, PruningPolicy::Age { .. }) {
+            self.edge_last_update = vec![self.age_steps; self.connections.weights.len()];
+            self.edge_weight_snapshot = self.connections.weights.clone();
+        }
+        self.pruning_policy = policy;
+        Ok(())
+    }
+
+    /// The active pruning policy.
+    pub fn pruning_policy(&self) -> PruningPolicy {
+        match self.pruning_policy {
+            PruningPolicy::Threshold { .. } => PruningPolicy::Threshold {
+                below: self.cfg.prune_below,
+            },
+            p => p,
+        }
+    }
+
+    /// Add or bump a connection from `from` to `target` by `bump`.
+    /// If connection exists, bumps weight. Otherwise appends to CSR (may require realloc).
+    fn add_or_bump_csr(&mut self, from: UnitId, target: UnitId, bump: f32) {
+        self.touch_csr();
+        let range = self.conn_range(from);
+
+        // First, try to find existing connection or a tombstone slot
+        for idx in range.clone() {
+            if self.connections.targets[idx] == target {
+                // Existing connection: bump weight
+                self.connections.weights[idx] =
+                    self.clip_weight(self.connections.weights[idx] + bump);
+                return;
+            }
+        }
+
+        // Try to reuse a tombstone slot within this unit's range
+        for idx in range {
+            if self.connections.targets[idx] == INVALID_UNIT {
+                self.connections.targets[idx] = target;
+                self.connections.weights[idx] = self.clip_weight(bump);
+                if idx < self.eligibility.len() {
+                    self.eligibility[idx] = 0.0;
+                }
+                self.csr_tombstones = self.csr_tombstones.saturating_sub(1);
+                return;
+            }
+        }
+
+        // No slot available: must append (requires CSR rebuild).
+        // This is expensive but rare after initial wiring stabilizes.
+        self.append_connection(from, target, self.clip_weight(bump));
+    }
+
+    /// Append a new connection (rebuilds CSR structure - expensive, use sparingly).
+    fn append_connection(&mut self, from: UnitId, target: UnitId, weight: f32) {
+        // Insert at the end of `from`'s segment, shifting later units.
+        let insert_pos = self.connections.offsets[from + 1];
+
+        self.connections.targets.insert(insert_pos, target);
+        self.connections.weights.insert(insert_pos, weight);
+        self.eligibility.insert(insert_pos, 0.0);
+        if !self.edge_last_update.is_empty() {
+            self.edge_last_update.insert(insert_pos, self.age_steps);
+            self.edge_weight_snapshot.insert(insert_pos, weight);
+        }
+        if !self.fast_weights.is_empty() {
+            self.fast_weights.insert(insert_pos, 0.0);
+            self.fast_weight_snapshot.insert(insert_pos, weight);
+        }
 
         // Update offsets for all units after `from`.
         for i in (from + 1)..self.connections.offsets.len() {
             self.connections.offsets[i] += 1;
         }
+        self.csr_layout_changed();
+    }
+
+    /// Record a change to CSR weights or targets; `sorted_adjacency` goes stale.
+    fn touch_csr(&mut self) {
+        self.csr_generation = self.csr_generation.wrapping_add(1);
+    }
+
+    /// Record a change to the CSR unit ranges; `sorted_adjacency` is rebuilt.
+    fn csr_layout_changed(&mut self) {
+        self.touch_csr();
+        self.sorted_adjacency.clear();
     }
 
     /// Compact the CSR by removing tombstoned entries. Call periodically.
@@ -2048,6 +3457,12 @@ impl Brain {
         let mut new_weights = Vec::with_capacity(self.connections.weights.len());
         let mut new_eligibility = Vec::with_capacity(self.eligibility.len());
         let mut new_offsets = Vec::with_capacity(unit_count + 1);
+        let track_age = !self.edge_last_update.is_empty();
+        let mut new_last_update = Vec::new();
+        let mut new_snapshot = Vec::new();
+        let track_fast = !self.fast_weights.is_empty();
+        let mut new_fast = Vec::new();
+        let mut new_fast_snapshot = Vec::new();
 
         for i in 0..unit_count {
             new_offsets.push(new_targets.len());
@@ -2058,6 +3473,21 @@ impl Brain {
                     new_targets.push(t);
                     new_weights.push(self.connections.weights[idx]);
                     new_eligibility.push(self.eligibility.get(idx).copied().unwrap_or(0.0));
+                    if track_age {
+                        new_last_update.push(
+                            self.edge_last_update
+                                .get(idx)
+                                .copied()
+                                .unwrap_or(self.age_steps),
+                        );
+                        new_snapshot
+                            .push(self.edge_weight_snapshot.get(idx).copied().unwrap_or(0.0));
+                    }
+                    if track_fast {
+                        new_fast.push(self.fast_weights.get(idx).copied().unwrap_or(0.0));
+                        new_fast_snapshot
+                            .push(self.fast_weight_snapshot.get(idx).copied().unwrap_or(0.0));
+                    }
                 }
             }
         }
@@ -2067,9 +3497,18 @@ impl Brain {
         self.connections.weights = new_weights;
         self.connections.offsets = new_offsets;
         self.eligibility = new_eligibility;
+        if track_age {
+            self.edge_last_update = new_last_update;
+            self.edge_weight_snapshot = new_snapshot;
+        }
+        if track_fast {
+            self.fast_weights = new_fast;
+            self.fast_weight_snapshot = new_fast_snapshot;
+        }
 
         // All tombstones are removed by compaction.
         self.csr_tombstones = 0;
+        self.csr_layout_changed();
     }
 
     // =========================================================================
@@ -2095,6 +3534,66 @@ impl Brain {
             if self.telemetry.last_committed_symbols.capacity() < 16 {
                 self.telemetry.last_committed_symbols.reserve(16);
             }
+        } else {
+            self.telemetry.activation_history.clear();
+            self.telemetry.oscillation_history.clear();
+            self.telemetry.causal_events.clear();
+        }
+    }
+
+    /// Causal edge updates recorded while telemetry is enabled.
+    pub fn causal_event_log(&self) -> &CausalEventLog {
+        &self.telemetry.causal_events
+    }
+
+    /// Set how many causal events telemetry keeps (default 1024).
+    pub fn set_causal_event_log_capacity(&mut self, capacity: usize) {
+        self.telemetry.causal_events.set_capacity(capacity);
+    }
+
+    /// Set how many amplitude frames telemetry keeps (clamped to `1..=ACTIVATION_HISTORY_MAX`).
+    pub fn set_activation_history_window(&mut self, frames: usize) {
+        let frames = frames.clamp(1, ACTIVATION_HISTORY_MAX);
+        self.telemetry.activation_window = frames;
+        while self.telemetry.activation_history.len() > frames {
+            self.telemetry.activation_history.pop_front();
+        }
+    }
+
+    /// Per-unit amplitude frames recorded after each step, oldest first.
+    ///
+    /// Requires telemetry to be enabled via [`set_observer_telemetry`]. Frames
+    /// recorded before neurogenesis are shorter than later ones.
+    pub fn activation_history(&self) -> impl Iterator<Item = &[f32]> {
+        self.telemetry
+            .activation_history
+            .iter()
+            .map(|frame| frame.as_slice())
+    }
+
+    fn record_activation_frame(&mut self) {
+        if !self.telemetry.enabled {
+            return;
+        }
+        let history = &mut self.telemetry.activation_history;
+        // Reuse the oldest frame's allocation once the window is full.
+        let mut frame = if history.len() >= self.telemetry.activation_window {
+            history.pop_front().unwrap_or_default()
+        } else {
+            Vec::with_capacity(self.units.len())
+        };
+        frame.clear();
+        frame.extend(self.units.iter().map(|u| u.amp));
+        history.push_back(frame);
+
+        #[cfg(feature = "std")]
+        {
+            let sample = self.oscillation_sample(OSCILLATION_HISTORY_UNITS);
+            let history = &mut self.telemetry.oscillation_history;
+            if history.len() >= OSCILLATION_HISTORY_MAX {
+                history.pop_front();
+            }
+            history.push_back(sample);
         }
     }
 
@@ -2107,6 +3606,7 @@ impl Brain {
     /// Returns the current neuromodulator (reward/salience) level.
     ///
     /// Neuromodulator scales learning rate: positive values increase plasticity.
+    /// With momentum this is the smoothed (effective) value.
     #[must_use]
     pub fn neuromodulator(&self) -> f32 {
         self.neuromod
@@ -2228,6 +3728,47 @@ impl Brain {
         &self.telemetry.last_committed_symbols
     }
 
+    /// Current homeostasis bias of unit `id`, for debugging units that saturate
+    /// or go silent despite homeostasis.
+    ///
+    /// This is only the part of the unit's bias that homeostasis added; the
+    /// action-group and reinforcement terms are left out.
+    ///
+    /// Requires telemetry to be enabled via [`set_observer_telemetry`]; returns
+    /// 0 otherwise, or when `id` is out of range.
+    #[must_use]
+    pub fn homeostasis_bias_for_unit(&self, id: UnitId) -> f32 {
+        if !self.telemetry.enabled {
+            return 0.0;
+        }
+        self.homeostasis_bias.get(id).copied().unwrap_or(0.0)
+    }
+
+    /// Override the homeostasis bias of unit `id`, keeping the unit's other
+    /// bias terms. The total bias stays within the `[-0.5, 0.5]` range
+    /// homeostasis uses. Homeostasis keeps adapting the bias afterwards unless
+    /// `homeostasis_rate` is 0.
+    ///
+    /// Requires telemetry to be enabled via [`set_observer_telemetry`]. Returns
+    /// whether the bias was written.
+    pub fn set_homeostasis_bias_for_unit(&mut self, id: UnitId, bias: f32) -> bool {
+        if !self.telemetry.enabled || !bias.is_finite() || id >= self.units.len() {
+            return false;
+        }
+        self.ensure_homeostasis_bias();
+        let other = self.units[id].bias - self.homeostasis_bias[id];
+        let total = (other + bias).clamp(-0.5, 0.5);
+        self.units[id].bias = total;
+        self.homeostasis_bias[id] = total - other;
+        true
+    }
+
+    fn ensure_homeostasis_bias(&mut self) {
+        if self.homeostasis_bias.len() != self.units.len() {
+            self.homeostasis_bias.resize(self.units.len(), 0.0);
+        }
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Serialization (std-only)
     // ─────────────────────────────────────────────────────────────────────────
@@ -2238,48 +3779,59 @@ impl Brain {
     /// `storage::CapacityWriter`.
     #[cfg(feature = "std")]
     pub fn save_image_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
-        self.save_image_v3_to(w)
+        self.save_image_v4_to(w)
     }
 
     #[cfg(feature = "std")]
-    fn save_image_v3_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn save_image_v4_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
         w.write_all(storage::MAGIC)?;
         storage::write_u32_le(w, storage::VERSION_CURRENT)?;
 
-        self.write_cfg_chunk_v2(w)?;
-        self.write_prng_chunk_v2(w)?;
-        self.write_stat_chunk_v2(w)?;
-        self.write_unit_chunk_v2(w)?;
-        self.write_mask_chunk_v2(w)?;
-        self.write_salience_chunk_v2(w)?;
-        self.write_groups_chunk_v2(w)?;
-        self.write_latent_modules_chunk_v2(w)?;
-        self.write_symbols_chunk_v2(w)?;
-        self.write_causality_chunk_v2(w)?;
+        self.write_cfg_chunk(w)?;
+        self.write_prng_chunk(w)?;
+        self.write_stat_chunk(w)?;
+        self.write_unit_chunk(w)?;
+        self.write_csr_chunk(w)?;
+        self.write_mask_chunk(w)?;
+        self.write_salience_chunk(w)?;
+        self.write_groups_chunk(w)?;
+        self.write_latent_modules_chunk(w)?;
+        self.write_symbols_chunk(w)?;
+        self.write_causality_chunk(w)?;
         Ok(())
     }
 
-    /// Load a versioned, chunked "brain image".
+    /// Read a brain image one chunk at a time.
     ///
-    /// Unknown chunks are skipped for forward-compatibility.
+    /// The first item is [`BrainChunk::Header`]; the rest follow in file
+    /// order. Use [`BrainImageChunks::skip_tags`] for partial loads, e.g.
+    /// weights only without the causal graph:
+    ///
+    /// ```
+    /// # use braine::substrate::{Brain, BrainChunk, BrainConfig};
+    /// let bytes = Brain::new(BrainConfig::with_size(32, 4)).save_image_bytes().unwrap();
+    /// for chunk in Brain::load_image_streaming(bytes.as_slice()).skip_tags(&[*b"CAUS", *b"SYMB"]) {
+    ///     if let BrainChunk::Connections(csr) = chunk.unwrap() {
+    ///         assert_eq!(csr.offsets.len(), 33);
+    ///     }
+    /// }
+    /// ```
     #[cfg(feature = "std")]
-    pub fn load_image_from<R: Read>(r: &mut R) -> io::Result<Self> {
-        let magic = storage::read_exact::<8, _>(r)?;
-        if &magic != storage::MAGIC {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "bad brain image magic",
-            ));
-        }
-
-        let version = storage::read_u32_le(r)?;
-        if version != storage::VERSION_CURRENT {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "unsupported brain image version",
-            ));
+    pub fn load_image_streaming<R: Read>(r: R) -> BrainImageChunks<R> {
+        BrainImageChunks {
+            r,
+            version: None,
+            skip: Vec::new(),
+            pending: None,
+            done: false,
         }
+    }
 
+    /// Load a versioned, chunked "brain image" (format version 3 or 4).
+    ///
+    /// Unknown chunks are skipped for forward-compatibility.
+    #[cfg(feature = "std")]
+    pub fn load_image_from<R: Read>(r: &mut R) -> io::Result<Self> {
         let mut cfg: Option<BrainConfig> = None;
         let mut rng_state: Option<u64> = None;
         let mut age_steps: Option<u64> = None;
@@ -2294,51 +3846,35 @@ impl Brain {
         let mut symbols_rev: Option<Vec<String>> = None;
         let mut causal: Option<CausalMemory> = None;
 
-        loop {
-            let (tag, len) = match storage::read_chunk_header(r) {
-                Ok(v) => v,
-                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
-                Err(e) => return Err(e),
-            };
-
-            // Current chunks are LZ4-compressed.
-            let payload = {
-                let mut take = r.take(len as u64);
-                let uncompressed_len = storage::read_u32_le(&mut take)? as usize;
-                let mut compressed = Vec::with_capacity((len as usize).saturating_sub(4));
-                take.read_to_end(&mut compressed)?;
-                let decompressed = storage::decompress_lz4(&compressed, uncompressed_len)?;
-                io::copy(&mut take, &mut io::sink())?;
-                decompressed
-            };
-
-            let mut cursor = io::Cursor::new(payload);
-            match &tag {
-                b"CFG0" => cfg = Some(Self::read_cfg_payload(&mut cursor)?),
-                b"PRNG" => rng_state = Some(storage::read_u64_le(&mut cursor)?),
-                b"STAT" => age_steps = Some(storage::read_u64_le(&mut cursor)?),
-                b"UNIT" => {
-                    let (u, c) = Self::read_unit_payload(&mut cursor)?;
-                    units = Some(u);
-                    connections = Some(c);
-                }
-                b"MASK" => {
-                    let (rsv, learn) = Self::read_mask_payload(&mut cursor)?;
+        let named = |groups: Vec<(String, Vec<UnitId>)>| -> Vec<NamedGroup> {
+            groups
+                .into_iter()
+                .map(|(name, units)| NamedGroup { name, units })
+                .collect()
+        };
+        for chunk in Self::load_image_streaming(r) {
+            match chunk? {
+                BrainChunk::Header { .. } | BrainChunk::Skipped { .. } => {}
+                BrainChunk::Config(c) => cfg = Some(*c),
+                BrainChunk::Prng { state } => rng_state = Some(state),
+                BrainChunk::Stats { age_steps: a } => age_steps = Some(a),
+                BrainChunk::Units(u) => units = Some(u),
+                BrainChunk::Connections(c) => connections = Some(c),
+                BrainChunk::Masks {
+                    reserved: rsv,
+                    learning_enabled: learn,
+                } => {
                     reserved = Some(rsv);
                     learning_enabled = Some(learn);
                 }
-                b"SALI" => salience = Some(Self::read_salience_payload(&mut cursor)?),
-                b"GRPS" => {
-                    let (sg, ag) = Self::read_groups_payload(&mut cursor)?;
-                    sensor_groups = Some(sg);
-                    action_groups = Some(ag);
-                }
-                b"LMOD" => latent_groups = Some(Self::read_latent_modules_payload(&mut cursor)?),
-                b"SYMB" => symbols_rev = Some(Self::read_symbols_payload(&mut cursor)?),
-                b"CAUS" => causal = Some(CausalMemory::read_image_payload(&mut cursor)?),
-                _ => {
-                    // Unknown chunk: skipped.
+                BrainChunk::Salience(sal) => salience = Some(sal),
+                BrainChunk::Groups { sensors, actions } => {
+                    sensor_groups = Some(named(sensors));
+                    action_groups = Some(named(actions));
                 }
+                BrainChunk::LatentModules(groups) => latent_groups = Some(named(groups)),
+                BrainChunk::Symbols(syms) => symbols_rev = Some(syms),
+                BrainChunk::Causal(c) => causal = Some(*c),
             }
         }
 
@@ -2348,6 +3884,17 @@ impl Brain {
         let connections = connections.ok_or_else(|| {
             io::Error::new(io::ErrorKind::InvalidData, "missing UNIT connections")
         })?;
+        if connections.offsets.len() != units.len() + 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "CSR0 unit_count mismatch",
+            ));
+        }
+        let csr_tombstones = connections
+            .targets
+            .iter()
+            .filter(|&&t| t == INVALID_UNIT)
+            .count();
         if cfg.unit_count != units.len() {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidData,
@@ -2400,16 +3947,24 @@ impl Brain {
         let activity_trace: Vec<f32> = units.iter().map(|u| u.amp.max(0.0)).collect();
 
         let eligibility_len = connections.weights.len();
+        let pruning_policy = PruningPolicy::Threshold {
+            below: cfg.prune_below,
+        };
 
         let mut brain = Self {
             cfg,
             units,
             activity_trace,
+            homeostasis_bias: Vec::new(),
             growth_eligibility_norm_ema: 0.0,
             growth_commit_ema: 0.0,
+            plasticity_rate_ema: 0.0,
+            plasticity_rate_steps: 0,
+            td_error_ema: 1.0,
             growth_prune_norm_ema: 0.0,
             growth_last_birth_step: 0,
             causal_lag_history: Vec::new(),
+            causal_window_history: Vec::new(),
             connections,
             eligibility: vec![0.0; eligibility_len],
             tier: ExecutionTier::default(),
@@ -2445,15 +4000,36 @@ impl Brain {
             reward_neg_symbol,
             pruned_last_step: 0,
             births_last_step: 0,
-            csr_tombstones: 0,
+            csr_tombstones,
+            sorted_adjacency: Vec::new(),
+            csr_generation: 0,
+            sorted_adjacency_generation: None,
             age_steps,
             telemetry: Telemetry::default(),
             learning_monitors: LearningMonitors::default(),
+            coactive_last_step: Vec::new(),
+            intrinsic_reward: 0.0,
+            causal_novelty_weight: 0.0,
+            learning_priority: 1.0,
+            oja_units: Vec::new(),
+            pruning_policy,
+            edge_last_update: Vec::new(),
+            edge_weight_snapshot: Vec::new(),
+            fast_weights: Vec::new(),
+            fast_weight_snapshot: Vec::new(),
+            dream_traces: VecDeque::new(),
         };
 
         brain.rebuild_group_membership();
         brain.rebuild_sensor_group_index();
         brain.rebuild_routing_from_groups();
+        // Only homeostasis writes the bias of units outside groups.
+        brain.homeostasis_bias = brain
+            .units
+            .iter()
+            .zip(&brain.reserved)
+            .map(|(u, &reserved)| if reserved { 0.0 } else { u.bias })
+            .collect();
         Ok(brain)
     }
 
@@ -2490,11 +4066,191 @@ impl Brain {
         Self::load_image_from(&mut cursor)
     }
 
+    /// Serialize only the causal memory (plus the symbol table it refers to).
+    ///
+    /// Much smaller than a full image; intended for frequent checkpoints while
+    /// weights are stable. Restore with [`Brain::load_causal_only_from`].
+    #[cfg(feature = "std")]
+    pub fn save_causal_only<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(storage::CAUSAL_MAGIC)?;
+        storage::write_u32_le(w, storage::VERSION_CURRENT)?;
+        self.write_symbols_chunk(w)?;
+        self.write_causality_chunk(w)?;
+        Ok(())
+    }
+
+    /// Replace the live causal memory with one written by [`Brain::save_causal_only`].
+    ///
+    /// Weights, unit state and groups are untouched. Symbols are matched by
+    /// name, so the image may come from an earlier point in this brain's life;
+    /// names unknown to the live brain are interned.
+    #[cfg(feature = "std")]
+    pub fn load_causal_only_from<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        let magic = storage::read_exact::<8, _>(r)?;
+        if &magic != storage::CAUSAL_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "bad causal image magic",
+            ));
+        }
+        let version = storage::read_u32_le(r)?;
+        if version != storage::VERSION_V3 && version != storage::VERSION_V4 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unsupported causal image version",
+            ));
+        }
+
+        let mut symbols_rev: Option<Vec<String>> = None;
+        let mut causal: Option<CausalMemory> = None;
+        while let Some((tag, payload)) = storage::read_chunk_lz4(r, version)? {
+            let mut cursor = io::Cursor::new(payload);
+            match &tag {
+                b"SYMB" => symbols_rev = Some(Self::read_symbols_payload(&mut cursor)?),
+                b"CAUS" => causal = Some(CausalMemory::read_image_payload(&mut cursor)?),
+                _ => {}
+            }
+        }
+        let symbols_rev = symbols_rev
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing SYMB"))?;
+        let mut causal =
+            causal.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing CAUS"))?;
+
+        let map: Vec<SymbolId> = symbols_rev.iter().map(|name| self.intern(name)).collect();
+        if !causal.remap_symbols(&map) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "CAUS references unknown symbol",
+            ));
+        }
+        self.causal = causal;
+        Ok(())
+    }
+
+    /// The strongest causal edges as compact JSON, for external (LLM) consumers.
+    ///
+    /// At most [`CAUSAL_GRAPH_JSON_MAX_EDGES`] edges, sorted by descending
+    /// `|causal_strength|` (`weight`), with their raw transition/co-occurrence
+    /// counts and the base counts of every symbol they touch:
+    ///
+    /// `{"base_total":f,"symbols":[{"name","count"}],"edges":[{"from","to","weight","transition","cooccur"}]}`
+    ///
+    /// [`Brain::load_causal_graph_from`] rebuilds a causal memory from it; the
+    /// snapshot is lossy (weaker edges and unrelated symbols are dropped).
+    /// Edges touching a symbol without a name are skipped, since they could
+    /// not be matched back on load.
+    #[cfg(feature = "std")]
+    pub fn serialize_causal_graph(&self) -> Vec<u8> {
+        let top: Vec<(SymbolId, SymbolId, f32, &str, &str)> = self
+            .causal
+            .top_edges(CAUSAL_GRAPH_JSON_MAX_EDGES)
+            .into_iter()
+            .filter_map(|(a, b, weight)| {
+                let from = self.symbol_name(a).filter(|n| !n.is_empty())?;
+                let to = self.symbol_name(b).filter(|n| !n.is_empty())?;
+                Some((a, b, weight, from, to))
+            })
+            .collect();
+
+        let mut symbols: Vec<(SymbolId, &str)> = top
+            .iter()
+            .flat_map(|&(a, b, _, from, to)| [(a, from), (b, to)])
+            .collect();
+        symbols.sort_unstable_by_key(|&(id, _)| id);
+        symbols.dedup_by_key(|&mut (id, _)| id);
+
+        let symbols: Vec<serde_json::Value> = symbols
+            .into_iter()
+            .map(|(id, name)| serde_json::json!({ "name": name, "count": self.causal.base_count(id) }))
+            .collect();
+        let edges: Vec<serde_json::Value> = top
+            .into_iter()
+            .map(|(a, b, weight, from, to)| {
+                serde_json::json!({
+                    "from": from,
+                    "to": to,
+                    "weight": weight,
+                    "transition": self.causal.transition_count(a, b),
+                    "cooccur": self.causal.cooccur_count(a, b),
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "base_total": self.causal.base_total(),
+            "symbols": symbols,
+            "edges": edges,
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    /// Replace the live causal memory with a snapshot from
+    /// [`Brain::serialize_causal_graph`].
+    ///
+    /// Symbols are matched by name and interned if unknown. The bytes are fully
+    /// validated first; on error the live causal memory is left untouched.
+    #[cfg(feature = "std")]
+    pub fn load_causal_graph_from(&mut self, bytes: &[u8]) -> io::Result<()> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+        let v: serde_json::Value = serde_json::from_slice(bytes)
+            .map_err(|e| invalid(&format!("causal graph JSON: {e}")))?;
+
+        let num = |x: &serde_json::Value, key: &str| -> io::Result<f32> {
+            x.get(key)
+                .and_then(serde_json::Value::as_f64)
+                .map(|f| f as f32)
+                .filter(|f| f.is_finite())
+                .ok_or_else(|| invalid(&format!("causal graph: bad or missing '{key}'")))
+        };
+        let text = |x: &serde_json::Value, key: &str| -> io::Result<String> {
+            x.get(key)
+                .and_then(serde_json::Value::as_str)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .ok_or_else(|| invalid(&format!("causal graph: bad or missing '{key}'")))
+        };
+        let list = |key: &str| -> io::Result<&Vec<serde_json::Value>> {
+            v.get(key)
+                .and_then(serde_json::Value::as_array)
+                .ok_or_else(|| invalid(&format!("causal graph: missing '{key}' array")))
+        };
+
+        let base_total = num(&v, "base_total")?;
+        let mut symbols = Vec::new();
+        for x in list("symbols")? {
+            symbols.push((text(x, "name")?, num(x, "count")?));
+        }
+        let mut edges = Vec::new();
+        for x in list("edges")? {
+            edges.push((
+                text(x, "from")?,
+                text(x, "to")?,
+                num(x, "transition")?,
+                num(x, "cooccur")?,
+            ));
+        }
+
+        self.causal.clear();
+        for (name, count) in symbols {
+            let id = self.intern(&name);
+            self.causal.set_base_count(id, count);
+        }
+        for (from, to, transition, cooccur) in edges {
+            let (a, b) = (self.intern(&from), self.intern(&to));
+            self.causal.set_edge_counts(a, b, transition, cooccur);
+        }
+        // The snapshot keeps only some symbols; restore the full total so
+        // strengths are normalized as before.
+        self.causal.set_base_total(base_total);
+        Ok(())
+    }
+
     #[cfg(feature = "std")]
-    fn write_cfg_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_cfg_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> = Vec::with_capacity(Self::cfg_payload_len_bytes() as usize);
         self.write_cfg_payload(&mut payload)?;
-        storage::write_chunk_v2_lz4(w, *b"CFG0", &payload)
+        storage::write_chunk_v4_lz4(w, *b"CFG0", &payload)
     }
 
     #[cfg(feature = "std")]
@@ -2559,6 +4315,16 @@ impl Brain {
                 + 4 // latent_module_auto_reward_threshold
                 + 4 // latent_module_retire_after_steps
                 + 4 // latent_module_retire_reward_threshold
+                + 4 // reward_symbol_threshold
+                + 4 // concept_validate_threshold
+                + 4 // weight_clip_min
+                + 4 // weight_clip_max
+                + 4 // novelty_window
+                + 4 // causal_window_frames
+                + 4 // enable_fast_weights
+                + 4 // fast_hebb_rate
+                + 4 // fast_forget_rate
+                + 4 // neuromodulator_momentum
     }
 
     #[cfg(feature = "std")]
@@ -2643,6 +4409,24 @@ impl Brain {
         // Reward event thresholds (appended; backwards compatible on load).
         storage::write_f32_le(w, self.cfg.reward_symbol_threshold)?;
         storage::write_f32_le(w, self.cfg.concept_validate_threshold)?;
+
+        // Weight clipping bounds (appended; backwards compatible on load).
+        storage::write_f32_le(w, self.cfg.weight_clip_min)?;
+        storage::write_f32_le(w, self.cfg.weight_clip_max)?;
+
+        // Novelty window (appended; backwards compatible on load).
+        storage::write_u32_le(w, self.cfg.novelty_window)?;
+
+        // Causal co-occurrence window (appended; backwards compatible on load).
+        storage::write_u32_le(w, self.cfg.causal_window_frames)?;
+
+        // Fast/slow weight banks (appended; backwards compatible on load).
+        storage::write_u32_le(w, if self.cfg.enable_fast_weights { 1 } else { 0 })?;
+        storage::write_f32_le(w, self.cfg.fast_hebb_rate)?;
+        storage::write_f32_le(w, self.cfg.fast_forget_rate)?;
+
+        // Neuromodulator momentum (appended; backwards compatible on load).
+        storage::write_f32_le(w, self.cfg.neuromodulator_momentum)?;
         Ok(())
     }
 
@@ -2784,6 +4568,25 @@ impl Brain {
             let reward_symbol_threshold = read_f32_default(&mut c, 0.2);
             let concept_validate_threshold = read_f32_default(&mut c, 0.2);
 
+            // Optional appended weight clipping bounds (default: unbounded).
+            let weight_clip_min = read_f32_default(&mut c, f32::NEG_INFINITY);
+            let weight_clip_max = read_f32_default(&mut c, f32::INFINITY);
+
+            // Optional appended novelty window.
+            let novelty_window = read_u32_default(&mut c, 50);
+
+            // Optional appended causal co-occurrence window. Images written before it
+            // existed only paired same-frame symbols, i.e. a window of 1.
+            let causal_window_frames = read_u32_default(&mut c, 1).clamp(1, CAUSAL_WINDOW_MAX);
+
+            // Optional appended fast/slow weight banks.
+            let enable_fast_weights = read_u32_default(&mut c, 0) != 0;
+            let fast_hebb_rate = read_f32_default(&mut c, 0.4);
+            let fast_forget_rate = read_f32_default(&mut c, 0.02);
+
+            // Optional appended neuromodulator momentum.
+            let neuromodulator_momentum = read_f32_default(&mut c, 0.0);
+
             let cfg = BrainConfig {
                 unit_count,
                 connectivity_per_unit,
@@ -2800,6 +4603,9 @@ impl Brain {
                 hebb_rate,
                 forget_rate,
                 prune_below,
+                enable_fast_weights,
+                fast_hebb_rate,
+                fast_forget_rate,
                 coactive_threshold,
                 phase_lock_threshold,
                 imprint_rate,
@@ -2817,11 +4623,14 @@ impl Brain {
                 causal_lag_steps,
                 causal_lag_decay,
                 causal_symbol_cap,
+                causal_window_frames,
                 seed: if seed_present != 0 { Some(seed) } else { None },
                 causal_decay,
                 learning_deadband,
                 eligibility_decay,
                 eligibility_gain,
+                weight_clip_min,
+                weight_clip_max,
                 reward_symbol_threshold,
                 concept_validate_threshold,
                 coactive_softness,
@@ -2850,6 +4659,12 @@ impl Brain {
                 latent_module_auto_reward_threshold,
                 latent_module_retire_after_steps,
                 latent_module_retire_reward_threshold,
+
+                novelty_window,
+                neuromodulator_momentum,
+
+                // Experimental flags are not stored in images.
+                experimental_flags: HashMap::new(),
             };
 
             // Basic sanity: only accept if seed_present looks plausible and cfg validates.
@@ -2874,48 +4689,52 @@ impl Brain {
     }
 
     #[cfg(feature = "std")]
-    fn write_prng_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_prng_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> = Vec::with_capacity(8);
         storage::write_u64_le(&mut payload, self.rng.state())?;
-        storage::write_chunk_v2_lz4(w, *b"PRNG", &payload)
+        storage::write_chunk_v4_lz4(w, *b"PRNG", &payload)
     }
 
     #[cfg(feature = "std")]
-    fn write_stat_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_stat_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> = Vec::with_capacity(8);
         storage::write_u64_le(&mut payload, self.age_steps)?;
-        storage::write_chunk_v2_lz4(w, *b"STAT", &payload)
+        storage::write_chunk_v4_lz4(w, *b"STAT", &payload)
     }
 
     #[cfg(feature = "std")]
-    fn unit_payload_len_bytes(&self) -> io::Result<u32> {
-        let mut len: u64 = 0;
-        len += 4; // unit_count
-                  // Unit scalar state: amp, phase, bias, decay (4 floats per unit).
-        len += (self.units.len() as u64) * 4 * 4;
-        // CSR connections: offsets, targets, weights.
-        len += 4; // total_connections count
+    fn write_unit_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut payload: Vec<u8> = Vec::with_capacity(4 + self.units.len() * 4 * 4);
+
+        // Unit scalar state: amp, phase, bias, decay (4 floats per unit).
+        storage::write_u32_le(&mut payload, self.units.len() as u32)?;
+        for u in &self.units {
+            storage::write_f32_le(&mut payload, u.amp)?;
+            storage::write_f32_le(&mut payload, u.phase)?;
+            storage::write_f32_le(&mut payload, u.bias)?;
+            storage::write_f32_le(&mut payload, u.decay)?;
+        }
+
+        storage::write_chunk_v4_lz4(w, UNIT_STATES_CHUNK, &payload)
+    }
+
+    #[cfg(feature = "std")]
+    fn csr_payload_len_bytes(&self) -> io::Result<u32> {
+        let mut len: u64 = 0;
+        len += 4; // unit_count
+        len += 4; // total_connections count
         len += (self.connections.offsets.len() as u64) * 4; // offsets (unit_count + 1)
                                                             // Only count valid (non-tombstoned) connections.
         let valid_count = self.total_connection_count() as u64;
         len += valid_count * 4; // targets
         len += valid_count * 4; // weights
         u32::try_from(len)
-            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "UNIT chunk too large"))
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "CSR0 chunk too large"))
     }
 
     #[cfg(feature = "std")]
-    fn write_unit_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
-        let mut payload: Vec<u8> = Vec::with_capacity(self.unit_payload_len_bytes()? as usize);
-
-        // Write unit scalars.
-        storage::write_u32_le(&mut payload, self.units.len() as u32)?;
-        for u in &self.units {
-            storage::write_f32_le(&mut payload, u.amp)?;
-            storage::write_f32_le(&mut payload, u.phase)?;
-            storage::write_f32_le(&mut payload, u.bias)?;
-            storage::write_f32_le(&mut payload, u.decay)?;
-        }
+    fn write_csr_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut payload: Vec<u8> = Vec::with_capacity(self.csr_payload_len_bytes()? as usize);
 
         // Write CSR connections (compacted: skip tombstones).
         let mut compact_offsets: Vec<usize> = Vec::with_capacity(self.units.len() + 1);
@@ -2931,6 +4750,7 @@ impl Brain {
         }
         compact_offsets.push(compact_targets.len());
 
+        storage::write_u32_le(&mut payload, self.units.len() as u32)?;
         storage::write_u32_le(&mut payload, compact_targets.len() as u32)?;
         for &off in &compact_offsets {
             storage::write_u32_le(&mut payload, off as u32)?;
@@ -2942,14 +4762,13 @@ impl Brain {
             storage::write_f32_le(&mut payload, wt)?;
         }
 
-        storage::write_chunk_v2_lz4(w, *b"UNIT", &payload)
+        storage::write_chunk_v4_lz4(w, CSR_CHUNK, &payload)
     }
 
     #[cfg(feature = "std")]
-    fn read_unit_payload<R: Read>(r: &mut R) -> io::Result<(Vec<Unit>, CsrConnections)> {
+    fn read_unit_states_payload<R: Read>(r: &mut R) -> io::Result<Vec<Unit>> {
         let unit_count = storage::read_u32_le(r)? as usize;
 
-        // Read unit scalars.
         // Note: salience is loaded separately from SALI chunk for backwards compatibility.
         let mut units: Vec<Unit> = Vec::with_capacity(unit_count);
         for _ in 0..unit_count {
@@ -2965,8 +4784,13 @@ impl Brain {
                 salience: 0.0, // Default; will be updated from SALI chunk if present
             });
         }
+        Ok(units)
+    }
 
-        // Read CSR connections.
+    /// CSR connections as written after the unit states (V3 `UNIT` chunk) or
+    /// after the unit count (V4 `CSR0` chunk).
+    #[cfg(feature = "std")]
+    fn read_csr_payload<R: Read>(r: &mut R, unit_count: usize) -> io::Result<CsrConnections> {
         let total_conns = storage::read_u32_le(r)? as usize;
         let mut offsets: Vec<usize> = Vec::with_capacity(unit_count + 1);
         for _ in 0..(unit_count + 1) {
@@ -2983,12 +4807,11 @@ impl Brain {
             weights.push(storage::read_f32_le(r)?);
         }
 
-        let connections = CsrConnections {
+        Ok(CsrConnections {
             targets,
             weights,
             offsets,
-        };
-        Ok((units, connections))
+        })
     }
 
     #[cfg(feature = "std")]
@@ -3000,7 +4823,7 @@ impl Brain {
     }
 
     #[cfg(feature = "std")]
-    fn write_mask_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_mask_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> = Vec::with_capacity(self.mask_payload_len_bytes() as usize);
         let n = self.units.len();
         storage::write_u32_le(&mut payload, n as u32)?;
@@ -3009,7 +4832,7 @@ impl Brain {
         Self::write_bool_bits(&mut payload, &self.reserved)?;
         storage::write_u32_le(&mut payload, bytes_len as u32)?;
         Self::write_bool_bits(&mut payload, &self.learning_enabled)?;
-        storage::write_chunk_v2_lz4(w, *b"MASK", &payload)
+        storage::write_chunk_v4_lz4(w, *b"MASK", &payload)
     }
 
     #[cfg(feature = "std")]
@@ -3092,13 +4915,13 @@ impl Brain {
     }
 
     #[cfg(feature = "std")]
-    fn write_salience_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_salience_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> = Vec::with_capacity(self.salience_payload_len_bytes() as usize);
         storage::write_u32_le(&mut payload, self.units.len() as u32)?;
         for u in &self.units {
             storage::write_f32_le(&mut payload, u.salience)?;
         }
-        storage::write_chunk_v2_lz4(w, *b"SALI", &payload)
+        storage::write_chunk_v4_lz4(w, *b"SALI", &payload)
     }
 
     #[cfg(feature = "std")]
@@ -3133,7 +4956,7 @@ impl Brain {
     }
 
     #[cfg(feature = "std")]
-    fn write_groups_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_groups_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> = Vec::with_capacity(self.groups_payload_len_bytes()? as usize);
 
         storage::write_u32_le(&mut payload, self.sensor_groups.len() as u32)?;
@@ -3154,7 +4977,7 @@ impl Brain {
             }
         }
 
-        storage::write_chunk_v2_lz4(w, *b"GRPS", &payload)
+        storage::write_chunk_v4_lz4(w, *b"GRPS", &payload)
     }
 
     #[cfg(feature = "std")]
@@ -3172,7 +4995,7 @@ impl Brain {
     }
 
     #[cfg(feature = "std")]
-    fn write_latent_modules_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_latent_modules_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> =
             Vec::with_capacity(self.latent_modules_payload_len_bytes()? as usize);
 
@@ -3185,7 +5008,7 @@ impl Brain {
             }
         }
 
-        storage::write_chunk_v2_lz4(w, LATENT_MODULES_CHUNK, &payload)
+        storage::write_chunk_v4_lz4(w, LATENT_MODULES_CHUNK, &payload)
     }
 
     #[cfg(feature = "std")]
@@ -3245,13 +5068,13 @@ impl Brain {
     }
 
     #[cfg(feature = "std")]
-    fn write_symbols_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_symbols_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> = Vec::with_capacity(self.symbols_payload_len_bytes()? as usize);
         storage::write_u32_le(&mut payload, self.symbols_rev.len() as u32)?;
         for s in &self.symbols_rev {
             storage::write_string(&mut payload, s)?;
         }
-        storage::write_chunk_v2_lz4(w, *b"SYMB", &payload)
+        storage::write_chunk_v4_lz4(w, *b"SYMB", &payload)
     }
 
     #[cfg(feature = "std")]
@@ -3299,19 +5122,22 @@ impl Brain {
     }
 
     #[cfg(feature = "std")]
-    fn write_causality_chunk_v2<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write_causality_chunk<W: Write>(&self, w: &mut W) -> io::Result<()> {
         let mut payload: Vec<u8> =
             Vec::with_capacity(self.causal.image_payload_len_bytes() as usize);
         self.causal.write_image_payload(&mut payload)?;
-        storage::write_chunk_v2_lz4(w, *b"CAUS", &payload)
+        storage::write_chunk_v4_lz4(w, *b"CAUS", &payload)
     }
 
     /// Ensure a sensor group exists; if missing, create it.
-    pub fn ensure_sensor(&mut self, name: &str, width: usize) {
+    ///
+    /// Returns `true` if the group was created.
+    pub fn ensure_sensor(&mut self, name: &str, width: usize) -> bool {
         if self.sensor_groups.iter().any(|g| g.name == name) {
-            return;
+            return false;
         }
         self.define_sensor(name, width);
+        true
     }
 
     /// Ensure a sensor group exists and has at least `min_width` units.
@@ -3319,17 +5145,23 @@ impl Brain {
     /// Unlike [`ensure_sensor`], this will *grow* an existing group by reserving
     /// additional unreserved units and adding them to the group.
     ///
-    /// Returns how many units were added.
-    pub fn ensure_sensor_min_width(&mut self, name: &str, min_width: usize) -> usize {
+    /// Returns whether the group was created and its width before and after.
+    pub fn ensure_sensor_min_width(&mut self, name: &str, min_width: usize) -> SensorEnsureResult {
+        let idx = self.sensor_groups.iter().position(|g| g.name == name);
+        let cur = idx.map_or(0, |i| self.sensor_groups[i].units.len());
+        let unchanged = SensorEnsureResult {
+            was_created: false,
+            old_width: cur,
+            new_width: cur,
+        };
         if min_width == 0 {
-            return 0;
+            return unchanged;
         }
 
-        if let Some(idx) = self.sensor_groups.iter().position(|g| g.name == name) {
+        if let Some(idx) = idx {
             let module = self.ensure_routing_module("sensor", name);
-            let cur = self.sensor_groups[idx].units.len();
             if cur >= min_width {
-                return 0;
+                return unchanged;
             }
             let want = min_width - cur;
             let extra = self.allocate_units(want);
@@ -3343,12 +5175,17 @@ impl Brain {
             self.module_unit_counts_dirty = true;
             self.sensor_groups[idx].units.extend(extra);
             self.intern(name);
-            min_width
-                .saturating_sub(cur)
-                .min(self.sensor_groups[idx].units.len().saturating_sub(cur))
+            SensorEnsureResult {
+                new_width: self.sensor_groups[idx].units.len(),
+                ..unchanged
+            }
         } else {
             self.define_sensor(name, min_width);
-            min_width
+            SensorEnsureResult {
+                was_created: true,
+                old_width: 0,
+                new_width: self.sensor_units(name).map_or(0, <[UnitId]>::len),
+            }
         }
     }
 
@@ -3458,11 +5295,46 @@ impl Brain {
                 is_reserved: self.reserved.get(id).copied().unwrap_or(false),
                 is_sensor_member: self.sensor_member.get(id).copied().unwrap_or(false),
                 is_group_member: self.group_member.get(id).copied().unwrap_or(false),
+                homeostasis_bias: self.homeostasis_bias_for_unit(id),
             });
         }
         out
     }
 
+    /// Fraction of `(sensor unit, action unit)` pairs that are phase-locked.
+    ///
+    /// A pair counts as locked when its phase alignment exceeds
+    /// `phase_lock_threshold`, the same gate Hebbian learning uses. Near 1.0
+    /// means sensor and action groups oscillate together (stimulus-response
+    /// binding); unlike [`oscillation_sample`](Self::oscillation_sample) this
+    /// ignores global synchrony among unrelated units. 0.0 without both kinds
+    /// of group.
+    #[must_use]
+    pub fn phase_locking_ratio(&self) -> f32 {
+        let thr = self.cfg.phase_lock_threshold;
+        let phases_of = |groups: &[NamedGroup]| -> Vec<f32> {
+            groups
+                .iter()
+                .flat_map(|g| g.units.iter())
+                .filter_map(|&id| self.units.get(id).map(|u| u.phase))
+                .collect()
+        };
+        let sensors = phases_of(&self.sensor_groups);
+        let actions = phases_of(&self.action_groups);
+        if sensors.is_empty() || actions.is_empty() {
+            return 0.0;
+        }
+
+        let mut locked = 0usize;
+        for &s in &sensors {
+            locked += actions
+                .iter()
+                .filter(|&&a| phase_alignment(s, a) > thr)
+                .count();
+        }
+        locked as f32 / (sensors.len() * actions.len()) as f32
+    }
+
     /// Return a lightweight, sampled "global oscillation" vector.
     ///
     /// Interprets each unit as a phasor (amp, phase) and computes the
@@ -3507,31 +5379,64 @@ impl Brain {
         (sum_x, sum_y, mag)
     }
 
+    /// Recent [`oscillation_sample`](Self::oscillation_sample) values, oldest
+    /// first, for plotting the global phase vector over time (x against y
+    /// traces a Lissajous-like curve).
+    ///
+    /// Covers the last `window` steps (at most [`OSCILLATION_HISTORY_MAX`]),
+    /// thinned to at most `n` evenly spaced points; the newest sample is
+    /// always included. Each sample uses up to 512 units. Requires telemetry
+    /// to be enabled via [`set_observer_telemetry`](Self::set_observer_telemetry).
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn oscillation_sample_windowed(&self, n: usize, window: usize) -> Vec<(f32, f32, f32)> {
+        let history = &self.telemetry.oscillation_history;
+        let len = window.min(history.len());
+        if len == 0 || n == 0 {
+            return Vec::new();
+        }
+        let start = history.len() - len;
+        let points = n.min(len);
+        // Walk back from the newest sample in even steps.
+        let mut out: Vec<(f32, f32, f32)> = (0..points)
+            .map(|k| history[start + len - 1 - k * len / points])
+            .collect();
+        out.reverse();
+        out
+    }
+
     /// Create a sandboxed child brain.
     ///
     /// Design intent:
     /// - child inherits structure (couplings + causal memory)
     /// - child can explore with different noise/plasticity
     /// - child cannot mutate a protected identity subset (action groups by default)
+    ///
+    /// This is a modified clone, not a raw [`Clone::clone`]: the child gets a
+    /// fresh seed and the config overrides, its eligibility traces start at zero,
+    /// ephemeral state (gates, routing, telemetry) is rebuilt from scratch, and
+    /// action-group units have learning disabled.
     #[cfg(feature = "std")]
     pub fn spawn_child(
         &self,
         seed: u64,
         overrides: crate::supervisor::ChildConfigOverrides,
     ) -> Brain {
-        let mut cfg = self.cfg;
+        let mut cfg = self.cfg.clone();
+        overrides.apply_to(&mut cfg);
         cfg.seed = Some(seed);
-        cfg.noise_amp = overrides.noise_amp;
-        cfg.noise_phase = overrides.noise_phase;
-        cfg.hebb_rate = overrides.hebb_rate;
-        cfg.forget_rate = overrides.forget_rate;
 
         let mut child = Brain::new(cfg);
 
         // Copy substrate state.
         child.units = self.units.clone();
         child.connections = self.connections.clone();
+        child.csr_tombstones = self.csr_tombstones;
         child.eligibility = vec![0.0; child.connections.weights.len()];
+        if !matches!(self.pruning_policy, PruningPolicy::Threshold { .. }) {
+            // Threshold follows the child's (possibly overridden) `prune_below`.
+            let _ = child.set_pruning_policy(self.pruning_policy);
+        }
         child.sensor_groups = self.sensor_groups.clone();
         child.action_groups = self.action_groups.clone();
         child.latent_groups = self.latent_groups.clone();
@@ -3576,6 +5481,7 @@ impl Brain {
     ) {
         let thr = policy.weight_threshold;
         let rate = policy.merge_rate.clamp(0.0, 1.0);
+        self.touch_csr();
 
         // Identity units are action group units.
         let mut protected = vec![false; self.units.len()];
@@ -3624,6 +5530,140 @@ impl Brain {
         self.causal.merge_from(&child.causal, 0.25);
     }
 
+    /// Build a new brain whose weights combine `self` and `other` (see
+    /// [`crate::supervisor::Supervisor::cross_consolidate`]). Neither input is modified.
+    ///
+    /// The result keeps `self`'s layout (config, groups, symbols, unit state).
+    /// Units are matched by id, so both brains should share a layout, e.g.
+    /// siblings spawned from the same parent. A sensor→action edge is weighted
+    /// by each brain's `pair_reward_edges(sensor, action).meaning` (clamped at
+    /// 0); every other edge by that brain's mean pair meaning. An edge missing
+    /// from one brain counts as weight 0 there. Eligibility traces start empty
+    /// and `other`'s causal memory is blended in at rate 0.5.
+    #[cfg(feature = "std")]
+    pub(crate) fn cross_consolidated(
+        &self,
+        other: &Brain,
+        strategy: crate::supervisor::MergeStrategy,
+    ) -> Brain {
+        use crate::supervisor::MergeStrategy;
+
+        // Keeps all-zero meanings from dividing by zero (equal weighting).
+        const EPS: f32 = 1e-3;
+
+        let n = self.units.len();
+        let mut sensor_of: Vec<Option<usize>> = vec![None; n];
+        let mut action_of: Vec<Option<usize>> = vec![None; n];
+        for (gi, g) in self.sensor_groups.iter().enumerate() {
+            for &id in &g.units {
+                sensor_of[id] = Some(gi);
+            }
+        }
+        for (gi, g) in self.action_groups.iter().enumerate() {
+            for &id in &g.units {
+                action_of[id] = Some(gi);
+            }
+        }
+
+        // Per-brain pair meaning table [sensor][action] and its mean.
+        let meanings = |brain: &Brain| -> (Vec<Vec<f32>>, f32) {
+            let table: Vec<Vec<f32>> = self
+                .sensor_groups
+                .iter()
+                .map(|s| {
+                    self.action_groups
+                        .iter()
+                        .map(|a| brain.pair_reward_edges(&s.name, &a.name).meaning.max(0.0))
+                        .collect()
+                })
+                .collect();
+            let cells = (self.sensor_groups.len() * self.action_groups.len()).max(1);
+            let mean = table.iter().flatten().sum::<f32>() / cells as f32;
+            (table, mean)
+        };
+        let (meaning_a, overall_a) = meanings(self);
+        let (meaning_b, overall_b) = meanings(other);
+
+        let mut targets: Vec<UnitId> = Vec::with_capacity(self.connections.targets.len());
+        let mut weights: Vec<Weight> = Vec::with_capacity(self.connections.weights.len());
+        let mut offsets: Vec<usize> = Vec::with_capacity(n + 1);
+        let mut row: Vec<(UnitId, Option<f32>, Option<f32>)> = Vec::new();
+        let mut merged: Vec<(UnitId, f32)> = Vec::new();
+
+        for (i, &sensor) in sensor_of.iter().enumerate() {
+            offsets.push(targets.len());
+
+            row.clear();
+            for (t, w) in self.neighbors(i) {
+                row.push((t, Some(w), None));
+            }
+            if i < other.units.len() {
+                for (t, w) in other.neighbors(i) {
+                    if t >= n {
+                        continue;
+                    }
+                    // Duplicate targets pair up in order of appearance.
+                    match row.iter_mut().find(|(rt, _, wb)| *rt == t && wb.is_none()) {
+                        Some(entry) => entry.2 = Some(w),
+                        None => row.push((t, None, Some(w))),
+                    }
+                }
+            }
+
+            merged.clear();
+            for &(t, wa, wb) in &row {
+                let (fa, fb) = match (sensor, action_of[t]) {
+                    (Some(s), Some(a)) => (meaning_a[s][a] + EPS, meaning_b[s][a] + EPS),
+                    _ => (overall_a + EPS, overall_b + EPS),
+                };
+                let (wa, wb) = match strategy {
+                    MergeStrategy::UnionStrong { threshold } => (
+                        wa.filter(|w| w.abs() >= threshold),
+                        wb.filter(|w| w.abs() >= threshold),
+                    ),
+                    _ => (Some(wa.unwrap_or(0.0)), Some(wb.unwrap_or(0.0))),
+                };
+                let w = match (wa, wb) {
+                    (Some(wa), Some(wb)) => (fa * wa + fb * wb) / (fa + fb),
+                    (Some(w), None) | (None, Some(w)) => w,
+                    (None, None) => continue,
+                };
+                merged.push((t, self.clip_weight(w)));
+            }
+
+            if let MergeStrategy::TopK { k } = strategy {
+                merged.sort_by(|x, y| y.1.abs().total_cmp(&x.1.abs()).then(x.0.cmp(&y.0)));
+                merged.truncate(k);
+            }
+            for &(t, w) in &merged {
+                targets.push(t);
+                weights.push(w);
+            }
+        }
+        offsets.push(targets.len());
+
+        let mut out = self.clone();
+        let len = targets.len();
+        out.connections = CsrConnections {
+            targets,
+            weights,
+            offsets,
+        };
+        out.eligibility = vec![0.0; len];
+        if !out.edge_last_update.is_empty() {
+            out.edge_last_update = vec![out.age_steps; len];
+            out.edge_weight_snapshot = out.connections.weights.clone();
+        }
+        if !out.fast_weights.is_empty() {
+            out.fast_weights = vec![0.0; len];
+            out.fast_weight_snapshot = out.connections.weights.clone();
+        }
+        out.csr_tombstones = 0;
+        out.csr_layout_changed();
+        out.causal.merge_from(&other.causal, 0.5);
+        out
+    }
+
     /// Define a named sensor group with the specified number of units.
     ///
     /// Sensor groups receive external stimuli via [`apply_stimulus`].
@@ -3681,6 +5721,76 @@ impl Brain {
         self.intern(name);
     }
 
+    /// Move unit `id` into the `group_type` group `group_name`, creating the
+    /// group if needed.
+    ///
+    /// The unit leaves whatever sensor/action group it was in; weights,
+    /// amplitude, phase and bias are untouched. Membership caches, routing and
+    /// [`Brain::io_groups_fingerprint`] follow the new layout. A group left
+    /// empty stays registered. Concept units and latent-module units cannot be
+    /// reassigned, and `group_name` must not name a group of another type.
+    pub fn assign_unit_to_group(
+        &mut self,
+        id: UnitId,
+        group_name: &str,
+        group_type: GroupType,
+    ) -> Result<(), &'static str> {
+        if id >= self.units.len() {
+            return Err("unit id out of range");
+        }
+        if self.latent_groups.iter().any(|g| g.units.contains(&id)) {
+            return Err("unit belongs to a latent module");
+        }
+        if self.reserved[id] && !self.group_member[id] {
+            return Err("unit is a concept unit");
+        }
+        let (other_type, module_kind) = match group_type {
+            GroupType::Sensor => (&self.action_groups, "sensor"),
+            GroupType::Action => (&self.sensor_groups, "action"),
+        };
+        if other_type.iter().any(|g| g.name == group_name)
+            || self.latent_groups.iter().any(|g| g.name == group_name)
+        {
+            return Err("group name is used by a group of another type");
+        }
+
+        for g in self
+            .sensor_groups
+            .iter_mut()
+            .chain(self.action_groups.iter_mut())
+        {
+            g.units.retain(|&u| u != id);
+        }
+
+        let groups = match group_type {
+            GroupType::Sensor => &mut self.sensor_groups,
+            GroupType::Action => &mut self.action_groups,
+        };
+        match groups.iter_mut().find(|g| g.name == group_name) {
+            Some(g) => g.units.push(id),
+            None => {
+                groups.push(NamedGroup {
+                    name: group_name.to_string(),
+                    units: vec![id],
+                });
+                if group_type == GroupType::Sensor {
+                    let idx = self.sensor_groups.len() - 1;
+                    self.sensor_group_index.insert(group_name.to_string(), idx);
+                }
+                self.intern(group_name);
+            }
+        }
+
+        self.reserved[id] = true;
+        self.rebuild_group_membership();
+        let module = self.ensure_routing_module(module_kind, group_name);
+        if id < self.unit_module.len() {
+            self.unit_module[id] = module;
+        }
+        self.module_unit_counts_dirty = true;
+        Ok(())
+    }
+
     /// Define a named latent module with the specified number of units.
     ///
     /// Latent modules are internal learning/routing partitions that are persisted
@@ -3819,6 +5929,152 @@ impl Brain {
         }
     }
 
+    /// Apply a one-shot structured perturbation to the unit state.
+    ///
+    /// Returns the number of units touched, or an error when a parameter is
+    /// out of range or the named sensor group does not exist. Amplitudes stay
+    /// clamped to `[-2, 2]` and phases are re-wrapped, as in `step()`.
+    pub fn apply_structured_noise(&mut self, pattern: &NoisePattern) -> Result<usize, String> {
+        match pattern {
+            NoisePattern::Uniform { sigma } => {
+                Self::check_noise_sigma(*sigma)?;
+                for i in 0..self.units.len() {
+                    self.jitter_unit(i, *sigma);
+                }
+                Ok(self.units.len())
+            }
+            NoisePattern::PerGroup { group, sigma } => {
+                Self::check_noise_sigma(*sigma)?;
+                let units = self.noise_group_units(group)?;
+                for &id in &units {
+                    self.jitter_unit(id, *sigma);
+                }
+                Ok(units.len())
+            }
+            NoisePattern::Occlusion { group, fraction } => {
+                if !fraction.is_finite() || !(0.0..=1.0).contains(fraction) {
+                    return Err("occlusion fraction must be in [0, 1]".to_string());
+                }
+                let mut units = self.noise_group_units(group)?;
+                // Partial Fisher-Yates: the first `n` entries are a uniform sample.
+                let n = ((units.len() as f32) * fraction).round() as usize;
+                for i in 0..n {
+                    let j = self.rng.gen_range_usize(i, units.len());
+                    units.swap(i, j);
+                }
+                for &id in &units[..n] {
+                    self.pending_input[id] = 0.0;
+                    self.units[id].amp = 0.0;
+                }
+                Ok(n)
+            }
+            NoisePattern::Adversarial { units, direction } => {
+                if !direction.is_finite() {
+                    return Err("adversarial direction must be finite".to_string());
+                }
+                if let Some(&bad) = units.iter().find(|&&id| id >= self.units.len()) {
+                    return Err(format!("unit {bad} out of range"));
+                }
+                for &id in units {
+                    let u = &mut self.units[id];
+                    u.amp = (u.amp + direction).clamp(-2.0, 2.0);
+                }
+                Ok(units.len())
+            }
+        }
+    }
+
+    fn check_noise_sigma(sigma: f32) -> Result<(), String> {
+        if !sigma.is_finite() || sigma < 0.0 {
+            return Err("noise sigma must be finite and >= 0".to_string());
+        }
+        Ok(())
+    }
+
+    fn noise_group_units(&self, group: &str) -> Result<Vec<UnitId>, String> {
+        self.sensor_units(group)
+            .map(|u| u.to_vec())
+            .ok_or_else(|| format!("unknown sensor group '{group}'"))
+    }
+
+    fn jitter_unit(&mut self, id: UnitId, sigma: f32) {
+        let da = self.rng.gen_range_f32(-sigma, sigma);
+        let dp = self.rng.gen_range_f32(-sigma, sigma);
+        let u = &mut self.units[id];
+        u.amp = (u.amp + da).clamp(-2.0, 2.0);
+        u.phase = wrap_angle(u.phase + dp);
+    }
+
+    /// Subtract `strength` from the current amplitude of every unit within
+    /// `radius` hops of `source` along outgoing connections (BFS, `source`
+    /// itself excluded). Amplitudes stay in `[-2, 2]`.
+    ///
+    /// Local winner-take-all for spatial codes such as position bins, where
+    /// [`BrainConfig::global_inhibition`] would suppress everything equally.
+    /// Only the amplitude buffer is touched, not weights; the next step's
+    /// dynamics see the result. Returns the number of units affected (0 for an
+    /// out-of-range `source`).
+    pub fn apply_lateral_inhibition(
+        &mut self,
+        source: UnitId,
+        radius: usize,
+        strength: f32,
+    ) -> usize {
+        self.shift_neighborhood_amp(source, radius, -strength)
+    }
+
+    /// Counterpart of [`Brain::apply_lateral_inhibition`]: add `strength` to the
+    /// amplitude of the units within `radius` hops of `source`.
+    pub fn apply_lateral_excitation(
+        &mut self,
+        source: UnitId,
+        radius: usize,
+        strength: f32,
+    ) -> usize {
+        self.shift_neighborhood_amp(source, radius, strength)
+    }
+
+    fn shift_neighborhood_amp(&mut self, source: UnitId, radius: usize, delta: f32) -> usize {
+        if !delta.is_finite() {
+            return 0;
+        }
+        let reached = self.units_within_hops(source, radius);
+        for &id in &reached {
+            let u = &mut self.units[id];
+            u.amp = (u.amp + delta).clamp(-2.0, 2.0);
+        }
+        reached.len()
+    }
+
+    /// Units reachable from `source` in `1..=radius` outgoing hops, in BFS order.
+    fn units_within_hops(&self, source: UnitId, radius: usize) -> Vec<UnitId> {
+        let n = self.units.len();
+        if source >= n || radius == 0 {
+            return Vec::new();
+        }
+        let mut seen = vec![false; n];
+        seen[source] = true;
+        let mut reached = Vec::new();
+        let mut frontier = vec![source];
+        for _ in 0..radius {
+            let mut next = Vec::new();
+            for &u in &frontier {
+                for (t, _) in self.neighbors(u) {
+                    if !seen[t] {
+                        seen[t] = true;
+                        next.push(t);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            reached.extend_from_slice(&next);
+            frontier = next;
+        }
+        reached
+    }
+
     #[inline]
     fn build_compound_symbol<'a>(buf: &'a mut [u8; 256], parts: &[&str]) -> Option<&'a str> {
         let mut idx: usize = 0;
@@ -3866,11 +6122,54 @@ impl Brain {
     /// This avoids returning `&str` that would keep borrowing `self` across subsequent
     /// mutation steps (important for tight control loops like `brained`).
     pub fn select_action_with_meaning_index(&self, stimulus: &str, alpha: f32) -> (usize, f32) {
+        let mut best: Option<(usize, f32)> = None;
+        for (idx, score) in self.meaning_action_scores(stimulus, alpha) {
+            if best.as_ref().map(|b| score > b.1).unwrap_or(true) {
+                best = Some((idx, score));
+            }
+        }
+
+        best.unwrap_or((usize::MAX, 0.0))
+    }
+
+    /// Like [`Brain::select_action_with_meaning`], plus a confidence in `[0, 1]`:
+    /// the normalized gap between the best and second-best score (see
+    /// [`action_confidence`]). Near 0 means the top two actions are tied.
+    #[must_use]
+    pub fn select_action_with_confidence(&self, stimulus: &str, alpha: f32) -> (String, f32, f32) {
+        let mut top: Option<(usize, f32)> = None;
+        let mut second: Option<f32> = None;
+        for (idx, score) in self.meaning_action_scores(stimulus, alpha) {
+            match top {
+                Some((_, best)) if score <= best => {
+                    if !matches!(second, Some(s) if s >= score) {
+                        second = Some(score);
+                    }
+                }
+                _ => {
+                    second = top.map(|(_, best)| best);
+                    top = Some((idx, score));
+                }
+            }
+        }
+        let Some((idx, score)) = top else {
+            return ("idle".to_string(), 0.0, 0.0);
+        };
+        let confidence = second.map_or(1.0, |s2| action_confidence(score, s2));
+        let act = self.action_name(idx).unwrap_or("idle");
+        (act.to_string(), score, confidence)
+    }
+
+    /// Habit + meaning score of every action group (index, score), in group order.
+    fn meaning_action_scores<'a>(
+        &'a self,
+        stimulus: &'a str,
+        alpha: f32,
+    ) -> impl Iterator<Item = (usize, f32)> + 'a {
         let alpha = alpha.clamp(0.0, 20.0);
         let stimulus_id = self.symbol_id(stimulus);
 
-        let mut best: Option<(usize, f32)> = None;
-        for (idx, g) in self.action_groups.iter().enumerate() {
+        self.action_groups.iter().enumerate().map(move |(idx, g)| {
             let action_name: &str = g.name.as_str();
 
             // Habit readout: treat negative amplitude as "inactive" and normalize to ~[0,1].
@@ -3905,13 +6204,46 @@ impl Brain {
                 0.0
             };
 
-            let score = habit_norm * 0.5 + alpha * meaning;
-            if best.as_ref().map(|b| score > b.1).unwrap_or(true) {
-                best = Some((idx, score));
+            (idx, habit_norm * 0.5 + alpha * meaning)
+        })
+    }
+
+    /// Run each input on its own clone of this brain and report the results.
+    ///
+    /// Every clone applies its stimuli via [`Brain::apply_stimulus_inference`],
+    /// advances [`Brain::step_inference`] `steps` times, then scores actions for
+    /// `context_key`. `self` is never modified. With the `parallel` feature the
+    /// clones run on the rayon pool.
+    #[cfg(feature = "std")]
+    pub fn step_batch(&self, inputs: &[BrainInput]) -> Vec<BrainOutput> {
+        let run = |input: &BrainInput| {
+            let mut brain = self.clone();
+            for stim in &input.stimuli {
+                brain.apply_stimulus_inference(stim.as_stimulus());
+            }
+            for _ in 0..input.steps {
+                brain.step_inference();
+            }
+            BrainOutput {
+                action_scores: brain
+                    .action_score_breakdown(&input.context_key, input.meaning_alpha),
+                diagnostics: brain.diagnostics(),
+                action_debug: if input.verbose {
+                    brain.ranked_actions_with_meaning_debug(&input.context_key, input.meaning_alpha)
+                } else {
+                    Vec::new()
+                },
             }
-        }
+        };
 
-        best.unwrap_or((usize::MAX, 0.0))
+        #[cfg(feature = "parallel")]
+        {
+            inputs.par_iter().map(run).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            inputs.iter().map(run).collect()
+        }
     }
 
     /// Compute the per-action breakdown used by `select_action_with_meaning_index`.
@@ -3975,6 +6307,29 @@ impl Brain {
         out
     }
 
+    /// [`Brain::action_score_breakdown`] for every registered sensor, keyed by
+    /// sensor name.
+    ///
+    /// Gives a full view of the preferred action per context (e.g. all 16
+    /// SpotXY position sensors) in one call. With the `parallel` feature the
+    /// contexts are scored on the rayon pool.
+    #[cfg(feature = "std")]
+    pub fn action_score_breakdown_all_contexts(
+        &self,
+        alpha: f32,
+    ) -> HashMap<String, Vec<ActionScoreBreakdown>> {
+        let run = |g: &NamedGroup| (g.name.clone(), self.action_score_breakdown(&g.name, alpha));
+
+        #[cfg(feature = "parallel")]
+        {
+            self.sensor_groups.par_iter().map(run).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.sensor_groups.iter().map(run).collect()
+        }
+    }
+
     /// Return causal edge strengths from `pair::<stimulus>::<action>` to `reward_pos/reward_neg`.
     ///
     /// This is allocation-free and intended for UI/debugging.
@@ -3993,6 +6348,19 @@ impl Brain {
         }
     }
 
+    /// Return `pair::<stimulus>::<action>` reward edges for every registered
+    /// action group, sorted by descending meaning (ties keep registration order).
+    #[cfg(feature = "std")]
+    pub fn pair_reward_edges_all_actions(&self, stimulus: &str) -> Vec<(String, RewardEdges)> {
+        let mut out: Vec<(String, RewardEdges)> = self
+            .action_groups
+            .iter()
+            .map(|g| (g.name.clone(), self.pair_reward_edges(stimulus, &g.name)))
+            .collect();
+        out.sort_by(|a, b| b.1.meaning.total_cmp(&a.1.meaning));
+        out
+    }
+
     /// Return causal edge strengths from an action symbol to `reward_pos/reward_neg`.
     ///
     /// This is allocation-free and intended for UI/debugging.
@@ -4011,6 +6379,22 @@ impl Brain {
         }
     }
 
+    /// Return [`action_reward_edges`](Self::action_reward_edges) for every
+    /// registered action group, sorted by descending meaning (ties keep
+    /// registration order). Together with
+    /// [`pair_reward_edges_all_actions`](Self::pair_reward_edges_all_actions)
+    /// this gives the global and per-stimulus rows of the meaning matrix.
+    #[cfg(feature = "std")]
+    pub fn action_reward_edges_all(&self) -> Vec<(String, RewardEdges)> {
+        let mut out: Vec<(String, RewardEdges)> = self
+            .action_groups
+            .iter()
+            .map(|g| (g.name.clone(), self.action_reward_edges(&g.name)))
+            .collect();
+        out.sort_by(|a, b| b.1.meaning.total_cmp(&a.1.meaning));
+        out
+    }
+
     /// Borrow the action name for an action group index.
     #[must_use]
     pub fn action_name(&self, index: usize) -> Option<&str> {
@@ -4085,12 +6469,31 @@ impl Brain {
     /// - select_action + note_action
     /// - (optional) reinforce_action
     pub fn commit_observation(&mut self) {
-        // Map reward scalar to discrete events.
-        if self.neuromod > self.cfg.reward_symbol_threshold {
-            self.active_symbols.push(self.reward_pos_symbol);
-        } else if self.neuromod < -self.cfg.reward_symbol_threshold {
-            self.active_symbols.push(self.reward_neg_symbol);
-        }
+        self.commit_observation_with_priority(1.0);
+    }
+
+    /// [`Brain::commit_observation`] with an importance weight for prioritized
+    /// learning.
+    ///
+    /// `priority` (clamped to `[0, 2]`, non-finite treated as 1) scales the
+    /// plasticity of the next learning [`Brain::step`], which is the step that
+    /// turns this cycle's reward into weight changes:
+    /// `hebb_rate_eff = hebb_rate * priority`. It is also stored with the
+    /// remembered observation, so [`Brain::dream_replay_with_source`] reactivates
+    /// high-priority observations proportionally more often.
+    pub fn commit_observation_with_priority(&mut self, priority: f32) {
+        self.learning_priority = if priority.is_finite() {
+            priority.clamp(0.0, 2.0)
+        } else {
+            1.0
+        };
+
+        // Map reward scalar to discrete events.
+        if self.neuromod > self.cfg.reward_symbol_threshold {
+            self.active_symbols.push(self.reward_pos_symbol);
+        } else if self.neuromod < -self.cfg.reward_symbol_threshold {
+            self.active_symbols.push(self.reward_neg_symbol);
+        }
 
         // Validate concepts that are active during sufficiently strong positive reinforcement.
         if self.neuromod > self.cfg.concept_validate_threshold {
@@ -4170,13 +6573,53 @@ impl Brain {
             self.causal_lag_history.truncate(max_hist);
         }
 
+        self.record_dream_trace();
+
         let prev_lag1: Vec<SymbolId> = self.causal.prev_symbols().to_vec();
+        let events = if self.telemetry.enabled {
+            self.telemetry.causal_events.set_step(self.age_steps);
+            Some(&mut self.telemetry.causal_events)
+        } else {
+            None
+        };
         self.causal.observe_lagged(
             &self.active_symbols,
             &self.causal_lag_history,
             self.cfg.causal_lag_decay,
+            events,
         );
 
+        // Co-occurrence window: symbols of the previous `causal_window_frames - 1`
+        // frames co-occur with this frame's. Reward events stay same-frame only,
+        // so meaning (reward association) is not smeared across trials.
+        let window_hist = self.cfg.causal_window_frames.clamp(1, CAUSAL_WINDOW_MAX) as usize - 1;
+        self.causal_window_history.truncate(window_hist);
+        if window_hist > 0 {
+            let (pos, neg) = (self.reward_pos_symbol, self.reward_neg_symbol);
+            let mut frame = if self.causal_window_history.len() >= window_hist {
+                // Reuse the oldest frame's allocation.
+                self.causal_window_history.pop().unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+            frame.clear();
+            frame.extend(
+                self.active_symbols
+                    .iter()
+                    .copied()
+                    .filter(|&s| s != pos && s != neg),
+            );
+
+            let events = if self.telemetry.enabled {
+                Some(&mut self.telemetry.causal_events)
+            } else {
+                None
+            };
+            self.causal
+                .observe_window(&frame, &self.causal_window_history, events);
+            self.causal_window_history.insert(0, frame);
+        }
+
         // Shift history: previous lag1 becomes lag2 for the next tick.
         if max_hist > 0 && !prev_lag1.is_empty() {
             self.causal_lag_history.insert(0, prev_lag1);
@@ -4253,51 +6696,97 @@ impl Brain {
 
         let mut scored: Vec<(String, f32)> = Vec::with_capacity(self.action_groups.len());
         for g in &self.action_groups {
-            let habit = g
-                .units
-                .iter()
-                .map(|&id| self.units[id].amp.max(0.0))
-                .sum::<f32>();
-            let habit_norm = if g.units.is_empty() {
-                0.0
-            } else {
-                (habit / (g.units.len() as f32 * 2.0)).clamp(0.0, 1.0)
-            };
+            let (habit_norm, meaning) = self.habit_and_meaning(g, stimulus, stimulus_id);
+            let score = habit_norm * 0.5 + alpha * meaning;
+            scored.push((g.name.clone(), score));
+        }
 
-            let meaning = if let Some(aid) = self.symbol_id(&g.name) {
-                let global = self
-                    .causal
-                    .association_strength(aid, self.reward_pos_symbol)
-                    - self
-                        .causal
-                        .association_strength(aid, self.reward_neg_symbol);
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored
+    }
 
-                let conditional = if stimulus_id.is_some() {
-                    if let Some(pid) = self.compound_symbol_id(&["pair", stimulus, g.name.as_str()])
-                    {
-                        self.causal
-                            .association_strength(pid, self.reward_pos_symbol)
-                            - self
-                                .causal
-                                .association_strength(pid, self.reward_neg_symbol)
-                    } else {
-                        0.0
-                    }
+    /// [`Brain::ranked_actions_with_meaning`] with the parts of each score.
+    ///
+    /// Same order and `composite_score` as the plain ranking, plus the reward
+    /// edges and the visit count of each `pair::<ctx>::<action>` symbol. Meant for
+    /// the advisor and for debugging why an action wins.
+    #[cfg(feature = "std")]
+    pub fn ranked_actions_with_meaning_debug(&self, ctx: &str, alpha: f32) -> Vec<ActionDebugInfo> {
+        let alpha = alpha.clamp(0.0, 20.0);
+        let stimulus_id = self.symbol_id(ctx);
+
+        let mut out: Vec<ActionDebugInfo> = Vec::with_capacity(self.action_groups.len());
+        for g in &self.action_groups {
+            let (habit_score, meaning_score) = self.habit_and_meaning(g, ctx, stimulus_id);
+            let pair_reward_edges = self.pair_reward_edges(ctx, &g.name);
+            let action_reward_edges = self.action_reward_edges(&g.name);
+            let visit_count = self
+                .compound_symbol_id(&["pair", ctx, g.name.as_str()])
+                .map(|pid| self.causal.base_count(pid))
+                .unwrap_or(0.0);
+            out.push(ActionDebugInfo {
+                name: g.name.clone(),
+                habit_score,
+                meaning_score,
+                causal_score: pair_reward_edges.meaning + action_reward_edges.meaning * 0.15,
+                composite_score: habit_score * 0.5 + alpha * meaning_score,
+                pair_reward_edges,
+                action_reward_edges,
+                visit_count,
+            });
+        }
+
+        out.sort_by(|a, b| b.composite_score.total_cmp(&a.composite_score));
+        out
+    }
+
+    /// Normalized habit readout and association-based meaning of one action
+    /// group, as scored by [`Brain::ranked_actions_with_meaning`].
+    fn habit_and_meaning(
+        &self,
+        g: &NamedGroup,
+        stimulus: &str,
+        stimulus_id: Option<SymbolId>,
+    ) -> (f32, f32) {
+        let habit = g
+            .units
+            .iter()
+            .map(|&id| self.units[id].amp.max(0.0))
+            .sum::<f32>();
+        let habit_norm = if g.units.is_empty() {
+            0.0
+        } else {
+            (habit / (g.units.len() as f32 * 2.0)).clamp(0.0, 1.0)
+        };
+
+        let meaning = if let Some(aid) = self.symbol_id(&g.name) {
+            let global = self
+                .causal
+                .association_strength(aid, self.reward_pos_symbol)
+                - self
+                    .causal
+                    .association_strength(aid, self.reward_neg_symbol);
+
+            let conditional = if stimulus_id.is_some() {
+                if let Some(pid) = self.compound_symbol_id(&["pair", stimulus, g.name.as_str()]) {
+                    self.causal
+                        .association_strength(pid, self.reward_pos_symbol)
+                        - self
+                            .causal
+                            .association_strength(pid, self.reward_neg_symbol)
                 } else {
                     0.0
-                };
-
-                conditional * 1.0 + global * 0.15
+                }
             } else {
                 0.0
             };
 
-            let score = habit_norm * 0.5 + alpha * meaning;
-            scored.push((g.name.clone(), score));
-        }
+            conditional * 1.0 + global * 0.15
+        } else {
+            0.0
+        };
 
-        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
-        scored
+        (habit_norm, meaning)
     }
 
     pub fn top_actions_with_meaning(
@@ -4324,6 +6813,151 @@ impl Brain {
             .collect()
     }
 
+    /// The `top_n` symbols whose directed causal edges most strongly predict `to`.
+    ///
+    /// Reverse of [`Brain::top_causal_links_from`], e.g. "what preceded `reward_pos`?".
+    /// Sorted by descending causal strength.
+    pub fn causal_predecessor_symbols(&self, to: &str, top_n: usize) -> Vec<(String, f32)> {
+        let Some(b) = self.symbol_id(to) else {
+            return Vec::new();
+        };
+
+        self.causal
+            .top_incoming(b, top_n)
+            .into_iter()
+            .filter_map(|(aid, s)| self.symbol_name(aid).map(|name| (name.to_string(), s)))
+            .collect()
+    }
+
+    /// Every causal edge leaving `from` as `(to, transition_count, cooccur_count)`.
+    ///
+    /// Raw decayed counts (see [`CausalMemory::edge_counts_from`]); empty if
+    /// `from` is unknown.
+    pub fn causal_edges_from(&self, from: &str) -> Vec<(String, f32, f32)> {
+        let Some(a) = self.symbol_id(from) else {
+            return Vec::new();
+        };
+
+        self.causal
+            .edge_counts_from(a)
+            .into_iter()
+            .filter_map(|(bid, t, c)| self.symbol_name(bid).map(|name| (name.to_string(), t, c)))
+            .collect()
+    }
+
+    /// Decayed number of observations committed with symbol `name` (0 if unknown).
+    #[must_use]
+    pub fn causal_symbol_count(&self, name: &str) -> f32 {
+        self.symbol_id(name)
+            .map_or(0.0, |id| self.causal.base_count(id))
+    }
+
+    /// How similar two context symbols are in what follows them causally, in `[0, 1]`.
+    ///
+    /// Cosine of their outgoing association profiles (see
+    /// [`CausalMemory::context_similarity`]); `0.0` if either name is unknown.
+    pub fn causal_context_similarity(&self, a: &str, b: &str) -> f32 {
+        if a == b {
+            return 1.0;
+        }
+        match (self.symbol_id(a), self.symbol_id(b)) {
+            (Some(a), Some(b)) => self.causal.context_similarity(a, b),
+            _ => 0.0,
+        }
+    }
+
+    /// How well established the event chain `symbols[0] -> symbols[1] -> ...` is, in `[0, 1]`.
+    ///
+    /// Product of the directed [`CausalMemory::causal_strength`] of every link,
+    /// each clamped to `[0, 1]`. A link with no transition edge (or an unknown
+    /// symbol) makes the whole chain 0, as does a chain of fewer than two symbols.
+    #[must_use]
+    pub fn causal_chain_score(&self, symbols: &[&str]) -> f32 {
+        if symbols.len() < 2 {
+            return 0.0;
+        }
+        let mut score = 1.0f32;
+        for pair in symbols.windows(2) {
+            let (Some(a), Some(b)) = (self.symbol_id(pair[0]), self.symbol_id(pair[1])) else {
+                return 0.0;
+            };
+            if self.causal.transition_count(a, b) <= 0.0 {
+                return 0.0;
+            }
+            score *= self.causal.causal_strength(a, b).clamp(0.0, 1.0);
+        }
+        score.clamp(0.0, 1.0)
+    }
+
+    /// Most likely temporal order of `symbols`, as a permutation of their indices.
+    ///
+    /// Topological sort over the directed causal edges between the given
+    /// symbols (weights as in [`causal_chain_score`](Self::causal_chain_score)).
+    /// Among symbols with no incoming edge from those still unplaced, the one
+    /// with the least total incoming weight goes first, then the lowest index.
+    /// When a cycle leaves no such symbol, the one with the least incoming
+    /// weight from the unplaced symbols breaks it. Unknown symbols have no edges.
+    ///
+    /// Runs in `O(n²)` time and memory for `n` symbols.
+    #[must_use]
+    pub fn causal_temporal_order(&self, symbols: &[&str]) -> Vec<usize> {
+        let n = symbols.len();
+        let ids: Vec<Option<SymbolId>> = symbols.iter().map(|s| self.symbol_id(s)).collect();
+        // weight[i * n + j]: directed strength symbols[i] -> symbols[j].
+        let mut weight = vec![0.0f32; n * n];
+        for (i, a) in ids.iter().enumerate() {
+            for (j, b) in ids.iter().enumerate() {
+                let (Some(a), Some(b)) = (*a, *b) else {
+                    continue;
+                };
+                if i != j && self.causal.transition_count(a, b) > 0.0 {
+                    weight[i * n + j] = self.causal.causal_strength(a, b).clamp(0.0, 1.0);
+                }
+            }
+        }
+        let total_in: Vec<f32> = (0..n)
+            .map(|j| (0..n).map(|i| weight[i * n + j]).sum())
+            .collect();
+
+        // Incoming weight (and edge count) from symbols not yet placed, kept up
+        // to date as symbols are placed. The count makes "no pending edges"
+        // exact even when the subtracted weights leave rounding residue.
+        let mut pending_in = total_in.clone();
+        let mut pending_edges: Vec<usize> = (0..n)
+            .map(|j| (0..n).filter(|&i| weight[i * n + j] > 0.0).count())
+            .collect();
+        let mut placed = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        while order.len() < n {
+            let key = |j: usize| -> f32 {
+                if pending_edges[j] == 0 {
+                    0.0
+                } else {
+                    pending_in[j]
+                }
+            };
+            let next = (0..n)
+                .filter(|&j| !placed[j])
+                .min_by(|&a, &b| {
+                    key(a)
+                        .total_cmp(&key(b))
+                        .then_with(|| total_in[a].total_cmp(&total_in[b]))
+                        .then_with(|| a.cmp(&b))
+                })
+                .unwrap_or(0);
+            placed[next] = true;
+            order.push(next);
+            for j in 0..n {
+                let w = weight[next * n + j];
+                if w > 0.0 {
+                    pending_in[j] -= w;
+                    pending_edges[j] -= 1;
+                }
+            }
+        }
+        order
+    }
+
     /// Predict the most likely next context symbols given `(stimulus, action)`.
     ///
     /// Uses the `pair::<stimulus>::<action>` symbol's outgoing causal edges to context symbols.
@@ -4452,13 +7086,51 @@ impl Brain {
     /// Set the neuromodulator (reward/salience) level.
     ///
     /// Positive values increase learning rate; negative values decrease it.
-    /// The value is clamped to [-1.0, 1.0].
+    /// The value is clamped to [-1.0, 1.0]. With a non-zero
+    /// [`neuromodulator momentum`](Self::set_neuromodulator_momentum) `m`, the
+    /// level becomes `(1 - m) * value + m * previous` instead.
     ///
     /// # Arguments
     /// * `value` - The neuromodulator level
     pub fn set_neuromodulator(&mut self, value: f32) {
         // Clamp to a reasonable range.
-        self.neuromod = value.clamp(-1.0, 1.0);
+        let value = value.clamp(-1.0, 1.0);
+        let m = self.cfg.neuromodulator_momentum;
+        self.neuromod = if m > 0.0 {
+            ((1.0 - m) * value + m * self.neuromod).clamp(-1.0, 1.0)
+        } else {
+            value
+        };
+    }
+
+    /// Smooth noisy rewards: later [`set_neuromodulator`](Self::set_neuromodulator)
+    /// calls blend into the previous level with this momentum. Clamped to
+    /// `[0, 0.99]` (non-finite → 0); 0.0 restores instant rewards.
+    pub fn set_neuromodulator_momentum(&mut self, momentum: f32) {
+        self.cfg.neuromodulator_momentum = if momentum.is_finite() {
+            momentum.clamp(0.0, 0.99)
+        } else {
+            0.0
+        };
+    }
+
+    /// Current neuromodulator momentum (see [`Brain::set_neuromodulator_momentum`]).
+    #[must_use]
+    pub fn neuromodulator_momentum(&self) -> f32 {
+        self.cfg.neuromodulator_momentum
+    }
+
+    /// Set the neuromodulator to the reward prediction error for TD-style learning.
+    ///
+    /// The neuromodulator becomes `(actual - predicted).clamp(-1.0, 1.0)` instead
+    /// of the raw reward, so fully expected outcomes stop driving plasticity.
+    /// `|RPE|` is also folded into [`LearningStats::td_prediction_error_ema`].
+    /// Returns the clamped RPE.
+    pub fn reward_prediction_error(&mut self, predicted: f32, actual: f32) -> f32 {
+        let rpe = (actual - predicted).clamp(-1.0, 1.0);
+        self.td_error_ema += TD_ERROR_ALPHA * (rpe.abs() - self.td_error_ema);
+        self.set_neuromodulator(rpe);
+        rpe
     }
 
     /// Reinforce an action by adjusting the bias of its units.
@@ -4483,6 +7155,81 @@ impl Brain {
         }
     }
 
+    /// Contrastive counterpart of a positive trial: mark choosing `action` under
+    /// `stimulus` as wrong without touching any other pairing.
+    ///
+    /// Adds one `pair::stimulus::action → reward_neg` transition to causal memory
+    /// (so the meaning score of exactly this pair drops) and halves the
+    /// eligibility traces on edges from the stimulus into the action group, so
+    /// the next reward cannot credit them. When `stimulus` is not a sensor group
+    /// (e.g. a compound context key), edges from every unit currently above
+    /// `coactive_threshold` are used instead. Unlike `reinforce_action(action, -r)`,
+    /// the action's bias and its pairings with other stimuli are unchanged.
+    pub fn note_negative_example(&mut self, stimulus: &str, action: &str) {
+        let Some(group) = self.action_groups.iter().position(|g| g.name == action) else {
+            return;
+        };
+
+        let mut buf = [0u8; 256];
+        if let Some(name) = Self::build_compound_symbol(&mut buf, &["pair", stimulus, action]) {
+            let pair = self.intern(name);
+            let events = if self.telemetry.enabled {
+                self.telemetry.causal_events.set_step(self.age_steps);
+                Some(&mut self.telemetry.causal_events)
+            } else {
+                None
+            };
+            self.causal
+                .observe_transition(pair, self.reward_neg_symbol, 1.0, events);
+        }
+
+        let sources: Vec<UnitId> = match self.sensor_units(stimulus) {
+            Some(units) => units.to_vec(),
+            None => (0..self.units.len())
+                .filter(|&i| self.units[i].amp > self.cfg.coactive_threshold)
+                .collect(),
+        };
+        let targets = &self.action_groups[group].units;
+        for src in sources {
+            for idx in self.conn_range(src) {
+                let t = self.connections.targets[idx];
+                if t != INVALID_UNIT && targets.contains(&t) {
+                    if let Some(e) = self.eligibility.get_mut(idx) {
+                        *e *= 0.5;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Read connection storage in the order [`Brain::step`] visits it.
+    ///
+    /// Pulls unit state, CSR offsets/targets/weights and eligibility into cache
+    /// so the first steps after a load or resume avoid cold misses. This is a
+    /// pure hint: no state is modified.
+    pub fn warm_cache(&self) {
+        let mut amp = 0.0f32;
+        for u in &self.units {
+            amp += u.amp + u.phase;
+        }
+
+        let mut weight = 0.0f32;
+        let mut target = 0usize;
+        for w in self.connections.offsets.windows(2) {
+            for idx in w[0]..w[1] {
+                target = target.wrapping_add(self.connections.targets[idx]);
+                weight += self.connections.weights[idx];
+            }
+        }
+
+        let mut elig = 0.0f32;
+        for &e in &self.eligibility {
+            elig += e;
+        }
+
+        core::hint::black_box((amp, weight, target, elig));
+    }
+
     /// Advance the simulation by one timestep.
     ///
     /// This is the main update loop that:
@@ -4494,7 +7241,52 @@ impl Brain {
     /// Call this once per control cycle after applying stimuli and setting
     /// the neuromodulator.
     pub fn step(&mut self) {
+        self.step_with_clock(&mut ());
+    }
+
+    /// Run one [`Brain::step`] and report how long each phase took.
+    #[cfg(feature = "std")]
+    pub fn profile_step(&mut self) -> StepProfile {
+        let start = std::time::Instant::now();
+        let mut clock = ProfileClock {
+            last: start,
+            profile: StepProfile::default(),
+        };
+        self.step_with_clock(&mut clock);
+        clock.profile.total_ns = start.elapsed().as_nanos() as u64;
+        clock.profile
+    }
+
+    /// Run one full control cycle and report how long each phase took.
+    ///
+    /// Applies `stimuli` with [`Brain::apply_stimulus`] (timed as
+    /// `imprint_ns`), runs [`Brain::step`], then
+    /// [`Brain::commit_observation`] (timed as `causal_ns`).
+    #[cfg(feature = "std")]
+    pub fn profile_cycle(&mut self, stimuli: &[Stimulus<'_>]) -> StepProfile {
+        let start = std::time::Instant::now();
+        for &stimulus in stimuli {
+            self.apply_stimulus(stimulus);
+        }
+        let imprint_ns = start.elapsed().as_nanos() as u64;
+
+        let mut clock = ProfileClock {
+            last: std::time::Instant::now(),
+            profile: StepProfile::default(),
+        };
+        self.step_with_clock(&mut clock);
+
+        let commit = std::time::Instant::now();
+        self.commit_observation();
+        clock.profile.causal_ns = commit.elapsed().as_nanos() as u64;
+        clock.profile.imprint_ns = imprint_ns;
+        clock.profile.total_ns = start.elapsed().as_nanos() as u64;
+        clock.profile
+    }
+
+    fn step_with_clock<C: PhaseClock>(&mut self, clock: &mut C) {
         self.pruned_last_step = 0;
+        self.sync_fast_weight_snapshot();
 
         // Reset per-step monitors.
         self.learning_monitors = LearningMonitors::default();
@@ -4535,17 +7327,55 @@ impl Brain {
             *x = 0.0;
         }
 
-        // Eligibility traces always update (local and cheap).
-        self.update_eligibility_scalar();
+        self.update_novelty();
+        self.record_activation_frame();
+        clock.lap(StepPhase::Oscillator);
 
-        // Plasticity is committed only when neuromodulation is present.
-        self.apply_plasticity_scalar();
+        // Eligibility traces always update (local and cheap), and plasticity
+        // is committed only when neuromodulation is present.
+        match self.effective_execution_tier() {
+            ExecutionTier::Simd => {
+                self.update_eligibility_simd();
+                self.apply_plasticity_simd();
+            }
+            _ => {
+                self.update_eligibility_scalar();
+                self.apply_plasticity_scalar();
+            }
+        }
+        self.learning_priority = 1.0;
+
+        // Unsupervised principal-component learning for selected units.
+        if let Some(scale) = self.cfg.experimental_flag("oja_units_all") {
+            let rate = self.cfg.hebb_rate * scale;
+            for unit in 0..self.units.len() {
+                self.apply_oja_rule(unit, rate);
+            }
+        } else if !self.oja_units.is_empty() {
+            let rate = self.cfg.hebb_rate;
+            for k in 0..self.oja_units.len() {
+                self.apply_oja_rule(self.oja_units[k], rate);
+            }
+        }
+        clock.lap(StepPhase::Hebbian);
 
         self.forget_and_prune();
+        clock.lap(StepPhase::Pruning);
+
+        if self.cfg.experimental_flag("sorted_adjacency").is_some() {
+            self.refresh_sorted_adjacency();
+            clock.lap(StepPhase::SortedAdjacency);
+        }
 
         self.update_growth_signals();
+        let edge_n = self.connections.weights.len().max(1) as f32;
+        let rate = self.learning_monitors.plasticity_l1 / edge_n;
+        self.plasticity_rate_ema += PLASTICITY_RATE_ALPHA * (rate - self.plasticity_rate_ema);
+        self.plasticity_rate_steps = self.plasticity_rate_steps.saturating_add(1);
+        clock.lap(StepPhase::Neurogenesis);
 
         self.homeostasis_step();
+        clock.lap(StepPhase::Oscillator);
     }
 
     fn update_growth_signals(&mut self) {
@@ -4608,41 +7438,159 @@ impl Brain {
         for x in &mut self.pending_input {
             *x = 0.0;
         }
+
+        self.update_novelty();
+        self.record_activation_frame();
     }
 
-    /// Compute global inhibition signal based on inhibition_mode.
-    fn compute_inhibition(&self) -> f32 {
-        let avg = match self.cfg.inhibition_mode {
-            0 => {
-                // Signed mean (legacy)
-                self.units.iter().map(|u| u.amp).sum::<f32>() / self.units.len() as f32
-            }
-            1 => {
-                // Mean absolute amplitude
-                self.units.iter().map(|u| u.amp.abs()).sum::<f32>() / self.units.len() as f32
-            }
-            2 => {
-                // Rectified mean (max(0, a))
-                self.units.iter().map(|u| u.amp.max(0.0)).sum::<f32>() / self.units.len() as f32
-            }
-            _ => {
-                // Default to signed mean
-                self.units.iter().map(|u| u.amp).sum::<f32>() / self.units.len() as f32
+    /// Curiosity signal in `[0, 1]`: the fraction of units coactive on the most
+    /// recent step that were not coactive in the previous
+    /// [`BrainConfig::novelty_window`] steps.
+    ///
+    /// High values mean the current activation pattern is novel; repeated
+    /// exposure to the same stimulus drives it toward 0. Returns 0 when no
+    /// unit is coactive.
+    ///
+    /// When [`Brain::set_causal_novelty_weight`] is non-zero, the estimate blends
+    /// in the mean [`Brain::causal_surprise`] of the symbols recorded for the
+    /// pending (not yet committed) observation.
+    pub fn intrinsic_reward_estimate(&self) -> f32 {
+        let w = self.causal_novelty_weight;
+        if w <= 0.0 {
+            return self.intrinsic_reward;
+        }
+
+        let (pos, neg) = (self.reward_pos_symbol, self.reward_neg_symbol);
+        let mut sum = 0.0f32;
+        let mut n = 0u32;
+        for &s in &self.active_symbols {
+            if s == pos || s == neg {
+                continue;
             }
+            sum += self.causal_surprise_id(s);
+            n += 1;
+        }
+        if n == 0 {
+            return self.intrinsic_reward;
+        }
+
+        ((1.0 - w) * self.intrinsic_reward + w * (sum / n as f32)).clamp(0.0, 1.0)
+    }
+
+    /// Set how much causal surprise contributes to
+    /// [`Brain::intrinsic_reward_estimate`], clamped to `[0, 1]`.
+    ///
+    /// 0 (the default) keeps the estimate purely unit-level novelty. Runtime
+    /// only; not stored in brain images.
+    pub fn set_causal_novelty_weight(&mut self, weight: f32) {
+        self.causal_novelty_weight = if weight.is_finite() {
+            weight.clamp(0.0, 1.0)
+        } else {
+            0.0
         };
-        self.cfg.global_inhibition * avg
     }
 
-    /// Scalar (baseline) dynamics update.
-    fn step_dynamics_scalar(&mut self) {
-        let inhibition = self.compute_inhibition();
+    #[must_use]
+    pub fn causal_novelty_weight(&self) -> f32 {
+        self.causal_novelty_weight
+    }
 
-        let mut next_amp = vec![0.0; self.units.len()];
-        let mut next_phase = vec![0.0; self.units.len()];
+    /// How unexpected `symbol` is given what just happened, in `[0, 1]`.
+    ///
+    /// Looks at every symbol of the recent committed frames (the lag-1 frame,
+    /// the lagged history and the co-occurrence window) and takes the strongest
+    /// directed [`CausalMemory::causal_strength`] from any of them to `symbol`.
+    /// The surprise is `1 - max_strength.clamp(0, 1)`: 1.0 when nothing that
+    /// just fired predicts `symbol` (including unknown symbols), approaching 0
+    /// when its usual predecessors just fired.
+    #[must_use]
+    pub fn causal_surprise(&self, symbol: &str) -> f32 {
+        match self.symbol_id(symbol) {
+            Some(id) => self.causal_surprise_id(id),
+            None => 1.0,
+        }
+    }
 
-        for i in 0..self.units.len() {
-            let u = &self.units[i];
-            let mut influence_amp = 0.0;
+    fn causal_surprise_id(&self, symbol: SymbolId) -> f32 {
+        let frames = core::iter::once(self.causal.prev_symbols())
+            .chain(self.causal_lag_history.iter().map(Vec::as_slice))
+            .chain(self.causal_window_history.iter().map(Vec::as_slice));
+
+        let mut best = 0.0f32;
+        for frame in frames {
+            for &pred in frame {
+                if pred == symbol {
+                    continue;
+                }
+                best = best.max(self.causal.causal_strength(pred, symbol));
+            }
+        }
+        1.0 - best.clamp(0.0, 1.0)
+    }
+
+    fn update_novelty(&mut self) {
+        let n = self.units.len();
+        if self.coactive_last_step.len() != n {
+            self.coactive_last_step.resize(n, NEVER_COACTIVE);
+        }
+
+        let now = self.age_steps;
+        let window = self.cfg.novelty_window.max(1) as u64;
+        let thr = self.cfg.coactive_threshold;
+
+        let mut active = 0u32;
+        let mut novel = 0u32;
+        for (u, last) in self.units.iter().zip(self.coactive_last_step.iter_mut()) {
+            if u.amp <= thr {
+                continue;
+            }
+            active += 1;
+            if *last == NEVER_COACTIVE || now.saturating_sub(*last) > window {
+                novel += 1;
+            }
+            *last = now;
+        }
+
+        self.intrinsic_reward = if active == 0 {
+            0.0
+        } else {
+            novel as f32 / active as f32
+        };
+    }
+
+    /// Compute global inhibition signal based on inhibition_mode.
+    fn compute_inhibition(&self) -> f32 {
+        let avg = match self.cfg.inhibition_mode {
+            0 => {
+                // Signed mean (legacy)
+                self.units.iter().map(|u| u.amp).sum::<f32>() / self.units.len() as f32
+            }
+            1 => {
+                // Mean absolute amplitude
+                self.units.iter().map(|u| u.amp.abs()).sum::<f32>() / self.units.len() as f32
+            }
+            2 => {
+                // Rectified mean (max(0, a))
+                self.units.iter().map(|u| u.amp.max(0.0)).sum::<f32>() / self.units.len() as f32
+            }
+            _ => {
+                // Default to signed mean
+                self.units.iter().map(|u| u.amp).sum::<f32>() / self.units.len() as f32
+            }
+        };
+        self.cfg.global_inhibition * avg
+    }
+
+    /// Scalar (baseline) dynamics update.
+    fn step_dynamics_scalar(&mut self) {
+        let inhibition = self.compute_inhibition();
+
+        let mut next_amp = vec![0.0; self.units.len()];
+        let mut next_phase = vec![0.0; self.units.len()];
+
+        for i in 0..self.units.len() {
+            let u = &self.units[i];
+            let mut influence_amp = 0.0;
             let mut influence_phase = 0.0;
 
             for (target, weight) in self.neighbors(i) {
@@ -5274,12 +8222,60 @@ impl Brain {
         }
     }
 
+    /// Number of units, including units grown by neurogenesis. O(1).
+    #[must_use]
+    pub fn unit_count(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Number of live (non-pruned) connections. O(1): derived from the CSR
+    /// length and the tombstone count kept up to date by pruning and growth.
+    #[must_use]
+    pub fn connection_count(&self) -> usize {
+        self.connections.targets.len() - self.csr_tombstones
+    }
+
+    /// Fraction of possible directed unit pairs that are not connected:
+    /// `1 - connection_count / unit_count²`. O(1).
+    #[must_use]
+    pub fn connection_sparsity(&self) -> f32 {
+        let n = self.units.len() as f64;
+        if n == 0.0 {
+            return 1.0;
+        }
+        (1.0 - self.connection_count() as f64 / (n * n)) as f32
+    }
+
+    /// `histogram[k]` is the number of units with exactly `k` live outgoing
+    /// connections; the last bucket is the maximum out-degree.
+    ///
+    /// O(unit_count) from the CSR offsets while nothing is pruned; once
+    /// connections are tombstoned the pruned slots are skipped, which is O(edges).
+    #[must_use]
+    pub fn unit_degree_histogram(&self) -> Vec<u32> {
+        let mut hist: Vec<u32> = Vec::new();
+        for i in 0..self.units.len() {
+            let k = if self.csr_tombstones == 0 {
+                self.conn_range(i).len()
+            } else {
+                self.conn_count(i)
+            };
+            if k >= hist.len() {
+                hist.resize(k + 1, 0);
+            }
+            hist[k] += 1;
+        }
+        hist
+    }
+
     /// Returns diagnostic information about the brain's current state.
     ///
-    /// Useful for monitoring and visualization.
+    /// Comprehensive snapshot (scans all units and weights); prefer the direct
+    /// methods such as [`Brain::unit_count`] and [`Brain::connection_count`]
+    /// on hot paths.
     #[must_use]
     pub fn diagnostics(&self) -> Diagnostics {
-        let connection_count = self.total_connection_count();
+        let connection_count = self.connection_count();
         let avg_amp =
             self.units.iter().map(|u| u.amp).sum::<Amplitude>() / self.units.len() as Amplitude;
         let avg_weight = if connection_count > 0 {
@@ -5317,9 +8313,104 @@ impl Brain {
             plasticity_budget_used: self.learning_monitors.plasticity_budget_used,
             eligibility_l1: self.learning_monitors.eligibility_l1,
             homeostasis_bias_l1: self.learning_monitors.homeostasis_bias_l1,
+            plasticity_rate: self.plasticity_rate_ema,
+            plasticity_rate_steps: self.plasticity_rate_steps,
+            td_prediction_error_ema: self.td_error_ema,
+            fast_weight_l1: self.learning_monitors.fast_weight_l1,
+            slow_weight_l1: self.learning_monitors.slow_weight_l1,
         }
     }
 
+    /// Scalar metrics as a flat map with dot-separated names
+    /// (`brain.*`, `learning.*`, `causal.*`, `config.*`, `oscillation.*`),
+    /// for monitoring exporters.
+    ///
+    /// Only counters and cached monitors are read, plus a bounded oscillation
+    /// sample, so the cost does not grow with the brain. The O(n) averages of
+    /// [`Brain::diagnostics`] (`avg_amp`, `avg_weight`) are not included.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn metrics(&self) -> HashMap<String, f32> {
+        const OSCILLATION_SAMPLE_UNITS: usize = 512;
+
+        let ls = self.learning_stats();
+        let cs = self.causal_stats();
+        let (osc_x, osc_y, osc_mag) = self.oscillation_sample(OSCILLATION_SAMPLE_UNITS);
+        let cfg = &self.cfg;
+
+        let entries: [(&str, f32); 33] = [
+            ("brain.unit_count", self.units.len() as f32),
+            ("brain.connection_count", self.connection_count() as f32),
+            ("brain.pruned_last_step", self.pruned_last_step as f32),
+            ("brain.births_last_step", self.births_last_step as f32),
+            ("brain.memory_bytes", self.estimate_memory_bytes() as f32),
+            ("brain.age_steps", self.age_steps as f32),
+            ("brain.neuromodulator", self.neuromod),
+            (
+                "learning.plasticity_committed",
+                if ls.plasticity_committed { 1.0 } else { 0.0 },
+            ),
+            ("learning.plasticity_l1", ls.plasticity_l1),
+            ("learning.plasticity_edges", ls.plasticity_edges as f32),
+            ("learning.plasticity_budget", ls.plasticity_budget),
+            ("learning.plasticity_budget_used", ls.plasticity_budget_used),
+            ("learning.eligibility_l1", ls.eligibility_l1),
+            ("learning.homeostasis_bias_l1", ls.homeostasis_bias_l1),
+            ("learning.plasticity_rate", ls.plasticity_rate),
+            (
+                "learning.td_prediction_error_ema",
+                ls.td_prediction_error_ema,
+            ),
+            ("learning.fast_weight_l1", ls.fast_weight_l1),
+            ("learning.slow_weight_l1", ls.slow_weight_l1),
+            ("causal.base_symbols", cs.base_symbols as f32),
+            ("causal.edge_count", cs.edges as f32),
+            (
+                "causal.last_directed_edge_updates",
+                cs.last_directed_edge_updates as f32,
+            ),
+            (
+                "causal.last_cooccur_edge_updates",
+                cs.last_cooccur_edge_updates as f32,
+            ),
+            ("config.dt", cfg.dt),
+            ("config.noise_amp", cfg.noise_amp),
+            ("config.noise_phase", cfg.noise_phase),
+            ("config.hebb_rate", cfg.hebb_rate),
+            ("config.forget_rate", cfg.forget_rate),
+            ("config.prune_below", cfg.prune_below),
+            ("config.global_inhibition", cfg.global_inhibition),
+            ("config.learning_deadband", cfg.learning_deadband),
+            ("oscillation.x", osc_x),
+            ("oscillation.y", osc_y),
+            ("oscillation.coherence", osc_mag),
+        ];
+        entries
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect()
+    }
+
+    /// Copy of every edge's eligibility trace, indexed like [`Brain::snapshot_weights`].
+    ///
+    /// Large magnitudes mark synapses that the next committed reward would change
+    /// most. [`LearningStats::eligibility_l1`] is its L1 norm as of the last trace update.
+    #[must_use]
+    pub fn eligibility_trace_snapshot(&self) -> Vec<f32> {
+        let mut traces = self.eligibility.clone();
+        traces.resize(self.connections.weights.len(), 0.0);
+        traces
+    }
+
+    /// Copy of every connection weight in CSR order.
+    ///
+    /// Two snapshots are comparable index-by-index only while the topology is
+    /// unchanged (same length and no pruning/growth in between).
+    #[must_use]
+    pub fn snapshot_weights(&self) -> Vec<Weight> {
+        self.connections.weights.clone()
+    }
+
     /// Actual memory usage estimate (accounts for neurogenesis growth).
     #[must_use]
     pub fn estimate_memory_bytes(&self) -> usize {
@@ -5439,6 +8530,129 @@ impl Brain {
             .map(|g| g.units.as_slice())
     }
 
+    /// Units of the named action group (empty if there is no such group).
+    #[must_use]
+    pub fn get_action_group_units(&self, name: &str) -> Vec<UnitId> {
+        self.action_units(name)
+            .map(<[UnitId]>::to_vec)
+            .unwrap_or_default()
+    }
+
+    /// Units of the named sensor group (empty if there is no such group).
+    #[must_use]
+    pub fn get_sensor_group_units(&self, name: &str) -> Vec<UnitId> {
+        self.sensor_units(name)
+            .map(<[UnitId]>::to_vec)
+            .unwrap_or_default()
+    }
+
+    /// Mean amplitude of the named sensor group's units, each clamped to
+    /// `[0, 1]` (0.0 for an unknown or empty group).
+    #[must_use]
+    pub fn get_sensor_group_activity(&self, name: &str) -> f32 {
+        self.mean_unit_activity(self.sensor_units(name).unwrap_or_default())
+    }
+
+    /// Mean amplitude of the named action group's units, each clamped to
+    /// `[0, 1]` (0.0 for an unknown or empty group).
+    #[must_use]
+    pub fn get_action_group_activity(&self, name: &str) -> f32 {
+        self.mean_unit_activity(self.action_units(name).unwrap_or_default())
+    }
+
+    fn mean_unit_activity(&self, units: &[UnitId]) -> f32 {
+        let amps: Vec<f32> = units
+            .iter()
+            .filter_map(|&id| self.units.get(id).map(|u| u.amp.clamp(0.0, 1.0)))
+            .collect();
+        if amps.is_empty() {
+            return 0.0;
+        }
+        amps.iter().sum::<f32>() / amps.len() as f32
+    }
+
+    /// Names of all action groups, in registration order.
+    pub fn action_group_names(&self) -> impl Iterator<Item = &str> {
+        self.action_groups.iter().map(|g| g.name.as_str())
+    }
+
+    /// Names of all sensor groups, in registration order.
+    pub fn sensor_group_names(&self) -> impl Iterator<Item = &str> {
+        self.sensor_groups.iter().map(|g| g.name.as_str())
+    }
+
+    /// Live connections from units of `from_group` to units of `to_group`,
+    /// as `(from, to, weight)` sorted by `|weight|` descending.
+    ///
+    /// Groups are looked up by name among sensor and action groups; an empty
+    /// name matches every unit. An unknown group name yields no edges.
+    #[must_use]
+    pub fn connections_by_group_pair(
+        &self,
+        from_group: &str,
+        to_group: &str,
+    ) -> Vec<(UnitId, UnitId, Weight)> {
+        let n = self.units.len();
+        let Some(sources) = self.group_units_or_all(from_group) else {
+            return Vec::new();
+        };
+        let Some(targets) = self.group_units_or_all(to_group) else {
+            return Vec::new();
+        };
+        let mut is_target = vec![false; n];
+        for id in targets.into_iter().filter(|&id| id < n) {
+            is_target[id] = true;
+        }
+
+        let mut edges: Vec<(UnitId, UnitId, Weight)> = Vec::new();
+        for from in sources.into_iter().filter(|&id| id < n) {
+            for (to, w) in self.neighbors(from) {
+                if is_target[to] {
+                    edges.push((from, to, w));
+                }
+            }
+        }
+        edges.sort_by(|a, b| {
+            b.2.abs()
+                .total_cmp(&a.2.abs())
+                .then_with(|| (a.0, a.1).cmp(&(b.0, b.1)))
+        });
+        edges
+    }
+
+    /// Units of the named sensor or action group; every unit for `""`.
+    fn group_units_or_all(&self, name: &str) -> Option<Vec<UnitId>> {
+        if name.is_empty() {
+            return Some((0..self.units.len()).collect());
+        }
+        self.sensor_units(name)
+            .or_else(|| self.action_units(name))
+            .map(<[UnitId]>::to_vec)
+    }
+
+    /// IO group membership as JSON, for tools that post-process exported weights:
+    /// `{ "unit_count": N, "groups": { name: { "type": "sensor"|"action", "units": [id, ...] } } }`.
+    ///
+    /// Groups are keyed by name; an action group shadows a sensor group of the same name.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn export_unit_metadata_json(&self) -> String {
+        let mut groups = serde_json::Map::new();
+        let kinds = [
+            ("sensor", &self.sensor_groups),
+            ("action", &self.action_groups),
+        ];
+        for (kind, list) in kinds {
+            for g in list {
+                groups.insert(
+                    g.name.clone(),
+                    serde_json::json!({ "type": kind, "units": g.units }),
+                );
+            }
+        }
+        serde_json::json!({ "unit_count": self.units.len(), "groups": groups }).to_string()
+    }
+
     /// Returns the current configuration (read-only).
     #[must_use]
     pub fn config(&self) -> &BrainConfig {
@@ -5456,10 +8670,10 @@ impl Brain {
     where
         F: FnOnce(&mut BrainConfig),
     {
-        let old = self.cfg;
+        let old = self.cfg.clone();
         let old_seed = old.seed;
 
-        let mut cfg = old;
+        let mut cfg = old.clone();
         f(&mut cfg);
 
         if cfg.unit_count != old.unit_count {
@@ -5487,12 +8701,19 @@ impl Brain {
     // ─────────────────────────────────────────────────────────────────────────
 
     /// Check if neurogenesis is needed based on network saturation.
-    ///
-    /// Returns true if the average connection weight magnitude exceeds the threshold,
-    /// indicating the network may benefit from fresh capacity.
+    #[deprecated(note = "renamed to `is_saturated`")]
     #[must_use]
     pub fn should_grow(&self, saturation_threshold: f32) -> bool {
-        let valid_count = self.total_connection_count();
+        self.is_saturated(saturation_threshold)
+    }
+
+    /// Whether the network is saturated and would benefit from fresh capacity.
+    ///
+    /// Returns true if the average connection weight magnitude exceeds the threshold
+    /// (in hybrid growth mode, subject to the growth cooldown and signals).
+    #[must_use]
+    pub fn is_saturated(&self, saturation_threshold: f32) -> bool {
+        let valid_count = self.connection_count();
         if valid_count == 0 {
             return false;
         }
@@ -5539,6 +8760,12 @@ impl Brain {
     /// # Arguments
     /// * `connectivity` - Number of outgoing connections to create
     pub fn grow_unit(&mut self, connectivity: usize) -> UnitId {
+        self.grow_unit_near(connectivity, &[])
+    }
+
+    /// [`grow_unit`](Self::grow_unit) with connection partners sampled from
+    /// `anchors` (all existing units when empty).
+    fn grow_unit_near(&mut self, connectivity: usize, anchors: &[UnitId]) -> UnitId {
         let new_id = self.units.len();
 
         // Create the new unit.
@@ -5566,11 +8793,16 @@ impl Brain {
         // Append to CSR: add offset for new unit, then add connections.
         let old_end = *self.connections.offsets.last().unwrap_or(&0);
         self.connections.offsets.push(old_end + connectivity);
+        self.csr_layout_changed();
 
         // Add connections FROM the new unit TO existing units.
         // This does not create tombstones; it just extends the CSR arrays.
         for _ in 0..connectivity {
-            let mut target = self.rng.gen_range_usize(0, new_id);
+            let mut target = if anchors.is_empty() {
+                self.rng.gen_range_usize(0, new_id)
+            } else {
+                anchors[self.rng.gen_range_usize(0, anchors.len())]
+            };
             // Avoid self-connection (though new_id isn't connected yet)
             if new_id > 0 && target == new_id {
                 target = (target + 1) % new_id;
@@ -5589,7 +8821,11 @@ impl Brain {
             if new_id == 0 {
                 break;
             }
-            let source = self.rng.gen_range_usize(0, new_id);
+            let source = if anchors.is_empty() {
+                self.rng.gen_range_usize(0, new_id)
+            } else {
+                anchors[self.rng.gen_range_usize(0, anchors.len())]
+            };
             let weight = self.rng.gen_range_f32(0.05, 0.15); // Slightly positive
             self.add_or_bump_csr(source, new_id, weight);
         }
@@ -5649,6 +8885,24 @@ impl Brain {
         saturation_threshold: f32,
         growth_count: usize,
         max_units: usize,
+    ) -> usize {
+        let policy = NeurogenesisPolicy::Saturation {
+            threshold: saturation_threshold,
+            amount: u32::try_from(growth_count).unwrap_or(u32::MAX),
+        };
+        self.maybe_neurogenesis_with_policy(&policy, max_units)
+    }
+
+    /// Automatic neurogenesis driven by a [`NeurogenesisPolicy`].
+    ///
+    /// Evaluates the policy against the current state and grows the number of
+    /// units it asks for, never exceeding `max_units` in total.
+    ///
+    /// Returns the number of units added.
+    pub fn maybe_neurogenesis_with_policy(
+        &mut self,
+        policy: &NeurogenesisPolicy,
+        max_units: usize,
     ) -> usize {
         self.births_last_step = 0;
 
@@ -5656,17 +8910,102 @@ impl Brain {
             return 0;
         }
 
-        if !self.should_grow(saturation_threshold) {
+        let wanted = match *policy {
+            NeurogenesisPolicy::Saturation { threshold, amount } => {
+                if self.is_saturated(threshold) {
+                    amount
+                } else {
+                    0
+                }
+            }
+            NeurogenesisPolicy::Periodic {
+                every_steps,
+                amount,
+            } => {
+                // Compare against the last birth rather than `age % every == 0`:
+                // callers poll at trial ends, which rarely land on exact multiples.
+                let since_birth = self.age_steps.wrapping_sub(self.growth_last_birth_step);
+                if every_steps > 0 && since_birth >= every_steps {
+                    amount
+                } else {
+                    0
+                }
+            }
+            NeurogenesisPolicy::RewardDriven { min_reward, amount } => {
+                if self.neuromod >= min_reward {
+                    amount
+                } else {
+                    0
+                }
+            }
+            NeurogenesisPolicy::Custom { f } => f(self),
+            NeurogenesisPolicy::Gradient {
+                threshold, amount, ..
+            } => {
+                if self.is_saturated(threshold) {
+                    amount
+                } else {
+                    0
+                }
+            }
+        };
+        if wanted == 0 {
             return 0;
         }
 
-        let to_add = growth_count.min(max_units - self.units.len());
+        let to_add = (wanted as usize).min(max_units - self.units.len());
         let connectivity = self.cfg.connectivity_per_unit;
 
-        self.grow_units(to_add, connectivity);
+        match policy {
+            NeurogenesisPolicy::Gradient { target_group, .. } => {
+                let anchors = self.gradient_anchors(target_group);
+                for _ in 0..to_add {
+                    self.grow_unit_near(connectivity, &anchors);
+                }
+            }
+            _ => {
+                self.grow_units(to_add, connectivity);
+            }
+        }
         to_add
     }
 
+    /// Placement candidates for [`NeurogenesisPolicy::Gradient`]: units within
+    /// two hops of the `GRADIENT_TOP_K` most active units of `group` (the
+    /// group's own units if that neighborhood is empty).
+    fn gradient_anchors(&self, group: &str) -> Vec<UnitId> {
+        const GRADIENT_TOP_K: usize = 4;
+        let Some(units) = self
+            .sensor_units(group)
+            .or_else(|| self.action_units(group))
+        else {
+            return Vec::new();
+        };
+        let mut ranked: Vec<UnitId> = units
+            .iter()
+            .copied()
+            .filter(|&id| id < self.units.len())
+            .collect();
+        ranked.sort_by(|&a, &b| {
+            self.units[b]
+                .amp
+                .total_cmp(&self.units[a].amp)
+                .then_with(|| a.cmp(&b))
+        });
+        ranked.truncate(GRADIENT_TOP_K);
+
+        let mut anchors: Vec<UnitId> = ranked
+            .iter()
+            .flat_map(|&id| self.units_within_hops(id, 2))
+            .collect();
+        anchors.sort_unstable();
+        anchors.dedup();
+        if anchors.is_empty() {
+            anchors = units.to_vec();
+        }
+        anchors
+    }
+
     /// Targeted neurogenesis: grow units specifically connected to a named group.
     ///
     /// Creates new units that are wired to receive from and project to units
@@ -5747,6 +9086,7 @@ impl Brain {
 
             let old_end = *self.connections.offsets.last().unwrap_or(&0);
             self.connections.offsets.push(old_end + outgoing.len());
+            self.csr_layout_changed();
             self.connections.targets.extend(&outgoing);
             self.connections.weights.extend(&weights);
             self.eligibility
@@ -5812,6 +9152,7 @@ impl Brain {
 
         // For now, just mark them as ineffective by zeroing their connections.
         // Full removal would require re-indexing all references.
+        self.touch_csr();
         for &id in &to_prune {
             let range = self.conn_range(id);
             for idx in range {
@@ -5832,6 +9173,9 @@ impl Brain {
             // Zero the unit's state.
             self.units[id].amp = 0.0;
             self.units[id].bias = 0.0;
+            if id < self.homeostasis_bias.len() {
+                self.homeostasis_bias[id] = 0.0;
+            }
             self.learning_enabled[id] = false;
         }
 
@@ -5901,11 +9245,91 @@ impl Brain {
         }
     }
 
+    /// Forget everything in causal memory while keeping weights and unit state.
+    ///
+    /// All edge and base counts go back to zero, along with lagged history.
+    /// The symbol table is kept, so existing symbols restart from zero under
+    /// their old ids. Cheaper than rebuilding the brain when switching tasks
+    /// whose structure is still useful.
+    pub fn reset_causality(&mut self) {
+        self.causal.clear();
+        self.causal_lag_history.clear();
+        self.causal_window_history.clear();
+    }
+
+    /// Set how many observation frames count as co-occurring in causal memory.
+    ///
+    /// Each [`Brain::commit_observation`] is one frame. Symbols of the current
+    /// frame gain co-occurrence edges with those of the previous
+    /// `window_frames - 1` frames, so 1–2 frames keep associations tight and
+    /// 20–50 frames capture looser context. Reward symbols are excluded from
+    /// the window. Clamped to `1..=CAUSAL_WINDOW_MAX`; shrinking the window
+    /// forgets the frames that fall outside it.
+    pub fn set_causal_window(&mut self, window_frames: u32) {
+        let frames = window_frames.clamp(1, CAUSAL_WINDOW_MAX);
+        self.cfg.causal_window_frames = frames;
+        self.causal_window_history.truncate(frames as usize - 1);
+    }
+
+    /// Current causal co-occurrence window in frames.
+    #[must_use]
+    pub fn causal_window(&self) -> u32 {
+        self.cfg.causal_window_frames
+    }
+
+    /// Whether the fast weight bank is active (`enable_fast_weights` or the
+    /// `fast_weights` experimental flag).
+    fn fast_weights_enabled(&self) -> bool {
+        self.cfg.enable_fast_weights || self.cfg.experimental_flag("fast_weights").is_some()
+    }
+
+    /// Move `rate` (clamped to `[0, 1]`) of every fast weight into the slow bank
+    /// (episodic → semantic consolidation).
+    ///
+    /// Effective weights are unchanged; the consolidated part just stops
+    /// decaying at `fast_forget_rate`. Returns the L1 mass moved. No-op unless
+    /// `enable_fast_weights` is set.
+    pub fn consolidate_fast_to_slow(&mut self, rate: f32) -> f32 {
+        let rate = rate.clamp(0.0, 1.0);
+        let mut moved = 0.0f32;
+        for f in &mut self.fast_weights {
+            let delta = *f * rate;
+            *f -= delta;
+            moved += delta.abs();
+        }
+        moved
+    }
+
+    /// Per-connection `(fast, slow)` weights in CSR order, or `None` unless
+    /// `enable_fast_weights` is set. `fast + slow` is the effective weight.
+    ///
+    /// The fast bank is populated on the first learning `step()` after enabling.
+    #[must_use]
+    pub fn multi_timescale_weights(&self) -> Option<(Vec<Weight>, Vec<Weight>)> {
+        if !self.fast_weights_enabled() {
+            return None;
+        }
+        let n = self.connections.weights.len();
+        let fast: Vec<Weight> = (0..n)
+            .map(|i| self.fast_weights.get(i).copied().unwrap_or(0.0))
+            .collect();
+        let slow = self
+            .connections
+            .weights
+            .iter()
+            .zip(&fast)
+            .map(|(w, f)| w - f)
+            .collect();
+        Some((fast, slow))
+    }
+
     /// Dream replay: offline memory consolidation.
     ///
     /// Disconnects from external input and runs internal replay with boosted
     /// learning rate. Patterns that were active during waking will reactivate
-    /// and strengthen through noise-driven exploration.
+    /// and strengthen through noise-driven exploration. With fast weights
+    /// enabled, the dream ends by consolidating part of the fast bank into the
+    /// slow one.
     ///
     /// # Arguments
     /// * `steps` - Number of dream steps to run
@@ -5922,8 +9346,23 @@ impl Brain {
     /// brain.dream(100, 5.0, 3.0); // Consolidate memories
     /// ```
     pub fn dream(&mut self, steps: usize, learning_boost: f32, noise_boost: f32) -> f32 {
+        self.dream_seeded(steps, learning_boost, noise_boost, &[])
+    }
+
+    /// [`Brain::dream`], but each step reactivates one randomly chosen entry of
+    /// `seeds` (a unit set) instead of random unreserved units. Empty `seeds`
+    /// is a plain dream.
+    fn dream_seeded(
+        &mut self,
+        steps: usize,
+        learning_boost: f32,
+        noise_boost: f32,
+        seeds: &[(Vec<UnitId>, f32)],
+    ) -> f32 {
         // Save original settings.
         let orig_hebb = self.cfg.hebb_rate;
+        let orig_priority = self.learning_priority;
+        self.learning_priority = 1.0;
         let orig_noise_amp = self.cfg.noise_amp;
         let orig_noise_phase = self.cfg.noise_phase;
         let orig_neuromod = self.neuromod;
@@ -5942,12 +9381,33 @@ impl Brain {
         // Run dream steps.
         let mut total_amp = 0.0f32;
         for _ in 0..steps {
-            // Inject small random activations to trigger memory reactivation.
-            let inject_count = (self.units.len() / 20).max(1);
-            for _ in 0..inject_count {
-                let id = self.rng.gen_range_usize(0, self.units.len());
-                if !self.reserved[id] {
-                    self.pending_input[id] = self.rng.gen_range_f32(0.2, 0.6);
+            if seeds.is_empty() {
+                // Inject small random activations to trigger memory reactivation.
+                let inject_count = (self.units.len() / 20).max(1);
+                for _ in 0..inject_count {
+                    let id = self.rng.gen_range_usize(0, self.units.len());
+                    if !self.reserved[id] {
+                        self.pending_input[id] = self.rng.gen_range_f32(0.2, 0.6);
+                    }
+                }
+            } else {
+                // Reactivate one remembered observation, picked proportionally
+                // to its priority (uniformly if every priority is 0).
+                let total: f32 = seeds.iter().map(|(_, p)| p).sum();
+                let pick = if total > 0.0 {
+                    let mut r = self.rng.gen_range_f32(0.0, total);
+                    seeds
+                        .iter()
+                        .position(|(_, p)| {
+                            r -= p;
+                            r < 0.0
+                        })
+                        .unwrap_or(seeds.len() - 1)
+                } else {
+                    self.rng.gen_range_usize(0, seeds.len())
+                };
+                for &id in &seeds[pick].0 {
+                    self.pending_input[id] = self.rng.gen_range_f32(0.4, 0.6);
                 }
             }
 
@@ -5962,6 +9422,11 @@ impl Brain {
         self.cfg.noise_amp = orig_noise_amp;
         self.cfg.noise_phase = orig_noise_phase;
         self.neuromod = orig_neuromod;
+        self.learning_priority = orig_priority;
+
+        if self.fast_weights_enabled() {
+            self.consolidate_fast_to_slow(DREAM_FAST_CONSOLIDATION_RATE);
+        }
 
         total_amp / (steps * self.units.len()) as f32
     }
@@ -5993,6 +9458,7 @@ impl Brain {
         let mut burst_count = 0;
         let base_lr = self.cfg.hebb_rate;
         let boosted_lr = (base_lr * boost_factor).min(0.5);
+        self.touch_csr();
 
         for (i, &prev_amp) in prev_amps.iter().enumerate().take(self.units.len()) {
             let delta = self.units[i].amp - prev_amp;
@@ -6012,7 +9478,7 @@ impl Brain {
                         let align = phase_alignment(self.units[i].phase, self.units[target].phase);
                         let delta_w = boosted_lr * align;
                         self.connections.weights[idx] =
-                            (self.connections.weights[idx] + delta_w).clamp(-1.5, 1.5);
+                            self.clip_weight(self.connections.weights[idx] + delta_w);
                     }
                 }
 
@@ -6215,19 +9681,7 @@ impl Brain {
                 }
 
                 let align = phase_alignment(a_phase, self.units[target].phase);
-
-                // Smooth blend between no eligibility (misaligned) and alignment.
-                // softness=0 keeps the legacy hard gate.
-                let corr = if self.cfg.phase_gate_softness <= 0.0 {
-                    if align > phase_thr {
-                        align
-                    } else {
-                        0.0
-                    }
-                } else {
-                    let sigma = sigmoid((align - phase_thr) / self.cfg.phase_gate_softness);
-                    sigma * align
-                };
+                let corr = phase_gate(align, phase_thr, self.cfg.phase_gate_softness);
 
                 // Co-activity magnitude (soft-thresholded). softness=0 keeps hard ReLU.
                 // Apply sqrt to bound the multiplicative term and prevent eligibility saturation.
@@ -6248,27 +9702,179 @@ impl Brain {
         self.learning_monitors.eligibility_l1 = l1;
     }
 
-    /// Apply a gated plasticity commit from eligibility traces.
+    /// SIMD variant of [`Self::update_eligibility_scalar`], eight edges per
+    /// `f32x8` over each owner's CSR row.
     ///
-    /// Weight update is proportional to `hebb_rate * neuromod * eligibility`.
-    /// The `learning_deadband` prevents constant drift when neuromod ≈ 0.
-    fn apply_plasticity_scalar(&mut self) {
-        if self.cfg.hebb_rate <= 0.0 {
+    /// Target gates and soft co-activity depend only on the unit, so they are
+    /// computed once per step into dense arrays. The row loop then gathers
+    /// them per lane and does the phase gate in vector registers.
+    #[cfg(feature = "simd")]
+    fn update_eligibility_simd(&mut self) {
+        let gain = self.cfg.eligibility_gain;
+        if gain <= 0.0 {
+            self.update_eligibility_scalar();
             return;
         }
+        if self.activity_trace.len() != self.units.len() {
+            self.activity_trace.resize(self.units.len(), 0.0);
+        }
+        self.ensure_gate_vectors();
         if self.eligibility.len() != self.connections.weights.len() {
             self.eligibility.resize(self.connections.weights.len(), 0.0);
         }
 
-        let neuromod = self.neuromod;
-        if neuromod.abs() <= self.cfg.learning_deadband {
-            self.learning_monitors.plasticity_committed = false;
-            return;
-        }
-        self.learning_monitors.plasticity_committed = true;
-
-        // Sign-correct: negative neuromod reduces/undoes recent eligibility.
-        let lr = self.cfg.hebb_rate * neuromod;
+        let thr = self.cfg.coactive_threshold;
+        let phase_thr = self.cfg.phase_lock_threshold;
+        let softness = self.cfg.phase_gate_softness;
+        let activity_thr = self.cfg.module_learning_activity_threshold;
+        let decay = (1.0 - self.cfg.eligibility_decay).clamp(0.0, 1.0);
+
+        // Decay all traces first and accumulate magnitude.
+        let decay_v = f32x8::splat(decay);
+        let mut l1_v = f32x8::ZERO;
+        let mut l1 = 0.0f32;
+        let mut chunks = self.eligibility.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            let e = load_f32x8(chunk) * decay_v;
+            chunk.copy_from_slice(&e.to_array());
+            l1_v += e.abs();
+        }
+        for e in chunks.into_remainder() {
+            *e *= decay;
+            l1 += e.abs();
+        }
+
+        // Soft co-activity per unit, zero exactly when the unit is gated out.
+        let n = self.units.len();
+        let mut co_unit = vec![0.0f32; n];
+        let mut phase = vec![0.0f32; n];
+        for (u, (co, ph)) in co_unit.iter_mut().zip(phase.iter_mut()).enumerate() {
+            *ph = self.units[u].phase;
+            if self.unit_learning_blocked(u) {
+                continue;
+            }
+            let instant = self.units[u].amp.max(0.0);
+            let amp = if self.cfg.activity_trace_decay <= 0.0 {
+                instant
+            } else {
+                self.activity_trace[u].max(instant)
+            };
+            if (activity_thr > 0.0 && amp < activity_thr) || amp <= thr {
+                continue;
+            }
+            *co = smooth_relu(amp - thr, self.cfg.coactive_softness);
+        }
+
+        let pi = core::f32::consts::PI;
+        let two_pi_v = f32x8::splat(2.0 * pi);
+        let inv_two_pi_v = f32x8::splat(0.5 / pi);
+        let inv_pi_v = f32x8::splat(1.0 / pi);
+        let gain_v = f32x8::splat(gain);
+        let phase_thr_v = f32x8::splat(phase_thr);
+        let inv_soft_v = f32x8::splat(if softness > 0.0 { 1.0 / softness } else { 0.0 });
+        let e_lo = f32x8::splat(-2.0);
+        let e_hi = f32x8::splat(2.0);
+
+        for owner in 0..n {
+            if !self.learning_enabled[owner] || !self.learning_allowed_for_unit(owner) {
+                continue;
+            }
+            let co_a = co_unit[owner];
+            if co_a == 0.0 {
+                continue;
+            }
+
+            let a_phase = phase[owner];
+            let a_phase_v = f32x8::splat(a_phase);
+            let co_a_v = f32x8::splat(co_a);
+            let range = self.conn_range(owner);
+            let simd_end = range.start + (range.len() / 8) * 8;
+
+            for base in (range.start..simd_end).step_by(8) {
+                let mut co_b = [0.0f32; 8];
+                let mut ph_b = [0.0f32; 8];
+                for (k, &target) in self.connections.targets[base..base + 8].iter().enumerate() {
+                    if target != INVALID_UNIT {
+                        co_b[k] = co_unit[target];
+                        ph_b[k] = phase[target];
+                    }
+                }
+                let co_b = f32x8::from(co_b);
+                if co_b.cmp_gt(f32x8::ZERO).none() {
+                    continue;
+                }
+
+                // phase_alignment: wrap the difference into [-pi, pi].
+                let d = a_phase_v - f32x8::from(ph_b);
+                let d = d - two_pi_v * (d * inv_two_pi_v).round();
+                let align = (f32x8::ONE - d.abs() * inv_pi_v)
+                    .max(f32x8::ZERO)
+                    .min(f32x8::ONE);
+                let corr = if softness <= 0.0 {
+                    align.cmp_gt(phase_thr_v).blend(align, f32x8::ZERO)
+                } else {
+                    let x = ((align - phase_thr_v) * inv_soft_v)
+                        .max(f32x8::splat(-80.0))
+                        .min(f32x8::splat(80.0));
+                    align / (f32x8::ONE + (-x).exp())
+                };
+
+                // Gated lanes have co_b = 0 and keep their trace.
+                let de = gain_v * (co_a_v * co_b).sqrt() * corr;
+                let prev = load_f32x8(&self.eligibility[base..base + 8]);
+                let next = (prev + de).max(e_lo).min(e_hi);
+                self.eligibility[base..base + 8].copy_from_slice(&next.to_array());
+                l1_v += next.abs() - prev.abs();
+            }
+
+            // Tail that does not fill a full vector.
+            for idx in simd_end..range.end {
+                let target = self.connections.targets[idx];
+                if target == INVALID_UNIT || co_unit[target] == 0.0 {
+                    continue;
+                }
+                let align = phase_alignment(a_phase, phase[target]);
+                let corr = phase_gate(align, phase_thr, softness);
+                let de = gain * (co_a * co_unit[target]).sqrt() * corr;
+                let e = &mut self.eligibility[idx];
+                let prev = *e;
+                let next = (prev + de).clamp(-2.0, 2.0);
+                *e = next;
+                l1 += next.abs() - prev.abs();
+            }
+        }
+
+        l1 += l1_v.to_array().iter().sum::<f32>();
+        self.learning_monitors.eligibility_l1 = l1;
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn update_eligibility_simd(&mut self) {
+        self.update_eligibility_scalar();
+    }
+
+    /// Apply a gated plasticity commit from eligibility traces.
+    ///
+    /// Weight update is proportional to `hebb_rate * neuromod * eligibility`.
+    /// The `learning_deadband` prevents constant drift when neuromod ≈ 0.
+    fn apply_plasticity_scalar(&mut self) {
+        if self.cfg.hebb_rate <= 0.0 {
+            return;
+        }
+        if self.eligibility.len() != self.connections.weights.len() {
+            self.eligibility.resize(self.connections.weights.len(), 0.0);
+        }
+
+        let neuromod = self.neuromod;
+        if neuromod.abs() <= self.cfg.learning_deadband {
+            self.learning_monitors.plasticity_committed = false;
+            return;
+        }
+        self.learning_monitors.plasticity_committed = true;
+        self.touch_csr();
+
+        // Sign-correct: negative neuromod reduces/undoes recent eligibility.
+        let lr = self.cfg.hebb_rate * neuromod * self.learning_priority;
 
         // Optional per-step plasticity budget.
         let budget = self.cfg.plasticity_budget;
@@ -6389,7 +9995,7 @@ impl Brain {
                 }
 
                 self.connections.weights[idx] =
-                    (self.connections.weights[idx] + dw).clamp(-1.5, 1.5);
+                    self.clip_weight(self.connections.weights[idx] + dw);
 
                 l1 += cost;
                 edges = edges.saturating_add(1);
@@ -6413,6 +10019,167 @@ impl Brain {
         }
     }
 
+    /// SIMD plasticity commit: the same rule as [`Brain::apply_plasticity_scalar`],
+    /// eight edges per instruction over the CSR spans of learning owners.
+    ///
+    /// Plasticity budgets and cross-module scaling make each edge depend on the
+    /// edges before it, so those configurations use the scalar path.
+    #[cfg(feature = "simd")]
+    fn apply_plasticity_simd(&mut self) {
+        let cross_plasticity_scale = self.cfg.cross_module_plasticity_scale;
+        if self.cfg.plasticity_budget > 0.0
+            || self.cfg.module_plasticity_budget > 0.0
+            || (cross_plasticity_scale != 1.0 && cross_plasticity_scale >= 0.0)
+        {
+            self.apply_plasticity_scalar();
+            return;
+        }
+
+        if self.cfg.hebb_rate <= 0.0 {
+            return;
+        }
+        if self.eligibility.len() != self.connections.weights.len() {
+            self.eligibility.resize(self.connections.weights.len(), 0.0);
+        }
+
+        let neuromod = self.neuromod;
+        if neuromod.abs() <= self.cfg.learning_deadband {
+            self.learning_monitors.plasticity_committed = false;
+            return;
+        }
+        self.learning_monitors.plasticity_committed = true;
+        self.touch_csr();
+
+        let lr = self.cfg.hebb_rate * neuromod * self.learning_priority;
+        let activity_thr = self.cfg.module_learning_activity_threshold;
+
+        let lr_v = f32x8::splat(lr);
+        let step_lo = f32x8::splat(-0.25);
+        let step_hi = f32x8::splat(0.25);
+        let w_lo = f32x8::splat(-1.5);
+        let w_hi = f32x8::splat(1.5);
+        let clip_lo = f32x8::splat(self.cfg.weight_clip_min);
+        let clip_hi = f32x8::splat(self.cfg.weight_clip_max);
+        // Vector accumulators, reduced once at the end.
+        let mut l1_v = f32x8::ZERO;
+        let mut edges_v = f32x8::ZERO;
+        let mut l1 = 0.0f32;
+        let mut edges = 0u32;
+
+        self.ensure_gate_vectors();
+        let any_blocked =
+            self.frozen_units.iter().any(|&f| f) || self.paralyzed_units.iter().any(|&p| p);
+        // Without tombstones or gated units every lane takes its update.
+        let all_clean = self.csr_tombstones == 0 && !any_blocked;
+
+        let owner_ok = |brain: &Brain, owner: usize| {
+            if !brain.learning_enabled[owner]
+                || !brain.learning_allowed_for_unit(owner)
+                || brain.unit_learning_blocked(owner)
+            {
+                return false;
+            }
+            if activity_thr > 0.0 {
+                let instant = brain.units[owner].amp.max(0.0);
+                let a = if brain.cfg.activity_trace_decay <= 0.0 {
+                    instant
+                } else {
+                    brain
+                        .activity_trace
+                        .get(owner)
+                        .copied()
+                        .unwrap_or(0.0)
+                        .max(instant)
+                };
+                if a < activity_thr {
+                    return false;
+                }
+            }
+            true
+        };
+        let target_ok = |brain: &Brain, target: UnitId| {
+            target != INVALID_UNIT && !(any_blocked && brain.unit_learning_blocked(target))
+        };
+
+        // Consecutive learning owners own one contiguous CSR span, so the
+        // kernel runs over whole spans rather than row by row.
+        let n = self.units.len();
+        let mut owner = 0;
+        while owner < n {
+            if !owner_ok(self, owner) {
+                owner += 1;
+                continue;
+            }
+            let start = self.connections.offsets[owner];
+            while owner < n && owner_ok(self, owner) {
+                owner += 1;
+            }
+            let end = self.connections.offsets[owner];
+            let simd_end = start + ((end - start) / 8) * 8;
+
+            for base in (start..simd_end).step_by(8) {
+                let mut e = load_f32x8(&self.eligibility[base..base + 8]);
+                if !all_clean
+                    && !self.connections.targets[base..base + 8]
+                        .iter()
+                        .all(|&t| target_ok(self, t))
+                {
+                    // Lanes whose target is invalid or gated contribute no update.
+                    let mut gate = [0.0f32; 8];
+                    for (g, &target) in gate
+                        .iter_mut()
+                        .zip(&self.connections.targets[base..base + 8])
+                    {
+                        if target_ok(self, target) {
+                            *g = 1.0;
+                        }
+                    }
+                    e *= f32x8::from(gate);
+                }
+
+                let w = load_f32x8(&self.connections.weights[base..base + 8]);
+                let dw = (lr_v * e).max(step_lo).min(step_hi);
+                let next = (w + dw).max(w_lo).min(w_hi).max(clip_lo).min(clip_hi);
+
+                // Untouched lanes keep their weight exactly, as in the scalar path.
+                let active = e.cmp_ne(f32x8::ZERO);
+                self.connections.weights[base..base + 8]
+                    .copy_from_slice(&active.blend(next, w).to_array());
+
+                l1_v += active.blend(dw.abs(), f32x8::ZERO);
+                edges_v += active.blend(f32x8::ONE, f32x8::ZERO);
+            }
+
+            // Tail that does not fill a full vector.
+            for idx in simd_end..end {
+                if !target_ok(self, self.connections.targets[idx]) {
+                    continue;
+                }
+                let e = self.eligibility[idx];
+                if e == 0.0 {
+                    continue;
+                }
+                let dw = (lr * e).clamp(-0.25, 0.25);
+                self.connections.weights[idx] =
+                    self.clip_weight(self.connections.weights[idx] + dw);
+                l1 += dw.abs();
+                edges = edges.saturating_add(1);
+            }
+        }
+
+        l1 += l1_v.to_array().iter().sum::<f32>();
+        edges = edges.saturating_add(edges_v.to_array().iter().sum::<f32>() as u32);
+
+        self.learning_monitors.plasticity_l1 = l1;
+        self.learning_monitors.plasticity_edges = edges;
+        self.learning_monitors.plasticity_budget_used = 0.0;
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn apply_plasticity_simd(&mut self) {
+        self.apply_plasticity_scalar();
+    }
+
     /// Slow homeostasis: nudges unit biases to keep activity near a target.
     #[allow(clippy::manual_is_multiple_of)]
     fn homeostasis_step(&mut self) {
@@ -6430,6 +10197,7 @@ impl Brain {
 
         let target = self.cfg.homeostasis_target_amp;
         let mut l1 = 0.0f32;
+        self.ensure_homeostasis_bias();
         for i in 0..self.units.len() {
             if self.reserved[i] {
                 continue;
@@ -6438,21 +10206,38 @@ impl Brain {
             let err = target - amp;
             let prev = self.units[i].bias;
             self.units[i].bias = (prev + rate * err).clamp(-0.5, 0.5);
-            l1 += (self.units[i].bias - prev).abs();
+            let delta = self.units[i].bias - prev;
+            self.homeostasis_bias[i] += delta;
+            l1 += delta.abs();
         }
 
         self.learning_monitors.homeostasis_bias_l1 = l1;
     }
 
     fn forget_and_prune(&mut self) {
+        self.touch_csr();
         let decay = 1.0 - self.cfg.forget_rate;
         let prune_below = self.cfg.prune_below;
         let cross_forget = self.cfg.cross_module_forget_boost;
         let cross_prune = self.cfg.cross_module_prune_bonus;
+        let policy = self.pruning_policy;
 
         // Optional extra decay for cross-module edges.
         let cross_decay = (1.0 - self.cfg.forget_rate - cross_forget).clamp(0.0, 1.0);
 
+        let track_age = matches!(policy, PruningPolicy::Age { .. });
+        if track_age {
+            self.refresh_edge_ages();
+        }
+        let track_fast = self.fast_weights_enabled();
+        if track_fast {
+            self.update_fast_weights();
+        } else if !self.fast_weights.is_empty() {
+            // Disabled at runtime: whatever is in the fast bank stays as slow weight.
+            self.fast_weights.clear();
+            self.fast_weight_snapshot.clear();
+        }
+
         // Apply decay and prune. For “engrams” (sensor↔concept links), keep a weak trace:
         // allow decay, but do not prune to zero.
         //
@@ -6460,9 +10245,6 @@ impl Brain {
         // in `sensor_member`. This avoids changing the on-disk image format.
         let unit_count = self.units.len();
         for owner in 0..unit_count {
-            let owner_is_concept = self.reserved[owner] && !self.group_member[owner];
-            let owner_is_sensor = self.sensor_member[owner];
-
             let start = self.connections.offsets[owner];
             let end = self.connections.offsets[owner + 1];
             for idx in start..end {
@@ -6477,13 +10259,14 @@ impl Brain {
                     owner_mid != NO_MODULE && target_mid != NO_MODULE && owner_mid != target_mid;
 
                 // Decay all active weights (optionally harsher across module boundaries).
-                self.connections.weights[idx] *= if is_cross_module { cross_decay } else { decay };
-
-                let target_is_concept = self.reserved[target] && !self.group_member[target];
-                let target_is_sensor = self.sensor_member[target];
-                let is_engram_edge =
-                    (owner_is_sensor && target_is_concept && self.concept_validated[target])
-                        || (owner_is_concept && target_is_sensor && self.concept_validated[owner]);
+                let d = if is_cross_module { cross_decay } else { decay };
+                self.connections.weights[idx] *= d;
+                if track_fast {
+                    self.fast_weights[idx] *= d;
+                }
+                if track_age {
+                    self.edge_weight_snapshot[idx] = self.connections.weights[idx];
+                }
 
                 let w = self.connections.weights[idx];
                 let abs = w.abs();
@@ -6494,7 +10277,7 @@ impl Brain {
                     prune_below
                 };
 
-                if is_engram_edge {
+                if self.is_engram_edge(owner, target) {
                     // Keep a minimal, non-zero trace so it can be rapidly re-strengthened
                     // on re-exposure (“savings” / muscle memory).
                     if abs < prune_thr {
@@ -6504,16 +10287,56 @@ impl Brain {
                     continue;
                 }
 
-                if abs < prune_thr {
-                    self.connections.targets[idx] = INVALID_UNIT;
-                    self.connections.weights[idx] = 0.0;
-                    if idx < self.eligibility.len() {
-                        self.eligibility[idx] = 0.0;
+                let prune = match policy {
+                    PruningPolicy::Threshold { .. } => abs < prune_thr,
+                    // Ranked per unit after the decay pass.
+                    PruningPolicy::TopK { .. } => false,
+                    PruningPolicy::Probabilistic { rate, temperature } => {
+                        let p = rate * (-abs / temperature).exp();
+                        self.rng.gen_range_f32(0.0, 1.0) < p
+                    }
+                    PruningPolicy::Age { max_age_steps } => {
+                        self.age_steps.saturating_sub(self.edge_last_update[idx]) > max_age_steps
+                    }
+                };
+                if prune {
+                    self.tombstone_edge(idx);
+                }
+            }
+        }
+
+        if let PruningPolicy::TopK { k_per_unit } = policy {
+            let mut live: Vec<(f32, usize)> = Vec::new();
+            for owner in 0..unit_count {
+                live.clear();
+                for idx in self.conn_range(owner) {
+                    let target = self.connections.targets[idx];
+                    if target != INVALID_UNIT && !self.is_engram_edge(owner, target) {
+                        live.push((self.connections.weights[idx].abs(), idx));
                     }
-                    self.pruned_last_step += 1;
-                    self.csr_tombstones += 1;
                 }
+                if live.len() <= k_per_unit {
+                    continue;
+                }
+                // Move the `len - k` weakest connections to the front.
+                let drop = live.len() - k_per_unit;
+                live.select_nth_unstable_by(drop - 1, |a, b| a.0.total_cmp(&b.0));
+                for &(_, idx) in &live[..drop] {
+                    self.tombstone_edge(idx);
+                }
+            }
+        }
+
+        if track_fast {
+            self.fast_weight_snapshot
+                .copy_from_slice(&self.connections.weights);
+            let (mut fast_l1, mut slow_l1) = (0.0f32, 0.0f32);
+            for (w, f) in self.connections.weights.iter().zip(&self.fast_weights) {
+                fast_l1 += f.abs();
+                slow_l1 += (w - f).abs();
             }
+            self.learning_monitors.fast_weight_l1 = fast_l1;
+            self.learning_monitors.slow_weight_l1 = slow_l1;
         }
 
         // Compact to reclaim tombstones.
@@ -6531,6 +10354,103 @@ impl Brain {
         }
     }
 
+    /// Sensor↔validated-concept links, which pruning must never remove.
+    fn is_engram_edge(&self, owner: UnitId, target: UnitId) -> bool {
+        let owner_is_concept = self.reserved[owner] && !self.group_member[owner];
+        let target_is_concept = self.reserved[target] && !self.group_member[target];
+        (self.sensor_member[owner] && target_is_concept && self.concept_validated[target])
+            || (owner_is_concept && self.sensor_member[target] && self.concept_validated[owner])
+    }
+
+    fn tombstone_edge(&mut self, idx: usize) {
+        self.touch_csr();
+        self.connections.targets[idx] = INVALID_UNIT;
+        self.connections.weights[idx] = 0.0;
+        if idx < self.fast_weights.len() {
+            self.fast_weights[idx] = 0.0;
+        }
+        if idx < self.eligibility.len() {
+            self.eligibility[idx] = 0.0;
+        }
+        self.pruned_last_step += 1;
+        self.csr_tombstones += 1;
+    }
+
+    /// Absorb weight writes made since the last step (imprinting, weight
+    /// deltas, Hebbian epochs) into the fast-weight baseline, so
+    /// [`Brain::update_fast_weights`] only sees this step's plasticity.
+    fn sync_fast_weight_snapshot(&mut self) {
+        let n = self
+            .fast_weight_snapshot
+            .len()
+            .min(self.connections.weights.len());
+        self.fast_weight_snapshot[..n].copy_from_slice(&self.connections.weights[..n]);
+    }
+
+    /// Route this step's learning into the fast bank.
+    ///
+    /// Plasticity kernels write the slow-rate update straight into
+    /// `connections.weights`; the change since the previous snapshot is that
+    /// update. The fast bank receives the same update scaled to
+    /// `fast_hebb_rate` and decays at `fast_forget_rate`, and the effective
+    /// weight moves by the change in the fast bank.
+    fn update_fast_weights(&mut self) {
+        let n = self.connections.weights.len();
+        if self.fast_weights.len() > n {
+            // Layout changed in a way we didn't track; fold the fast bank into slow.
+            self.fast_weights.clear();
+            self.fast_weight_snapshot.clear();
+        }
+        for idx in self.fast_weights.len()..n {
+            self.fast_weights.push(0.0);
+            self.fast_weight_snapshot
+                .push(self.connections.weights[idx]);
+        }
+
+        let gain = if self.cfg.hebb_rate > 0.0 {
+            self.cfg.fast_hebb_rate / self.cfg.hebb_rate
+        } else {
+            0.0
+        };
+        let fast_decay = 1.0 - self.cfg.fast_forget_rate;
+        for idx in 0..n {
+            if self.connections.targets[idx] == INVALID_UNIT {
+                self.fast_weights[idx] = 0.0;
+                continue;
+            }
+            let w = self.connections.weights[idx];
+            let old_fast = self.fast_weights[idx];
+            let new_fast = old_fast * fast_decay + (w - self.fast_weight_snapshot[idx]) * gain;
+            let wanted = w + new_fast - old_fast;
+            let effective = self.clip_weight(wanted);
+            // Charge any clipping loss to the fast bank so slow = effective - fast holds.
+            self.fast_weights[idx] = new_fast - (wanted - effective);
+            self.connections.weights[idx] = effective;
+        }
+    }
+
+    /// Stamp every edge whose weight moved since the last prune pass (i.e. was
+    /// touched by learning, not just decay) with the current step. Edges added
+    /// by growth since then are appended as fresh.
+    fn refresh_edge_ages(&mut self) {
+        let n = self.connections.weights.len();
+        if self.edge_last_update.len() > n {
+            // Layout changed in a way we didn't track; restart the clocks.
+            self.edge_last_update.clear();
+            self.edge_weight_snapshot.clear();
+        }
+        for idx in self.edge_last_update.len()..n {
+            self.edge_last_update.push(self.age_steps);
+            self.edge_weight_snapshot
+                .push(self.connections.weights[idx]);
+        }
+        for idx in 0..n {
+            if self.connections.weights[idx] != self.edge_weight_snapshot[idx] {
+                self.edge_last_update[idx] = self.age_steps;
+            }
+        }
+    }
+
     fn allocate_units(&mut self, n: usize) -> Vec<UnitId> {
         // Choose from currently unreserved units only.
         let mut idxs: Vec<(UnitId, f32)> = self
@@ -6656,9 +10576,128 @@ impl Brain {
         }
     }
 
-    /// Force synchronization of all sensor groups.
+    /// [`Brain::dream_replay`] restricted to remembered observations picked by
+    /// `source`.
+    ///
+    /// Each episode step reactivates the sensor and action groups of one
+    /// matching observation (picked in proportion to its commit priority, see
+    /// [`Brain::commit_observation_with_priority`]), so consolidation strengthens those associations
+    /// rather than whatever random noise happens to excite. `Internal` behaves
+    /// exactly like `dream_replay`. If no remembered observation matches, nothing
+    /// runs and 0 is returned.
+    #[cfg(feature = "std")]
+    pub fn dream_replay_with_source(
+        &mut self,
+        episodes: usize,
+        learning_boost: f32,
+        source: &DreamSource,
+    ) -> f32 {
+        if *source == DreamSource::Internal {
+            return self.dream_replay(episodes, learning_boost);
+        }
+
+        let context = match source {
+            DreamSource::Context(name) => match self.symbol_id(name) {
+                Some(id) => Some(id),
+                None => return 0.0,
+            },
+            _ => None,
+        };
+        let now = self.age_steps;
+        let matches = |t: &DreamTrace| match source {
+            DreamSource::Internal => true,
+            DreamSource::Context(_) => context.is_some_and(|id| t.symbols.contains(&id)),
+            DreamSource::HighReward { min_reward } => t.reward >= *min_reward,
+            DreamSource::Recent { window_steps } => now.saturating_sub(t.step) <= *window_steps,
+            DreamSource::Rare { min_surprise } => -t.rarity.ln() >= *min_surprise,
+        };
+
+        let seeds: Vec<(Vec<UnitId>, f32)> = self
+            .dream_traces
+            .iter()
+            .filter(|t| matches(t))
+            .map(|t| (self.dream_trace_units(t), t.priority))
+            .filter(|(units, _)| !units.is_empty())
+            .collect();
+        if seeds.is_empty() {
+            return 0.0;
+        }
+
+        let steps_per_episode = 20;
+        let noise_boost = 2.5;
+        let mut total_activity = 0.0;
+        for _ in 0..episodes {
+            total_activity +=
+                self.dream_seeded(steps_per_episode, learning_boost, noise_boost, &seeds);
+        }
+        if episodes > 0 {
+            total_activity / episodes as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// Units of the sensor and action groups named by a trace's symbols.
+    #[cfg(feature = "std")]
+    fn dream_trace_units(&self, trace: &DreamTrace) -> Vec<UnitId> {
+        let mut units = Vec::new();
+        for &sym in &trace.symbols {
+            let Some(name) = self.symbols_rev.get(sym as usize) else {
+                continue;
+            };
+            let group = self
+                .sensor_groups
+                .iter()
+                .chain(&self.action_groups)
+                .find(|g| &g.name == name);
+            if let Some(g) = group {
+                units.extend_from_slice(&g.units);
+            }
+        }
+        units
+    }
+
+    /// Remember the observation being committed (before causal memory sees it).
+    fn record_dream_trace(&mut self) {
+        let (pos, neg) = (self.reward_pos_symbol, self.reward_neg_symbol);
+        let symbols: Vec<SymbolId> = self
+            .active_symbols
+            .iter()
+            .copied()
+            .filter(|&s| s != pos && s != neg)
+            .collect();
+        if symbols.is_empty() {
+            return;
+        }
+        let total = self.causal.base_total() + 1.0;
+        let rarity = symbols
+            .iter()
+            .map(|&s| (self.causal.base_count(s) + 1.0) / total)
+            .fold(1.0f32, f32::min);
+
+        if self.dream_traces.len() >= DREAM_TRACE_CAP {
+            self.dream_traces.pop_front();
+        }
+        self.dream_traces.push_back(DreamTrace {
+            step: self.age_steps,
+            reward: self.neuromod,
+            rarity,
+            priority: self.learning_priority,
+            symbols,
+        });
+    }
+
+    /// Phase-lock every sensor-group unit to a common reference phase (0).
+    ///
+    /// Oscillators drift apart over time, both within a group and between
+    /// groups, which weakens the phase-gated coupling between co-driven inputs.
+    /// This resets that accumulated drift: afterwards
+    /// [`Brain::sensor_group_phase_spread`] is 0 for every sensor group and
+    /// all sensor groups share one phase. Each sensor unit also gets a `+0.5`
+    /// amplitude kick (capped at 2.0) so the realigned inputs actually
+    /// propagate on the next step. Other units, weights and learning state are
+    /// untouched; see [`Brain::global_sync`] for the whole-substrate variant.
     ///
-    /// Aligns phases of sensor units to enhance coherent encoding.
     /// Call this after regime shifts to help the brain adapt.
     pub fn force_synchronize_sensors(&mut self) {
         let target_phase = 0.0;
@@ -6673,9 +10712,97 @@ impl Brain {
         }
     }
 
-    /// Enable or disable burst-mode learning with a rate multiplier.
+    /// Circular standard deviation of the unit phases in sensor group `group`,
+    /// `sqrt(-2 ln R)` where `R` is the length of the mean phase vector.
     ///
-    /// When enabled, Hebbian learning rate is boosted by the given factor.
+    /// 0 when all units share one phase; grows without bound as phases spread
+    /// uniformly. Returns 0 for unknown or empty groups.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn sensor_group_phase_spread(&self, group: &str) -> f32 {
+        let Some(units) = self.sensor_units(group) else {
+            return 0.0;
+        };
+        let (mut sx, mut sy, mut n) = (0.0f32, 0.0f32, 0usize);
+        for &id in units {
+            if let Some(u) = self.units.get(id) {
+                sx += u.phase.cos();
+                sy += u.phase.sin();
+                n += 1;
+            }
+        }
+        if n == 0 {
+            return 0.0;
+        }
+        let r = ((sx * sx + sy * sy).sqrt() / n as f32).clamp(1e-12, 1.0);
+        (-2.0 * r.ln()).max(0.0).sqrt()
+    }
+
+    /// One-shot Hebbian pass over the currently co-active unit pairs.
+    ///
+    /// Every edge whose endpoints both have `amp > threshold` changes by
+    /// `hebb_rate * neuromod * sqrt((a - thr) * (b - thr))` (clamped to ±0.25 per
+    /// edge, like a plasticity commit). Unlike [`Brain::step`] there are no
+    /// dynamics, phase gating, eligibility traces or forgetting: phases and
+    /// amplitudes stay as they are, so a held pattern can be trained in
+    /// isolation. `coactive_threshold` overrides [`BrainConfig::coactive_threshold`].
+    /// Frozen or paralyzed units and units with learning disabled are skipped.
+    /// Returns the number of edges updated.
+    pub fn hebbian_epoch(&mut self, coactive_threshold: Option<f32>) -> u32 {
+        let thr = coactive_threshold.unwrap_or(self.cfg.coactive_threshold);
+        self.coactive_weight_pass(thr, self.cfg.hebb_rate * self.neuromod)
+    }
+
+    /// Anti-Hebbian counterpart of [`Brain::hebbian_epoch`]: weakens edges
+    /// between co-active units by `hebb_rate` (scaled by co-activity) regardless
+    /// of the neuromodulator. Useful for unlearning a specific association.
+    /// Returns the number of edges updated.
+    pub fn anti_hebbian_epoch(&mut self, threshold: f32) -> u32 {
+        self.coactive_weight_pass(threshold, -self.cfg.hebb_rate)
+    }
+
+    fn coactive_weight_pass(&mut self, thr: f32, lr: f32) -> u32 {
+        self.learning_monitors = LearningMonitors::default();
+        if lr == 0.0 {
+            return 0;
+        }
+        self.ensure_gate_vectors();
+        self.touch_csr();
+
+        let mut l1 = 0.0f32;
+        let mut edges = 0u32;
+        for owner in 0..self.units.len() {
+            let a = self.units[owner].amp;
+            if a <= thr || !self.learning_enabled[owner] || self.unit_learning_blocked(owner) {
+                continue;
+            }
+            for idx in self.conn_range(owner) {
+                let target = self.connections.targets[idx];
+                if target == INVALID_UNIT || self.unit_learning_blocked(target) {
+                    continue;
+                }
+                let b = self.units[target].amp;
+                if b <= thr {
+                    continue;
+                }
+                let dw = (lr * ((a - thr) * (b - thr)).sqrt()).clamp(-0.25, 0.25);
+                let old = self.connections.weights[idx];
+                let new = self.clip_weight(old + dw);
+                self.connections.weights[idx] = new;
+                l1 += (new - old).abs();
+                edges = edges.saturating_add(1);
+            }
+        }
+
+        self.learning_monitors.plasticity_committed = edges > 0;
+        self.learning_monitors.plasticity_l1 = l1;
+        self.learning_monitors.plasticity_edges = edges;
+        edges
+    }
+
+    /// Enable or disable burst-mode learning with a rate multiplier.
+    ///
+    /// When enabled, Hebbian learning rate is boosted by the given factor.
     /// This is applied on the next step() call.
     ///
     /// # Arguments
@@ -6756,6 +10883,142 @@ impl Brain {
         self.force_associate(&active_sensors, &targets, strength);
     }
 
+    /// Imprint the current active context with a separate rate per edge type.
+    ///
+    /// Uses the same active sensors and top (non-reserved) target units as
+    /// [`Brain::imprint_current_context`], plus the currently active action units:
+    /// - `sensor_rate` applies to sensor → target edges (sensor-outgoing),
+    /// - `action_rate` applies to target → action edges (action-incoming),
+    /// - `pair_rate` applies to direct sensor → action edges (cross-group).
+    ///
+    /// Rates are clamped to `[0.1, 1.0]`; a rate `<= 0.0` skips that edge type.
+    /// Reverse edges get 70% of the forward rate, as in [`Brain::force_associate`].
+    /// `imprint_current_context(r)` matches `imprint_current_context_biased(r, 0.0, 0.0)`.
+    /// Target → action edges are imprinted even when no sensor is active.
+    pub fn imprint_current_context_biased(
+        &mut self,
+        sensor_rate: f32,
+        action_rate: f32,
+        pair_rate: f32,
+    ) {
+        let rate = |r: f32| (r > 0.0).then(|| r.clamp(0.1, 1.0));
+        let (sensor_rate, action_rate, pair_rate) =
+            (rate(sensor_rate), rate(action_rate), rate(pair_rate));
+
+        // Collect currently active sensor units. Target → action edges do not
+        // need any, so there is no early return here.
+        let mut active_sensors: Vec<UnitId> = Vec::new();
+        for group in &self.sensor_groups {
+            for &unit_id in &group.units {
+                if unit_id < self.units.len() && self.units[unit_id].amp > 0.5 {
+                    active_sensors.push(unit_id);
+                }
+            }
+        }
+
+        // Find the most active non-reserved units to associate with.
+        let mut candidates: Vec<(UnitId, f32)> = self
+            .units
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.reserved[*i] && !active_sensors.contains(i))
+            .map(|(i, u)| (i, u.amp))
+            .collect();
+        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(core::cmp::Ordering::Equal));
+
+        // Take top 5 most active units.
+        let targets: Vec<UnitId> = candidates.into_iter().take(5).map(|(id, _)| id).collect();
+
+        let active_actions: Vec<UnitId> = if action_rate.is_some() || pair_rate.is_some() {
+            self.action_groups
+                .iter()
+                .flat_map(|g| g.units.iter().copied())
+                .filter(|&id| id < self.units.len() && self.units[id].amp > 0.5)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // Forward edges first, then the weaker reverse edges.
+        let mut edges: Vec<(UnitId, UnitId, f32)> = Vec::new();
+        let mut link = |from: &[UnitId], to: &[UnitId], rate: Option<f32>| {
+            let Some(r) = rate else {
+                return;
+            };
+            for &a in from {
+                for &b in to {
+                    edges.push((a, b, r));
+                }
+            }
+        };
+        link(&active_sensors, &targets, sensor_rate);
+        link(&targets, &active_actions, action_rate);
+        link(&active_sensors, &active_actions, pair_rate);
+        if edges.is_empty() {
+            return;
+        }
+        let forward = edges.len();
+        let reverse: Vec<(UnitId, UnitId, f32)> =
+            edges.iter().map(|&(a, b, r)| (b, a, r * 0.7)).collect();
+        edges.extend(reverse);
+
+        // Force the involved units to the same phase and high amplitude.
+        for &(a, b, _) in &edges[..forward] {
+            for id in [a, b] {
+                self.units[id].phase = 0.0;
+                self.units[id].amp = 1.5;
+            }
+        }
+        for &(from, to, r) in &edges {
+            if from != to {
+                self.add_or_bump_csr(from, to, r);
+            }
+        }
+
+        // Run a single step with max neuromodulator to cement the association.
+        let orig_neuromod = self.neuromod;
+        self.neuromod = 1.0;
+        self.step();
+        self.neuromod = orig_neuromod;
+    }
+
+    /// Imprint only the active units of one sensor group onto co-active actions.
+    ///
+    /// A focused variant of [`Brain::imprint_current_context`]: units of the named
+    /// sensor group above the activity threshold are associated with every
+    /// currently active action-group unit, so irrelevant context channels are
+    /// not reinforced. Returns `false` if the group is unknown or either side
+    /// has no active units.
+    ///
+    /// # Arguments
+    /// * `group` - Sensor group name
+    /// * `strength` - Imprint strength (0.3-0.8 recommended)
+    pub fn imprint_sensor_group(&mut self, group: &str, strength: f32) -> bool {
+        let Some(sensor) = self.sensor_groups.iter().find(|g| g.name == group) else {
+            return false;
+        };
+        let active_sensors: Vec<UnitId> = sensor
+            .units
+            .iter()
+            .copied()
+            .filter(|&id| id < self.units.len() && self.units[id].amp > 0.5)
+            .collect();
+
+        let active_actions: Vec<UnitId> = self
+            .action_groups
+            .iter()
+            .flat_map(|g| g.units.iter().copied())
+            .filter(|&id| id < self.units.len() && self.units[id].amp > 0.5)
+            .collect();
+
+        if active_sensors.is_empty() || active_actions.is_empty() {
+            return false;
+        }
+
+        self.force_associate(&active_sensors, &active_actions, strength);
+        true
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Idle Dreaming & Sync API (for background processing when inactive)
     // ─────────────────────────────────────────────────────────────────────────
@@ -6930,6 +11193,27 @@ impl Brain {
     }
 }
 
+/// Normalized gap between the best and second-best action score,
+/// `(top - second) / (|top| + |second| + 1e-6)`, clamped to `[0, 1]`.
+#[must_use]
+pub fn action_confidence(top: f32, second: f32) -> f32 {
+    ((top - second) / (top.abs() + second.abs() + 1e-6)).clamp(0.0, 1.0)
+}
+
+const FINGERPRINT_SEED: u64 = 14695981039346656037;
+
+#[inline]
+fn mix64(mut h: u64, x: u64) -> u64 {
+    // FNV-1a-ish mixing
+    h ^= x;
+    h = h.wrapping_mul(1099511628211);
+    // extra avalanching
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51afd7ed558ccd);
+    h ^= h >> 33;
+    h
+}
+
 fn intern_symbol(
     map: &mut HashMap<String, SymbolId>,
     rev: &mut Vec<String>,
@@ -7007,6 +11291,20 @@ fn phase_alignment(a: f32, b: f32) -> f32 {
     x.clamp(0.0, 1.0)
 }
 
+fn phase_gate(align: f32, threshold: f32, softness: f32) -> f32 {
+    // Smooth blend between no eligibility (misaligned) and alignment.
+    // softness=0 keeps the legacy hard gate.
+    if softness <= 0.0 {
+        if align > threshold {
+            align
+        } else {
+            0.0
+        }
+    } else {
+        sigmoid((align - threshold) / softness) * align
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7167,6 +11465,369 @@ mod tests {
         assert!(brain.eligibility[0] > 0.0);
     }
 
+    #[test]
+    fn causal_chain_score_multiplies_links_and_zeroes_missing_ones() {
+        let mut brain = Brain::new(BrainConfig::with_size(64, 4).with_seed(6));
+        for name in ["a", "b", "c", "d"] {
+            brain.define_action(name, 4);
+        }
+        for _ in 0..30 {
+            for name in ["a", "b", "c"] {
+                brain.note_action(name);
+                brain.commit_observation();
+            }
+        }
+
+        let ab = brain.causal_chain_score(&["a", "b"]);
+        let bc = brain.causal_chain_score(&["b", "c"]);
+        assert!(ab > 0.3 && bc > 0.3);
+        assert!((brain.causal_chain_score(&["a", "b", "c"]) - ab * bc).abs() < 1e-6);
+        assert_eq!(brain.causal_chain_score(&["a", "b", "d"]), 0.0);
+        assert_eq!(brain.causal_chain_score(&["a", "nope"]), 0.0);
+        assert_eq!(brain.causal_chain_score(&["a"]), 0.0);
+    }
+
+    #[test]
+    fn causal_temporal_order_follows_directed_edges() {
+        let mut brain = Brain::new(BrainConfig::with_size(64, 4).with_seed(6));
+        for name in ["a", "b", "c", "gap"] {
+            brain.define_action(name, 4);
+        }
+        for _ in 0..30 {
+            for name in ["a", "b", "c", "gap"] {
+                brain.note_action(name);
+                brain.commit_observation();
+            }
+        }
+
+        assert_eq!(brain.causal_temporal_order(&["c", "a", "b"]), [1, 2, 0]);
+        assert_eq!(brain.causal_temporal_order(&["b", "nope", "a"]), [1, 2, 0]);
+        assert!(brain.causal_temporal_order(&[]).is_empty());
+    }
+
+    #[test]
+    fn causal_graph_json_round_trips_strengths() {
+        let mut brain = Brain::new(BrainConfig::with_size(64, 4).with_seed(8));
+        for name in ["a", "b", "c"] {
+            brain.define_action(name, 4);
+        }
+        for _ in 0..20 {
+            for name in ["a", "b", "c"] {
+                brain.note_action(name);
+                brain.commit_observation();
+            }
+        }
+
+        let json = brain.serialize_causal_graph();
+        let v: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        let edges = v["edges"].as_array().unwrap();
+        assert!(!edges.is_empty() && edges.len() <= CAUSAL_GRAPH_JSON_MAX_EDGES);
+        let w: Vec<f64> = edges
+            .iter()
+            .map(|e| e["weight"].as_f64().unwrap())
+            .collect();
+        assert!(w.windows(2).all(|p| p[0].abs() >= p[1].abs()));
+
+        let ab = brain.causal_chain_score(&["a", "b"]);
+        let mut fresh = Brain::new(BrainConfig::with_size(64, 4).with_seed(9));
+        fresh.load_causal_graph_from(&json).unwrap();
+        assert!((fresh.causal_chain_score(&["a", "b"]) - ab).abs() < 1e-4);
+        assert_eq!(fresh.causal.base_total(), brain.causal.base_total());
+
+        // Loading the reloaded memory's export gives the same export back.
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&fresh.serialize_causal_graph()).unwrap(),
+            v
+        );
+
+        assert!(fresh.load_causal_graph_from(b"{\"edges\":[]}").is_err());
+        assert!((fresh.causal_chain_score(&["a", "b"]) - ab).abs() < 1e-4);
+    }
+
+    #[test]
+    fn causal_surprise_drops_when_predecessor_just_fired() {
+        let mut brain = Brain::new(BrainConfig::with_size(64, 4).with_seed(5));
+        for name in ["a", "b", "c", "d"] {
+            brain.define_action(name, 4);
+        }
+
+        for _ in 0..30 {
+            for name in ["a", "b", "c"] {
+                brain.note_action(name);
+                brain.commit_observation();
+            }
+        }
+        brain.note_action("a");
+        brain.commit_observation();
+
+        let expected = brain.causal_surprise("b");
+        assert!(expected < 0.5, "b follows a: {expected}");
+        assert!(brain.causal_surprise("d") > 0.9);
+        assert_eq!(brain.causal_surprise("never_seen"), 1.0);
+
+        // Off by default; at weight 1 the estimate is the pending symbols' surprise.
+        brain.note_action("b");
+        assert_eq!(brain.intrinsic_reward_estimate(), 0.0);
+        brain.set_causal_novelty_weight(1.0);
+        assert!((brain.intrinsic_reward_estimate() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn intrinsic_reward_decays_with_repeated_stimulus() {
+        let mut brain = Brain::new(BrainConfig {
+            unit_count: 64,
+            connectivity_per_unit: 4,
+            noise_amp: 0.0,
+            noise_phase: 0.0,
+            novelty_window: 20,
+            seed: Some(11),
+            ..Default::default()
+        });
+        brain.define_sensor("a", 8);
+        brain.define_sensor("b", 8);
+        assert_eq!(brain.intrinsic_reward_estimate(), 0.0);
+
+        let present = |brain: &mut Brain, name: &str| {
+            brain.apply_stimulus(Stimulus::new(name, 1.0));
+            brain.step_inference();
+            brain.intrinsic_reward_estimate()
+        };
+
+        // Drive "a" until its units cross the coactive threshold.
+        let mut first = 0.0;
+        for _ in 0..20 {
+            first = present(&mut brain, "a");
+            if first > 0.0 {
+                break;
+            }
+        }
+        assert!(first > 0.9, "first exposure should be novel: {first}");
+
+        let later: Vec<f32> = (0..30).map(|_| present(&mut brain, "a")).collect();
+        let last = later[20..].iter().sum::<f32>() / 10.0;
+        assert!(last < 0.1, "repeated exposure should be familiar: {last}");
+
+        // A different stimulus recruits unseen units again.
+        let mut peak = 0.0f32;
+        for _ in 0..20 {
+            peak = peak.max(present(&mut brain, "b"));
+        }
+        assert!(peak > last, "new stimulus should raise novelty: {peak}");
+    }
+
+    #[test]
+    fn oja_rule_converges_to_first_principal_component() {
+        let mut brain = Brain::new(BrainConfig {
+            unit_count: 4,
+            connectivity_per_unit: 2,
+            seed: Some(5),
+            ..Default::default()
+        });
+
+        // Unit 0 reads from units 1 and 2; any extra slots point at silent unit 3.
+        let range = brain.conn_range(0);
+        assert!(range.len() >= 2);
+        for (k, idx) in range.clone().enumerate() {
+            brain.connections.targets[idx] = match k {
+                0 => 1,
+                1 => 2,
+                _ => 3,
+            };
+            brain.connections.weights[idx] = if k == 0 { 0.1 } else { 0.6 };
+        }
+        brain.units[3].amp = 0.0;
+
+        // Inputs x2 = 0.5 * x1 + small noise: first PC is (2, 1) / sqrt(5).
+        let mut rng = Prng::new(9);
+        for _ in 0..5000 {
+            let s = rng.gen_range_f32(-1.0, 1.0);
+            let x1 = s;
+            let x2 = 0.5 * s + rng.gen_range_f32(-0.05, 0.05);
+            brain.units[1].amp = x1;
+            brain.units[2].amp = x2;
+            let w: Vec<f32> = brain.neighbors(0).map(|(_, w)| w).collect();
+            brain.units[0].amp = w[0] * x1 + w[1] * x2;
+            brain.apply_oja_rule(0, 0.02);
+        }
+
+        let w: Vec<f32> = brain.neighbors(0).map(|(_, w)| w).collect();
+        let norm = (w[0] * w[0] + w[1] * w[1]).sqrt();
+        let pc = [2.0 / 5f32.sqrt(), 1.0 / 5f32.sqrt()];
+        let cos = (w[0] * pc[0] + w[1] * pc[1]) / norm;
+        assert!((norm - 1.0).abs() < 0.05, "weight norm {norm}");
+        assert!(cos.abs() > 0.99, "cosine to first PC {cos}");
+        assert!(w[2..].iter().all(|w| w.abs() < 1e-3));
+    }
+
+    #[test]
+    fn weight_clipping_bounds_runaway_potentiation() {
+        let cfg = BrainConfig {
+            unit_count: 4,
+            connectivity_per_unit: 1,
+            noise_amp: 0.0,
+            noise_phase: 0.0,
+            global_inhibition: 0.0,
+            hebb_rate: 0.2,
+            forget_rate: 0.0,
+            coactive_threshold: 0.1,
+            phase_lock_threshold: 0.5,
+            eligibility_decay: 0.0,
+            eligibility_gain: 1.0,
+            learning_deadband: 0.0,
+            seed: Some(4),
+            ..Default::default()
+        };
+
+        let run = |brain: &mut Brain| {
+            brain.connections.targets[0] = 1;
+            brain.connections.weights[0] = 0.0;
+            brain
+                .eligibility
+                .resize(brain.connections.weights.len(), 0.0);
+            for _ in 0..200 {
+                brain.units[0].amp = 1.0;
+                brain.units[1].amp = 1.0;
+                brain.units[0].phase = 0.0;
+                brain.units[1].phase = 0.0;
+                brain.update_eligibility_scalar();
+                brain.set_neuromodulator(1.0);
+                brain.apply_plasticity_scalar();
+            }
+            brain.connections.weights[0]
+        };
+
+        // Without clipping, consistent reward saturates at the built-in clamp.
+        let mut unclipped = Brain::new(cfg.clone());
+        assert_eq!(
+            unclipped.weight_clipping(),
+            (f32::NEG_INFINITY, f32::INFINITY)
+        );
+        assert!(run(&mut unclipped) > 1.0);
+
+        let mut clipped = Brain::new(cfg.clone());
+        clipped.set_weight_clipping(-1.0, 1.0);
+        assert_eq!(run(&mut clipped), 1.0);
+
+        // Bounds set via config behave the same way.
+        let mut from_cfg = Brain::new(BrainConfig {
+            weight_clip_min: -0.5,
+            weight_clip_max: 0.5,
+            ..cfg.clone()
+        });
+        assert_eq!(run(&mut from_cfg), 0.5);
+
+        assert!(BrainConfig {
+            weight_clip_min: 1.0,
+            weight_clip_max: -1.0,
+            ..cfg
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn causal_only_image_restores_memory_by_symbol_name() {
+        let cfg = BrainConfig::with_size(32, 4).with_seed(5);
+        let mut trained = Brain::new(cfg.clone());
+        for _ in 0..6 {
+            trained.note_compound_symbol(&["cue"]);
+            trained.commit_observation();
+            trained.note_compound_symbol(&["goal"]);
+            trained.commit_observation();
+        }
+        let expected = trained.top_causal_links_from("cue", 4);
+        assert!(expected.iter().any(|(name, _)| name == "goal"));
+
+        let mut bytes = Vec::new();
+        trained.save_causal_only(&mut bytes).unwrap();
+        assert!(bytes.len() < trained.image_size_bytes().unwrap());
+
+        // Intern symbols in a different order so ids disagree with `trained`.
+        let mut other = Brain::new(cfg.with_seed(6));
+        other.note_compound_symbol(&["goal"]);
+        other.note_compound_symbol(&["unrelated"]);
+        let weights = other.connections.weights.clone();
+        other
+            .load_causal_only_from(&mut std::io::Cursor::new(&bytes))
+            .unwrap();
+
+        assert_eq!(other.connections.weights, weights);
+        assert_eq!(other.top_causal_links_from("cue", 4), expected);
+        assert_eq!(other.causal_stats().edges, trained.causal_stats().edges);
+
+        let mut full = Vec::new();
+        trained.save_image_to(&mut full).unwrap();
+        assert!(other
+            .load_causal_only_from(&mut std::io::Cursor::new(&full))
+            .is_err());
+    }
+
+    #[test]
+    fn streaming_image_load_skips_chunks_and_reads_v3() {
+        let mut brain = Brain::new(BrainConfig::with_size(32, 4).with_seed(29));
+        brain.define_sensor("s", 4);
+        brain.define_action("a", 2);
+        let bytes = brain.save_image_bytes().unwrap();
+
+        let mut tags = Vec::new();
+        let mut weights = None;
+        for chunk in Brain::load_image_streaming(bytes.as_slice()).skip_tags(&[*b"CAUS"]) {
+            match chunk.unwrap() {
+                BrainChunk::Header { version } => assert_eq!(version, storage::VERSION_V4),
+                BrainChunk::Connections(csr) => weights = Some(csr.weights),
+                BrainChunk::Causal(_) => panic!("CAUS should be skipped"),
+                BrainChunk::Skipped { tag } => tags.push(tag),
+                _ => {}
+            }
+        }
+        assert_eq!(tags, vec![*b"CAUS"]);
+        assert_eq!(weights.unwrap().len(), brain.connection_count());
+
+        // A flipped byte inside a chunk body fails its CRC.
+        let mut corrupt = bytes.clone();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 0xFF;
+        assert!(Brain::load_image_bytes(&corrupt).is_err());
+
+        // Re-frame as a version 3 image (no CRC, units + CSR in one UNIT chunk).
+        let mut r = &bytes[12..];
+        let mut v3: Vec<u8> = Vec::new();
+        v3.extend_from_slice(storage::MAGIC);
+        storage::write_u32_le(&mut v3, storage::VERSION_V3).unwrap();
+        let mut unit_states = Vec::new();
+        while let Some((tag, payload)) =
+            storage::read_chunk_lz4(&mut r, storage::VERSION_V4).unwrap()
+        {
+            match tag {
+                UNIT_STATES_CHUNK => unit_states = payload,
+                CSR_CHUNK => {
+                    unit_states.extend_from_slice(&payload[4..]);
+                    storage::write_chunk_v2_lz4(&mut v3, *b"UNIT", &unit_states).unwrap();
+                }
+                _ => storage::write_chunk_v2_lz4(&mut v3, tag, &payload).unwrap(),
+            }
+        }
+        let loaded = Brain::load_image_bytes(&v3).unwrap();
+        assert_eq!(loaded.connection_count(), brain.connection_count());
+        assert_eq!(loaded.sensor_units("s"), brain.sensor_units("s"));
+    }
+
+    #[test]
+    fn unit_metadata_json_lists_io_groups() {
+        let mut brain = Brain::new(BrainConfig::with_size(32, 4).with_seed(31));
+        brain.define_sensor("spot_left", 3);
+        brain.define_action("left", 2);
+
+        let v: serde_json::Value =
+            serde_json::from_str(&brain.export_unit_metadata_json()).unwrap();
+        assert_eq!(v["unit_count"], 32);
+        assert_eq!(v["groups"]["spot_left"]["type"], "sensor");
+        assert_eq!(v["groups"]["left"]["type"], "action");
+        let units: Vec<usize> =
+            serde_json::from_value(v["groups"]["left"]["units"].clone()).unwrap();
+        assert_eq!(units, brain.action_units("left").unwrap());
+    }
+
     #[test]
     fn brain_image_roundtrip_basic() {
         let cfg = BrainConfig {
@@ -7194,6 +11855,7 @@ mod tests {
             causal_lag_steps: 5,
             causal_lag_decay: 0.62,
             causal_symbol_cap: 21,
+            causal_window_frames: 7,
             module_routing_top_k: 3,
             module_routing_strict: true,
             module_routing_beta: 0.9,
@@ -7204,6 +11866,7 @@ mod tests {
             cross_module_plasticity_scale: 0.25,
             cross_module_forget_boost: 0.02,
             cross_module_prune_bonus: 0.003,
+            weight_clip_max: 0.8,
             ..Default::default()
         };
 
@@ -7245,6 +11908,10 @@ mod tests {
         assert_eq!(loaded.cfg.causal_lag_steps, brain.cfg.causal_lag_steps);
         assert!((loaded.cfg.causal_lag_decay - brain.cfg.causal_lag_decay).abs() < 1e-6);
         assert_eq!(loaded.cfg.causal_symbol_cap, brain.cfg.causal_symbol_cap);
+        assert_eq!(
+            loaded.cfg.causal_window_frames,
+            brain.cfg.causal_window_frames
+        );
         assert_eq!(
             loaded.cfg.module_routing_top_k,
             brain.cfg.module_routing_top_k
@@ -7282,6 +11949,7 @@ mod tests {
         assert!(
             (loaded.cfg.cross_module_prune_bonus - brain.cfg.cross_module_prune_bonus).abs() < 1e-6
         );
+        assert_eq!(loaded.weight_clipping(), (f32::NEG_INFINITY, 0.8));
         assert_eq!(loaded.units.len(), brain.units.len());
         assert_eq!(loaded.reserved.len(), brain.reserved.len());
         assert_eq!(loaded.learning_enabled.len(), brain.learning_enabled.len());
@@ -7578,6 +12246,8 @@ mod tests {
 
         // Total connections = 8 * 2 = 16.
         assert_eq!(brain.total_connection_count(), 16);
+        assert_eq!(brain.connection_count(), 16);
+        assert_eq!(brain.unit_count(), 8);
     }
 
     #[test]
@@ -7629,16 +12299,65 @@ mod tests {
         let grew_s = brain.ensure_sensor_min_width("s", 5);
         let grew_a = brain.ensure_action_min_width("a", 6);
 
-        assert!(grew_s > 0);
+        assert_eq!(
+            grew_s,
+            SensorEnsureResult {
+                was_created: false,
+                old_width: 2,
+                new_width: 5
+            }
+        );
+        assert_eq!(grew_s.added(), 3);
         assert!(grew_a > 0);
 
+        let same = brain.ensure_sensor_min_width("s", 3);
+        assert_eq!((same.was_created, same.added()), (false, 0));
+        let created = brain.ensure_sensor_min_width("t", 4);
+        assert_eq!(
+            (created.was_created, created.old_width, created.new_width),
+            (true, 0, 4)
+        );
+        assert!(!brain.ensure_sensor("t", 1));
+        assert!(brain.ensure_sensor("u", 1));
+
         assert_eq!(brain.sensor_units("s").unwrap().len(), 5);
         assert_eq!(brain.action_units("a").unwrap().len(), 6);
     }
 
     #[test]
-    fn execution_tier_switch() {
-        let cfg = BrainConfig {
+    fn auto_select_picks_first_available_tier() {
+        let mut brain = Brain::new(BrainConfig {
+            unit_count: 8,
+            connectivity_per_unit: 2,
+            seed: Some(5),
+            ..Default::default()
+        });
+
+        let tiers = brain.available_tiers();
+        assert_eq!(tiers.last(), Some(&ExecutionTier::Scalar));
+        // Fastest first, no duplicates.
+        let rank = |t: &ExecutionTier| match t {
+            ExecutionTier::Gpu => 0,
+            ExecutionTier::Parallel => 1,
+            ExecutionTier::Simd => 2,
+            ExecutionTier::Scalar => 3,
+        };
+        assert!(tiers.windows(2).all(|w| rank(&w[0]) < rank(&w[1])));
+
+        let selected = brain.auto_select_execution_tier();
+        assert_eq!(selected, tiers[0]);
+        assert_eq!(brain.execution_tier(), selected);
+        assert_eq!(brain.effective_execution_tier(), selected);
+
+        assert_eq!(ExecutionTier::Gpu.to_string(), "GPU");
+        assert_eq!(ExecutionTier::Parallel.to_string(), "Parallel");
+        assert_eq!(ExecutionTier::Simd.to_string(), "SIMD");
+        assert_eq!(ExecutionTier::Scalar.to_string(), "Scalar");
+    }
+
+    #[test]
+    fn execution_tier_switch() {
+        let cfg = BrainConfig {
             unit_count: 16,
             connectivity_per_unit: 2,
             dt: 0.05,
@@ -7726,6 +12445,62 @@ mod tests {
         assert_eq!(cloned.age_steps() + 1, brain.age_steps());
     }
 
+    #[test]
+    fn brain_clone_is_deep_copy() {
+        let cfg = BrainConfig::with_size(32, 4).with_seed(7);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("stim", 4);
+        brain.define_action("act", 4);
+        brain.set_observer_telemetry(true);
+
+        for _ in 0..5 {
+            brain.apply_stimulus(Stimulus::new("stim", 1.0));
+            brain.note_action("act");
+            brain.set_neuromodulator(0.5);
+            brain.step();
+            brain.commit_observation();
+        }
+
+        let weights = brain.connections.weights.clone();
+        let phases = brain.unit_phases();
+        let biases: Vec<f32> = brain.units.iter().map(|u| u.bias).collect();
+        let eligibility = brain.eligibility.clone();
+        let causal_edges = brain.causal_stats().edges;
+
+        let mut cloned = brain.clone();
+        assert_eq!(cloned.connections.weights, weights);
+        assert_eq!(cloned.eligibility, eligibility);
+
+        // Mutate every deep-copied component of the clone.
+        for w in cloned.connections.weights.iter_mut() {
+            *w += 0.25;
+        }
+        for u in cloned.units.iter_mut() {
+            u.phase += 0.5;
+            u.bias += 0.1;
+        }
+        for e in cloned.eligibility.iter_mut() {
+            *e = 9.0;
+        }
+        cloned.define_sensor("extra", 2);
+        for _ in 0..3 {
+            cloned.apply_stimulus(Stimulus::new("extra", 1.0));
+            cloned.note_action("act");
+            cloned.step();
+            cloned.commit_observation();
+        }
+
+        assert_eq!(brain.connections.weights, weights);
+        assert_eq!(brain.unit_phases(), phases);
+        let biases_after: Vec<f32> = brain.units.iter().map(|u| u.bias).collect();
+        assert_eq!(biases_after, biases);
+        assert_eq!(brain.eligibility, eligibility);
+        assert_eq!(brain.causal_stats().edges, causal_edges);
+        assert!(!brain.has_sensor("extra"));
+        assert!(cloned.has_sensor("extra"));
+        assert!(cloned.causal_stats().edges > causal_edges);
+    }
+
     #[test]
     fn brain_config_default() {
         let cfg = BrainConfig::default();
@@ -7764,82 +12539,1256 @@ mod tests {
         let phases = brain.unit_phases();
         assert_eq!(phases.len(), 64);
 
-        // Test connection accessors
-        assert!(!brain.connection_weights().is_empty());
-        assert!(!brain.connection_targets().is_empty());
-        assert_eq!(brain.connection_offsets().len(), 65); // unit_count + 1
+        // Test connection accessors
+        assert!(!brain.connection_weights().is_empty());
+        assert!(!brain.connection_targets().is_empty());
+        assert_eq!(brain.connection_offsets().len(), 65); // unit_count + 1
+
+        // Test connection_matrix
+        let matrix = brain.connection_matrix();
+        assert_eq!(matrix.len(), 64);
+        assert_eq!(matrix[0].len(), 64);
+
+        // Test top_active_units
+        brain.apply_stimulus(Stimulus::new("vision", 1.0));
+        brain.step();
+        let top = brain.top_active_units(5);
+        assert!(top.len() <= 5);
+
+        // Test sensor/action unit accessors
+        assert!(brain.sensor_units("vision").is_some());
+        assert!(brain.action_units("move").is_some());
+        assert!(brain.sensor_units("nonexistent").is_none());
+
+        // Test config accessor
+        let cfg_ref = brain.config();
+        assert_eq!(cfg_ref.unit_count, 64);
+    }
+
+    #[test]
+    fn inference_step_does_not_modify_weights() {
+        let cfg = BrainConfig::with_size(64, 6).with_seed(7);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("stim", 8);
+        brain.define_action("left", 4);
+        brain.define_action("right", 4);
+
+        let base = brain.clone();
+
+        brain.apply_stimulus_inference(Stimulus::new("stim", 1.0));
+        for _ in 0..3 {
+            brain.step_inference();
+        }
+
+        let delta = brain.diff_weights_topk(&base, 32);
+        assert!(
+            delta.weight_deltas.is_empty(),
+            "inference stepping should not change weights"
+        );
+    }
+
+    #[test]
+    fn masked_weight_delta_skips_action_edges() {
+        let cfg = BrainConfig::with_size(64, 6).with_seed(11);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("stim", 8);
+        brain.define_action("left", 4);
+        brain.define_action("right", 4);
+
+        let action_mask = brain.action_unit_mask();
+        let sensor_mask = brain.sensor_unit_mask();
+        assert_eq!(action_mask.len(), brain.weights_len());
+        assert_eq!(sensor_mask.len(), brain.weights_len());
+        assert!(action_mask.iter().any(|&m| m) && !action_mask.iter().all(|&m| m));
+        assert!(sensor_mask.iter().any(|&m| m));
+
+        let delta = BrainDelta {
+            weight_deltas: (0..brain.weights_len()).map(|i| (i, 0.1)).collect(),
+        };
+        let keep = brain.non_action_unit_mask();
+        assert!(keep.iter().zip(&action_mask).all(|(&k, &a)| !(k && a)));
+        let before = brain.clone();
+        let applied = brain.apply_weight_delta_masked(&delta, 0.05, Some(&keep));
+        assert_eq!(applied, keep.iter().filter(|&&k| k).count());
+
+        let after = brain.diff_weights_topk(&before, usize::MAX);
+        assert_eq!(after.weight_deltas.len(), applied);
+        for (idx, dw) in after.weight_deltas {
+            assert!(!action_mask[idx]);
+            assert!((dw - 0.05).abs() < 1.0e-6);
+        }
+
+        // A short mask only covers its own prefix.
+        let mut short = brain.clone();
+        assert_eq!(
+            short.apply_weight_delta_masked(&delta, 0.05, Some(&[true])),
+            1
+        );
+
+        // Tombstoned slots are in neither mask nor its complement.
+        let dead = keep.iter().position(|&k| k).unwrap();
+        brain.tombstone_edge(dead);
+        assert!(!brain.non_action_unit_mask()[dead]);
+        assert!(!brain.non_sensor_unit_mask()[dead]);
+        assert!(!brain.action_unit_mask()[dead]);
+    }
+
+    #[test]
+    fn env_overrides_apply_on_top_of_defaults() {
+        let vars = [
+            ("BRAINE_UNIT_COUNT", "96"),
+            ("BRAINE_HEBB_RATE", " 0.2 "),
+            ("BRAINE_SEED", "none"),
+            ("BRAINE_NOISE_AMP", "loud"),
+        ];
+        let get = |var: &str| {
+            vars.iter()
+                .find(|(k, _)| *k == var)
+                .map(|(_, v)| v.to_string())
+        };
+
+        let mut cfg = BrainConfig::default().with_seed(9);
+        let err = cfg.apply_env_vars(get).unwrap_err();
+        assert!(err.starts_with("BRAINE_NOISE_AMP"), "{err}");
+        assert_eq!(cfg.unit_count, 96);
+        assert_eq!(cfg.hebb_rate, 0.2);
+        assert_eq!(cfg.seed, None);
+        assert_eq!(cfg.noise_amp, BrainConfig::default().noise_amp);
+
+        let template = cfg.env_template();
+        assert_eq!(template.lines().count(), BrainConfig::ENV_VARS.len());
+        assert!(template.contains("BRAINE_UNIT_COUNT=96\n"));
+        assert!(template.contains("BRAINE_SEED=none\n"));
+
+        // The template round-trips.
+        let mut back = BrainConfig::default();
+        back.apply_env_vars(|var| {
+            template
+                .lines()
+                .find_map(|l| l.strip_prefix(var)?.strip_prefix('='))
+                .map(str::to_string)
+        })
+        .unwrap();
+        assert_eq!(back.env_template(), template);
+    }
+
+    #[test]
+    fn config_validation() {
+        // Valid config
+        let cfg = BrainConfig::with_size(64, 8);
+        assert!(cfg.validate().is_ok());
+
+        // Test estimated memory
+        let mem = cfg.estimated_memory_bytes();
+        assert!(mem > 0);
+        assert!(mem < 1024 * 1024); // Should be < 1MB for 64 units
+
+        // Test diagnostics includes new fields
+        let brain = Brain::new(cfg);
+        let diag = brain.diagnostics();
+        assert!(diag.memory_bytes > 0);
+        assert_eq!(diag.execution_tier, ExecutionTier::Scalar);
+    }
+
+    #[test]
+    #[should_panic(expected = "unit_count must be >= 4")]
+    fn config_rejects_tiny_network() {
+        let _ = BrainConfig::with_size(2, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "connectivity_per_unit must be < unit_count")]
+    fn config_rejects_overconnected() {
+        let _ = BrainConfig::with_size(16, 20);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_plasticity_matches_scalar() {
+        let cfg = BrainConfig::with_size(256, 37).with_seed(11);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("s", 16);
+        for _ in 0..20 {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.set_neuromodulator(0.0);
+            brain.step();
+        }
+        brain.set_neuromodulator(0.8);
+        brain.set_unit_frozen(3, true);
+
+        let mut scalar = brain.clone();
+        let mut simd = brain.clone();
+        scalar.apply_plasticity_scalar();
+        simd.apply_plasticity_simd();
+
+        assert!(scalar.learning_monitors.plasticity_edges > 0);
+        assert_eq!(
+            scalar.learning_monitors.plasticity_edges,
+            simd.learning_monitors.plasticity_edges
+        );
+        assert!(
+            (scalar.learning_monitors.plasticity_l1 - simd.learning_monitors.plasticity_l1).abs()
+                < 1e-3
+        );
+        for (a, b) in scalar
+            .connections
+            .weights
+            .iter()
+            .zip(simd.connections.weights.iter())
+        {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_eligibility_matches_scalar() {
+        let cfg = BrainConfig::with_size(256, 37).with_seed(5);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("s", 24);
+        for _ in 0..20 {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.step();
+        }
+        brain.apply_stimulus(Stimulus::new("s", 1.0));
+        brain.set_unit_frozen(5, true);
+
+        for softness in [0.0, 0.05] {
+            let mut scalar = brain.clone();
+            scalar.cfg.phase_gate_softness = softness;
+            let mut simd = scalar.clone();
+            scalar.update_eligibility_scalar();
+            simd.update_eligibility_simd();
+
+            assert!(scalar.eligibility.iter().any(|&e| e != 0.0));
+            let l1_scalar = scalar.learning_monitors.eligibility_l1;
+            let l1_simd = simd.learning_monitors.eligibility_l1;
+            assert!((l1_scalar - l1_simd).abs() <= 1e-3 * l1_scalar.max(1.0));
+            for (a, b) in scalar.eligibility.iter().zip(simd.eligibility.iter()) {
+                assert!((a - b).abs() < 1e-4, "{a} vs {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn step_batch_matches_sequential_inference_and_leaves_brain_untouched() {
+        let cfg = BrainConfig::with_size(64, 8).with_seed(3);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("a", 4);
+        brain.define_sensor("b", 4);
+        brain.define_action("left", 4);
+        brain.define_action("right", 4);
+        let age = brain.age_steps;
+
+        let input = |name: &str, steps: u32| BrainInput {
+            stimuli: vec![OwnedStimulus {
+                name: name.to_string(),
+                strength: 1.0,
+            }],
+            steps,
+            context_key: name.to_string(),
+            meaning_alpha: 1.0,
+            verbose: false,
+        };
+        let inputs = [input("a", 3), input("b", 5)];
+        let outputs = brain.step_batch(&inputs);
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(brain.age_steps, age);
+
+        for (inp, out) in inputs.iter().zip(&outputs) {
+            let mut clone = brain.clone();
+            clone.apply_stimulus_inference(inp.stimuli[0].as_stimulus());
+            for _ in 0..inp.steps {
+                clone.step_inference();
+            }
+            let expected = clone.action_score_breakdown(&inp.context_key, inp.meaning_alpha);
+            assert_eq!(out.action_scores.len(), expected.len());
+            for (a, b) in out.action_scores.iter().zip(&expected) {
+                assert_eq!(a.name, b.name);
+                assert_eq!(a.score, b.score);
+            }
+            assert_eq!(out.diagnostics.avg_amp, clone.diagnostics().avg_amp);
+        }
+    }
+
+    #[test]
+    fn ranked_actions_debug_matches_plain_ranking() {
+        let cfg = BrainConfig::with_size(64, 8).with_seed(11);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("a", 4);
+        brain.define_action("left", 4);
+        brain.define_action("right", 4);
+
+        for _ in 0..20 {
+            brain.apply_stimulus(Stimulus::new("a", 1.0));
+            brain.note_compound_symbol(&["a"]);
+            brain.step();
+            brain.note_action("right");
+            brain.note_compound_symbol(&["pair", "a", "right"]);
+            brain.set_neuromodulator(1.0);
+            brain.reinforce_action("right", 1.0);
+            brain.commit_observation();
+        }
+
+        let plain = brain.ranked_actions_with_meaning("a", 2.0);
+        let debug = brain.ranked_actions_with_meaning_debug("a", 2.0);
+        assert_eq!(debug.len(), plain.len());
+        for (d, (name, score)) in debug.iter().zip(&plain) {
+            assert_eq!(&d.name, name);
+            assert_eq!(d.composite_score, *score);
+            assert_eq!(
+                d.composite_score,
+                d.habit_score * 0.5 + 2.0 * d.meaning_score
+            );
+        }
+
+        let right = debug.iter().find(|d| d.name == "right").unwrap();
+        let left = debug.iter().find(|d| d.name == "left").unwrap();
+        assert_eq!(debug[0].name, "right");
+        assert!(right.visit_count > 0.0);
+        assert_eq!(left.visit_count, 0.0);
+        assert!(right.pair_reward_edges.meaning > 0.0);
+        assert!(right.causal_score > left.causal_score);
+    }
+
+    #[test]
+    fn all_context_scores_match_per_context_breakdown() {
+        let cfg = BrainConfig::with_size(64, 8).with_seed(5);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("a", 4);
+        brain.define_sensor("b", 4);
+        brain.define_action("left", 4);
+        brain.define_action("right", 4);
+
+        brain.apply_stimulus(Stimulus::new("a", 1.0));
+        brain.note_action("left");
+        brain.note_compound_symbol(&["pair", "a", "left"]);
+        brain.set_neuromodulator(1.0);
+        brain.reinforce_action("left", 1.0);
+        brain.commit_observation();
+
+        let all = brain.action_score_breakdown_all_contexts(2.0);
+        assert_eq!(all.len(), 2);
+        for ctx in ["a", "b"] {
+            let expected = brain.action_score_breakdown(ctx, 2.0);
+            let got = &all[ctx];
+            assert_eq!(got.len(), expected.len());
+            for (x, y) in got.iter().zip(&expected) {
+                assert_eq!(x.name, y.name);
+                assert_eq!(x.score, y.score);
+            }
+        }
+    }
+
+    #[test]
+    fn neuromodulator_momentum_smooths_rewards() {
+        let cfg = BrainConfig {
+            neuromodulator_momentum: 0.5,
+            ..BrainConfig::with_size(16, 4)
+        };
+        let mut brain = Brain::new(cfg);
+        brain.set_neuromodulator(1.0);
+        assert!((brain.neuromodulator() - 0.5).abs() < 1e-6);
+        brain.set_neuromodulator(-1.0);
+        assert!((brain.neuromodulator() + 0.25).abs() < 1e-6);
+
+        brain.set_neuromodulator_momentum(0.0);
+        brain.set_neuromodulator(0.8);
+        assert_eq!(brain.neuromodulator(), 0.8);
+
+        brain.set_neuromodulator_momentum(f32::NAN);
+        assert_eq!(brain.neuromodulator_momentum(), 0.0);
+        brain.set_neuromodulator_momentum(1.0);
+        assert!(brain.neuromodulator_momentum() < 1.0);
+    }
+
+    #[test]
+    fn pair_reward_edges_all_actions_ranks_by_meaning() {
+        let cfg = BrainConfig::with_size(64, 8).with_seed(6);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("s", 4);
+        for a in ["a", "b", "c"] {
+            brain.define_action(a, 4);
+        }
+        for (action, reward) in [("c", 1.0), ("a", -1.0), ("c", 1.0), ("a", -1.0)] {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.note_action(action);
+            brain.note_compound_symbol(&["pair", "s", action]);
+            brain.set_neuromodulator(0.0);
+            brain.commit_observation();
+            // Reward lands on the following boundary (lag-1 transition edge).
+            brain.set_neuromodulator(reward);
+            brain.commit_observation();
+        }
+
+        let ranked = brain.pair_reward_edges_all_actions("s");
+        let names: Vec<&str> = ranked.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["c", "b", "a"]);
+        for (name, edges) in &ranked {
+            assert_eq!(edges.meaning, brain.pair_reward_edges("s", name).meaning);
+        }
+
+        let global = brain.action_reward_edges_all();
+        let names: Vec<&str> = global.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["c", "b", "a"]);
+        for (name, edges) in &global {
+            assert_eq!(edges.meaning, brain.action_reward_edges(name).meaning);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn brain_config_toml_round_trips_and_validates() {
+        let cfg = BrainConfig::with_size(128, 10).with_seed(9);
+        let parsed = BrainConfig::from_toml(&cfg.to_toml().unwrap()).unwrap();
+        assert_eq!(parsed.unit_count, 128);
+        assert_eq!(parsed.connectivity_per_unit, 10);
+        assert_eq!(parsed.seed, Some(9));
+        assert_eq!(parsed.hebb_rate, cfg.hebb_rate);
+
+        // Omitted fields fall back to defaults.
+        let partial =
+            BrainConfig::from_toml("unit_count = 64\nconnectivity_per_unit = 6\n").unwrap();
+        assert_eq!(partial.unit_count, 64);
+        assert_eq!(partial.dt, BrainConfig::default().dt);
+
+        assert_eq!(
+            BrainConfig::from_toml("unit_count = 64\nconnectivity_per_unit = 64\n").unwrap_err(),
+            ConfigError::Invalid("connectivity_per_unit must be < unit_count")
+        );
+        assert!(matches!(
+            BrainConfig::from_toml("unit_count = \"many\""),
+            Err(ConfigError::Toml(_))
+        ));
+    }
+
+    #[test]
+    fn activation_history_keeps_a_rolling_window_of_amplitudes() {
+        let cfg = BrainConfig::with_size(32, 4).with_seed(2);
+        let mut brain = Brain::new(cfg);
+        brain.step();
+        assert_eq!(brain.activation_history().count(), 0);
+
+        brain.set_observer_telemetry(true);
+        brain.set_activation_history_window(4);
+        for _ in 0..3 {
+            brain.step();
+        }
+        brain.step_inference();
+        brain.step();
+        let frames: Vec<&[f32]> = brain.activation_history().collect();
+        assert_eq!(frames.len(), 4);
+        assert!(frames.iter().all(|f| f.len() == 32));
+        let last: Vec<f32> = brain.units.iter().map(|u| u.amp).collect();
+        assert_eq!(frames[3], last.as_slice());
+
+        let adapter = crate::observer::BrainAdapter::new(&brain);
+        let recent = adapter.unit_activation_history(2);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[1], last);
+        assert_eq!(adapter.unit_activation_history(100).len(), 4);
+
+        brain.set_activation_history_window(10_000);
+        assert_eq!(brain.telemetry.activation_window, ACTIVATION_HISTORY_MAX);
+        brain.set_observer_telemetry(false);
+        assert_eq!(brain.activation_history().count(), 0);
+    }
+
+    #[test]
+    fn oscillation_history_is_windowed_and_thinned() {
+        let mut brain = Brain::new(BrainConfig::with_size(32, 4).with_seed(6));
+        brain.step();
+        assert!(brain.oscillation_sample_windowed(8, 8).is_empty());
+
+        brain.set_observer_telemetry(true);
+        for _ in 0..20 {
+            brain.step();
+        }
+        let all = brain.oscillation_sample_windowed(100, 100);
+        assert_eq!(all.len(), 20);
+        assert_eq!(*all.last().unwrap(), brain.oscillation_sample(512));
+
+        let recent = brain.oscillation_sample_windowed(100, 5);
+        assert_eq!(recent.as_slice(), &all[15..]);
+
+        let thinned = brain.oscillation_sample_windowed(4, 20);
+        assert_eq!(thinned, vec![all[4], all[9], all[14], all[19]]);
+
+        brain.set_observer_telemetry(false);
+        assert!(brain.oscillation_sample_windowed(8, 8).is_empty());
+    }
+
+    #[test]
+    fn plasticity_rate_tracks_committed_weight_change() {
+        let cfg = BrainConfig::with_size(64, 8).with_seed(4);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("s", 8);
+        assert_eq!(brain.learning_stats().plasticity_rate_steps, 0);
+        let mut monitor = crate::observer::PlasticityMonitor::new(1, 0.5);
+        brain.step();
+        monitor.observe(&brain);
+        assert!(!monitor.is_converged(1.0));
+
+        for _ in 0..20 {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.set_neuromodulator(0.8);
+            brain.step();
+            monitor.observe(&brain);
+        }
+        let rate = brain.learning_stats().plasticity_rate;
+        assert!(rate > 0.0);
+        assert!(brain.learning_stats().plasticity_rate_steps >= 20);
+        assert!(monitor.current_plasticity() > 0.0);
+        assert!(!monitor.is_converged(0.0));
+
+        brain.set_neuromodulator(0.0);
+        for _ in 0..200 {
+            brain.step();
+        }
+        assert!(brain.learning_stats().plasticity_rate < rate);
+    }
+
+    #[test]
+    fn assign_unit_to_group_moves_membership_only() {
+        let mut brain = Brain::new(BrainConfig::with_size(64, 6).with_seed(17));
+        brain.define_sensor("vision", 3);
+        brain.define_sensor("audio", 2);
+        brain.define_action("go", 2);
+        for _ in 0..5 {
+            brain.apply_stimulus(Stimulus::new("vision", 1.0));
+            brain.step();
+        }
+
+        let id = brain.get_sensor_group_units("vision")[0];
+        let edges: Vec<(UnitId, Weight)> = brain.neighbors(id).collect();
+        let (phase, amp) = (brain.units[id].phase, brain.units[id].amp);
+        let fingerprint = brain.io_groups_fingerprint();
+
+        brain
+            .assign_unit_to_group(id, "audio", GroupType::Sensor)
+            .unwrap();
+        assert!(!brain.get_sensor_group_units("vision").contains(&id));
+        assert!(brain.get_sensor_group_units("audio").contains(&id));
+        assert_eq!(brain.get_sensor_group_units("vision").len(), 2);
+        assert_ne!(brain.io_groups_fingerprint(), fingerprint);
+        assert_eq!(brain.neighbors(id).collect::<Vec<_>>(), edges);
+        assert_eq!((brain.units[id].phase, brain.units[id].amp), (phase, amp));
+
+        // Crossing into a new action group creates it and clears sensor membership.
+        brain
+            .assign_unit_to_group(id, "stop", GroupType::Action)
+            .unwrap();
+        assert_eq!(brain.get_action_group_units("stop"), vec![id]);
+        assert!(!brain.get_sensor_group_units("audio").contains(&id));
+        assert!(!brain.sensor_member[id] && brain.group_member[id]);
+
+        assert!(brain
+            .assign_unit_to_group(id, "vision", GroupType::Action)
+            .is_err());
+        assert!(brain
+            .assign_unit_to_group(999, "vision", GroupType::Sensor)
+            .is_err());
+    }
+
+    #[test]
+    fn negative_examples_sharpen_reversal_discrimination() {
+        // Phase 1 pairs spot_a→left and spot_b→right; after the reversal spot_a→right
+        // is rewarded. The contrastive brain also marks the stale spot_a→left as wrong.
+        let make = || {
+            let mut brain = Brain::new(BrainConfig::with_size(64, 6).with_seed(13));
+            brain.define_sensor("spot_a", 3);
+            brain.define_sensor("spot_b", 3);
+            brain.define_action("left", 3);
+            brain.define_action("right", 3);
+            brain
+        };
+        let trial = |brain: &mut Brain, stim: &str, action: &str| {
+            brain.apply_stimulus(Stimulus::new(stim, 1.0));
+            brain.step();
+            brain.note_compound_symbol(&[stim]);
+            brain.note_action(action);
+            brain.note_compound_symbol(&["pair", stim, action]);
+            brain.set_neuromodulator(1.0);
+            brain.commit_observation();
+        };
+
+        let mut plain = make();
+        for _ in 0..20 {
+            trial(&mut plain, "spot_a", "left");
+            trial(&mut plain, "spot_b", "right");
+        }
+        let mut contrastive = plain.clone();
+        for _ in 0..8 {
+            trial(&mut plain, "spot_a", "right");
+            trial(&mut contrastive, "spot_a", "right");
+            contrastive.note_negative_example("spot_a", "left");
+        }
+
+        let margin = |brain: &Brain| {
+            let ranked = brain.ranked_actions_with_meaning("spot_a", 5.0);
+            let score = |name: &str| ranked.iter().find(|(n, _)| n == name).unwrap().1;
+            score("right") - score("left")
+        };
+        assert!(
+            margin(&contrastive) > margin(&plain),
+            "{} vs {}",
+            margin(&contrastive),
+            margin(&plain)
+        );
+        // The other stimulus keeps its own mapping.
+        assert_eq!(
+            contrastive.select_action_with_meaning("spot_b", 5.0).0,
+            "right"
+        );
+        assert_eq!(
+            contrastive.select_action_with_meaning("spot_a", 5.0).0,
+            "right"
+        );
+    }
+
+    #[test]
+    fn select_action_with_confidence_reports_score_gap() {
+        let mut brain = Brain::new(BrainConfig::with_size(48, 4).with_seed(5));
+        brain.define_sensor("cue", 2);
+        brain.define_action("left", 3);
+        brain.define_action("right", 3);
+
+        let (name, score, conf) = brain.select_action_with_confidence("cue", 1.0);
+        let (name_m, score_m) = brain.select_action_with_meaning("cue", 1.0);
+        assert_eq!((name.as_str(), score), (name_m.as_str(), score_m));
+        assert!((0.0..=1.0).contains(&conf));
+
+        for _ in 0..30 {
+            brain.apply_stimulus(Stimulus::new("cue", 1.0));
+            brain.note_action("left");
+            brain.note_compound_symbol(&["pair", "cue", "left"]);
+            brain.set_neuromodulator(1.0);
+            brain.reinforce_action("left", 1.0);
+            brain.step();
+            brain.commit_observation();
+        }
+        let (name, _, learned) = brain.select_action_with_confidence("cue", 5.0);
+        assert_eq!(name, "left");
+        assert!(learned > 0.1, "{learned}");
+
+        assert_eq!(action_confidence(1.0, 1.0), 0.0);
+        assert!((action_confidence(1.0, -1.0) - 1.0).abs() < 1e-5);
+        assert_eq!(action_confidence(0.2, 0.5), 0.0);
+    }
+
+    #[test]
+    fn phase_locking_ratio_rises_with_stimulus_reward_pairing() {
+        assert_eq!(
+            Brain::new(BrainConfig::with_size(16, 4)).phase_locking_ratio(),
+            0.0
+        );
+
+        // Single small brains are noisy, so compare the mean over a few seeds.
+        let (mut before, mut after) = (0.0, 0.0);
+        for seed in 1..=6 {
+            let mut brain = Brain::new(BrainConfig::with_size(64, 6).with_seed(seed));
+            brain.define_sensor("cue", 4);
+            brain.define_action("press", 4);
+            for _ in 0..20 {
+                brain.step();
+            }
+            before += brain.phase_locking_ratio();
+
+            for _ in 0..200 {
+                brain.apply_stimulus(Stimulus::new("cue", 1.0));
+                brain.note_action("press");
+                brain.set_neuromodulator(1.0);
+                brain.reinforce_action("press", 1.0);
+                brain.step();
+                brain.commit_observation();
+            }
+            let ratio = brain.phase_locking_ratio();
+            assert!((0.0..=1.0).contains(&ratio));
+            after += ratio;
+        }
+        assert!(after > before + 1.0, "{before} -> {after}");
+    }
+
+    #[test]
+    fn force_synchronize_sensors_collapses_phase_spread() {
+        let mut brain = Brain::new(BrainConfig::with_size(96, 6).with_seed(21));
+        brain.define_sensor("a", 6);
+        brain.define_sensor("b", 6);
+        for t in 0..40 {
+            let name = if t % 2 == 0 { "a" } else { "b" };
+            brain.apply_stimulus(Stimulus::new(name, 0.8));
+            brain.step();
+        }
+
+        let before: Vec<f32> = ["a", "b"]
+            .iter()
+            .map(|g| brain.sensor_group_phase_spread(g))
+            .collect();
+        assert!(before.iter().all(|&s| s > 1e-3), "{before:?}");
+
+        brain.force_synchronize_sensors();
+        for (g, b) in ["a", "b"].iter().zip(&before) {
+            let after = brain.sensor_group_phase_spread(g);
+            assert!(after < *b && after < 1e-3, "{g}: {b} -> {after}");
+        }
+        assert_eq!(brain.sensor_group_phase_spread("missing"), 0.0);
+    }
+
+    #[test]
+    fn group_unit_queries_match_registered_groups() {
+        let mut brain = Brain::new(BrainConfig::with_size(64, 4).with_seed(2));
+        brain.define_sensor("s", 3);
+        brain.define_action("a", 5);
+
+        let s = brain.get_sensor_group_units("s");
+        let a = brain.get_action_group_units("a");
+        assert_eq!(s.as_slice(), brain.sensor_units("s").unwrap());
+        assert_eq!(a.len(), 5);
+        assert!(a.iter().all(|u| !s.contains(u)));
+        assert!(brain.get_action_group_units("s").is_empty());
+        assert!(brain.get_sensor_group_units("missing").is_empty());
+        assert_eq!(brain.sensor_group_names().collect::<Vec<_>>(), ["s"]);
+        assert_eq!(brain.action_group_names().collect::<Vec<_>>(), ["a"]);
+    }
+
+    #[test]
+    fn homeostasis_bias_query_is_gated_by_telemetry() {
+        let mut brain = Brain::new(BrainConfig::with_size(16, 4).with_seed(8));
+        assert!(!brain.set_homeostasis_bias_for_unit(3, 0.2));
+        assert_eq!(brain.homeostasis_bias_for_unit(3), 0.0);
+
+        brain.set_observer_telemetry(true);
+        assert!(brain.set_homeostasis_bias_for_unit(3, 0.2));
+        assert!((brain.homeostasis_bias_for_unit(3) - 0.2).abs() < 1e-6);
+        assert!(brain.set_homeostasis_bias_for_unit(3, 9.0));
+        assert_eq!(brain.homeostasis_bias_for_unit(3), 0.5);
+        assert!(!brain.set_homeostasis_bias_for_unit(99, 0.1));
+        assert_eq!(brain.homeostasis_bias_for_unit(99), 0.0);
+
+        let plot = brain.unit_plot_points(16);
+        assert_eq!(plot[3].homeostasis_bias, 0.5);
+    }
+
+    #[test]
+    fn homeostasis_bias_excludes_group_and_reinforcement_terms() {
+        let mut brain = Brain::new(BrainConfig {
+            homeostasis_rate: 0.05,
+            homeostasis_every: 1,
+            ..BrainConfig::with_size(32, 4).with_seed(8)
+        });
+        brain.set_observer_telemetry(true);
+        brain.define_action("go", 2);
+        brain.reinforce_action("go", 1.0);
+        let unit = brain.action_units("go").unwrap()[0];
+        assert!(brain.units[unit].bias > 0.0);
+        assert_eq!(brain.homeostasis_bias_for_unit(unit), 0.0);
+
+        // Overriding the homeostatic part keeps the other terms.
+        let other = brain.units[unit].bias;
+        assert!(brain.set_homeostasis_bias_for_unit(unit, 0.1));
+        assert!((brain.units[unit].bias - (other + 0.1)).abs() < 1e-6);
+        assert!((brain.homeostasis_bias_for_unit(unit) - 0.1).abs() < 1e-6);
+
+        // Units outside groups only get bias from homeostasis.
+        for _ in 0..5 {
+            brain.step();
+        }
+        let free = (0..brain.units.len())
+            .find(|&i| !brain.reserved[i])
+            .unwrap();
+        assert_ne!(brain.units[free].bias, 0.0);
+        assert_eq!(
+            brain.homeostasis_bias_for_unit(free),
+            brain.units[free].bias
+        );
+    }
+
+    #[test]
+    fn degree_histogram_and_sparsity_match_connection_count() {
+        let mut brain = Brain::new(BrainConfig::with_size(40, 6).with_seed(4));
+        let check = |brain: &Brain| {
+            let hist = brain.unit_degree_histogram();
+            let units: u32 = hist.iter().sum();
+            let edges: usize = hist.iter().enumerate().map(|(k, &c)| k * c as usize).sum();
+            assert_eq!(units as usize, brain.unit_count());
+            assert_eq!(edges, brain.connection_count());
+            let expected = 1.0 - brain.connection_count() as f32 / (40.0 * 40.0);
+            assert!((brain.connection_sparsity() - expected).abs() < 1e-6);
+        };
+        check(&brain);
+
+        // Tombstone a few edges so the slow path is exercised too.
+        for idx in [0, 3, 7] {
+            brain.tombstone_edge(idx);
+        }
+        check(&brain);
+    }
+
+    #[test]
+    fn eligibility_trace_snapshot_matches_weight_indexing() {
+        let cfg = BrainConfig {
+            unit_count: 4,
+            connectivity_per_unit: 1,
+            noise_amp: 0.0,
+            noise_phase: 0.0,
+            coactive_threshold: 0.1,
+            phase_lock_threshold: 0.5,
+            eligibility_decay: 0.0,
+            eligibility_gain: 1.0,
+            seed: Some(3),
+            ..Default::default()
+        };
+        let mut brain = Brain::new(cfg);
+        assert!(brain.eligibility_trace_snapshot().iter().all(|&e| e == 0.0));
+
+        // Force a known single edge 0 -> 1 at index 0 and make it coactive.
+        brain.connections.targets[0] = 1;
+        brain.units[0].amp = 1.0;
+        brain.units[1].amp = 1.0;
+        brain.units[0].phase = 0.0;
+        brain.units[1].phase = 0.0;
+        brain.update_eligibility_scalar();
+
+        let traces = brain.eligibility_trace_snapshot();
+        assert_eq!(traces.len(), brain.weights_len());
+        assert!(traces[0] > 0.0);
+        assert_eq!(traces[0], brain.eligibility[0]);
+    }
+
+    #[test]
+    fn connection_triplets_cover_every_live_connection() {
+        let brain = Brain::new(BrainConfig::with_size(48, 5).with_seed(12));
+        let triplets: Vec<_> = brain.connection_triplets().collect();
+        assert_eq!(triplets.len(), brain.connection_count());
+        let (from, to, w) = triplets[0];
+        assert!(brain.neighbors(from).any(|(t, x)| t == to && x == w));
+    }
+
+    #[test]
+    fn metrics_flattens_scalar_monitors() {
+        let mut brain = Brain::new(BrainConfig::with_size(64, 6).with_seed(9));
+        brain.define_sensor("s", 4);
+        brain.apply_stimulus(Stimulus::new("s", 1.0));
+        brain.step();
+        brain.commit_observation();
+
+        let m = brain.metrics();
+        assert_eq!(m["brain.unit_count"], brain.unit_count() as f32);
+        assert_eq!(m["brain.connection_count"], brain.connection_count() as f32);
+        assert_eq!(
+            m["learning.plasticity_l1"],
+            brain.learning_stats().plasticity_l1
+        );
+        assert_eq!(m["causal.edge_count"], brain.causal_stats().edges as f32);
+        assert_eq!(m["oscillation.coherence"], brain.oscillation_sample(512).2);
+        assert!(m.values().all(|v| v.is_finite()));
+    }
+
+    #[test]
+    fn stimulus_conversions_round_trip() {
+        let s: Stimulus = ("vision", 0.5).into();
+        assert_eq!((s.name, s.strength), ("vision", 0.5));
+
+        let owned = OwnedStimulus::from(s);
+        assert_eq!(owned.name, "vision");
+        let back = Stimulus::from(&owned);
+        assert_eq!((back.name, back.strength), ("vision", 0.5));
+
+        let owned: OwnedStimulus = (String::from("touch"), 1.0).into();
+        assert_eq!(owned.as_stimulus().name, "touch");
+    }
+
+    #[test]
+    fn activity_heatmap_rolls_frames_and_renders_rgba() {
+        let mut brain = Brain::new(BrainConfig::with_size(200, 8).with_seed(6));
+        brain.define_sensor("s", 8);
+        let mut heatmap = crate::observer::ActivityHeatmap::for_brain(&brain, 1000);
+        assert_eq!((heatmap.width(), heatmap.height()), (256, 128));
+
+        let mut small = crate::observer::ActivityHeatmap::new(4, 2);
+        small.push_frame(&[2.0, 2.0, -2.0, -2.0]);
+        let rgba = small.render_rgba();
+        assert_eq!(rgba.len(), 4 * 2 * 4);
+        // Only the rightmost column is filled: unit block 0 hot, block 1 cold.
+        assert_eq!(&rgba[12..16], &[255, 255, 0, 255]);
+        assert_eq!(&rgba[28..32], &[0, 0, 255, 255]);
+        assert_eq!(rgba[3], 0);
+
+        for _ in 0..300 {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.step();
+            heatmap.observe(&brain);
+        }
+        assert_eq!(heatmap.len(), 256);
+        assert!(heatmap.render_rgba().chunks(4).all(|px| px[3] == 255));
+    }
+
+    #[test]
+    fn reward_prediction_error_sets_neuromod_and_tracks_calibration() {
+        let mut brain = Brain::new(BrainConfig::with_size(16, 4).with_seed(1));
+        assert_eq!(brain.learning_stats().td_prediction_error_ema, 1.0);
+
+        assert_eq!(brain.reward_prediction_error(0.25, 1.0), 0.75);
+        assert_eq!(brain.neuromodulator(), 0.75);
+        assert_eq!(brain.reward_prediction_error(-1.0, 1.0), 1.0);
+
+        for _ in 0..200 {
+            brain.reward_prediction_error(0.9, 1.0);
+        }
+        let ema = brain.learning_stats().td_prediction_error_ema;
+        assert!((ema - 0.1).abs() < 0.01, "ema={ema}");
+    }
+
+    #[test]
+    fn hebbian_epochs_change_only_coactive_edges_without_stepping() {
+        let cfg = BrainConfig::with_size(32, 6).with_seed(12);
+        let mut brain = Brain::new(cfg);
+        for u in &mut brain.units {
+            u.amp = 0.0;
+        }
+        let idx = brain
+            .conn_range(0)
+            .find(|&i| brain.connections.targets[i] != INVALID_UNIT)
+            .unwrap();
+        let target = brain.connections.targets[idx];
+        brain.units[0].amp = 1.0;
+        brain.units[target].amp = 1.0;
+        let phases: Vec<f32> = brain.units.iter().map(|u| u.phase).collect();
+        let age = brain.age_steps;
+        let w0 = brain.connections.weights[idx];
+
+        brain.set_neuromodulator(1.0);
+        assert!(brain.hebbian_epoch(Some(0.5)) >= 1);
+        let w1 = brain.connections.weights[idx];
+        assert!(w1 > w0);
+        assert_eq!(brain.age_steps, age);
+        assert!(brain.units.iter().zip(&phases).all(|(u, &p)| u.phase == p));
+
+        // Above the override threshold nothing is co-active.
+        assert_eq!(brain.hebbian_epoch(Some(1.5)), 0);
+
+        assert!(brain.anti_hebbian_epoch(0.5) >= 1);
+        assert!(brain.connections.weights[idx] < w1);
+
+        brain.set_unit_frozen(target, true);
+        let w2 = brain.connections.weights[idx];
+        brain.hebbian_epoch(Some(0.5));
+        brain.anti_hebbian_epoch(0.5);
+        assert_eq!(brain.connections.weights[idx], w2);
+    }
+
+    #[test]
+    fn cached_counts_track_pruning_and_growth() {
+        let cfg = BrainConfig {
+            prune_below: 0.08,
+            ..BrainConfig::with_size(48, 6).with_seed(21)
+        };
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("s", 6);
+        for _ in 0..50 {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.step();
+        }
+        brain.grow_units(3, 4);
+        brain.step();
+        assert!(brain.csr_tombstones > 0);
+        assert_eq!(brain.connection_count(), brain.total_connection_count());
+        assert_eq!(brain.unit_count(), brain.diagnostics().unit_count);
+
+        let child = brain.spawn_child(1, crate::supervisor::ChildConfigOverrides::inherit());
+        assert_eq!(child.connection_count(), child.total_connection_count());
+    }
+
+    #[test]
+    fn pruning_policies_select_edges_to_remove() {
+        let cfg = BrainConfig {
+            prune_below: 0.0,
+            forget_rate: 0.0,
+            ..BrainConfig::with_size(40, 8).with_seed(13)
+        };
+        let live_per_unit = |b: &Brain| -> usize {
+            (0..b.units.len())
+                .map(|u| {
+                    b.conn_range(u)
+                        .filter(|&i| b.connections.targets[i] != INVALID_UNIT)
+                        .count()
+                })
+                .max()
+                .unwrap_or(0)
+        };
+
+        let mut top_k = Brain::new(cfg.clone());
+        top_k
+            .set_pruning_policy(PruningPolicy::TopK { k_per_unit: 3 })
+            .unwrap();
+        top_k.step();
+        assert_eq!(live_per_unit(&top_k), 3);
+        assert_eq!(top_k.connection_count(), 3 * 40);
+
+        let mut aged = Brain::new(cfg.clone());
+        aged.set_pruning_policy(PruningPolicy::Age { max_age_steps: 5 })
+            .unwrap();
+        let before = aged.connection_count();
+        for _ in 0..3 {
+            aged.step();
+        }
+        assert_eq!(aged.connection_count(), before);
+        // Without a reward signal nothing is refreshed, so every edge ages out.
+        for _ in 0..10 {
+            aged.step();
+        }
+        assert_eq!(aged.connection_count(), 0);
+
+        let mut thr = Brain::new(cfg);
+        thr.set_pruning_policy(PruningPolicy::Threshold { below: 0.05 })
+            .unwrap();
+        assert_eq!(thr.config().prune_below, 0.05);
+        assert_eq!(
+            thr.pruning_policy(),
+            PruningPolicy::Threshold { below: 0.05 }
+        );
+        assert!(thr
+            .set_pruning_policy(PruningPolicy::Probabilistic {
+                rate: 2.0,
+                temperature: 0.1
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn structured_noise_targets_requested_units() {
+        let mut brain = Brain::new(BrainConfig::with_size(32, 4).with_seed(23));
+        brain.define_sensor("eye", 10);
+        brain.define_sensor("ear", 4);
+        let eye = brain.sensor_units("eye").unwrap().to_vec();
+        let ear = brain.sensor_units("ear").unwrap().to_vec();
+
+        let before = brain.unit_amplitudes();
+        let n = brain
+            .apply_structured_noise(&NoisePattern::PerGroup {
+                group: "eye".to_string(),
+                sigma: 0.5,
+            })
+            .unwrap();
+        assert_eq!(n, eye.len());
+        let after = brain.unit_amplitudes();
+        assert!(eye.iter().any(|&i| after[i] != before[i]));
+        assert!(ear.iter().all(|&i| after[i] == before[i]));
+
+        brain.apply_stimulus(Stimulus::new("eye", 1.0));
+        let n = brain
+            .apply_structured_noise(&NoisePattern::Occlusion {
+                group: "eye".to_string(),
+                fraction: 0.5,
+            })
+            .unwrap();
+        assert_eq!(n, 5);
+        let occluded = eye
+            .iter()
+            .filter(|&&i| brain.pending_input[i] == 0.0)
+            .count();
+        assert_eq!(occluded, 5);
+
+        brain
+            .apply_structured_noise(&NoisePattern::Adversarial {
+                units: vec![ear[0]],
+                direction: 5.0,
+            })
+            .unwrap();
+        assert_eq!(brain.units[ear[0]].amp, 2.0);
+
+        assert!(brain
+            .apply_structured_noise(&NoisePattern::PerGroup {
+                group: "nose".to_string(),
+                sigma: 0.1,
+            })
+            .is_err());
+        assert!(brain
+            .apply_structured_noise(&NoisePattern::Adversarial {
+                units: vec![9999],
+                direction: 1.0,
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn experimental_flags_toggle_features_when_present_and_nonzero() {
+        let mut cfg = BrainConfig::with_size(48, 8).with_seed(19);
+        assert_eq!(cfg.experimental_flag("fast_weights"), None);
+        cfg.experimental_flags
+            .insert("fast_weights".to_string(), 1.0);
+        cfg.experimental_flags
+            .insert("oja_units_all".to_string(), 0.0);
+        assert_eq!(cfg.experimental_flag("fast_weights"), Some(1.0));
+        assert_eq!(cfg.experimental_flag("oja_units_all"), None);
+
+        let mut brain = Brain::new(cfg.clone());
+        brain.define_sensor("s", 6);
+        for _ in 0..10 {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.set_neuromodulator(1.0);
+            brain.step();
+        }
+        assert!(brain.multi_timescale_weights().is_some());
+        assert!(brain.learning_stats().fast_weight_l1 > 0.0);
+
+        brain
+            .update_config(|c| c.experimental_flags.clear())
+            .unwrap();
+        assert!(brain.multi_timescale_weights().is_none());
+
+        cfg.experimental_flags
+            .insert("bcm_theta".to_string(), f32::NAN);
+        assert!(cfg.validate().is_err());
+    }
 
-        // Test connection_matrix
-        let matrix = brain.connection_matrix();
-        assert_eq!(matrix.len(), 64);
-        assert_eq!(matrix[0].len(), 64);
+    #[test]
+    fn fast_weights_learn_and_consolidate_into_slow_bank() {
+        let cfg = BrainConfig {
+            enable_fast_weights: true,
+            ..BrainConfig::with_size(48, 8).with_seed(17)
+        };
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("s", 6);
+        brain.define_action("a", 6);
+        for _ in 0..30 {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.set_neuromodulator(1.0);
+            brain.step();
+        }
 
-        // Test top_active_units
-        brain.apply_stimulus(Stimulus::new("vision", 1.0));
-        brain.step();
-        let top = brain.top_active_units(5);
-        assert!(top.len() <= 5);
+        let stats = brain.learning_stats();
+        assert!(stats.fast_weight_l1 > 0.0);
+        assert!(stats.slow_weight_l1 > 0.0);
+        let (fast, slow) = brain.multi_timescale_weights().unwrap();
+        for ((f, s), w) in fast.iter().zip(&slow).zip(&brain.connections.weights) {
+            assert!((f + s - w).abs() < 1e-6);
+        }
 
-        // Test sensor/action unit accessors
-        assert!(brain.sensor_units("vision").is_some());
-        assert!(brain.action_units("move").is_some());
-        assert!(brain.sensor_units("nonexistent").is_none());
+        let effective = brain.snapshot_weights();
+        let fast_l1: f32 = fast.iter().map(|f| f.abs()).sum();
+        let moved = brain.consolidate_fast_to_slow(0.25);
+        assert!((moved - 0.25 * fast_l1).abs() < 1e-3 * fast_l1.max(1.0));
+        assert_eq!(brain.snapshot_weights(), effective);
+        let (fast_after, _) = brain.multi_timescale_weights().unwrap();
+        let after_l1: f32 = fast_after.iter().map(|f| f.abs()).sum();
+        assert!((after_l1 - 0.75 * fast_l1).abs() < 1e-3 * fast_l1.max(1.0));
 
-        // Test config accessor
-        let cfg_ref = brain.config();
-        assert_eq!(cfg_ref.unit_count, 64);
+        let plain = Brain::new(BrainConfig::with_size(48, 8).with_seed(17));
+        assert!(plain.multi_timescale_weights().is_none());
     }
 
     #[test]
-    fn inference_step_does_not_modify_weights() {
-        let cfg = BrainConfig::with_size(64, 6).with_seed(7);
+    fn fast_weights_ignore_weight_writes_made_outside_step() {
+        let cfg = BrainConfig {
+            enable_fast_weights: true,
+            ..BrainConfig::with_size(48, 8).with_seed(17)
+        };
         let mut brain = Brain::new(cfg);
-        brain.define_sensor("stim", 8);
-        brain.define_action("left", 4);
-        brain.define_action("right", 4);
+        brain.define_sensor("s", 6);
+        for _ in 0..5 {
+            brain.apply_stimulus(Stimulus::new("s", 1.0));
+            brain.set_neuromodulator(1.0);
+            brain.step();
+        }
+        brain.set_neuromodulator(0.0);
 
-        let base = brain.clone();
+        let idx = (0..brain.connections.weights.len())
+            .find(|&i| {
+                brain.connections.targets[i] != INVALID_UNIT
+                    && brain.connections.weights[i].abs() < 0.2
+            })
+            .unwrap();
+        let mut edited = brain.clone();
+        edited.connections.weights[idx] += 0.05;
+        brain.step();
+        edited.step();
 
-        brain.apply_stimulus_inference(Stimulus::new("stim", 1.0));
-        for _ in 0..3 {
-            brain.step_inference();
+        let diff = edited.connections.weights[idx] - brain.connections.weights[idx];
+        assert!(diff <= 0.05 + 1e-5, "external edit was amplified: {diff}");
+        assert!(diff >= 0.05 * 0.9, "external edit was lost: {diff}");
+    }
+
+    #[test]
+    fn reset_causality_keeps_weights_and_symbol_ids() {
+        let cfg = BrainConfig::with_size(32, 4).with_seed(8);
+        let mut brain = Brain::new(cfg);
+        for _ in 0..4 {
+            brain.note_compound_symbol(&["cue"]);
+            brain.commit_observation();
+            brain.note_compound_symbol(&["goal"]);
+            brain.commit_observation();
+            brain.step();
         }
+        assert!(!brain.top_causal_links_from("cue", 4).is_empty());
+        let cue = brain.symbol_id("cue");
+        let weights = brain.connections.weights.clone();
+        let age = brain.age_steps;
 
-        let delta = brain.diff_weights_topk(&base, 32);
-        assert!(
-            delta.weight_deltas.is_empty(),
-            "inference stepping should not change weights"
-        );
+        brain.reset_causality();
+        assert_eq!(brain.causal_stats().edges, 0);
+        assert_eq!(brain.causal_stats().base_symbols, 0);
+        assert!(brain.top_causal_links_from("cue", 4).is_empty());
+        assert_eq!(brain.connections.weights, weights);
+        assert_eq!(brain.age_steps, age);
+
+        brain.note_compound_symbol(&["cue"]);
+        brain.commit_observation();
+        assert_eq!(brain.symbol_id("cue"), cue);
     }
 
     #[test]
-    fn config_validation() {
-        // Valid config
-        let cfg = BrainConfig::with_size(64, 8);
-        assert!(cfg.validate().is_ok());
-
-        // Test estimated memory
-        let mem = cfg.estimated_memory_bytes();
-        assert!(mem > 0);
-        assert!(mem < 1024 * 1024); // Should be < 1MB for 64 units
+    fn warm_cache_does_not_change_subsequent_steps() {
+        let cfg = BrainConfig::with_size(64, 8).with_seed(11);
+        let mut warmed = Brain::new(cfg);
+        let mut cold = warmed.clone();
 
-        // Test diagnostics includes new fields
-        let brain = Brain::new(cfg);
-        let diag = brain.diagnostics();
-        assert!(diag.memory_bytes > 0);
-        assert_eq!(diag.execution_tier, ExecutionTier::Scalar);
+        warmed.warm_cache();
+        for _ in 0..5 {
+            warmed.step();
+            cold.step();
+        }
+        assert_eq!(warmed.connections.weights, cold.connections.weights);
+        assert_eq!(warmed.diagnostics().avg_amp, cold.diagnostics().avg_amp);
     }
 
     #[test]
-    #[should_panic(expected = "unit_count must be >= 4")]
-    fn config_rejects_tiny_network() {
-        let _ = BrainConfig::with_size(2, 1);
+    fn profile_step_times_phases_of_a_normal_step() {
+        let cfg = BrainConfig::with_size(64, 8).with_seed(7);
+        let mut brain = Brain::new(cfg);
+        let age = brain.age_steps;
+
+        let p = brain.profile_step();
+        assert_eq!(brain.age_steps, age + 1);
+        assert_eq!(p.causal_ns, 0);
+        assert_eq!(p.imprint_ns, 0);
+        assert!(p.total_ns > 0);
+        let phases = p.oscillator_ns + p.hebbian_ns + p.pruning_ns + p.neurogenesis_ns;
+        assert!(phases <= p.total_ns);
+
+        let mean = StepProfile::mean(&[p, StepProfile::default()]);
+        assert_eq!(mean.total_ns, p.total_ns / 2);
     }
 
     #[test]
-    #[should_panic(expected = "connectivity_per_unit must be < unit_count")]
-    fn config_rejects_overconnected() {
-        let _ = BrainConfig::with_size(16, 20);
+    fn profile_cycle_times_stimulus_and_commit() {
+        let cfg = BrainConfig::with_size(64, 8).with_seed(7);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("cue", 4);
+        brain.define_action("go", 4);
+        let age = brain.age_steps;
+
+        let p = brain.profile_cycle(&[Stimulus::new("cue", 1.0)]);
+        assert_eq!(brain.age_steps, age + 1);
+        assert!(p.imprint_ns > 0);
+        assert!(p.causal_ns > 0);
+        let phases = p.oscillator_ns
+            + p.hebbian_ns
+            + p.pruning_ns
+            + p.neurogenesis_ns
+            + p.causal_ns
+            + p.imprint_ns;
+        assert!(phases <= p.total_ns);
     }
 
     // =========================================================================
@@ -7931,6 +13880,92 @@ mod tests {
         assert_eq!(grown, 0, "Should not grow beyond max_units");
     }
 
+    #[test]
+    fn neurogenesis_policy_variants_trigger() {
+        let cfg = BrainConfig::with_size(16, 4).with_seed(42);
+        let mut brain = Brain::new(cfg);
+
+        let periodic = NeurogenesisPolicy::Periodic {
+            every_steps: 5,
+            amount: 2,
+        };
+        brain.growth_last_birth_step = 5;
+        brain.age_steps = 7;
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&periodic, 64), 0);
+        // Polled off the exact multiple (as at trial ends): still grows.
+        brain.age_steps = 11;
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&periodic, 64), 2);
+        brain.age_steps = 14;
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&periodic, 64), 0);
+        brain.age_steps = 17;
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&periodic, 64), 2);
+
+        let reward = NeurogenesisPolicy::RewardDriven {
+            min_reward: 0.5,
+            amount: 3,
+        };
+        brain.set_neuromodulator(0.2);
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&reward, 64), 0);
+        brain.set_neuromodulator(0.9);
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&reward, 64), 3);
+
+        let custom = NeurogenesisPolicy::Custom {
+            f: |b| if b.units.len() < 26 { 10 } else { 0 },
+        };
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&custom, 26), 3);
+        assert_eq!(brain.units.len(), 26);
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&custom, 64), 0);
+    }
+
+    #[test]
+    fn gradient_neurogenesis_places_units_near_target_group() {
+        let cfg = BrainConfig::with_size(64, 4).with_seed(7);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("spot_left", 4);
+        brain.define_sensor("spot_right", 4);
+        brain.define_action("left", 4);
+        brain.define_action("right", 4);
+
+        brain.apply_stimulus(Stimulus::new("spot_left", 1.0));
+        brain.step();
+
+        let allowed = brain.gradient_anchors("spot_left");
+        assert!(!allowed.is_empty());
+
+        let before = brain.units.len();
+        let gradient = NeurogenesisPolicy::Gradient {
+            target_group: "spot_left".to_string(),
+            threshold: -1.0,
+            amount: 3,
+        };
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&gradient, 128), 3);
+        for id in before..brain.units.len() {
+            for (target, _w) in brain.neighbors(id) {
+                assert!(
+                    allowed.binary_search(&target).is_ok(),
+                    "gradient-grown unit {id} wired outside the target neighborhood"
+                );
+            }
+        }
+        // Incoming edges of grown units are sampled from the same neighborhood.
+        for (from, to, _w) in brain.connection_triplets() {
+            if from < before && to >= before {
+                assert!(
+                    allowed.binary_search(&from).is_ok(),
+                    "gradient-grown unit {to} fed from outside the target neighborhood"
+                );
+            }
+        }
+
+        // Unknown groups fall back to random placement instead of failing.
+        let unknown = NeurogenesisPolicy::Gradient {
+            target_group: "missing".to_string(),
+            threshold: -1.0,
+            amount: 1,
+        };
+        assert_eq!(brain.maybe_neurogenesis_with_policy(&unknown, 128), 1);
+    }
+
     #[test]
     fn neurogenesis_hybrid_policy_respects_cooldown() {
         let mut cfg = BrainConfig::with_size(16, 4).with_seed(42);
@@ -7953,13 +13988,13 @@ mod tests {
         brain.age_steps = 100;
         brain.growth_last_birth_step = 100;
         assert!(
-            !brain.should_grow(saturation_threshold),
+            !brain.is_saturated(saturation_threshold),
             "Hybrid mode should enforce cooldown"
         );
 
         brain.age_steps = 111;
         assert!(
-            brain.should_grow(saturation_threshold),
+            brain.is_saturated(saturation_threshold),
             "Hybrid mode should allow growth after cooldown"
         );
     }
@@ -8126,6 +14161,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn commit_priority_scales_next_plasticity_step() {
+        let mut brain = Brain::new(BrainConfig::with_size(256, 37).with_seed(11));
+        brain.define_sensor("cue", 16);
+        brain.define_action("go", 8);
+        for _ in 0..20 {
+            brain.apply_stimulus(Stimulus::new("cue", 1.0));
+            brain.step();
+        }
+        brain.note_action("go");
+        brain.set_neuromodulator(0.8);
+
+        let change = |priority: f32| {
+            let mut b = brain.clone();
+            b.commit_observation_with_priority(priority);
+            b.apply_stimulus(Stimulus::new("cue", 1.0));
+            b.step();
+            assert_eq!(b.learning_priority, 1.0);
+            b.learning_monitors.plasticity_l1
+        };
+        let (low, normal, high) = (change(0.0), change(1.0), change(5.0));
+        assert_eq!(low, 0.0);
+        assert!(normal > 0.0 && high > 1.5 * normal, "{normal} {high}");
+        assert_eq!(change(f32::NAN), normal);
+    }
+
+    #[test]
+    fn high_reward_dreams_potentiate_rewarded_pairs() {
+        let mut brain = Brain::new(BrainConfig::with_size(96, 8).with_seed(17));
+        brain.define_sensor("spot_left", 6);
+        brain.define_sensor("spot_right", 6);
+        brain.define_action("left", 6);
+        brain.define_action("right", 6);
+
+        // Waking: the correct response is rewarded, the wrong one punished.
+        for t in 0..40 {
+            let (stim, correct, wrong) = if t % 2 == 0 {
+                ("spot_left", "left", "right")
+            } else {
+                ("spot_right", "right", "left")
+            };
+            let (action, reward) = if t % 4 < 2 {
+                (correct, 1.0)
+            } else {
+                (wrong, -1.0)
+            };
+            brain.apply_stimulus(Stimulus::new(stim, 1.0));
+            brain.step();
+            brain.note_action(action);
+            brain.set_neuromodulator(reward);
+            brain.commit_observation();
+        }
+        brain.set_neuromodulator(0.0);
+
+        let rewarded_weight = |b: &Brain| {
+            let mut sum = 0.0;
+            for (stim, action) in [("spot_left", "left"), ("spot_right", "right")] {
+                let to = b.action_units(action).unwrap();
+                for &from in b.sensor_units(stim).unwrap() {
+                    sum += b
+                        .neighbors(from)
+                        .filter(|(t, _)| to.contains(t))
+                        .map(|(_, w)| w)
+                        .sum::<f32>();
+                }
+            }
+            sum
+        };
+        let before = rewarded_weight(&brain);
+
+        let mut internal = brain.clone();
+        internal.dream_replay_with_source(5, 2.0, &DreamSource::Internal);
+        let mut rewarded = brain.clone();
+        let activity =
+            rewarded.dream_replay_with_source(5, 2.0, &DreamSource::HighReward { min_reward: 0.5 });
+        assert!(activity > 0.0);
+
+        let gain_internal = rewarded_weight(&internal) - before;
+        let gain_rewarded = rewarded_weight(&rewarded) - before;
+        assert!(
+            gain_rewarded > gain_internal,
+            "high-reward replay {gain_rewarded} vs internal {gain_internal}"
+        );
+
+        // Sources that match nothing do not dream.
+        let mut idle = brain.clone();
+        let src = DreamSource::HighReward { min_reward: 2.0 };
+        assert_eq!(idle.dream_replay_with_source(5, 2.0, &src), 0.0);
+        let src = DreamSource::Context("missing".to_string());
+        assert_eq!(idle.dream_replay_with_source(5, 2.0, &src), 0.0);
+        assert_eq!(idle.connections.weights, brain.connections.weights);
+        let src = DreamSource::Recent { window_steps: 0 };
+        assert!(idle.dream_replay_with_source(1, 2.0, &src) > 0.0);
+    }
+
     #[test]
     fn burst_learning_detects_spikes() {
         let cfg = BrainConfig::with_size(16, 4).with_seed(42);
@@ -8210,6 +14340,169 @@ mod tests {
         assert!(!fail, "Should fail for non-existent groups");
     }
 
+    #[test]
+    fn imprint_sensor_group_skips_other_sensor_groups() {
+        let cfg = BrainConfig::with_size(32, 4).with_seed(42);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("pos", 2);
+        brain.define_sensor("noise", 2);
+        brain.define_action("move", 2);
+
+        let units_of = |brain: &Brain, name: &str| -> Vec<UnitId> {
+            brain
+                .sensor_groups
+                .iter()
+                .chain(brain.action_groups.iter())
+                .find(|g| g.name == name)
+                .unwrap()
+                .units
+                .clone()
+        };
+        let pos = units_of(&brain, "pos");
+        let noise = units_of(&brain, "noise");
+        let actions = units_of(&brain, "move");
+        for &id in pos.iter().chain(&noise).chain(&actions) {
+            brain.units[id].amp = 1.0;
+        }
+
+        let weight = |brain: &Brain, from: UnitId, to: UnitId| -> f32 {
+            brain
+                .neighbors(from)
+                .filter(|&(t, _)| t == to)
+                .map(|(_, w)| w)
+                .sum()
+        };
+        let noise_before = weight(&brain, noise[0], actions[0]);
+        let pos_before = weight(&brain, pos[0], actions[0]);
+
+        assert!(brain.imprint_sensor_group("pos", 0.5));
+        assert!(weight(&brain, pos[0], actions[0]) > pos_before);
+        assert_eq!(weight(&brain, noise[0], actions[0]), noise_before);
+
+        assert!(!brain.imprint_sensor_group("missing", 0.5));
+    }
+
+    #[test]
+    fn imprint_current_context_biased_rates_select_edge_types() {
+        let cfg = BrainConfig::with_size(32, 4).with_seed(42);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("pos", 2);
+        brain.define_action("move", 2);
+        let pos = brain.sensor_units("pos").unwrap().to_vec();
+        let actions = brain.action_units("move").unwrap().to_vec();
+
+        // An unassigned interneuron with no existing edge from the sensor.
+        let linked =
+            |brain: &Brain, from: UnitId, to: UnitId| brain.neighbors(from).any(|(t, _)| t == to);
+        let inter = (0..brain.units.len())
+            .find(|&id| !brain.reserved[id] && !linked(&brain, pos[0], id))
+            .unwrap();
+
+        let activate = |brain: &mut Brain| {
+            for u in brain.units.iter_mut() {
+                u.amp = 0.0;
+            }
+            for &id in pos.iter().chain(&actions) {
+                brain.units[id].amp = 1.0;
+            }
+            brain.units[inter].amp = 0.9;
+        };
+        let weight = |brain: &Brain, from: UnitId, to: UnitId| -> f32 {
+            brain
+                .neighbors(from)
+                .filter(|&(t, _)| t == to)
+                .map(|(_, w)| w)
+                .sum()
+        };
+
+        // Pair rate only: the sensor→action mapping grows, the interneuron stays unlinked.
+        activate(&mut brain);
+        let pair_before = weight(&brain, pos[0], actions[0]);
+        brain.imprint_current_context_biased(0.0, 0.0, 0.8);
+        assert!(weight(&brain, pos[0], actions[0]) > pair_before);
+        assert!(!linked(&brain, pos[0], inter));
+
+        // Sensor and action rates wire the interneuron in on both sides.
+        activate(&mut brain);
+        brain.imprint_current_context_biased(0.5, 0.5, 0.0);
+        assert!(linked(&brain, pos[0], inter));
+        assert!(linked(&brain, inter, actions[0]));
+
+        // Target → action edges do not need an active sensor.
+        let inter2 = (0..brain.units.len())
+            .find(|&id| !brain.reserved[id] && id != inter && !linked(&brain, id, actions[0]))
+            .unwrap();
+        for u in brain.units.iter_mut() {
+            u.amp = 0.0;
+        }
+        brain.units[actions[0]].amp = 1.0;
+        brain.units[inter2].amp = 0.9;
+        brain.imprint_current_context_biased(0.0, 0.5, 0.0);
+        assert!(linked(&brain, inter2, actions[0]));
+    }
+
+    #[test]
+    fn connections_by_group_pair_filters_and_sorts() {
+        let mut brain = Brain::new(BrainConfig::with_size(32, 4).with_seed(42));
+        brain.define_sensor("light", 3);
+        brain.define_action("lever", 3);
+        brain.force_associate_groups("light", "lever", 0.5);
+
+        let light = brain.get_sensor_group_units("light");
+        let lever = brain.get_action_group_units("lever");
+        let edges = brain.connections_by_group_pair("light", "lever");
+        assert!(!edges.is_empty());
+        for &(from, to, _) in &edges {
+            assert!(light.contains(&from) && lever.contains(&to));
+        }
+        assert!(edges.windows(2).all(|w| w[0].2.abs() >= w[1].2.abs()));
+
+        let from_light = brain.connections_by_group_pair("light", "");
+        assert!(from_light.len() >= edges.len());
+        assert!(from_light.iter().all(|(from, _, _)| light.contains(from)));
+        assert_eq!(
+            brain.connections_by_group_pair("", "").len(),
+            brain.connection_count()
+        );
+        assert!(brain.connections_by_group_pair("missing", "").is_empty());
+    }
+
+    #[test]
+    fn deployment_presets_are_valid() {
+        let embedded = BrainConfig::with_defaults_for_embedded();
+        assert!(embedded.validate().is_ok());
+        assert_eq!(
+            (embedded.unit_count, embedded.connectivity_per_unit),
+            (32, 4)
+        );
+        assert_eq!(embedded.causal_window_frames, 2);
+        assert!(!embedded.enable_fast_weights);
+
+        let server = BrainConfig::with_defaults_for_server();
+        assert!(server.validate().is_ok());
+        assert!(server.unit_count > embedded.unit_count);
+        assert!(server.enable_fast_weights);
+        assert!(embedded.estimated_memory_bytes() < server.estimated_memory_bytes());
+    }
+
+    #[test]
+    fn group_activity_is_mean_clamped_amplitude() {
+        let mut brain = Brain::new(BrainConfig::with_size(32, 4).with_seed(3));
+        brain.define_sensor("light", 2);
+        brain.define_action("lever", 2);
+        let light = brain.get_sensor_group_units("light");
+        brain.units[light[0]].amp = 1.8;
+        brain.units[light[1]].amp = 0.2;
+        for id in brain.get_action_group_units("lever") {
+            brain.units[id].amp = -0.5;
+        }
+
+        assert!((brain.get_sensor_group_activity("light") - 0.6).abs() < 1e-6);
+        assert_eq!(brain.get_action_group_activity("lever"), 0.0);
+        assert_eq!(brain.get_sensor_group_activity("lever"), 0.0);
+        assert_eq!(brain.get_action_group_activity("missing"), 0.0);
+    }
+
     #[test]
     fn combined_mechanisms_workflow() {
         let cfg = BrainConfig::with_size(64, 8).with_seed(42);
@@ -8254,6 +14547,180 @@ mod tests {
         assert!(diag.unit_count >= 64, "Should have at least initial units");
         assert!(diag.connection_count > 0, "Should have connections");
     }
+
+    #[test]
+    fn lateral_inhibition_reaches_only_units_within_radius() {
+        let mut brain = Brain::new(BrainConfig::with_size(8, 1).with_seed(2));
+        // Chain 0 -> 1 -> 2 -> 3; 4.. stay unconnected.
+        brain.connections = CsrConnections {
+            targets: vec![1, 2, 3],
+            weights: vec![0.5; 3],
+            offsets: vec![0, 1, 2, 3, 3, 3, 3, 3, 3],
+        };
+        for u in &mut brain.units {
+            u.amp = 1.0;
+        }
+
+        assert_eq!(brain.apply_lateral_inhibition(0, 2, 0.25), 2);
+        let amps = brain.unit_amplitudes();
+        assert_eq!(amps[..4], [1.0, 0.75, 0.75, 1.0]);
+
+        assert_eq!(brain.apply_lateral_excitation(0, 10, 0.5), 3);
+        let amps = brain.unit_amplitudes();
+        assert_eq!(amps[..5], [1.0, 1.25, 1.25, 1.5, 1.0]);
+        assert_eq!(brain.apply_lateral_inhibition(99, 2, 1.0), 0);
+    }
+
+    #[test]
+    fn neighbors_sorted_matches_sorted_neighbors() {
+        let mut cfg = BrainConfig::with_size(48, 6).with_seed(7);
+        cfg.experimental_flags
+            .insert("sorted_adjacency".to_string(), 1.0);
+        let mut brain = Brain::new(cfg);
+        brain.define_sensor("a", 4);
+        brain.define_action("b", 4);
+
+        let expected = |brain: &Brain, u: UnitId| {
+            let mut v: Vec<(UnitId, Weight)> = brain.neighbors(u).collect();
+            v.sort_by(|(ta, wa), (tb, wb)| wb.abs().total_cmp(&wa.abs()).then(ta.cmp(tb)));
+            v
+        };
+
+        // Before any step the order is computed on the fly.
+        for u in 0..48 {
+            assert_eq!(
+                brain.neighbors_sorted(u).collect::<Vec<_>>(),
+                expected(&brain, u)
+            );
+        }
+
+        for _ in 0..50 {
+            brain.apply_stimulus(Stimulus::new("a", 1.0));
+            brain.set_neuromodulator(0.8);
+            brain.step();
+        }
+        assert_eq!(
+            brain.sorted_adjacency.len(),
+            brain.connections.targets.len()
+        );
+        assert!(brain.profile_step().sorted_adjacency_ns > 0);
+        for u in 0..48 {
+            assert_eq!(
+                brain.neighbors_sorted(u).collect::<Vec<_>>(),
+                expected(&brain, u)
+            );
+        }
+    }
+
+    #[test]
+    fn step_leaves_neighbor_order_alone_without_the_flag() {
+        let mut brain = Brain::new(BrainConfig::with_size(32, 6).with_seed(5));
+        brain.step();
+        assert_eq!(brain.sorted_adjacency_generation, None);
+        assert_eq!(brain.profile_step().sorted_adjacency_ns, 0);
+
+        let mut expected: Vec<(UnitId, Weight)> = brain.neighbors(0).collect();
+        expected.sort_by(|(ta, wa), (tb, wb)| wb.abs().total_cmp(&wa.abs()).then(ta.cmp(tb)));
+        assert_eq!(brain.neighbors_sorted(0).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn neighbors_sorted_sees_weight_writes_made_outside_step() {
+        let mut cfg = BrainConfig::with_size(32, 6).with_seed(11);
+        cfg.experimental_flags
+            .insert("sorted_adjacency".to_string(), 1.0);
+        let mut brain = Brain::new(cfg);
+        brain.step();
+        assert_eq!(
+            brain.sorted_adjacency_generation,
+            Some(brain.csr_generation)
+        );
+
+        // Same layout, new weights: make unit 0's lightest edge its heaviest.
+        let range = brain.conn_range(0);
+        let (idx, w) = range
+            .clone()
+            .map(|i| (i, brain.connections.weights[i]))
+            .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+            .unwrap();
+        let delta = BrainDelta {
+            weight_deltas: vec![(idx, 5.0 - w)],
+        };
+        brain.apply_weight_delta(&delta, 10.0);
+
+        let first = brain.neighbors_sorted(0).next().unwrap();
+        assert_eq!(first.0, brain.connections.targets[idx]);
+
+        brain.step();
+        assert_eq!(
+            brain.sorted_adjacency_generation,
+            Some(brain.csr_generation)
+        );
+        assert_eq!(
+            brain.neighbors_sorted(0).next().unwrap().0,
+            brain.connections.targets[idx]
+        );
+    }
+
+    #[test]
+    fn config_without_causal_window_loads_as_window_one() {
+        let brain = Brain::new(BrainConfig::with_size(16, 4).with_seed(3));
+        assert_eq!(brain.cfg.causal_window_frames, 4);
+        let mut payload = Vec::new();
+        brain.write_cfg_payload(&mut payload).unwrap();
+
+        // Drop the window and everything appended after it (fast weights, momentum).
+        payload.truncate(payload.len() - 5 * 4);
+        let cfg = Brain::read_cfg_payload(&mut payload.as_slice()).unwrap();
+        assert_eq!(cfg.causal_window_frames, 1);
+    }
+
+    #[test]
+    fn shrinking_causal_window_stops_cross_frame_cooccurrence() {
+        let mut brain = Brain::new(BrainConfig::with_size(16, 4).with_seed(3));
+        brain.set_observer_telemetry(true);
+        brain.set_causal_window(10);
+
+        // "cue" and "outcome" are committed five frames apart, every eight frames.
+        let run = |brain: &mut Brain, cycles: usize| {
+            for _ in 0..cycles {
+                for f in 0..8 {
+                    match f {
+                        0 => brain.note_compound_symbol(&["cue"]),
+                        5 => brain.note_compound_symbol(&["outcome"]),
+                        _ => {}
+                    }
+                    brain.commit_observation();
+                }
+            }
+        };
+        let cue_outcome_updates = |brain: &Brain| {
+            let (cue, outcome) = (
+                brain.symbol_id("cue").unwrap(),
+                brain.symbol_id("outcome").unwrap(),
+            );
+            brain
+                .causal_event_log()
+                .iter()
+                .filter(|e| !e.is_directed && e.from == cue && e.to == outcome)
+                .count()
+        };
+
+        run(&mut brain, 4);
+        assert!(cue_outcome_updates(&brain) > 0);
+        let established = brain.causal.association_strength(
+            brain.symbol_id("cue").unwrap(),
+            brain.symbol_id("outcome").unwrap(),
+        );
+        assert!(established > 0.0);
+
+        brain.set_causal_window(2);
+        assert_eq!(brain.causal_window(), 2);
+        brain.telemetry.causal_events.clear();
+        run(&mut brain, 4);
+        assert_eq!(cue_outcome_updates(&brain), 0);
+    }
+
     #[test]
     fn spot_like_task_learns_high_accuracy() {
         use super::{Brain, BrainConfig, Stimulus};
@@ -1,4 +1,7 @@
-use crate::substrate::{ActionPolicy, Brain, Stimulus};
+use std::collections::HashMap;
+
+use crate::prng::Prng;
+use crate::substrate::{ActionPolicy, Brain, BrainConfig, Stimulus};
 
 #[derive(Debug, Clone)]
 pub struct ChildSpec {
@@ -41,6 +44,23 @@ pub enum ChildRequest {
 pub struct ConsolidationPolicy {
     pub weight_threshold: f32,
     pub merge_rate: f32,
+    /// How [`Supervisor::cross_consolidate`] combines two peers' edges.
+    pub merge_strategy: MergeStrategy,
+}
+
+/// Edge combination rule for [`Supervisor::cross_consolidate`].
+///
+/// Every rule starts from the meaning-weighted mean of the two inputs' weights.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MergeStrategy {
+    /// Keep every edge of either input; an edge missing from one counts as 0.
+    #[default]
+    WeightedMean,
+    /// Like `WeightedMean`, then keep only the `k` strongest out-edges per unit.
+    TopK { k: usize },
+    /// Keep only edges with `|w| >= threshold` in at least one input, averaging
+    /// over the inputs where the edge is that strong.
+    UnionStrong { threshold: f32 },
 }
 
 pub struct Supervisor {
@@ -52,6 +72,9 @@ pub struct Supervisor {
     milk_pool_steps: usize,
     allow_recursive_spawning: bool,
     next_child_id: u64,
+
+    evolution_rng: Prng,
+    mutation_sigma: f32,
 }
 
 impl Supervisor {
@@ -62,12 +85,16 @@ impl Supervisor {
             policy: ConsolidationPolicy {
                 weight_threshold: 0.15,
                 merge_rate: 0.35,
+                merge_strategy: MergeStrategy::WeightedMean,
             },
 
             max_parallelism: 1,
             milk_pool_steps: 0,
             allow_recursive_spawning: false,
             next_child_id: 1,
+
+            evolution_rng: Prng::new(0xE701),
+            mutation_sigma: 0.1,
         }
     }
 
@@ -87,6 +114,11 @@ impl Supervisor {
         self.allow_recursive_spawning = enabled;
     }
 
+    /// Relative perturbation applied to child hyperparameters in [`Self::evolution_round`].
+    pub fn set_mutation_sigma(&mut self, sigma: f32) {
+        self.mutation_sigma = sigma.max(0.0);
+    }
+
     pub fn spawn_child(&mut self, spec: ChildSpec, seed: u64, cfg_overrides: ChildConfigOverrides) {
         let mut child = self.parent.spawn_child(seed, cfg_overrides);
 
@@ -183,10 +215,11 @@ impl Supervisor {
 
                         // Grandchild exploration: slightly higher noise/plasticity.
                         let overrides = ChildConfigOverrides {
-                            noise_amp: 0.05,
-                            noise_phase: 0.025,
-                            hebb_rate: 0.16,
-                            forget_rate: 0.0013,
+                            noise_amp: Some(0.05),
+                            noise_phase: Some(0.025),
+                            hebb_rate: Some(0.16),
+                            forget_rate: Some(0.0013),
+                            ..ChildConfigOverrides::inherit()
                         };
 
                         // The supervisor will spawn this after the loop to avoid borrowing issues.
@@ -253,23 +286,545 @@ impl Supervisor {
 
         Some((child_name, best_score))
     }
+
+    /// Merge two peer brains (e.g. specialists that each solved part of a task)
+    /// into a new one using `policy.merge_strategy`; `a` and `b` are untouched.
+    ///
+    /// Each edge's weight is the mean of both inputs weighted by how much each
+    /// brain has learned: a sensor→action edge uses that brain's
+    /// `pair_reward_edges(sensor, action).meaning`, other edges the brain's mean
+    /// pair meaning. The result keeps `a`'s layout and units are matched by id,
+    /// so `a` and `b` should descend from the same parent.
+    pub fn cross_consolidate(&self, a: &Brain, b: &Brain) -> Brain {
+        a.cross_consolidated(b, self.policy.merge_strategy)
+    }
+
+    /// Run one generation of genetic search over `population`.
+    ///
+    /// `game` runs a single trial on the brain it is given and returns the
+    /// reward. Each individual is evaluated for `budget` trials on a scratch
+    /// clone (like `ExpertManager::score_child`, evaluation never touches the
+    /// individual); fitness is the mean reward.
+    ///
+    /// The top half survives unchanged. The remaining slots are refilled with
+    /// children of the survivors (round-robin) via [`Brain::spawn_child`] with
+    /// mutated [`ChildConfigOverrides`]; every other child is first crossed
+    /// with the next survivor using [`Brain::consolidate_from`].
+    ///
+    /// Returns `(index, fitness)` pairs for the evaluated population, best first.
+    pub fn evolution_round<G>(
+        &mut self,
+        population: &mut Vec<Brain>,
+        mut game: G,
+        budget: u32,
+    ) -> Vec<(usize, f32)>
+    where
+        G: FnMut(&mut Brain) -> f32,
+    {
+        let n = population.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut scored: Vec<(usize, f32)> = Vec::with_capacity(n);
+        for (idx, brain) in population.iter().enumerate() {
+            let mut trial = brain.clone();
+            let mut total = 0.0f32;
+            for _ in 0..budget {
+                total += game(&mut trial);
+            }
+            scored.push((idx, total / budget.max(1) as f32));
+        }
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let keep = n.div_ceil(2);
+        let mut old: Vec<Option<Brain>> = population.drain(..).map(Some).collect();
+        let survivors: Vec<Brain> = scored[..keep]
+            .iter()
+            .filter_map(|&(idx, _)| old[idx].take())
+            .collect();
+
+        let mut next: Vec<Brain> = survivors.clone();
+        let mut slot = 0usize;
+        while next.len() < n {
+            let a = &survivors[slot % keep];
+            let overrides = ChildConfigOverrides::from_config(a.config())
+                .mutate(&mut self.evolution_rng, self.mutation_sigma);
+            let seed = u64::from(self.evolution_rng.next_u32()) << 32
+                | u64::from(self.evolution_rng.next_u32());
+
+            let child = if keep > 1 && slot % 2 == 1 {
+                let mut crossed = a.clone();
+                crossed.consolidate_from(&survivors[(slot + 1) % keep], self.policy);
+                crossed.spawn_child(seed, overrides)
+            } else {
+                a.spawn_child(seed, overrides)
+            };
+            next.push(child);
+            slot += 1;
+        }
+
+        *population = next;
+        scored
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Per-child [`BrainConfig`] patch applied by [`Brain::spawn_child`].
+///
+/// Each field mirrors the `BrainConfig` field of the same name; `None` inherits
+/// the parent's value. Topology (`unit_count`, `connectivity_per_unit`) always
+/// comes from the parent because the child copies its substrate, and the seed
+/// is passed to `spawn_child` directly.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ChildConfigOverrides {
-    pub noise_amp: f32,
-    pub noise_phase: f32,
-    pub hebb_rate: f32,
-    pub forget_rate: f32,
+    pub dt: Option<f32>,
+    pub base_freq: Option<f32>,
+    pub noise_amp: Option<f32>,
+    pub noise_phase: Option<f32>,
+    pub amp_saturation_beta: Option<f32>,
+    pub phase_coupling_mode: Option<u8>,
+    pub phase_coupling_k: Option<f32>,
+    pub phase_coupling_gain: Option<f32>,
+    pub global_inhibition: Option<f32>,
+    pub inhibition_mode: Option<u8>,
+    pub hebb_rate: Option<f32>,
+    pub forget_rate: Option<f32>,
+    pub prune_below: Option<f32>,
+    pub coactive_threshold: Option<f32>,
+    pub phase_lock_threshold: Option<f32>,
+    pub imprint_rate: Option<f32>,
+    pub salience_decay: Option<f32>,
+    pub salience_gain: Option<f32>,
+    pub activity_trace_decay: Option<f32>,
+    pub growth_policy_mode: Option<u8>,
+    pub growth_cooldown_steps: Option<u32>,
+    pub growth_signal_alpha: Option<f32>,
+    pub growth_commit_ema_threshold: Option<f32>,
+    pub growth_eligibility_norm_ema_threshold: Option<f32>,
+    pub growth_prune_norm_ema_max: Option<f32>,
+    pub causal_lag_steps: Option<u8>,
+    pub causal_lag_decay: Option<f32>,
+    pub causal_symbol_cap: Option<u8>,
+    pub causal_window_frames: Option<u32>,
+    pub enable_fast_weights: Option<bool>,
+    pub fast_hebb_rate: Option<f32>,
+    pub fast_forget_rate: Option<f32>,
+    pub causal_decay: Option<f32>,
+    pub learning_deadband: Option<f32>,
+    pub eligibility_decay: Option<f32>,
+    pub eligibility_gain: Option<f32>,
+    pub weight_clip_min: Option<f32>,
+    pub weight_clip_max: Option<f32>,
+    pub reward_symbol_threshold: Option<f32>,
+    pub concept_validate_threshold: Option<f32>,
+    pub coactive_softness: Option<f32>,
+    pub phase_gate_softness: Option<f32>,
+    pub plasticity_budget: Option<f32>,
+    pub homeostasis_target_amp: Option<f32>,
+    pub homeostasis_rate: Option<f32>,
+    pub homeostasis_every: Option<u32>,
+    pub module_routing_top_k: Option<u8>,
+    pub module_routing_strict: Option<bool>,
+    pub module_routing_beta: Option<f32>,
+    pub module_signature_decay: Option<f32>,
+    pub module_signature_cap: Option<u8>,
+    pub module_learning_activity_threshold: Option<f32>,
+    pub module_plasticity_budget: Option<f32>,
+    pub cross_module_plasticity_scale: Option<f32>,
+    pub cross_module_forget_boost: Option<f32>,
+    pub cross_module_prune_bonus: Option<f32>,
+    pub latent_module_auto_create: Option<bool>,
+    pub latent_module_auto_width: Option<u32>,
+    pub latent_module_auto_cooldown_steps: Option<u32>,
+    pub latent_module_auto_max_active: Option<u32>,
+    pub latent_module_auto_reward_threshold: Option<f32>,
+    pub latent_module_retire_after_steps: Option<u32>,
+    pub latent_module_retire_reward_threshold: Option<f32>,
+    pub novelty_window: Option<u32>,
+    pub neuromodulator_momentum: Option<f32>,
+    /// Merged into the parent's `experimental_flags`: each entry replaces the
+    /// flag of the same name and other parent flags are kept. Set a flag to
+    /// `0.0` to turn an inherited one off.
+    pub experimental_flags: Option<HashMap<String, f32>>,
+}
+
+impl ChildConfigOverrides {
+    /// Overrides that change nothing: the child inherits every parent setting.
+    pub fn inherit() -> Self {
+        Self {
+            dt: None,
+            base_freq: None,
+            noise_amp: None,
+            noise_phase: None,
+            amp_saturation_beta: None,
+            phase_coupling_mode: None,
+            phase_coupling_k: None,
+            phase_coupling_gain: None,
+            global_inhibition: None,
+            inhibition_mode: None,
+            hebb_rate: None,
+            forget_rate: None,
+            prune_below: None,
+            coactive_threshold: None,
+            phase_lock_threshold: None,
+            imprint_rate: None,
+            salience_decay: None,
+            salience_gain: None,
+            activity_trace_decay: None,
+            growth_policy_mode: None,
+            growth_cooldown_steps: None,
+            growth_signal_alpha: None,
+            growth_commit_ema_threshold: None,
+            growth_eligibility_norm_ema_threshold: None,
+            growth_prune_norm_ema_max: None,
+            causal_lag_steps: None,
+            causal_lag_decay: None,
+            causal_symbol_cap: None,
+            causal_window_frames: None,
+            enable_fast_weights: None,
+            fast_hebb_rate: None,
+            fast_forget_rate: None,
+            causal_decay: None,
+            learning_deadband: None,
+            eligibility_decay: None,
+            eligibility_gain: None,
+            weight_clip_min: None,
+            weight_clip_max: None,
+            reward_symbol_threshold: None,
+            concept_validate_threshold: None,
+            coactive_softness: None,
+            phase_gate_softness: None,
+            plasticity_budget: None,
+            homeostasis_target_amp: None,
+            homeostasis_rate: None,
+            homeostasis_every: None,
+            module_routing_top_k: None,
+            module_routing_strict: None,
+            module_routing_beta: None,
+            module_signature_decay: None,
+            module_signature_cap: None,
+            module_learning_activity_threshold: None,
+            module_plasticity_budget: None,
+            cross_module_plasticity_scale: None,
+            cross_module_forget_boost: None,
+            cross_module_prune_bonus: None,
+            latent_module_auto_create: None,
+            latent_module_auto_width: None,
+            latent_module_auto_cooldown_steps: None,
+            latent_module_auto_max_active: None,
+            latent_module_auto_reward_threshold: None,
+            latent_module_retire_after_steps: None,
+            latent_module_retire_reward_threshold: None,
+            novelty_window: None,
+            neuromodulator_momentum: None,
+            experimental_flags: None,
+        }
+    }
+
+    /// Overrides pinning every field to the value `cfg` already uses.
+    pub fn from_config(cfg: &BrainConfig) -> Self {
+        Self {
+            dt: Some(cfg.dt),
+            base_freq: Some(cfg.base_freq),
+            noise_amp: Some(cfg.noise_amp),
+            noise_phase: Some(cfg.noise_phase),
+            amp_saturation_beta: Some(cfg.amp_saturation_beta),
+            phase_coupling_mode: Some(cfg.phase_coupling_mode),
+            phase_coupling_k: Some(cfg.phase_coupling_k),
+            phase_coupling_gain: Some(cfg.phase_coupling_gain),
+            global_inhibition: Some(cfg.global_inhibition),
+            inhibition_mode: Some(cfg.inhibition_mode),
+            hebb_rate: Some(cfg.hebb_rate),
+            forget_rate: Some(cfg.forget_rate),
+            prune_below: Some(cfg.prune_below),
+            coactive_threshold: Some(cfg.coactive_threshold),
+            phase_lock_threshold: Some(cfg.phase_lock_threshold),
+            imprint_rate: Some(cfg.imprint_rate),
+            salience_decay: Some(cfg.salience_decay),
+            salience_gain: Some(cfg.salience_gain),
+            activity_trace_decay: Some(cfg.activity_trace_decay),
+            growth_policy_mode: Some(cfg.growth_policy_mode),
+            growth_cooldown_steps: Some(cfg.growth_cooldown_steps),
+            growth_signal_alpha: Some(cfg.growth_signal_alpha),
+            growth_commit_ema_threshold: Some(cfg.growth_commit_ema_threshold),
+            growth_eligibility_norm_ema_threshold: Some(cfg.growth_eligibility_norm_ema_threshold),
+            growth_prune_norm_ema_max: Some(cfg.growth_prune_norm_ema_max),
+            causal_lag_steps: Some(cfg.causal_lag_steps),
+            causal_lag_decay: Some(cfg.causal_lag_decay),
+            causal_symbol_cap: Some(cfg.causal_symbol_cap),
+            causal_window_frames: Some(cfg.causal_window_frames),
+            enable_fast_weights: Some(cfg.enable_fast_weights),
+            fast_hebb_rate: Some(cfg.fast_hebb_rate),
+            fast_forget_rate: Some(cfg.fast_forget_rate),
+            causal_decay: Some(cfg.causal_decay),
+            learning_deadband: Some(cfg.learning_deadband),
+            eligibility_decay: Some(cfg.eligibility_decay),
+            eligibility_gain: Some(cfg.eligibility_gain),
+            weight_clip_min: Some(cfg.weight_clip_min),
+            weight_clip_max: Some(cfg.weight_clip_max),
+            reward_symbol_threshold: Some(cfg.reward_symbol_threshold),
+            concept_validate_threshold: Some(cfg.concept_validate_threshold),
+            coactive_softness: Some(cfg.coactive_softness),
+            phase_gate_softness: Some(cfg.phase_gate_softness),
+            plasticity_budget: Some(cfg.plasticity_budget),
+            homeostasis_target_amp: Some(cfg.homeostasis_target_amp),
+            homeostasis_rate: Some(cfg.homeostasis_rate),
+            homeostasis_every: Some(cfg.homeostasis_every),
+            module_routing_top_k: Some(cfg.module_routing_top_k),
+            module_routing_strict: Some(cfg.module_routing_strict),
+            module_routing_beta: Some(cfg.module_routing_beta),
+            module_signature_decay: Some(cfg.module_signature_decay),
+            module_signature_cap: Some(cfg.module_signature_cap),
+            module_learning_activity_threshold: Some(cfg.module_learning_activity_threshold),
+            module_plasticity_budget: Some(cfg.module_plasticity_budget),
+            cross_module_plasticity_scale: Some(cfg.cross_module_plasticity_scale),
+            cross_module_forget_boost: Some(cfg.cross_module_forget_boost),
+            cross_module_prune_bonus: Some(cfg.cross_module_prune_bonus),
+            latent_module_auto_create: Some(cfg.latent_module_auto_create),
+            latent_module_auto_width: Some(cfg.latent_module_auto_width),
+            latent_module_auto_cooldown_steps: Some(cfg.latent_module_auto_cooldown_steps),
+            latent_module_auto_max_active: Some(cfg.latent_module_auto_max_active),
+            latent_module_auto_reward_threshold: Some(cfg.latent_module_auto_reward_threshold),
+            latent_module_retire_after_steps: Some(cfg.latent_module_retire_after_steps),
+            latent_module_retire_reward_threshold: Some(cfg.latent_module_retire_reward_threshold),
+            novelty_window: Some(cfg.novelty_window),
+            neuromodulator_momentum: Some(cfg.neuromodulator_momentum),
+            experimental_flags: Some(cfg.experimental_flags.clone()),
+        }
+    }
+
+    /// Patch `cfg` with every `Some` field.
+    pub fn apply_to(&self, cfg: &mut BrainConfig) {
+        if let Some(v) = self.dt {
+            cfg.dt = v;
+        }
+        if let Some(v) = self.base_freq {
+            cfg.base_freq = v;
+        }
+        if let Some(v) = self.noise_amp {
+            cfg.noise_amp = v;
+        }
+        if let Some(v) = self.noise_phase {
+            cfg.noise_phase = v;
+        }
+        if let Some(v) = self.amp_saturation_beta {
+            cfg.amp_saturation_beta = v;
+        }
+        if let Some(v) = self.phase_coupling_mode {
+            cfg.phase_coupling_mode = v;
+        }
+        if let Some(v) = self.phase_coupling_k {
+            cfg.phase_coupling_k = v;
+        }
+        if let Some(v) = self.phase_coupling_gain {
+            cfg.phase_coupling_gain = v;
+        }
+        if let Some(v) = self.global_inhibition {
+            cfg.global_inhibition = v;
+        }
+        if let Some(v) = self.inhibition_mode {
+            cfg.inhibition_mode = v;
+        }
+        if let Some(v) = self.hebb_rate {
+            cfg.hebb_rate = v;
+        }
+        if let Some(v) = self.forget_rate {
+            cfg.forget_rate = v;
+        }
+        if let Some(v) = self.prune_below {
+            cfg.prune_below = v;
+        }
+        if let Some(v) = self.coactive_threshold {
+            cfg.coactive_threshold = v;
+        }
+        if let Some(v) = self.phase_lock_threshold {
+            cfg.phase_lock_threshold = v;
+        }
+        if let Some(v) = self.imprint_rate {
+            cfg.imprint_rate = v;
+        }
+        if let Some(v) = self.salience_decay {
+            cfg.salience_decay = v;
+        }
+        if let Some(v) = self.salience_gain {
+            cfg.salience_gain = v;
+        }
+        if let Some(v) = self.activity_trace_decay {
+            cfg.activity_trace_decay = v;
+        }
+        if let Some(v) = self.growth_policy_mode {
+            cfg.growth_policy_mode = v;
+        }
+        if let Some(v) = self.growth_cooldown_steps {
+            cfg.growth_cooldown_steps = v;
+        }
+        if let Some(v) = self.growth_signal_alpha {
+            cfg.growth_signal_alpha = v;
+        }
+        if let Some(v) = self.growth_commit_ema_threshold {
+            cfg.growth_commit_ema_threshold = v;
+        }
+        if let Some(v) = self.growth_eligibility_norm_ema_threshold {
+            cfg.growth_eligibility_norm_ema_threshold = v;
+        }
+        if let Some(v) = self.growth_prune_norm_ema_max {
+            cfg.growth_prune_norm_ema_max = v;
+        }
+        if let Some(v) = self.causal_lag_steps {
+            cfg.causal_lag_steps = v;
+        }
+        if let Some(v) = self.causal_lag_decay {
+            cfg.causal_lag_decay = v;
+        }
+        if let Some(v) = self.causal_symbol_cap {
+            cfg.causal_symbol_cap = v;
+        }
+        if let Some(v) = self.causal_window_frames {
+            cfg.causal_window_frames = v;
+        }
+        if let Some(v) = self.enable_fast_weights {
+            cfg.enable_fast_weights = v;
+        }
+        if let Some(v) = self.fast_hebb_rate {
+            cfg.fast_hebb_rate = v;
+        }
+        if let Some(v) = self.fast_forget_rate {
+            cfg.fast_forget_rate = v;
+        }
+        if let Some(v) = self.causal_decay {
+            cfg.causal_decay = v;
+        }
+        if let Some(v) = self.learning_deadband {
+            cfg.learning_deadband = v;
+        }
+        if let Some(v) = self.eligibility_decay {
+            cfg.eligibility_decay = v;
+        }
+        if let Some(v) = self.eligibility_gain {
+            cfg.eligibility_gain = v;
+        }
+        if let Some(v) = self.weight_clip_min {
+            cfg.weight_clip_min = v;
+        }
+        if let Some(v) = self.weight_clip_max {
+            cfg.weight_clip_max = v;
+        }
+        if let Some(v) = self.reward_symbol_threshold {
+            cfg.reward_symbol_threshold = v;
+        }
+        if let Some(v) = self.concept_validate_threshold {
+            cfg.concept_validate_threshold = v;
+        }
+        if let Some(v) = self.coactive_softness {
+            cfg.coactive_softness = v;
+        }
+        if let Some(v) = self.phase_gate_softness {
+            cfg.phase_gate_softness = v;
+        }
+        if let Some(v) = self.plasticity_budget {
+            cfg.plasticity_budget = v;
+        }
+        if let Some(v) = self.homeostasis_target_amp {
+            cfg.homeostasis_target_amp = v;
+        }
+        if let Some(v) = self.homeostasis_rate {
+            cfg.homeostasis_rate = v;
+        }
+        if let Some(v) = self.homeostasis_every {
+            cfg.homeostasis_every = v;
+        }
+        if let Some(v) = self.module_routing_top_k {
+            cfg.module_routing_top_k = v;
+        }
+        if let Some(v) = self.module_routing_strict {
+            cfg.module_routing_strict = v;
+        }
+        if let Some(v) = self.module_routing_beta {
+            cfg.module_routing_beta = v;
+        }
+        if let Some(v) = self.module_signature_decay {
+            cfg.module_signature_decay = v;
+        }
+        if let Some(v) = self.module_signature_cap {
+            cfg.module_signature_cap = v;
+        }
+        if let Some(v) = self.module_learning_activity_threshold {
+            cfg.module_learning_activity_threshold = v;
+        }
+        if let Some(v) = self.module_plasticity_budget {
+            cfg.module_plasticity_budget = v;
+        }
+        if let Some(v) = self.cross_module_plasticity_scale {
+            cfg.cross_module_plasticity_scale = v;
+        }
+        if let Some(v) = self.cross_module_forget_boost {
+            cfg.cross_module_forget_boost = v;
+        }
+        if let Some(v) = self.cross_module_prune_bonus {
+            cfg.cross_module_prune_bonus = v;
+        }
+        if let Some(v) = self.latent_module_auto_create {
+            cfg.latent_module_auto_create = v;
+        }
+        if let Some(v) = self.latent_module_auto_width {
+            cfg.latent_module_auto_width = v;
+        }
+        if let Some(v) = self.latent_module_auto_cooldown_steps {
+            cfg.latent_module_auto_cooldown_steps = v;
+        }
+        if let Some(v) = self.latent_module_auto_max_active {
+            cfg.latent_module_auto_max_active = v;
+        }
+        if let Some(v) = self.latent_module_auto_reward_threshold {
+            cfg.latent_module_auto_reward_threshold = v;
+        }
+        if let Some(v) = self.latent_module_retire_after_steps {
+            cfg.latent_module_retire_after_steps = v;
+        }
+        if let Some(v) = self.latent_module_retire_reward_threshold {
+            cfg.latent_module_retire_reward_threshold = v;
+        }
+        if let Some(v) = self.novelty_window {
+            cfg.novelty_window = v;
+        }
+        if let Some(v) = self.neuromodulator_momentum {
+            cfg.neuromodulator_momentum = v;
+        }
+        if let Some(flags) = &self.experimental_flags {
+            cfg.experimental_flags
+                .extend(flags.iter().map(|(k, &v)| (k.clone(), v)));
+        }
+    }
+
+    /// Copy with the exploration rates (`noise_amp`, `noise_phase`, `hebb_rate`,
+    /// `forget_rate`) scaled by independent factors in `1 ± sigma`.
+    ///
+    /// Inherited (`None`) rates stay inherited; rates are kept non-negative.
+    pub fn mutate(&self, rng: &mut Prng, sigma: f32) -> Self {
+        let sigma = sigma.max(0.0);
+        let mut jitter =
+            |v: Option<f32>| v.map(|v| (v * (1.0 + rng.gen_range_f32(-sigma, sigma))).max(0.0));
+        Self {
+            noise_amp: jitter(self.noise_amp),
+            noise_phase: jitter(self.noise_phase),
+            hebb_rate: jitter(self.hebb_rate),
+            forget_rate: jitter(self.forget_rate),
+            ..self.clone()
+        }
+    }
 }
 
 impl Default for ChildConfigOverrides {
+    /// Slightly noisier, more plastic than the stock config (exploration profile).
     fn default() -> Self {
         Self {
-            noise_amp: 0.03,
-            noise_phase: 0.015,
-            hebb_rate: 0.12,
-            forget_rate: 0.0012,
+            noise_amp: Some(0.03),
+            noise_phase: Some(0.015),
+            hebb_rate: Some(0.12),
+            forget_rate: Some(0.0012),
+            ..Self::inherit()
         }
     }
 }
@@ -550,12 +1105,195 @@ mod tests {
         );
     }
 
+    #[test]
+    fn child_config_overrides_mutate_stays_within_sigma() {
+        let base = ChildConfigOverrides::default();
+        let mut rng = Prng::new(7);
+        let m = base.mutate(&mut rng, 0.1);
+        for (a, b) in [
+            (base.noise_amp, m.noise_amp),
+            (base.noise_phase, m.noise_phase),
+            (base.hebb_rate, m.hebb_rate),
+            (base.forget_rate, m.forget_rate),
+        ] {
+            let (a, b) = (a.unwrap(), b.unwrap());
+            assert!((b - a).abs() <= 0.1 * a + 1e-9, "{a} -> {b}");
+        }
+        assert_ne!(m.hebb_rate, base.hebb_rate);
+
+        let same = base.mutate(&mut rng, 0.0);
+        assert_eq!(same.hebb_rate, base.hebb_rate);
+
+        // Inherited rates stay inherited.
+        let inherit = ChildConfigOverrides::inherit().mutate(&mut rng, 0.5);
+        assert_eq!(inherit, ChildConfigOverrides::inherit());
+    }
+
+    #[test]
+    fn spawn_child_patches_only_overridden_fields() {
+        let parent = make_test_brain();
+        let overrides = ChildConfigOverrides {
+            global_inhibition: Some(0.42),
+            imprint_rate: Some(0.9),
+            causal_decay: Some(0.05),
+            ..ChildConfigOverrides::inherit()
+        };
+        let child = parent.spawn_child(7, overrides);
+        let (p, c) = (parent.config(), child.config());
+        assert_eq!(c.global_inhibition, 0.42);
+        assert_eq!(c.imprint_rate, 0.9);
+        assert_eq!(c.causal_decay, 0.05);
+        assert_eq!(c.hebb_rate, p.hebb_rate);
+        assert_eq!(c.coactive_threshold, p.coactive_threshold);
+        assert_eq!(c.unit_count, p.unit_count);
+        assert_eq!(c.seed, Some(7));
+
+        let pinned = ChildConfigOverrides::from_config(p);
+        let mut cfg = BrainConfig::default();
+        pinned.apply_to(&mut cfg);
+        assert_eq!(cfg.noise_amp, p.noise_amp);
+        assert_eq!(cfg.novelty_window, p.novelty_window);
+    }
+
+    #[test]
+    fn child_config_overrides_merge_experimental_flags() {
+        let mut cfg = BrainConfig::with_size(64, 8).with_seed(1);
+        cfg.experimental_flags
+            .insert("fast_weights".to_string(), 1.0);
+        cfg.experimental_flags
+            .insert("oja_units_all".to_string(), 0.5);
+        let parent = Brain::new(cfg);
+
+        let overrides = ChildConfigOverrides {
+            experimental_flags: Some(HashMap::from([
+                ("oja_units_all".to_string(), 0.0),
+                ("new_flag".to_string(), 2.0),
+            ])),
+            ..ChildConfigOverrides::inherit()
+        };
+        let child = parent.spawn_child(2, overrides);
+        let flags = &child.config().experimental_flags;
+        assert_eq!(flags.get("fast_weights"), Some(&1.0));
+        assert_eq!(flags.get("oja_units_all"), Some(&0.0));
+        assert_eq!(flags.get("new_flag"), Some(&2.0));
+        assert_eq!(child.config().experimental_flag("oja_units_all"), None);
+    }
+
+    #[test]
+    fn evolution_round_keeps_fittest_and_refills_population() {
+        let mut sup = Supervisor::new(make_test_brain());
+        let mut population: Vec<Brain> = (0..5)
+            .map(|i| {
+                let cfg = BrainConfig::with_size(64, 8)
+                    .with_seed(i)
+                    .with_hebb_rate(0.02 * (i + 1) as f32);
+                let mut b = Brain::new(cfg);
+                b.define_sensor("test_stim", 4);
+                b.define_action("test_act", 4);
+                b
+            })
+            .collect();
+
+        // Fitness is the brain's Hebbian rate, so the ranking is known.
+        let scored = sup.evolution_round(&mut population, |b| b.config().hebb_rate, 3);
+        assert_eq!(scored.len(), 5);
+        assert_eq!(
+            scored.iter().map(|s| s.0).collect::<Vec<_>>(),
+            vec![4, 3, 2, 1, 0]
+        );
+        assert_eq!(population.len(), 5);
+
+        // Survivors come first, unchanged.
+        assert!((population[0].config().hebb_rate - 0.10).abs() < 1e-6);
+        assert!((population[1].config().hebb_rate - 0.08).abs() < 1e-6);
+        assert!((population[2].config().hebb_rate - 0.06).abs() < 1e-6);
+
+        // Children inherit a perturbed rate from a survivor.
+        let child_rate = population[3].config().hebb_rate;
+        assert!(
+            (child_rate - 0.10).abs() <= 0.1 * 0.10 + 1e-6,
+            "{child_rate}"
+        );
+        assert!(population[4].config().hebb_rate > 0.0);
+    }
+
+    #[test]
+    fn cross_consolidate_weights_peers_by_meaning() {
+        use std::collections::HashMap;
+
+        let mut a = make_test_brain();
+        let mut b = a.clone();
+        // `a` learns that test_act pays off under test_stim; `b` only drifts.
+        for _ in 0..20 {
+            a.apply_stimulus(Stimulus::new("test_stim", 1.0));
+            a.step();
+            a.note_compound_symbol(&["test_stim"]);
+            a.note_action("test_act");
+            a.note_compound_symbol(&["pair", "test_stim", "test_act"]);
+            a.set_neuromodulator(1.0);
+            a.commit_observation();
+        }
+        for _ in 0..20 {
+            b.apply_stimulus(Stimulus::new("test_stim", 1.0));
+            b.set_neuromodulator(0.8);
+            b.step();
+        }
+        assert!(a.pair_reward_edges("test_stim", "test_act").meaning > 0.0);
+
+        // Keyed by (from, to, occurrence): the CSR may hold duplicate targets.
+        let edges = |brain: &Brain| -> HashMap<(usize, usize, usize), f32> {
+            let mut seen: HashMap<(usize, usize), usize> = HashMap::new();
+            brain
+                .connection_triplets()
+                .map(|(f, t, w)| {
+                    let k = seen.entry((f, t)).or_default();
+                    *k += 1;
+                    ((f, t, *k), w)
+                })
+                .collect()
+        };
+        let (ea, eb) = (edges(&a), edges(&b));
+
+        let mut sup = Supervisor::new(make_test_brain());
+        let merged = edges(&sup.cross_consolidate(&a, &b));
+        assert_eq!(edges(&a), ea, "inputs are not modified");
+        assert_eq!(edges(&b), eb);
+        let mut differing = 0;
+        for (key, &m) in &merged {
+            let wa = ea.get(key).copied().unwrap_or(0.0);
+            let wb = eb.get(key).copied().unwrap_or(0.0);
+            assert!(m >= wa.min(wb) - 1e-6 && m <= wa.max(wb) + 1e-6);
+            // `a` has learned more, so the mean leans towards it.
+            assert!((m - wa).abs() <= (m - wb).abs() + 1e-6);
+            differing += usize::from((wa - wb).abs() > 1e-4);
+        }
+        assert!(differing > 0);
+
+        sup.policy.merge_strategy = MergeStrategy::TopK { k: 2 };
+        let top = sup.cross_consolidate(&a, &b);
+        assert!(top.unit_degree_histogram().len() <= 3);
+
+        sup.policy.merge_strategy = MergeStrategy::UnionStrong { threshold: 10.0 };
+        assert_eq!(sup.cross_consolidate(&a, &b).connection_count(), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn child_config_overrides_missing_fields_inherit() {
+        // Records written before full field coverage only carry the four rates.
+        let old = "noise_amp = 0.05\nnoise_phase = 0.02\nhebb_rate = 0.2\nforget_rate = 0.001\n";
+        let o: ChildConfigOverrides = toml::from_str(old).unwrap();
+        assert_eq!(o.hebb_rate, Some(0.2));
+        assert_eq!(o.global_inhibition, None);
+        assert_eq!(o.imprint_rate, None);
+    }
+
     #[test]
     fn child_config_overrides_default() {
         let overrides = ChildConfigOverrides::default();
 
         // Default overrides should have reasonable exploration values
-        assert!(overrides.noise_amp >= 0.0);
-        assert!(overrides.hebb_rate >= 0.0);
+        assert!(overrides.noise_amp.is_some_and(|v| v >= 0.0));
+        assert!(overrides.hebb_rate.is_some_and(|v| v >= 0.0));
     }
 }
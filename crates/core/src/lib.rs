@@ -41,6 +41,13 @@
 //! braine = { version = "0.1", default-features = false }
 //! ```
 //!
+//! [`substrate::Brain`] still needs a global allocator (`alloc`). For targets
+//! without one, [`causality::CausalGraphFixed`] provides array-backed causal
+//! memory behind the same [`causality::CausalGraph`] trait as
+//! [`causality::CausalMemory`]. It is a standalone graph: `Brain` does not
+//! use it yet, so a full `Brain` without `alloc` is still open. Build it in a
+//! `static`, since it is too large for most stacks.
+//!
 //! ## Modules
 //!
 //! - [`substrate`]: Core brain implementation
@@ -86,9 +93,12 @@ pub mod observer;
 pub mod prelude {
     pub use crate::causality::{CausalStats, SymbolId};
     pub use crate::substrate::{
-        ActionPolicy, Amplitude, Brain, BrainConfig, Diagnostics, ExecutionTier, Neuromodulator,
-        OwnedStimulus, Phase, Stimulus, UnitId, Weight,
+        ActionPolicy, Amplitude, Brain, BrainConfig, Diagnostics, DreamSource, ExecutionTier,
+        GroupType, NeurogenesisPolicy, Neuromodulator, OwnedStimulus, Phase, SensorEnsureResult,
+        Stimulus, UnitId, Weight,
     };
     #[cfg(feature = "std")]
-    pub use crate::supervisor::{ChildConfigOverrides, ChildSpec, ConsolidationPolicy, Supervisor};
+    pub use crate::supervisor::{
+        ChildConfigOverrides, ChildSpec, ConsolidationPolicy, MergeStrategy, Supervisor,
+    };
 }
@@ -232,10 +232,11 @@ fn run_autonomy_demo() {
         },
         999,
         ChildConfigOverrides {
-            noise_amp: 0.04,
-            noise_phase: 0.02,
-            hebb_rate: 0.15,
-            forget_rate: 0.0012,
+            noise_amp: Some(0.04),
+            noise_phase: Some(0.02),
+            hebb_rate: Some(0.15),
+            forget_rate: Some(0.0012),
+            ..ChildConfigOverrides::inherit()
         },
     );
 
@@ -347,10 +348,11 @@ fn run_spawn_demo() {
         },
         100,
         ChildConfigOverrides {
-            noise_amp: 0.03,
-            noise_phase: 0.015,
-            hebb_rate: 0.16,
-            forget_rate: 0.0012,
+            noise_amp: Some(0.03),
+            noise_phase: Some(0.015),
+            hebb_rate: Some(0.16),
+            forget_rate: Some(0.0012),
+            ..ChildConfigOverrides::inherit()
         },
     );
     sup.spawn_child(
@@ -360,10 +362,11 @@ fn run_spawn_demo() {
         },
         200,
         ChildConfigOverrides {
-            noise_amp: 0.02,
-            noise_phase: 0.010,
-            hebb_rate: 0.11,
-            forget_rate: 0.0010,
+            noise_amp: Some(0.02),
+            noise_phase: Some(0.010),
+            hebb_rate: Some(0.11),
+            forget_rate: Some(0.0010),
+            ..ChildConfigOverrides::inherit()
         },
     );
     sup.spawn_child(
@@ -373,10 +376,11 @@ fn run_spawn_demo() {
         },
         300,
         ChildConfigOverrides {
-            noise_amp: 0.045,
-            noise_phase: 0.02,
-            hebb_rate: 0.13,
-            forget_rate: 0.0014,
+            noise_amp: Some(0.045),
+            noise_phase: Some(0.02),
+            hebb_rate: Some(0.13),
+            forget_rate: Some(0.0014),
+            ..ChildConfigOverrides::inherit()
         },
     );
 
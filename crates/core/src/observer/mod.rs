@@ -1,3 +1,7 @@
+pub use crate::causality::{CausalEvent, CausalEventLog};
+
+use std::collections::VecDeque;
+
 use crate::causality::SymbolId;
 use crate::substrate::{Brain, Diagnostics};
 use crate::supervisor::Supervisor;
@@ -52,6 +56,19 @@ impl<'a> BrainAdapter<'a> {
             last_committed_symbols: ids_to_names(self.brain, self.brain.last_committed_symbols()),
         }
     }
+
+    /// The most recent `window` per-unit amplitude frames (outer = time, oldest first;
+    /// inner = unit).
+    ///
+    /// Empty unless telemetry is enabled. Returns fewer frames if fewer were recorded.
+    pub fn unit_activation_history(&self, window: usize) -> Vec<Vec<f32>> {
+        let stored = self.brain.activation_history().count();
+        self.brain
+            .activation_history()
+            .skip(stored.saturating_sub(window))
+            .map(<[f32]>::to_vec)
+            .collect()
+    }
 }
 
 fn ids_to_names(brain: &Brain, ids: &[SymbolId]) -> Vec<String> {
@@ -60,6 +77,182 @@ fn ids_to_names(brain: &Brain, ids: &[SymbolId]) -> Vec<String> {
         .collect()
 }
 
+/// Tracks how much the weights move between samples.
+///
+/// Call [`PlasticityMonitor::observe`] once per step; every `interval` calls it
+/// takes a [`Brain::snapshot_weights`] and folds `||W_t - W_{t-1}||_1 / num_weights`
+/// into an EMA. Samples across a topology change (pruning or growth altered the
+/// edge count) only re-baseline.
+#[derive(Debug, Clone)]
+pub struct PlasticityMonitor {
+    interval: u32,
+    alpha: f32,
+    calls: u32,
+    prev: Option<Vec<f32>>,
+    ema: f32,
+    samples: u32,
+}
+
+impl PlasticityMonitor {
+    /// Sample every `interval` steps (min 1) with EMA factor `alpha` in `(0, 1]`.
+    pub fn new(interval: u32, alpha: f32) -> Self {
+        Self {
+            interval: interval.max(1),
+            alpha: alpha.clamp(f32::EPSILON, 1.0),
+            calls: 0,
+            prev: None,
+            ema: 0.0,
+            samples: 0,
+        }
+    }
+
+    /// Count one step, sampling the brain's weights if the interval is due.
+    pub fn observe(&mut self, brain: &Brain) {
+        self.calls += 1;
+        if self.calls < self.interval {
+            return;
+        }
+        self.calls = 0;
+
+        let weights = brain.snapshot_weights();
+        if let Some(prev) = &self.prev {
+            if prev.len() == weights.len() && !weights.is_empty() {
+                let l1: f32 = prev.iter().zip(&weights).map(|(a, b)| (a - b).abs()).sum();
+                let rate = l1 / weights.len() as f32;
+                self.ema += self.alpha * (rate - self.ema);
+                self.samples = self.samples.saturating_add(1);
+            }
+        }
+        self.prev = Some(weights);
+    }
+
+    /// Current EMA of per-weight L1 change between samples.
+    pub fn current_plasticity(&self) -> f32 {
+        self.ema
+    }
+
+    /// True once at least one weight delta was measured and plasticity is below `threshold`.
+    pub fn is_converged(&self, threshold: f32) -> bool {
+        self.samples > 0 && self.ema < threshold
+    }
+}
+
+impl Default for PlasticityMonitor {
+    fn default() -> Self {
+        Self::new(10, 0.1)
+    }
+}
+
+/// Rolling unit-activity heatmap: x = time (oldest left), y = unit.
+///
+/// Each [`ActivityHeatmap::observe`] adds one column of amplitudes. When the
+/// brain has more units than rows, each row shows the mean amplitude of a
+/// contiguous block of units. [`ActivityHeatmap::render_rgba`] produces a
+/// row-major `width * height * 4` RGBA buffer for uploading as a texture;
+/// columns not yet filled are transparent.
+#[derive(Debug, Clone)]
+pub struct ActivityHeatmap {
+    width: usize,
+    height: usize,
+    frames: VecDeque<Vec<f32>>,
+}
+
+impl ActivityHeatmap {
+    /// Maximum number of frames (columns) kept.
+    pub const MAX_WIDTH: usize = 256;
+    /// Maximum number of rows.
+    pub const MAX_HEIGHT: usize = 128;
+
+    /// A heatmap of `width` frames by `height` rows, clamped to
+    /// `1..=MAX_WIDTH` and `1..=MAX_HEIGHT`.
+    pub fn new(width: usize, height: usize) -> Self {
+        let width = width.clamp(1, Self::MAX_WIDTH);
+        Self {
+            width,
+            height: height.clamp(1, Self::MAX_HEIGHT),
+            frames: VecDeque::with_capacity(width),
+        }
+    }
+
+    /// A heatmap with one row per unit of `brain` (up to `MAX_HEIGHT`).
+    pub fn for_brain(brain: &Brain, width: usize) -> Self {
+        Self::new(width, brain.unit_count())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of frames recorded so far (at most `width`).
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Record the brain's current unit amplitudes as the newest column.
+    pub fn observe(&mut self, brain: &Brain) {
+        self.push_frame(&brain.unit_amplitudes());
+    }
+
+    /// Record one frame of per-unit amplitudes, dropping the oldest frame
+    /// once `width` frames are held.
+    pub fn push_frame(&mut self, amps: &[f32]) {
+        let n = amps.len();
+        let rows = (0..self.height)
+            .map(|r| {
+                let start = r * n / self.height;
+                let end = ((r + 1) * n / self.height).max(start + 1).min(n);
+                if start >= end {
+                    return 0.0;
+                }
+                amps[start..end].iter().sum::<f32>() / (end - start) as f32
+            })
+            .collect();
+
+        if self.frames.len() == self.width {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(rows);
+    }
+
+    /// Render to RGBA, newest frame in the rightmost column.
+    ///
+    /// Positive amplitudes go black → red → yellow, negative ones black → blue
+    /// (full scale at `|amp| = 2`, the substrate's clamp).
+    pub fn render_rgba(&self) -> Vec<u8> {
+        let mut out = vec![0u8; self.width * self.height * 4];
+        let offset = self.width - self.frames.len();
+        for (i, frame) in self.frames.iter().enumerate() {
+            let x = offset + i;
+            for (y, &amp) in frame.iter().enumerate() {
+                let px = (y * self.width + x) * 4;
+                out[px..px + 4].copy_from_slice(&heat_rgba(amp));
+            }
+        }
+        out
+    }
+}
+
+fn heat_rgba(amp: f32) -> [u8; 4] {
+    let v = (amp / 2.0).clamp(-1.0, 1.0);
+    if v >= 0.0 {
+        [(255.0 * v) as u8, (255.0 * v * v) as u8, 0, 255]
+    } else {
+        [0, 0, (255.0 * -v) as u8, 255]
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SupervisorSnapshot {
     pub parent: BrainSnapshot,
@@ -14,7 +14,7 @@
 //! nuance between `apply_stimulus` and `apply_stimulus_inference`.
 
 #[cfg(feature = "braine")]
-use braine::substrate::{Brain, Stimulus};
+use braine::substrate::Brain;
 
 /// Apply a **sensor channel** (input-only).
 ///
@@ -25,7 +25,7 @@ use braine::substrate::{Brain, Stimulus};
 #[cfg(feature = "braine")]
 #[inline]
 pub fn apply_sensor_channel(brain: &mut Brain, name: &str, strength: f32) {
-    brain.apply_stimulus_inference(Stimulus::new(name, strength));
+    brain.apply_stimulus_inference((name, strength).into());
 }
 
 /// Apply a **task symbol** (symbol + potential imprinting).
@@ -37,7 +37,84 @@ pub fn apply_sensor_channel(brain: &mut Brain, name: &str, strength: f32) {
 #[cfg(feature = "braine")]
 #[inline]
 pub fn apply_task_symbol(brain: &mut Brain, name: &str, strength: f32) {
-    brain.apply_stimulus(Stimulus::new(name, strength));
+    brain.apply_stimulus((name, strength).into());
+}
+
+/// Population-code a scalar `value` onto `n_bins` sensor channels
+/// `{sensor_prefix}_00`, `{sensor_prefix}_01`, ... (input-only, like
+/// [`apply_sensor_channel`]).
+///
+/// Uses the same Gaussian bins as SpotXY's position sensors; see
+/// [`value_bin_activations`]. The sensors must already be defined.
+#[cfg(feature = "braine")]
+pub fn apply_value_stimulus(brain: &mut Brain, value: f32, n_bins: usize, sensor_prefix: &str) {
+    for (i, a) in value_bin_activations(value, n_bins).into_iter().enumerate() {
+        apply_sensor_channel(brain, &format!("{sensor_prefix}_{i:02}"), a);
+    }
+}
+
+/// Sensor name of grid cell (`row`, `col`): `{prefix}_{row:02}_{col:02}`.
+pub fn grid_sensor_name(prefix: &str, row: usize, col: usize) -> String {
+    format!("{prefix}_{row:02}_{col:02}")
+}
+
+/// Make sure every cell of a `rows` x `cols` grid has a sensor group of at
+/// least `min_width` units (see [`grid_sensor_name`]); existing narrower
+/// groups are grown.
+#[cfg(feature = "braine")]
+pub fn ensure_grid_sensors(
+    brain: &mut Brain,
+    rows: usize,
+    cols: usize,
+    min_width: usize,
+    prefix: &str,
+) {
+    for row in 0..rows {
+        for col in 0..cols {
+            brain.ensure_sensor_min_width(&grid_sensor_name(prefix, row, col), min_width);
+        }
+    }
+}
+
+/// Apply a 2D grid of amplitudes as sensor channels, one per cell
+/// (input-only, like [`apply_sensor_channel`]).
+///
+/// `grid[row][col]` drives `{prefix}_{row:02}_{col:02}`; rows may differ in
+/// length. Zero cells are skipped. The sensors must already be defined, e.g.
+/// with [`ensure_grid_sensors`].
+#[cfg(feature = "braine")]
+pub fn apply_grid_stimulus<R: AsRef<[f32]>>(brain: &mut Brain, grid: &[R], prefix: &str) {
+    for (row, cells) in grid.iter().enumerate() {
+        for (col, &a) in cells.as_ref().iter().enumerate() {
+            if a != 0.0 {
+                apply_sensor_channel(brain, &grid_sensor_name(prefix, row, col), a);
+            }
+        }
+    }
+}
+
+/// Activations of `n_bins` (at least 2) Gaussian bins for `value` in `[-1, 1]`.
+///
+/// Bin `i` is centred at `-1 + 2i/(n_bins-1)` with a width of one bin spacing;
+/// activations sum to 1 so the stimulus energy does not depend on `n_bins`.
+pub fn value_bin_activations(value: f32, n_bins: usize) -> Vec<f32> {
+    let n = n_bins.max(2);
+    let denom = (n - 1) as f32;
+    let sigma = 2.0 / denom;
+    let inv_2s2 = 1.0 / (2.0 * sigma * sigma);
+    let v = value.clamp(-1.0, 1.0);
+
+    let mut out: Vec<f32> = (0..n)
+        .map(|i| {
+            let d = v - (-1.0 + 2.0 * i as f32 / denom);
+            (-d * d * inv_2s2).exp()
+        })
+        .collect();
+    let sum: f32 = out.iter().sum();
+    for a in &mut out {
+        *a /= sum;
+    }
+    out
 }
 
 #[cfg(all(test, feature = "braine"))]
@@ -64,6 +141,54 @@ mod tests {
         assert_eq!(before, after);
     }
 
+    #[test]
+    fn value_bins_peak_at_the_nearest_centre() {
+        let acts = value_bin_activations(0.5, 5);
+        assert_eq!(acts.len(), 5);
+        assert!((acts.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        let argmax = (0..5).max_by(|&a, &b| acts[a].total_cmp(&acts[b])).unwrap();
+        assert_eq!(argmax, 3);
+        assert!((acts[2] - acts[4]).abs() < 1e-5);
+
+        let mut brain = Brain::new(BrainConfig {
+            unit_count: 32,
+            connectivity_per_unit: 4,
+            seed: Some(3),
+            ..Default::default()
+        });
+        for i in 0..5 {
+            brain.define_sensor(&format!("v_{i:02}"), 2);
+        }
+        apply_value_stimulus(&mut brain, 0.5, 5, "v");
+        brain.step();
+    }
+
+    #[test]
+    fn grid_stimulus_drives_only_active_cells() {
+        let mut brain = Brain::new(BrainConfig {
+            unit_count: 64,
+            connectivity_per_unit: 4,
+            seed: Some(4),
+            ..Default::default()
+        });
+        ensure_grid_sensors(&mut brain, 2, 3, 2, "g");
+        assert_eq!(brain.sensor_units("g_01_02").map(|u| u.len()), Some(2));
+        assert!(brain.sensor_units("g_02_00").is_none());
+
+        let symbols_before = brain.causal_stats().base_symbols;
+        apply_grid_stimulus(&mut brain, &[[0.0, 0.0, 0.0], [0.0, 1.0, 0.0]], "g");
+        brain.step();
+        brain.commit_observation();
+        assert_eq!(brain.causal_stats().base_symbols, symbols_before);
+
+        let mean_amp = |brain: &Brain, name: &str| {
+            let units = brain.sensor_units(name).unwrap();
+            let amps = brain.unit_amplitudes();
+            units.iter().map(|&u| amps[u]).sum::<f32>() / units.len() as f32
+        };
+        assert!(mean_amp(&brain, "g_01_01") > mean_amp(&brain, "g_00_00"));
+    }
+
     #[test]
     fn task_symbol_creates_causal_symbols() {
         let mut brain = Brain::new(BrainConfig {
@@ -0,0 +1,248 @@
+//! Conditional discrimination (go/no-go with a context cue).
+//!
+//! Each trial presents one context cue (`ctx_A` or `ctx_B`) together with one
+//! stimulus (`stim_go` or `stim_nogo`). The correct answer depends on the
+//! combination, not on either cue alone:
+//!
+//! | context | stimulus    | correct   |
+//! |---------|-------------|-----------|
+//! | `ctx_A` | `stim_go`   | `respond` |
+//! | `ctx_A` | `stim_nogo` | `inhibit` |
+//! | `ctx_B` | `stim_go`   | `inhibit` |
+//! | `ctx_B` | `stim_nogo` | `respond` |
+//!
+//! The active context flips every `switch_every_trials` trials (like the rule
+//! flip in [`SpotReversalGame`](crate::spot_reversal::SpotReversalGame)), so
+//! the task can only be solved through the conjunctive context key, e.g.
+//! `pair::ctx_A::stim_go::respond`.
+
+use crate::stats::GameStats;
+use crate::time::{Duration, Instant};
+
+#[cfg(feature = "braine")]
+use braine::substrate::{Brain, Stimulus};
+
+pub const CONTEXT_A: &str = "ctx_A";
+pub const CONTEXT_B: &str = "ctx_B";
+pub const STIM_GO: &str = "stim_go";
+pub const STIM_NOGO: &str = "stim_nogo";
+pub const RESPOND_ACTION: &str = "respond";
+pub const INHIBIT_ACTION: &str = "inhibit";
+
+#[derive(Debug)]
+pub struct ConditionalDiscriminationGame {
+    pub context_is_a: bool,
+    pub stim_is_go: bool,
+    pub switch_every_trials: u32,
+    /// Trials completed since the last context switch.
+    pub trials_in_context: u32,
+    pub context_switches: u32,
+
+    pub trial_frame: u32,
+    pub response_made: bool,
+    pub last_action: Option<String>,
+    pub stats: GameStats,
+
+    action_names: Vec<String>,
+    stimulus_key: String,
+    rng_seed: u64,
+    trial_started_at: Instant,
+}
+
+impl Default for ConditionalDiscriminationGame {
+    fn default() -> Self {
+        Self::new(50)
+    }
+}
+
+impl ConditionalDiscriminationGame {
+    pub const MIN_SWITCH_EVERY: u32 = 1;
+    pub const MAX_SWITCH_EVERY: u32 = 1000;
+
+    pub fn new(switch_every_trials: u32) -> Self {
+        let mut g = Self {
+            context_is_a: true,
+            stim_is_go: true,
+            switch_every_trials: switch_every_trials
+                .clamp(Self::MIN_SWITCH_EVERY, Self::MAX_SWITCH_EVERY),
+            trials_in_context: 0,
+            context_switches: 0,
+            trial_frame: 0,
+            response_made: false,
+            last_action: None,
+            stats: GameStats::new(),
+            action_names: vec![RESPOND_ACTION.to_string(), INHIBIT_ACTION.to_string()],
+            stimulus_key: String::new(),
+            rng_seed: 0xC0D1_D15Cu64,
+            trial_started_at: Instant::now(),
+        };
+        g.new_trial();
+        g
+    }
+
+    pub fn set_param(&mut self, key: &str, value: f32) -> Result<(), String> {
+        match key {
+            "switch_every" => {
+                self.switch_every_trials = (value.round().max(0.0) as u32)
+                    .clamp(Self::MIN_SWITCH_EVERY, Self::MAX_SWITCH_EVERY);
+                self.trials_in_context = 0;
+                Ok(())
+            }
+            _ => Err(format!(
+                "Unknown conditional discrimination param '{key}'. Use switch_every ({}..{})",
+                Self::MIN_SWITCH_EVERY,
+                Self::MAX_SWITCH_EVERY
+            )),
+        }
+    }
+
+    fn new_trial(&mut self) {
+        self.trial_frame = 0;
+        self.response_made = false;
+        self.last_action = None;
+        self.trial_started_at = Instant::now();
+
+        self.rng_seed = self
+            .rng_seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+        let u = (self.rng_seed >> 33) as u32;
+        self.stim_is_go = (u & 1) == 0;
+
+        self.stimulus_key = format!("{}::{}", self.context_name(), self.cue_name());
+    }
+
+    pub fn update_timing(&mut self, trial_period_ms: u32) {
+        let trial_period_ms = trial_period_ms.clamp(10, 60_000);
+        let trial_period = Duration::from_millis(trial_period_ms as u64);
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+
+        if elapsed >= trial_period {
+            self.new_trial();
+        }
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+        self.trial_frame = elapsed.as_millis().min(u32::MAX as u128) as u32;
+    }
+
+    pub fn context_name(&self) -> &'static str {
+        if self.context_is_a {
+            CONTEXT_A
+        } else {
+            CONTEXT_B
+        }
+    }
+
+    pub fn cue_name(&self) -> &'static str {
+        if self.stim_is_go {
+            STIM_GO
+        } else {
+            STIM_NOGO
+        }
+    }
+
+    /// All sensors this task drives (contexts first, then stimuli).
+    pub fn sensor_names() -> [&'static str; 4] {
+        [CONTEXT_A, CONTEXT_B, STIM_GO, STIM_NOGO]
+    }
+
+    pub fn stimulus_name(&self) -> &'static str {
+        self.cue_name()
+    }
+
+    /// Conjunctive context key, e.g. `ctx_A::stim_go`.
+    pub fn stimulus_key(&self) -> &str {
+        &self.stimulus_key
+    }
+
+    pub fn allowed_actions(&self) -> &[String] {
+        &self.action_names
+    }
+
+    pub fn correct_action(&self) -> &'static str {
+        if self.context_is_a == self.stim_is_go {
+            RESPOND_ACTION
+        } else {
+            INHIBIT_ACTION
+        }
+    }
+
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli(&self, brain: &mut Brain) {
+        brain.apply_stimulus(Stimulus::new(self.context_name(), 1.0));
+        brain.apply_stimulus(Stimulus::new(self.cue_name(), 1.0));
+    }
+
+    /// Apply stimuli in **inference-only** mode (no imprinting between decisions).
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli_inference(&self, brain: &mut Brain) {
+        brain.apply_stimulus_inference(Stimulus::new(self.context_name(), 1.0));
+        brain.apply_stimulus_inference(Stimulus::new(self.cue_name(), 1.0));
+    }
+
+    pub fn score_action(&mut self, action: &str) -> Option<(f32, bool)> {
+        if self.response_made {
+            return None;
+        }
+
+        let is_correct = action == self.correct_action();
+        let reward = if is_correct { 1.0 } else { -1.0 };
+
+        self.response_made = true;
+        self.last_action = Some(action.to_string());
+        self.stats.record_trial(is_correct);
+
+        self.trials_in_context += 1;
+        if self.trials_in_context >= self.switch_every_trials {
+            self.trials_in_context = 0;
+            self.context_is_a = !self.context_is_a;
+            self.context_switches += 1;
+        }
+
+        Some((reward, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_action_depends_on_context_and_stimulus() {
+        let mut g = ConditionalDiscriminationGame::new(10);
+        for (ctx_a, go, expect) in [
+            (true, true, RESPOND_ACTION),
+            (true, false, INHIBIT_ACTION),
+            (false, true, INHIBIT_ACTION),
+            (false, false, RESPOND_ACTION),
+        ] {
+            g.context_is_a = ctx_a;
+            g.stim_is_go = go;
+            assert_eq!(g.correct_action(), expect);
+        }
+    }
+
+    #[test]
+    fn context_flips_every_n_trials_and_key_is_conjunctive() {
+        let mut g = ConditionalDiscriminationGame::new(3);
+        let mut contexts = Vec::new();
+        for _ in 0..9 {
+            contexts.push(g.context_is_a);
+            assert_eq!(
+                g.stimulus_key(),
+                format!("{}::{}", g.context_name(), g.cue_name())
+            );
+            let _ = g.score_action(g.correct_action());
+            g.new_trial();
+        }
+        assert_eq!(
+            contexts,
+            [true, true, true, false, false, false, true, true, true]
+        );
+        assert_eq!(g.context_switches, 3);
+        assert_eq!(g.stats.trials, 9);
+    }
+}
@@ -0,0 +1,301 @@
+//! Continuous-armed bandit with a smooth, drifting reward function.
+//!
+//! The action space is the interval `[-1, 1]`, discretized into `n_bins` arms
+//! (`cbandit_arm_XX`; arm `i` stands for its bin centre). Pulling the arm at
+//! value `v` pays `2 * exp(-(v - peak)^2 / (2 * sigma^2)) - 1`, a Gaussian bump
+//! in `[-1, 1]`. Every `shift_every_outcomes` scored trials the peak jumps to a
+//! new location, so the agent has to track a moving optimum instead of
+//! memorizing one arm.
+//!
+//! The last pulled value is fed back on population-coded bin sensors
+//! (`cbandit_val_XX`, see `brain_io::apply_value_stimulus`), so neighbouring
+//! arms share credit the way SpotXY's position bins do.
+
+use crate::stats::GameStats;
+use crate::time::{Duration, Instant};
+
+#[cfg(feature = "braine")]
+use braine::substrate::Brain;
+
+#[cfg(feature = "braine")]
+use crate::brain_io;
+
+/// Sensor prefix of the value bins (`cbandit_val_00`, `cbandit_val_01`, ...).
+pub const VALUE_SENSOR_PREFIX: &str = "cbandit_val";
+
+#[derive(Debug)]
+pub struct ContinuousBanditGame {
+    pub trial_frame: u32,
+    pub response_made: bool,
+    pub last_action: Option<String>,
+    pub stats: GameStats,
+    /// Number of times the reward peak has moved.
+    pub peak_shifts: u32,
+
+    n_bins: usize,
+    sigma: f32,
+    shift_every_outcomes: u32,
+    peak: f32,
+    outcomes_since_shift: u32,
+    last_value: Option<f32>,
+    action_names: Vec<String>,
+    sensor_names: Vec<String>,
+
+    rng_seed: u64,
+    trial_started_at: Instant,
+}
+
+impl Default for ContinuousBanditGame {
+    fn default() -> Self {
+        Self::new(16, 0.25, 100)
+    }
+}
+
+impl ContinuousBanditGame {
+    pub const MIN_BINS: usize = 2;
+    pub const MAX_BINS: usize = 64;
+    pub const MIN_SIGMA: f32 = 0.05;
+    pub const MAX_SIGMA: f32 = 1.0;
+
+    /// `shift_every_outcomes == 0` keeps the peak fixed.
+    pub fn new(n_bins: usize, sigma: f32, shift_every_outcomes: u32) -> Self {
+        let now = Instant::now();
+        let mut g = Self {
+            trial_frame: 0,
+            response_made: false,
+            last_action: None,
+            stats: GameStats::new(),
+            peak_shifts: 0,
+            n_bins: 0,
+            sigma: sigma.clamp(Self::MIN_SIGMA, Self::MAX_SIGMA),
+            shift_every_outcomes,
+            peak: 0.0,
+            outcomes_since_shift: 0,
+            last_value: None,
+            action_names: Vec::new(),
+            sensor_names: Vec::new(),
+            rng_seed: 0xCB4D_1707u64,
+            trial_started_at: now,
+        };
+        g.set_n_bins(n_bins);
+        g.peak = g.sample_uniform(-1.0, 1.0);
+        g.new_trial();
+        g
+    }
+
+    /// Rebuild the arm/sensor names. Resets stats, since accuracy at different
+    /// resolutions is not comparable.
+    pub fn set_n_bins(&mut self, n_bins: usize) {
+        let n = n_bins.clamp(Self::MIN_BINS, Self::MAX_BINS);
+        self.n_bins = n;
+        self.action_names = (0..n).map(|i| format!("cbandit_arm_{i:02}")).collect();
+        self.sensor_names = (0..n)
+            .map(|i| format!("{VALUE_SENSOR_PREFIX}_{i:02}"))
+            .collect();
+        self.stats = GameStats::new();
+        self.last_value = None;
+        self.new_trial();
+    }
+
+    pub fn set_param(&mut self, key: &str, value: f32) -> Result<(), String> {
+        match key {
+            "n_bins" => self.set_n_bins(value.round().max(0.0) as usize),
+            "sigma" => self.sigma = value.clamp(Self::MIN_SIGMA, Self::MAX_SIGMA),
+            "shift_every_outcomes" => {
+                self.shift_every_outcomes = value.round().max(0.0) as u32;
+                self.outcomes_since_shift = 0;
+            }
+            _ => {
+                return Err(format!(
+                    "Unknown continuous-bandit param '{key}'. Use n_bins ({}..{}) | sigma ({}..{}) | shift_every_outcomes (0 = never)",
+                    Self::MIN_BINS,
+                    Self::MAX_BINS,
+                    Self::MIN_SIGMA,
+                    Self::MAX_SIGMA
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    pub fn n_bins(&self) -> usize {
+        self.n_bins
+    }
+
+    pub fn sigma(&self) -> f32 {
+        self.sigma
+    }
+
+    pub fn shift_every_outcomes(&self) -> u32 {
+        self.shift_every_outcomes
+    }
+
+    /// Current location of the reward peak in `[-1, 1]`.
+    pub fn peak(&self) -> f32 {
+        self.peak
+    }
+
+    /// Value of the most recently pulled arm, if any.
+    pub fn last_value(&self) -> Option<f32> {
+        self.last_value
+    }
+
+    pub fn allowed_actions(&self) -> &[String] {
+        &self.action_names
+    }
+
+    pub fn value_sensor_names(&self) -> &[String] {
+        &self.sensor_names
+    }
+
+    pub fn stimulus_name(&self) -> &'static str {
+        // A constant context sensor to attach meaning/credit assignment.
+        "cbandit"
+    }
+
+    /// Centre of arm `i`'s bin in `[-1, 1]`.
+    pub fn arm_value(&self, i: usize) -> f32 {
+        -1.0 + 2.0 * (i.min(self.n_bins - 1) as f32) / (self.n_bins - 1) as f32
+    }
+
+    /// Reward for pulling an arm at `value`, in `[-1, 1]`.
+    pub fn reward_at(&self, value: f32) -> f32 {
+        let d = value - self.peak;
+        2.0 * (-d * d / (2.0 * self.sigma * self.sigma)).exp() - 1.0
+    }
+
+    /// The arm whose centre is closest to the peak.
+    pub fn best_action(&self) -> &str {
+        let t = (self.peak + 1.0) * 0.5 * (self.n_bins - 1) as f32;
+        &self.action_names[(t.round() as usize).min(self.n_bins - 1)]
+    }
+
+    pub fn update_timing(&mut self, trial_period_ms: u32) {
+        let trial_period_ms = trial_period_ms.clamp(10, 60_000);
+        let trial_period = Duration::from_millis(trial_period_ms as u64);
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+        if elapsed >= trial_period {
+            self.new_trial();
+        }
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+        self.trial_frame = elapsed.as_millis().min(u32::MAX as u128) as u32;
+    }
+
+    /// Feed the last pulled value back on the value bins (nothing before the first pull).
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli(&self, brain: &mut Brain) {
+        if let Some(v) = self.last_value {
+            brain_io::apply_value_stimulus(brain, v, self.n_bins, VALUE_SENSOR_PREFIX);
+        }
+    }
+
+    /// Score exactly one response per trial.
+    ///
+    /// A pull counts as correct when it earns a positive reward, i.e. lands
+    /// within about `1.18 * sigma` of the peak.
+    pub fn score_action(&mut self, action: &str) -> Option<(f32, bool)> {
+        if self.response_made {
+            return None;
+        }
+        let arm = self.action_names.iter().position(|a| a == action)?;
+
+        let value = self.arm_value(arm);
+        let reward = self.reward_at(value);
+
+        self.response_made = true;
+        self.last_action = Some(action.to_string());
+        self.last_value = Some(value);
+        self.stats.record_trial(reward > 0.0);
+
+        self.outcomes_since_shift += 1;
+        if self.shift_every_outcomes > 0 && self.outcomes_since_shift >= self.shift_every_outcomes {
+            self.shift_peak();
+        }
+
+        Some((reward, true))
+    }
+
+    /// Move the peak to a new location at least `2 * sigma` away when possible.
+    fn shift_peak(&mut self) {
+        let old = self.peak;
+        for _ in 0..8 {
+            self.peak = self.sample_uniform(-1.0, 1.0);
+            if (self.peak - old).abs() >= 2.0 * self.sigma {
+                break;
+            }
+        }
+        self.outcomes_since_shift = 0;
+        self.peak_shifts += 1;
+    }
+
+    fn new_trial(&mut self) {
+        self.trial_frame = 0;
+        self.response_made = false;
+        self.last_action = None;
+        self.trial_started_at = Instant::now();
+    }
+
+    fn sample_uniform(&mut self, lo: f32, hi: f32) -> f32 {
+        let u = self.rng_next_f32();
+        lo + (hi - lo) * u
+    }
+
+    fn rng_next_u32(&mut self) -> u32 {
+        self.rng_seed = self
+            .rng_seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+        (self.rng_seed >> 11) as u32
+    }
+
+    fn rng_next_f32(&mut self) -> f32 {
+        let u = self.rng_next_u32();
+        let mantissa = u >> 8; // 24 bits
+        (mantissa as f32) / ((1u32 << 24) as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reward_is_a_bump_around_the_peak() {
+        let g = ContinuousBanditGame::new(16, 0.25, 0);
+        let p = g.peak();
+        assert!((g.reward_at(p) - 1.0).abs() < 1e-6);
+        assert!((g.reward_at(p + 0.2) - g.reward_at(p - 0.2)).abs() < 1e-6);
+        assert!(g.reward_at(p + 0.1) > g.reward_at(p + 0.3));
+        assert!(g.reward_at(p + 3.0) > -1.0 - 1e-6);
+
+        let best = g.best_action().to_string();
+        let arm = g.allowed_actions().iter().position(|a| *a == best).unwrap();
+        let bin = 2.0 / 15.0;
+        assert!((g.arm_value(arm) - p).abs() <= bin / 2.0 + 1e-6);
+    }
+
+    #[test]
+    fn peak_shifts_every_n_outcomes() {
+        let mut g = ContinuousBanditGame::new(8, 0.25, 5);
+        let mut peaks = vec![g.peak()];
+        for t in 1..=15 {
+            let best = g.best_action().to_string();
+            let (reward, done) = g.score_action(&best).unwrap();
+            assert!(done && reward > 0.0);
+            assert_eq!(g.score_action(&best), None);
+            assert_eq!(g.last_action.as_deref(), Some(best.as_str()));
+            if t % 5 == 0 {
+                peaks.push(g.peak());
+            }
+            g.response_made = false;
+        }
+        assert_eq!(g.peak_shifts, 3);
+        assert!(peaks.windows(2).all(|w| w[0] != w[1]));
+        assert_eq!((g.stats.trials, g.stats.correct), (15, 15));
+        assert_eq!(g.score_action("left"), None);
+    }
+}
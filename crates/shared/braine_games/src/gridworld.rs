@@ -0,0 +1,537 @@
+//! GridWorld: tabular navigation on an N×N grid with obstacle cells.
+//!
+//! The agent starts at a random free cell and must reach a fixed goal cell
+//! using `up/right/down/left`. Position is one-hot encoded on two sensor axes
+//! (`gridworld_pos_x_NN` for the column, `gridworld_pos_y_NN` for the row),
+//! mirroring SpotXY's `pos_x_NN` / `pos_y_NN` naming.
+
+use crate::maze::{MazeAction, MazeEvent};
+use crate::stats::GameStats;
+use crate::time::{Duration, Instant};
+
+use std::collections::VecDeque;
+
+#[cfg(feature = "braine")]
+use braine::substrate::Brain;
+
+#[cfg(feature = "braine")]
+use crate::brain_io;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GridWorldParams {
+    /// Grid dimension (N×N cells).
+    pub grid_size: u32,
+    /// Fraction of cells turned into obstacles in `[0, 0.5]`.
+    pub wall_density: f32,
+    /// Episode length limit; the episode fails when it is reached.
+    pub max_steps_per_episode: u32,
+
+    /// Reward for reaching the goal.
+    pub goal_reward: f32,
+    /// Cost applied to every step (positive value is subtracted).
+    pub step_cost: f32,
+    /// Extra penalty for bumping into an obstacle or the grid edge.
+    pub bump_penalty: f32,
+}
+
+impl Default for GridWorldParams {
+    fn default() -> Self {
+        Self {
+            grid_size: 5,
+            wall_density: 0.15,
+            max_steps_per_episode: 50,
+            goal_reward: 1.0,
+            step_cost: 0.01,
+            bump_penalty: 0.05,
+        }
+    }
+}
+
+impl GridWorldParams {
+    pub const MIN_GRID_SIZE: u32 = 2;
+    pub const MAX_GRID_SIZE: u32 = 16;
+    pub const MAX_WALL_DENSITY: f32 = 0.5;
+}
+
+#[derive(Debug)]
+pub struct GridWorldGame {
+    pub params: GridWorldParams,
+
+    pub player_x: u32,
+    pub player_y: u32,
+    pub goal_x: u32,
+    pub goal_y: u32,
+    pub seed: u64,
+
+    pub trial_frame: u32,
+    pub response_made: bool,
+    pub last_action: Option<String>,
+    pub last_event: MazeEvent,
+    pub stats: GameStats,
+
+    pub steps_in_episode: u32,
+
+    /// Obstacle flags, row-major (`y * grid_size + x`).
+    walls: Vec<bool>,
+    /// Shortest-path distance to the goal per cell (`u16::MAX` = unreachable).
+    dist_to_goal: Vec<u16>,
+
+    rng_state: u64,
+    action_names: Vec<String>,
+    x_names: Vec<String>,
+    y_names: Vec<String>,
+    stimulus_key: String,
+    trial_started_at: Instant,
+}
+
+impl GridWorldGame {
+    pub fn new() -> Self {
+        Self::with_params(GridWorldParams::default())
+    }
+
+    pub fn with_params(params: GridWorldParams) -> Self {
+        let seed = 0x4752_4944u64; // "GRID"
+        let mut g = Self {
+            params,
+            player_x: 0,
+            player_y: 0,
+            goal_x: 0,
+            goal_y: 0,
+            seed,
+            trial_frame: 0,
+            response_made: false,
+            last_action: None,
+            last_event: MazeEvent::None,
+            stats: GameStats::new(),
+            steps_in_episode: 0,
+            walls: Vec::new(),
+            dist_to_goal: Vec::new(),
+            rng_state: seed,
+            action_names: vec![
+                "up".to_string(),
+                "right".to_string(),
+                "down".to_string(),
+                "left".to_string(),
+            ],
+            x_names: Vec::new(),
+            y_names: Vec::new(),
+            stimulus_key: String::new(),
+            trial_started_at: Instant::now(),
+        };
+        g.regenerate();
+        g
+    }
+
+    pub fn grid_size(&self) -> u32 {
+        self.params.grid_size
+    }
+
+    pub fn wall_density(&self) -> f32 {
+        self.params.wall_density
+    }
+
+    pub fn max_steps_per_episode(&self) -> u32 {
+        self.params.max_steps_per_episode
+    }
+
+    pub fn set_grid_size(&mut self, n: u32) {
+        self.params.grid_size = n;
+        self.regenerate();
+    }
+
+    pub fn set_wall_density(&mut self, density: f32) {
+        self.params.wall_density = density;
+        self.regenerate();
+    }
+
+    pub fn set_max_steps_per_episode(&mut self, n: u32) {
+        self.params.max_steps_per_episode = n.clamp(1, 10_000);
+    }
+
+    pub fn set_param(&mut self, key: &str, value: f32) -> Result<(), String> {
+        match key {
+            "grid_size" => {
+                self.set_grid_size(value.round().max(0.0) as u32);
+                Ok(())
+            }
+            "wall_density" => {
+                self.set_wall_density(value);
+                Ok(())
+            }
+            "max_steps_per_episode" => {
+                self.set_max_steps_per_episode(value.round().max(0.0) as u32);
+                Ok(())
+            }
+            "goal_reward" => {
+                self.params.goal_reward = value.clamp(0.0, 5.0);
+                Ok(())
+            }
+            "step_cost" => {
+                self.params.step_cost = value.clamp(0.0, 1.0);
+                Ok(())
+            }
+            "bump_penalty" => {
+                self.params.bump_penalty = value.clamp(0.0, 1.0);
+                Ok(())
+            }
+            _ => Err(format!(
+                "Unknown GridWorld param '{key}'. Use grid_size|wall_density|max_steps_per_episode|goal_reward|step_cost|bump_penalty"
+            )),
+        }
+    }
+
+    pub fn is_wall(&self, x: u32, y: u32) -> bool {
+        let n = self.params.grid_size;
+        if x >= n || y >= n {
+            return true;
+        }
+        self.walls[(y * n + x) as usize]
+    }
+
+    /// Sensor names for the column (x) axis, one per grid column.
+    pub fn x_sensor_names(&self) -> &[String] {
+        &self.x_names
+    }
+
+    /// Sensor names for the row (y) axis, one per grid row.
+    pub fn y_sensor_names(&self) -> &[String] {
+        &self.y_names
+    }
+
+    pub fn stimulus_name(&self) -> &'static str {
+        "gridworld"
+    }
+
+    pub fn stimulus_key(&self) -> &str {
+        &self.stimulus_key
+    }
+
+    pub fn allowed_actions(&self) -> &[String] {
+        &self.action_names
+    }
+
+    /// Shortest-path distance from the player to the goal (obstacle-aware).
+    pub fn distance_to_goal(&self) -> Option<u32> {
+        self.dist_at(self.player_x, self.player_y).map(u32::from)
+    }
+
+    pub fn update_timing(&mut self, trial_period_ms: u32) {
+        let trial_period_ms = trial_period_ms.clamp(10, 60_000);
+        let trial_period = Duration::from_millis(trial_period_ms as u64);
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+        if elapsed >= trial_period {
+            self.response_made = false;
+            self.last_action = None;
+            self.trial_started_at = now;
+            self.last_event = MazeEvent::None;
+        }
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+        self.trial_frame = elapsed.as_millis().min(u32::MAX as u128) as u32;
+    }
+
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli(&self, brain: &mut Brain) {
+        for (i, name) in self.x_names.iter().enumerate() {
+            let on = i as u32 == self.player_x;
+            brain_io::apply_sensor_channel(brain, name, if on { 1.0 } else { 0.0 });
+        }
+        for (i, name) in self.y_names.iter().enumerate() {
+            let on = i as u32 == self.player_y;
+            brain_io::apply_sensor_channel(brain, name, if on { 1.0 } else { 0.0 });
+        }
+        brain_io::apply_sensor_channel(
+            brain,
+            "gridworld_bump",
+            if self.last_event == MazeEvent::Bump {
+                1.0
+            } else {
+                0.0
+            },
+        );
+    }
+
+    pub fn score_action(&mut self, action: &str) -> Option<(f32, bool)> {
+        if self.response_made {
+            return None;
+        }
+
+        let act = MazeAction::from_action_str(action)?;
+
+        let event = self.try_step(act);
+        self.last_event = event;
+        self.steps_in_episode = self.steps_in_episode.saturating_add(1);
+
+        let mut reward = -self.params.step_cost;
+        if event == MazeEvent::Bump {
+            reward -= self.params.bump_penalty;
+        }
+
+        let reached = event == MazeEvent::ReachedGoal;
+        if reached {
+            reward += self.params.goal_reward;
+        }
+
+        let timed_out = !reached && self.steps_in_episode >= self.params.max_steps_per_episode;
+        if timed_out {
+            self.last_event = MazeEvent::Timeout;
+        }
+
+        self.response_made = true;
+        self.last_action = Some(action.to_string());
+
+        if reached {
+            self.stats.record_trial(true);
+            self.reset_episode();
+        } else if timed_out {
+            self.stats.record_trial(false);
+            self.reset_episode();
+        } else {
+            self.refresh_stimulus_key();
+        }
+
+        Some((reward.clamp(-5.0, 5.0), reached || timed_out))
+    }
+
+    fn try_step(&mut self, action: MazeAction) -> MazeEvent {
+        let (x, y) = (self.player_x, self.player_y);
+        let n = self.params.grid_size;
+        let (nx, ny) = match action {
+            MazeAction::Up if y > 0 => (x, y - 1),
+            MazeAction::Right if x + 1 < n => (x + 1, y),
+            MazeAction::Down if y + 1 < n => (x, y + 1),
+            MazeAction::Left if x > 0 => (x - 1, y),
+            _ => return MazeEvent::Bump,
+        };
+        if self.is_wall(nx, ny) {
+            return MazeEvent::Bump;
+        }
+
+        self.player_x = nx;
+        self.player_y = ny;
+        if nx == self.goal_x && ny == self.goal_y {
+            MazeEvent::ReachedGoal
+        } else {
+            MazeEvent::Moved
+        }
+    }
+
+    /// Rebuild the layout from the current params and seed, then start a new episode.
+    fn regenerate(&mut self) {
+        let n = self.params.grid_size.clamp(
+            GridWorldParams::MIN_GRID_SIZE,
+            GridWorldParams::MAX_GRID_SIZE,
+        );
+        self.params.grid_size = n;
+        self.params.wall_density = if self.params.wall_density.is_finite() {
+            self.params
+                .wall_density
+                .clamp(0.0, GridWorldParams::MAX_WALL_DENSITY)
+        } else {
+            0.0
+        };
+
+        self.x_names = (0..n).map(|i| format!("gridworld_pos_x_{i:02}")).collect();
+        self.y_names = (0..n).map(|i| format!("gridworld_pos_y_{i:02}")).collect();
+
+        self.rng_state = self.seed;
+        self.goal_x = n - 1;
+        self.goal_y = n - 1;
+
+        let cells = (n * n) as usize;
+        self.walls = vec![false; cells];
+        let goal_idx = (self.goal_y * n + self.goal_x) as usize;
+        for idx in 0..cells {
+            if idx != goal_idx && self.rng_next_f32() < self.params.wall_density {
+                self.walls[idx] = true;
+            }
+        }
+        self.recompute_goal_distances();
+
+        // Cells cut off from the goal become walls so every start is solvable.
+        for idx in 0..cells {
+            if self.dist_to_goal[idx] == u16::MAX {
+                self.walls[idx] = true;
+            }
+        }
+
+        self.stats = GameStats::new();
+        self.last_event = MazeEvent::None;
+        self.response_made = false;
+        self.last_action = None;
+        self.trial_started_at = Instant::now();
+        self.reset_episode();
+    }
+
+    fn reset_episode(&mut self) {
+        self.steps_in_episode = 0;
+
+        // Start on a random free cell other than the goal. The goal is always
+        // free, and a 2×2+ grid always keeps at least one other reachable cell
+        // unless the density removed all of them; fall back to the goal then.
+        let n = self.params.grid_size;
+        let free: Vec<u32> = (0..n * n)
+            .filter(|&idx| !self.walls[idx as usize] && idx != self.goal_y * n + self.goal_x)
+            .collect();
+        let start = if free.is_empty() {
+            self.goal_y * n + self.goal_x
+        } else {
+            free[(self.rng_next_u32() as usize) % free.len()]
+        };
+        self.player_x = start % n;
+        self.player_y = start / n;
+        self.refresh_stimulus_key();
+    }
+
+    fn dist_at(&self, x: u32, y: u32) -> Option<u16> {
+        let n = self.params.grid_size;
+        if x >= n || y >= n {
+            return None;
+        }
+        let d = *self.dist_to_goal.get((y * n + x) as usize)?;
+        if d == u16::MAX {
+            None
+        } else {
+            Some(d)
+        }
+    }
+
+    fn recompute_goal_distances(&mut self) {
+        let n = self.params.grid_size;
+        self.dist_to_goal.clear();
+        self.dist_to_goal.resize((n * n) as usize, u16::MAX);
+        self.dist_to_goal[(self.goal_y * n + self.goal_x) as usize] = 0;
+
+        let mut q: VecDeque<(u32, u32)> = VecDeque::new();
+        q.push_back((self.goal_x, self.goal_y));
+        while let Some((x, y)) = q.pop_front() {
+            let d = self.dist_to_goal[(y * n + x) as usize].saturating_add(1);
+            let neighbors = [
+                (x, y.wrapping_sub(1)),
+                (x + 1, y),
+                (x, y + 1),
+                (x.wrapping_sub(1), y),
+            ];
+            for (nx, ny) in neighbors {
+                if self.is_wall(nx, ny) {
+                    continue;
+                }
+                let cell = &mut self.dist_to_goal[(ny * n + nx) as usize];
+                if *cell > d {
+                    *cell = d;
+                    q.push_back((nx, ny));
+                }
+            }
+        }
+    }
+
+    fn refresh_stimulus_key(&mut self) {
+        self.stimulus_key = format!(
+            "gridworld_{:02}_{:02}_{:02}",
+            self.params.grid_size, self.player_x, self.player_y
+        );
+    }
+
+    fn rng_next_u32(&mut self) -> u32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+        (self.rng_state >> 32) as u32
+    }
+
+    fn rng_next_f32(&mut self) -> f32 {
+        let u = self.rng_next_u32();
+        let mantissa = u >> 8; // 24 bits
+        (mantissa as f32) / ((1u32 << 24) as f32)
+    }
+}
+
+impl Default for GridWorldGame {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_free_cell_can_reach_the_goal() {
+        let g = GridWorldGame::with_params(GridWorldParams {
+            grid_size: 8,
+            wall_density: 0.4,
+            ..Default::default()
+        });
+        let n = g.grid_size();
+        assert!(g.walls.iter().any(|&w| w));
+        for y in 0..n {
+            for x in 0..n {
+                if !g.is_wall(x, y) {
+                    assert!(g.dist_at(x, y).is_some(), "cell ({x},{y}) unreachable");
+                }
+            }
+        }
+        assert!(!g.is_wall(g.player_x, g.player_y));
+    }
+
+    #[test]
+    fn following_the_distance_field_reaches_the_goal() {
+        let mut g = GridWorldGame::with_params(GridWorldParams {
+            grid_size: 6,
+            wall_density: 0.2,
+            max_steps_per_episode: 100,
+            ..Default::default()
+        });
+
+        let mut total = 0.0;
+        for _ in 0..100 {
+            let d0 = g.distance_to_goal().unwrap();
+            let best = ["up", "right", "down", "left"]
+                .into_iter()
+                .find(|a| {
+                    let (x, y) = (g.player_x, g.player_y);
+                    let (nx, ny) = match *a {
+                        "up" => (x, y.wrapping_sub(1)),
+                        "right" => (x + 1, y),
+                        "down" => (x, y + 1),
+                        _ => (x.wrapping_sub(1), y),
+                    };
+                    g.dist_at(nx, ny).is_some_and(|d| u32::from(d) < d0)
+                })
+                .unwrap();
+
+            g.response_made = false;
+            let (reward, done) = g.score_action(best).unwrap();
+            total += reward;
+            if done {
+                assert_eq!(g.stats.correct, 1);
+                assert!(total > 0.0);
+                return;
+            }
+        }
+        panic!("greedy policy never reached the goal");
+    }
+
+    #[test]
+    fn timeout_records_a_failed_trial() {
+        let mut g = GridWorldGame::with_params(GridWorldParams {
+            wall_density: 0.0,
+            max_steps_per_episode: 3,
+            ..Default::default()
+        });
+        // Bump into the top edge until the episode times out.
+        g.player_y = 0;
+        for _ in 0..3 {
+            g.response_made = false;
+            g.score_action("up");
+        }
+        assert_eq!(g.stats.trials, 1);
+        assert_eq!(g.stats.incorrect, 1);
+        assert_eq!(g.steps_in_episode, 0);
+    }
+}
@@ -4,6 +4,7 @@
 extern crate alloc;
 
 pub mod pong;
+pub mod sequence_prediction;
 
 // Explicit helper API for applying stimuli to a `braine::substrate::Brain`.
 // Kept behind the optional `braine` feature.
@@ -20,8 +21,20 @@ pub(crate) mod time;
 #[cfg(feature = "std")]
 pub mod bandit;
 #[cfg(feature = "std")]
+pub mod conditional_discrimination;
+#[cfg(feature = "std")]
+pub mod continuous_bandit;
+#[cfg(feature = "std")]
+pub mod gridworld;
+#[cfg(feature = "std")]
+pub mod match_to_sample;
+#[cfg(feature = "std")]
 pub mod maze;
 #[cfg(feature = "std")]
+pub mod n_back;
+#[cfg(feature = "std")]
+pub mod pattern_completion;
+#[cfg(feature = "std")]
 pub mod replay;
 #[cfg(feature = "std")]
 pub mod sequence;
@@ -34,4 +47,6 @@ pub mod spot_xy;
 #[cfg(feature = "std")]
 pub mod stats;
 #[cfg(feature = "std")]
+pub mod temporal_xor;
+#[cfg(feature = "std")]
 pub mod text_next_token;
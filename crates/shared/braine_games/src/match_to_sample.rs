@@ -0,0 +1,426 @@
+//! Delayed match-to-sample (DMS) working memory task.
+//!
+//! Each trial runs through three phases, counted in ticks:
+//!
+//! 1. **sample**: a sample token is shown for `sample_steps` ticks;
+//! 2. **delay**: nothing is shown for `delay_steps` ticks;
+//! 3. **test**: a test token is shown for up to `test_steps` ticks and the
+//!    agent answers `match` (same token as the sample) or `no_match`.
+//!
+//! Only the test phase accepts a response, so the sample has to be carried
+//! across the delay by the substrate's own state (oscillators, recurrent
+//! activity, causal memory). Accuracy is tracked per `delay_steps` value, so
+//! sweeping the delay yields an accuracy-vs-delay curve.
+//!
+//! Tokens use the [`TextNextTokenGame`](crate::text_next_token::TextNextTokenGame)
+//! sensor encoding (`txt_tok_XX`, one sensor per token).
+
+use crate::n_back::{NBackCounts, MATCH_ACTION, NO_MATCH_ACTION};
+use crate::stats::GameStats;
+use crate::text_next_token::TextToken;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[cfg(feature = "braine")]
+use braine::substrate::{Brain, Stimulus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmsPhase {
+    Sample,
+    Delay,
+    Test,
+}
+
+impl DmsPhase {
+    pub fn name(self) -> &'static str {
+        match self {
+            DmsPhase::Sample => "sample",
+            DmsPhase::Delay => "delay",
+            DmsPhase::Test => "test",
+        }
+    }
+}
+
+/// Scored trials at one delay length.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DelayAccuracy {
+    pub delay_steps: u32,
+    pub trials: u32,
+    pub correct: u32,
+}
+
+impl DelayAccuracy {
+    pub fn accuracy(&self) -> f32 {
+        if self.trials == 0 {
+            0.0
+        } else {
+            self.correct as f32 / self.trials as f32
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MatchToSampleGame {
+    sample_steps: u32,
+    delay_steps: u32,
+    test_steps: u32,
+    vocab: Vec<TextToken>,
+    sensor_names: Vec<String>,
+    action_names: Vec<String>,
+
+    phase: DmsPhase,
+    /// Ticks spent in the current phase.
+    phase_step: u32,
+    sample: TextToken,
+    test: TextToken,
+
+    pub trial_frame: u32,
+    pub response_made: bool,
+    pub last_action: Option<String>,
+    pub stats: GameStats,
+    pub counts: NBackCounts,
+    by_delay: BTreeMap<u32, DelayAccuracy>,
+
+    rng_state: u64,
+    stimulus_key: String,
+}
+
+impl Default for MatchToSampleGame {
+    fn default() -> Self {
+        Self::new(3, 5, 5, 4)
+    }
+}
+
+impl MatchToSampleGame {
+    pub const MIN_SAMPLE_STEPS: u32 = 1;
+    pub const MAX_SAMPLE_STEPS: u32 = 50;
+    pub const MIN_DELAY_STEPS: u32 = 0;
+    pub const MAX_DELAY_STEPS: u32 = 200;
+    pub const MIN_TEST_STEPS: u32 = 1;
+    pub const MAX_TEST_STEPS: u32 = 50;
+    pub const MIN_VOCAB: usize = 2;
+    pub const MAX_VOCAB: usize = 26;
+
+    /// Probability that the test token equals the sample.
+    const MATCH_PROBABILITY: f32 = 0.5;
+
+    pub fn new(sample_steps: u32, delay_steps: u32, test_steps: u32, vocab_size: usize) -> Self {
+        let mut g = Self {
+            sample_steps: sample_steps.clamp(Self::MIN_SAMPLE_STEPS, Self::MAX_SAMPLE_STEPS),
+            delay_steps: delay_steps.clamp(Self::MIN_DELAY_STEPS, Self::MAX_DELAY_STEPS),
+            test_steps: test_steps.clamp(Self::MIN_TEST_STEPS, Self::MAX_TEST_STEPS),
+            vocab: Vec::new(),
+            sensor_names: Vec::new(),
+            action_names: vec![MATCH_ACTION.to_string(), NO_MATCH_ACTION.to_string()],
+            phase: DmsPhase::Sample,
+            phase_step: 0,
+            sample: TextToken::Unk,
+            test: TextToken::Unk,
+            trial_frame: 0,
+            response_made: true,
+            last_action: None,
+            stats: GameStats::new(),
+            counts: NBackCounts::default(),
+            by_delay: BTreeMap::new(),
+            rng_state: 0x444D_5331u64, // "DMS1"
+            stimulus_key: String::new(),
+        };
+        g.set_vocab_size(vocab_size);
+        g
+    }
+
+    /// Rebuild the vocabulary and restart from a fresh sample. Resets stats,
+    /// since accuracy at different vocabulary sizes is not comparable.
+    pub fn set_vocab_size(&mut self, vocab_size: usize) {
+        let vocab_size = vocab_size.clamp(Self::MIN_VOCAB, Self::MAX_VOCAB);
+        self.vocab = (0..vocab_size as u8)
+            .map(|i| TextToken::Byte(b'A' + i))
+            .collect();
+        self.sensor_names = self.vocab.iter().map(|t| t.sensor_name()).collect();
+        self.stats = GameStats::new();
+        self.counts = NBackCounts::default();
+        self.by_delay.clear();
+        self.new_trial();
+    }
+
+    pub fn set_param(&mut self, key: &str, value: f32) -> Result<(), String> {
+        let v = value.round().max(0.0) as u32;
+        match key {
+            "sample_steps" => {
+                self.sample_steps = v.clamp(Self::MIN_SAMPLE_STEPS, Self::MAX_SAMPLE_STEPS);
+            }
+            "delay_steps" => {
+                self.delay_steps = v.clamp(Self::MIN_DELAY_STEPS, Self::MAX_DELAY_STEPS);
+            }
+            "test_steps" => {
+                self.test_steps = v.clamp(Self::MIN_TEST_STEPS, Self::MAX_TEST_STEPS);
+            }
+            "vocab_size" => self.set_vocab_size(v as usize),
+            _ => {
+                return Err(format!(
+                    "Unknown match-to-sample param '{key}'. Use sample_steps ({}..{}) | delay_steps ({}..{}) | test_steps ({}..{}) | vocab_size ({}..{})",
+                    Self::MIN_SAMPLE_STEPS,
+                    Self::MAX_SAMPLE_STEPS,
+                    Self::MIN_DELAY_STEPS,
+                    Self::MAX_DELAY_STEPS,
+                    Self::MIN_TEST_STEPS,
+                    Self::MAX_TEST_STEPS,
+                    Self::MIN_VOCAB,
+                    Self::MAX_VOCAB
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    pub fn sample_steps(&self) -> u32 {
+        self.sample_steps
+    }
+
+    pub fn delay_steps(&self) -> u32 {
+        self.delay_steps
+    }
+
+    pub fn test_steps(&self) -> u32 {
+        self.test_steps
+    }
+
+    pub fn vocab_size(&self) -> usize {
+        self.vocab.len()
+    }
+
+    pub fn token_sensor_names(&self) -> &[String] {
+        &self.sensor_names
+    }
+
+    pub fn phase(&self) -> DmsPhase {
+        self.phase
+    }
+
+    pub fn sample_token(&self) -> TextToken {
+        self.sample
+    }
+
+    pub fn test_token(&self) -> TextToken {
+        self.test
+    }
+
+    /// Token currently on screen (`None` during the delay).
+    pub fn shown_token(&self) -> Option<TextToken> {
+        match self.phase {
+            DmsPhase::Sample => Some(self.sample),
+            DmsPhase::Delay => None,
+            DmsPhase::Test => Some(self.test),
+        }
+    }
+
+    pub fn is_match(&self) -> bool {
+        self.sample == self.test
+    }
+
+    pub fn d_prime(&self) -> f32 {
+        self.counts.d_prime()
+    }
+
+    /// Accuracy at every delay length played so far, shortest delay first.
+    pub fn delay_curve(&self) -> Vec<DelayAccuracy> {
+        self.by_delay.values().copied().collect()
+    }
+
+    pub fn stimulus_name(&self) -> &'static str {
+        "match_to_sample"
+    }
+
+    pub fn stimulus_key(&self) -> &str {
+        &self.stimulus_key
+    }
+
+    pub fn allowed_actions(&self) -> &[String] {
+        &self.action_names
+    }
+
+    pub fn correct_action(&self) -> &'static str {
+        if self.is_match() {
+            MATCH_ACTION
+        } else {
+            NO_MATCH_ACTION
+        }
+    }
+
+    /// Advance one tick through the sample → delay → test phases. The phase
+    /// schedule is counted in ticks, so `trial_period_ms` is not used.
+    pub fn update_timing(&mut self, trial_period_ms: u32) {
+        let _ = trial_period_ms;
+        self.phase_step += 1;
+        self.trial_frame = self.trial_frame.saturating_add(1);
+
+        match self.phase {
+            DmsPhase::Sample if self.phase_step >= self.sample_steps => {
+                if self.delay_steps == 0 {
+                    self.enter_test();
+                } else {
+                    self.set_phase(DmsPhase::Delay);
+                }
+            }
+            DmsPhase::Delay if self.phase_step >= self.delay_steps => self.enter_test(),
+            // Answered, or timed out without a response (not scored).
+            DmsPhase::Test if self.response_made || self.phase_step >= self.test_steps => {
+                self.new_trial()
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli(&self, brain: &mut Brain) {
+        if let Some(tok) = self.shown_token() {
+            brain.apply_stimulus(Stimulus::new(&tok.sensor_name(), 1.0));
+        }
+    }
+
+    /// Apply stimuli in **inference-only** mode (no imprinting between decisions).
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli_inference(&self, brain: &mut Brain) {
+        if let Some(tok) = self.shown_token() {
+            brain.apply_stimulus_inference(Stimulus::new(&tok.sensor_name(), 1.0));
+        }
+    }
+
+    pub fn score_action(&mut self, action: &str) -> Option<(f32, bool)> {
+        if self.response_made || self.phase != DmsPhase::Test {
+            return None;
+        }
+
+        let said_match = match action {
+            MATCH_ACTION => true,
+            NO_MATCH_ACTION => false,
+            _ => return None,
+        };
+
+        let is_match = self.is_match();
+        match (is_match, said_match) {
+            (true, true) => self.counts.hits += 1,
+            (true, false) => self.counts.misses += 1,
+            (false, true) => self.counts.false_alarms += 1,
+            (false, false) => self.counts.correct_rejections += 1,
+        }
+
+        let is_correct = said_match == is_match;
+        let entry = self
+            .by_delay
+            .entry(self.delay_steps)
+            .or_insert(DelayAccuracy {
+                delay_steps: self.delay_steps,
+                ..DelayAccuracy::default()
+            });
+        entry.trials += 1;
+        entry.correct += is_correct as u32;
+
+        self.response_made = true;
+        self.last_action = Some(action.to_string());
+        self.stats.record_trial(is_correct);
+
+        Some((if is_correct { 1.0 } else { -1.0 }, true))
+    }
+
+    fn set_phase(&mut self, phase: DmsPhase) {
+        self.phase = phase;
+        self.phase_step = 0;
+        self.stimulus_key = match self.shown_token() {
+            Some(tok) => format!("dms_{}_{}", phase.name(), tok.action_name()),
+            None => format!("dms_{}", phase.name()),
+        };
+    }
+
+    fn enter_test(&mut self) {
+        self.response_made = false;
+        self.set_phase(DmsPhase::Test);
+    }
+
+    fn new_trial(&mut self) {
+        let i = (self.rng_next_u32() as usize) % self.vocab.len();
+        self.sample = self.vocab[i];
+        self.test = if self.rng_next_f32() < Self::MATCH_PROBABILITY {
+            self.sample
+        } else {
+            // Uniform over the other tokens.
+            let j = (self.rng_next_u32() as usize) % (self.vocab.len() - 1);
+            self.vocab[if j >= i { j + 1 } else { j }]
+        };
+
+        self.trial_frame = 0;
+        self.response_made = true;
+        self.last_action = None;
+        self.set_phase(DmsPhase::Sample);
+    }
+
+    fn rng_next_u32(&mut self) -> u32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+        (self.rng_state >> 32) as u32
+    }
+
+    fn rng_next_f32(&mut self) -> f32 {
+        let u = self.rng_next_u32();
+        let mantissa = u >> 8; // 24 bits
+        (mantissa as f32) / ((1u32 << 24) as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_follow_configured_step_counts() {
+        let mut g = MatchToSampleGame::new(2, 3, 4, 4);
+        let mut phases = Vec::new();
+        for _ in 0..9 {
+            phases.push(g.phase());
+            assert_eq!(g.response_made, g.phase() != DmsPhase::Test);
+            assert_eq!(g.shown_token().is_none(), g.phase() == DmsPhase::Delay);
+            g.update_timing(0);
+        }
+        use DmsPhase::*;
+        assert_eq!(
+            phases,
+            [Sample, Sample, Delay, Delay, Delay, Test, Test, Test, Test]
+        );
+        // Unanswered test times out into a fresh sample.
+        assert_eq!(g.phase(), Sample);
+        assert_eq!(g.stats.trials, 0);
+    }
+
+    #[test]
+    fn accuracy_is_tracked_per_delay() {
+        let mut g = MatchToSampleGame::new(1, 0, 1, 3);
+        for delay in [0, 4] {
+            g.set_param("delay_steps", delay as f32).unwrap();
+            for _ in 0..60 {
+                while g.phase() != DmsPhase::Test {
+                    g.update_timing(0);
+                }
+                // Perfect at delay 0, always "match" at delay 4.
+                let action = if delay == 0 {
+                    g.correct_action()
+                } else {
+                    MATCH_ACTION
+                };
+                assert!(g.score_action(action).is_some());
+                assert_eq!(g.score_action(action), None);
+                g.update_timing(0);
+            }
+        }
+
+        let curve = g.delay_curve();
+        assert_eq!(curve.len(), 2);
+        assert_eq!((curve[0].delay_steps, curve[0].trials), (0, 60));
+        assert_eq!(curve[0].accuracy(), 1.0);
+        assert_eq!((curve[1].delay_steps, curve[1].trials), (4, 60));
+        assert!(curve[1].accuracy() > 0.2 && curve[1].accuracy() < 0.8);
+        assert_eq!(g.stats.trials, 120);
+        assert_eq!(g.counts.misses, 0);
+    }
+}
@@ -0,0 +1,390 @@
+//! N-back working memory task.
+//!
+//! A stream of tokens is presented one per trial. The agent answers `match`
+//! if the current token equals the one shown `n` trials earlier, otherwise
+//! `no_match`. Only the current token is presented, so solving the task
+//! requires holding the recent history in the substrate's own dynamics.
+//!
+//! Tokens are encoded with the same sensor scheme as
+//! [`TextNextTokenGame`](crate::text_next_token::TextNextTokenGame)
+//! (`txt_tok_XX`), so brains trained on the text task share input channels.
+
+use crate::stats::GameStats;
+use crate::text_next_token::TextToken;
+use crate::time::{Duration, Instant};
+use std::collections::VecDeque;
+
+#[cfg(feature = "braine")]
+use braine::substrate::{Brain, Stimulus};
+
+pub const MATCH_ACTION: &str = "match";
+pub const NO_MATCH_ACTION: &str = "no_match";
+
+/// Signal-detection outcome counts for the N-back task.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NBackCounts {
+    /// `match` on a true match.
+    pub hits: u32,
+    /// `no_match` on a true match.
+    pub misses: u32,
+    /// `match` when there was no match.
+    pub false_alarms: u32,
+    /// `no_match` when there was no match.
+    pub correct_rejections: u32,
+}
+
+impl NBackCounts {
+    /// Sensitivity index `d' = z(hit rate) - z(false alarm rate)`.
+    ///
+    /// Rates use the log-linear correction (`+0.5` / `+1`) so perfect or empty
+    /// cells stay finite. Returns 0 when either signal class has no trials.
+    pub fn d_prime(&self) -> f32 {
+        let signal = self.hits + self.misses;
+        let noise = self.false_alarms + self.correct_rejections;
+        if signal == 0 || noise == 0 {
+            return 0.0;
+        }
+        let hit_rate = (self.hits as f64 + 0.5) / (signal as f64 + 1.0);
+        let fa_rate = (self.false_alarms as f64 + 0.5) / (noise as f64 + 1.0);
+        (inverse_normal_cdf(hit_rate) - inverse_normal_cdf(fa_rate)) as f32
+    }
+}
+
+#[derive(Debug)]
+pub struct NBackGame {
+    n: usize,
+    vocab: Vec<TextToken>,
+    sensor_names: Vec<String>,
+    action_names: Vec<String>,
+
+    /// Most recent tokens, newest at the back (at most `n + 1` entries).
+    history: VecDeque<TextToken>,
+    is_match: bool,
+
+    pub trial_frame: u32,
+    pub response_made: bool,
+    pub last_action: Option<String>,
+    pub stats: GameStats,
+    pub counts: NBackCounts,
+
+    rng_state: u64,
+    stimulus_key: String,
+    trial_started_at: Instant,
+}
+
+impl Default for NBackGame {
+    fn default() -> Self {
+        Self::new(2, 4)
+    }
+}
+
+impl NBackGame {
+    pub const MIN_N: usize = 1;
+    pub const MAX_N: usize = 4;
+    pub const MIN_VOCAB: usize = 2;
+    pub const MAX_VOCAB: usize = 26;
+
+    /// Probability that a trial (after the first `n`) is constructed as a match.
+    const MATCH_PROBABILITY: f32 = 0.33;
+
+    pub fn new(n: usize, vocab_size: usize) -> Self {
+        let mut g = Self {
+            n: 0,
+            vocab: Vec::new(),
+            sensor_names: Vec::new(),
+            action_names: vec![MATCH_ACTION.to_string(), NO_MATCH_ACTION.to_string()],
+            history: VecDeque::new(),
+            is_match: false,
+            trial_frame: 0,
+            response_made: false,
+            last_action: None,
+            stats: GameStats::new(),
+            counts: NBackCounts::default(),
+            rng_state: 0x4E42_4143u64, // "NBAC"
+            stimulus_key: String::new(),
+            trial_started_at: Instant::now(),
+        };
+        g.reconfigure(n, vocab_size);
+        g
+    }
+
+    fn reconfigure(&mut self, n: usize, vocab_size: usize) {
+        self.n = n.clamp(Self::MIN_N, Self::MAX_N);
+        let vocab_size = vocab_size.clamp(Self::MIN_VOCAB, Self::MAX_VOCAB);
+        self.vocab = (0..vocab_size as u8)
+            .map(|i| TextToken::Byte(b'A' + i))
+            .collect();
+        self.sensor_names = self.vocab.iter().map(|t| t.sensor_name()).collect();
+
+        self.history.clear();
+        self.stats = GameStats::new();
+        self.counts = NBackCounts::default();
+        self.response_made = false;
+        self.last_action = None;
+        self.trial_started_at = Instant::now();
+        self.next_token();
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn vocab_size(&self) -> usize {
+        self.vocab.len()
+    }
+
+    pub fn set_n(&mut self, n: usize) {
+        self.reconfigure(n, self.vocab.len());
+    }
+
+    pub fn set_vocab_size(&mut self, vocab_size: usize) {
+        self.reconfigure(self.n, vocab_size);
+    }
+
+    pub fn set_param(&mut self, key: &str, value: f32) -> Result<(), String> {
+        let v = value.round().max(0.0) as usize;
+        match key {
+            "n" => {
+                self.set_n(v);
+                Ok(())
+            }
+            "vocab_size" => {
+                self.set_vocab_size(v);
+                Ok(())
+            }
+            _ => Err(format!(
+                "Unknown N-back param '{key}'. Use n ({}..{}) | vocab_size ({}..{})",
+                Self::MIN_N,
+                Self::MAX_N,
+                Self::MIN_VOCAB,
+                Self::MAX_VOCAB
+            )),
+        }
+    }
+
+    pub fn token_sensor_names(&self) -> &[String] {
+        &self.sensor_names
+    }
+
+    pub fn current_token(&self) -> TextToken {
+        self.history.back().copied().unwrap_or(TextToken::Unk)
+    }
+
+    pub fn is_match(&self) -> bool {
+        self.is_match
+    }
+
+    pub fn d_prime(&self) -> f32 {
+        self.counts.d_prime()
+    }
+
+    pub fn stimulus_name(&self) -> &'static str {
+        "n_back"
+    }
+
+    pub fn stimulus_key(&self) -> &str {
+        &self.stimulus_key
+    }
+
+    pub fn allowed_actions(&self) -> &[String] {
+        &self.action_names
+    }
+
+    pub fn correct_action(&self) -> &'static str {
+        if self.is_match {
+            MATCH_ACTION
+        } else {
+            NO_MATCH_ACTION
+        }
+    }
+
+    pub fn update_timing(&mut self, trial_period_ms: u32) {
+        let trial_period_ms = trial_period_ms.clamp(10, 60_000);
+        let trial_period = Duration::from_millis(trial_period_ms as u64);
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+        if elapsed >= trial_period {
+            self.response_made = false;
+            self.last_action = None;
+            self.trial_started_at = now;
+        }
+
+        let elapsed = now.duration_since(self.trial_started_at);
+        self.trial_frame = elapsed.as_millis().min(u32::MAX as u128) as u32;
+    }
+
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli(&self, brain: &mut Brain) {
+        brain.apply_stimulus(Stimulus::new(&self.current_token().sensor_name(), 1.0));
+    }
+
+    /// Apply stimuli in **inference-only** mode (no imprinting between decisions).
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli_inference(&self, brain: &mut Brain) {
+        brain.apply_stimulus_inference(Stimulus::new(&self.current_token().sensor_name(), 1.0));
+    }
+
+    pub fn score_action(&mut self, action: &str) -> Option<(f32, bool)> {
+        if self.response_made {
+            return None;
+        }
+
+        let said_match = match action {
+            MATCH_ACTION => true,
+            NO_MATCH_ACTION => false,
+            _ => return None,
+        };
+
+        match (self.is_match, said_match) {
+            (true, true) => self.counts.hits += 1,
+            (true, false) => self.counts.misses += 1,
+            (false, true) => self.counts.false_alarms += 1,
+            (false, false) => self.counts.correct_rejections += 1,
+        }
+
+        let is_correct = said_match == self.is_match;
+        let reward = if is_correct { 1.0 } else { -1.0 };
+
+        self.response_made = true;
+        self.last_action = Some(action.to_string());
+        self.stats.record_trial(is_correct);
+
+        self.next_token();
+        Some((reward, true))
+    }
+
+    fn next_token(&mut self) {
+        let n = self.n;
+        let target = if self.history.len() >= n {
+            Some(self.history[self.history.len() - n])
+        } else {
+            None
+        };
+
+        let token = match target {
+            Some(t) if self.rng_next_f32() < Self::MATCH_PROBABILITY => t,
+            _ => {
+                let i = (self.rng_next_u32() as usize) % self.vocab.len();
+                self.vocab[i]
+            }
+        };
+
+        self.is_match = target == Some(token);
+        self.history.push_back(token);
+        while self.history.len() > n + 1 {
+            self.history.pop_front();
+        }
+
+        self.stimulus_key = format!("nback_n{}_{}", n, token.action_name());
+    }
+
+    fn rng_next_u32(&mut self) -> u32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+        (self.rng_state >> 32) as u32
+    }
+
+    fn rng_next_f32(&mut self) -> f32 {
+        let u = self.rng_next_u32();
+        let mantissa = u >> 8; // 24 bits
+        (mantissa as f32) / ((1u32 << 24) as f32)
+    }
+}
+
+/// Inverse of the standard normal CDF (Acklam's rational approximation).
+fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+
+    let p = p.clamp(1e-12, 1.0 - 1e-12);
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -inverse_normal_cdf(1.0 - p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_action_tracks_token_n_steps_back() {
+        let mut g = NBackGame::new(2, 3);
+        let mut seen: Vec<TextToken> = vec![g.current_token()];
+        let mut matches = 0;
+        for _ in 0..200 {
+            let i = seen.len() - 1;
+            let expect = i >= 2 && seen[i] == seen[i - 2];
+            assert_eq!(g.is_match(), expect);
+            matches += expect as u32;
+
+            let _ = g.score_action(g.correct_action());
+            g.response_made = false;
+            seen.push(g.current_token());
+        }
+        assert!(matches > 20, "too few match trials: {matches}");
+        assert_eq!(g.counts.misses + g.counts.false_alarms, 0);
+    }
+
+    #[test]
+    fn d_prime_separates_perfect_from_chance_responders() {
+        let mut perfect = NBackGame::new(1, 4);
+        let mut always_match = NBackGame::new(1, 4);
+        for _ in 0..200 {
+            let _ = perfect.score_action(perfect.correct_action());
+            perfect.response_made = false;
+            let _ = always_match.score_action(MATCH_ACTION);
+            always_match.response_made = false;
+        }
+        assert!(perfect.d_prime() > 2.0);
+        assert!(always_match.d_prime().abs() < 0.5);
+        assert_eq!(always_match.counts.misses, 0);
+        assert_eq!(always_match.counts.correct_rejections, 0);
+    }
+
+    #[test]
+    fn inverse_normal_cdf_matches_known_quantiles() {
+        assert!(inverse_normal_cdf(0.5).abs() < 1e-9);
+        assert!((inverse_normal_cdf(0.975) - 1.959_964).abs() < 1e-5);
+        assert!((inverse_normal_cdf(0.01) + 2.326_348).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,504 @@
+//! Pattern completion (associative recall from a partial cue).
+//!
+//! The game holds `n_patterns` random binary patterns over `k_sensors` bits,
+//! each mapped to its own action (`pc_pattern_<i>`). Every trial picks one
+//! pattern and reveals a random `reveal_fraction` of its bits; the agent has
+//! to answer with the action of the full pattern.
+//!
+//! A revealed bit drives one of two sensors (`pc_bit<i>_on` / `pc_bit<i>_off`),
+//! a hidden bit drives neither, so "off" and "unknown" stay distinguishable.
+//! The stimulus key is built from the revealed bits only (`pc::1?0?…`), never
+//! from the pattern index, so a partial cue that was not seen before can only
+//! be answered by completing it from what was learned about the full pattern.
+//!
+//! Accuracy is tracked per pattern and per reveal fraction (in percent), so
+//! sweeping `reveal_fraction` yields a completion-rate curve. Every
+//! `shift_every_outcomes` scored trials the patterns are redrawn (0 keeps them
+//! fixed).
+
+use crate::stats::GameStats;
+use crate::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[cfg(feature = "braine")]
+use braine::substrate::{Brain, Stimulus};
+
+/// Prefix of the bit sensors (`pc_bit<i>_on`, `pc_bit<i>_off`).
+pub const SENSOR_PREFIX: &str = "pc_bit";
+/// Prefix of the per-pattern actions (`pc_pattern_<i>`).
+pub const ACTION_PREFIX: &str = "pc_pattern";
+
+/// Scored trials for one pattern, or at one reveal fraction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletionAccuracy {
+    /// Reveal fraction in percent (0 for per-pattern entries).
+    pub reveal_percent: u32,
+    pub trials: u32,
+    pub correct: u32,
+}
+
+impl CompletionAccuracy {
+    pub fn accuracy(&self) -> f32 {
+        if self.trials == 0 {
+            0.0
+        } else {
+            self.correct as f32 / self.trials as f32
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PatternCompletionGame {
+    n_patterns: usize,
+    k_sensors: usize,
+    reveal_fraction: f32,
+    shift_every_outcomes: u32,
+    outcomes_since_shift: u32,
+    /// Number of times the patterns have been redrawn.
+    pub pattern_shifts: u32,
+
+    patterns: Vec<Vec<bool>>,
+    current: usize,
+    revealed: Vec<bool>,
+
+    pub trial_frame: u32,
+    pub response_made: bool,
+    pub last_action: Option<String>,
+    pub stats: GameStats,
+    by_pattern: Vec<CompletionAccuracy>,
+    by_reveal: BTreeMap<u32, CompletionAccuracy>,
+
+    action_names: Vec<String>,
+    stimulus_key: String,
+    rng_state: u64,
+    trial_started_at: Instant,
+}
+
+impl Default for PatternCompletionGame {
+    fn default() -> Self {
+        Self::new(4, 16, 0.5, 0)
+    }
+}
+
+impl PatternCompletionGame {
+    pub const MIN_PATTERNS: usize = 2;
+    pub const MAX_PATTERNS: usize = 16;
+    pub const MIN_SENSORS: usize = 4;
+    pub const MAX_SENSORS: usize = 64;
+
+    /// `n_patterns` and `k_sensors` are clamped to their supported ranges
+    /// (`n_patterns` also to the `2^k - 1` distinct non-empty patterns),
+    /// `reveal_fraction` to `0..=1` (at least one bit is always revealed);
+    /// `shift_every_outcomes == 0` keeps the patterns fixed.
+    pub fn new(
+        n_patterns: usize,
+        k_sensors: usize,
+        reveal_fraction: f32,
+        shift_every_outcomes: u32,
+    ) -> Self {
+        let k_sensors = k_sensors.clamp(Self::MIN_SENSORS, Self::MAX_SENSORS);
+        let mut g = Self {
+            n_patterns: Self::clamp_patterns(n_patterns, k_sensors),
+            k_sensors,
+            reveal_fraction: Self::clamp_fraction(reveal_fraction),
+            shift_every_outcomes,
+            outcomes_since_shift: 0,
+            pattern_shifts: 0,
+            patterns: Vec::new(),
+            current: 0,
+            revealed: Vec::new(),
+            trial_frame: 0,
+            response_made: false,
+            last_action: None,
+            stats: GameStats::new(),
+            by_pattern: Vec::new(),
+            by_reveal: BTreeMap::new(),
+            action_names: Vec::new(),
+            stimulus_key: String::new(),
+            rng_state: 0x5043_4F4Du64, // "PCOM"
+            trial_started_at: Instant::now(),
+        };
+        g.rebuild();
+        g
+    }
+
+    pub fn set_param(&mut self, key: &str, value: f32) -> Result<(), String> {
+        let v = value.round().max(0.0) as usize;
+        match key {
+            "n_patterns" => {
+                self.n_patterns = Self::clamp_patterns(v, self.k_sensors);
+                self.rebuild();
+            }
+            "k_sensors" => {
+                self.k_sensors = v.clamp(Self::MIN_SENSORS, Self::MAX_SENSORS);
+                self.n_patterns = Self::clamp_patterns(self.n_patterns, self.k_sensors);
+                self.rebuild();
+            }
+            "reveal_fraction" => {
+                self.reveal_fraction = Self::clamp_fraction(value);
+                self.new_trial();
+            }
+            "shift_every" => {
+                self.shift_every_outcomes = v as u32;
+                self.outcomes_since_shift = 0;
+            }
+            _ => {
+                return Err(format!(
+                    "Unknown pattern completion param '{key}'. Use n_patterns ({}..{}) | k_sensors ({}..{}) | reveal_fraction (0..1) | shift_every (0 = fixed)",
+                    Self::MIN_PATTERNS,
+                    Self::MAX_PATTERNS,
+                    Self::MIN_SENSORS,
+                    Self::MAX_SENSORS
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    pub fn n_patterns(&self) -> usize {
+        self.n_patterns
+    }
+
+    pub fn k_sensors(&self) -> usize {
+        self.k_sensors
+    }
+
+    pub fn reveal_fraction(&self) -> f32 {
+        self.reveal_fraction
+    }
+
+    pub fn shift_every_outcomes(&self) -> u32 {
+        self.shift_every_outcomes
+    }
+
+    /// Full pattern `i` (one bool per sensor bit).
+    pub fn pattern(&self, i: usize) -> &[bool] {
+        &self.patterns[i.min(self.n_patterns - 1)]
+    }
+
+    /// Index of the pattern behind the current cue.
+    pub fn current_pattern(&self) -> usize {
+        self.current
+    }
+
+    /// Which bits of the current pattern are shown this trial.
+    pub fn revealed_bits(&self) -> &[bool] {
+        &self.revealed
+    }
+
+    /// Number of bits revealed per trial: `round(reveal_fraction * k)`, at least 1.
+    pub fn revealed_count(&self) -> usize {
+        ((self.reveal_fraction * self.k_sensors as f32).round() as usize).clamp(1, self.k_sensors)
+    }
+
+    /// Accuracy per pattern since the patterns were last drawn.
+    pub fn pattern_accuracy(&self) -> &[CompletionAccuracy] {
+        &self.by_pattern
+    }
+
+    /// Completion rate at every reveal fraction played so far, lowest first.
+    pub fn reveal_curve(&self) -> Vec<CompletionAccuracy> {
+        self.by_reveal.values().copied().collect()
+    }
+
+    /// Overall completion rate across all scored trials.
+    pub fn completion_rate(&self) -> f32 {
+        if self.stats.trials == 0 {
+            0.0
+        } else {
+            self.stats.correct as f32 / self.stats.trials as f32
+        }
+    }
+
+    /// Sensor driven by bit `bit` when revealed with value `on`.
+    pub fn sensor_name(bit: usize, on: bool) -> String {
+        format!("{SENSOR_PREFIX}{bit}_{}", if on { "on" } else { "off" })
+    }
+
+    /// Action of pattern `i`.
+    pub fn action_name(i: usize) -> String {
+        format!("{ACTION_PREFIX}_{i}")
+    }
+
+    pub fn stimulus_name(&self) -> &'static str {
+        "pattern_completion"
+    }
+
+    /// Revealed bits of the current cue, e.g. `pc::1?0?`.
+    pub fn stimulus_key(&self) -> &str {
+        &self.stimulus_key
+    }
+
+    pub fn allowed_actions(&self) -> &[String] {
+        &self.action_names
+    }
+
+    pub fn correct_action(&self) -> &str {
+        &self.action_names[self.current]
+    }
+
+    pub fn update_timing(&mut self, trial_period_ms: u32) {
+        let trial_period_ms = trial_period_ms.clamp(10, 60_000);
+        let trial_period = Duration::from_millis(trial_period_ms as u64);
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+
+        if elapsed >= trial_period {
+            self.new_trial();
+        }
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.trial_started_at);
+        self.trial_frame = elapsed.as_millis().min(u32::MAX as u128) as u32;
+    }
+
+    pub fn score_action(&mut self, action: &str) -> Option<(f32, bool)> {
+        if self.response_made {
+            return None;
+        }
+        if !self.action_names.iter().any(|a| a == action) {
+            return None;
+        }
+
+        let is_correct = action == self.correct_action();
+        let p = &mut self.by_pattern[self.current];
+        p.trials += 1;
+        p.correct += is_correct as u32;
+
+        let percent = (self.reveal_fraction * 100.0).round() as u32;
+        let r = self.by_reveal.entry(percent).or_insert(CompletionAccuracy {
+            reveal_percent: percent,
+            ..CompletionAccuracy::default()
+        });
+        r.trials += 1;
+        r.correct += is_correct as u32;
+
+        self.response_made = true;
+        self.last_action = Some(action.to_string());
+        self.stats.record_trial(is_correct);
+
+        self.outcomes_since_shift += 1;
+        if self.shift_every_outcomes > 0 && self.outcomes_since_shift >= self.shift_every_outcomes {
+            self.draw_patterns();
+            self.outcomes_since_shift = 0;
+            self.pattern_shifts += 1;
+        }
+
+        Some((if is_correct { 1.0 } else { -1.0 }, true))
+    }
+
+    /// Clamp a pattern count to the supported range and to the `2^k - 1`
+    /// distinct non-empty patterns `k` bits can hold.
+    fn clamp_patterns(n: usize, k: usize) -> usize {
+        let distinct = if k >= usize::BITS as usize {
+            usize::MAX
+        } else {
+            (1usize << k) - 1
+        };
+        n.clamp(Self::MIN_PATTERNS, Self::MAX_PATTERNS.min(distinct))
+    }
+
+    fn clamp_fraction(f: f32) -> f32 {
+        if f.is_finite() {
+            f.clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Redraw everything after a size change. Resets stats, since accuracy at
+    /// different sizes is not comparable.
+    fn rebuild(&mut self) {
+        self.action_names = (0..self.n_patterns).map(Self::action_name).collect();
+        self.stats = GameStats::new();
+        self.by_reveal.clear();
+        self.outcomes_since_shift = 0;
+        self.draw_patterns();
+        self.new_trial();
+    }
+
+    /// Draw `n_patterns` distinct, non-empty patterns. The current trial is
+    /// left as is, so a shift after scoring does not reopen it.
+    fn draw_patterns(&mut self) {
+        let k = self.k_sensors;
+        let mut patterns: Vec<Vec<bool>> = Vec::with_capacity(self.n_patterns);
+        while patterns.len() < self.n_patterns {
+            let p: Vec<bool> = (0..k).map(|_| self.rng_next_u32() & 1 == 1).collect();
+            if p.iter().any(|&b| b) && !patterns.contains(&p) {
+                patterns.push(p);
+            }
+        }
+        self.patterns = patterns;
+        self.by_pattern = vec![CompletionAccuracy::default(); self.n_patterns];
+    }
+
+    fn new_trial(&mut self) {
+        self.current = (self.rng_next_u32() as usize) % self.n_patterns;
+
+        // Partial Fisher-Yates: the first `n` bits of `order` are revealed.
+        let k = self.k_sensors;
+        let n = self.revealed_count();
+        let mut order: Vec<usize> = (0..k).collect();
+        for i in 0..n {
+            let j = i + (self.rng_next_u32() as usize) % (k - i);
+            order.swap(i, j);
+        }
+        self.revealed = vec![false; k];
+        for &bit in &order[..n] {
+            self.revealed[bit] = true;
+        }
+
+        let pattern = &self.patterns[self.current];
+        let cue: String = (0..k)
+            .map(|i| match (self.revealed[i], pattern[i]) {
+                (false, _) => '?',
+                (true, true) => '1',
+                (true, false) => '0',
+            })
+            .collect();
+        self.stimulus_key = format!("pc::{cue}");
+
+        self.trial_frame = 0;
+        self.response_made = false;
+        self.last_action = None;
+        self.trial_started_at = Instant::now();
+    }
+
+    fn rng_next_u32(&mut self) -> u32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+        (self.rng_state >> 33) as u32
+    }
+}
+
+#[cfg(feature = "braine")]
+impl PatternCompletionGame {
+    /// Make sure every bit has its on/off sensors and every pattern its action.
+    pub fn ensure_io(&self, brain: &mut Brain, width: usize) {
+        for bit in 0..self.k_sensors {
+            brain.ensure_sensor(&Self::sensor_name(bit, true), width);
+            brain.ensure_sensor(&Self::sensor_name(bit, false), width);
+        }
+        for action in &self.action_names {
+            brain.ensure_action(action, width);
+        }
+    }
+
+    /// Present the revealed bits of the current pattern.
+    pub fn apply_stimuli(&self, brain: &mut Brain) {
+        for (bit, on) in self.revealed_values() {
+            brain.apply_stimulus(Stimulus::new(&Self::sensor_name(bit, on), 1.0));
+        }
+    }
+
+    /// Apply stimuli in **inference-only** mode (no imprinting between decisions).
+    pub fn apply_stimuli_inference(&self, brain: &mut Brain) {
+        for (bit, on) in self.revealed_values() {
+            brain.apply_stimulus_inference(Stimulus::new(&Self::sensor_name(bit, on), 1.0));
+        }
+    }
+
+    fn revealed_values(&self) -> impl Iterator<Item = (usize, bool)> + '_ {
+        let pattern = &self.patterns[self.current];
+        self.revealed
+            .iter()
+            .enumerate()
+            .filter(|(_, &shown)| shown)
+            .map(move |(bit, _)| (bit, pattern[bit]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cue_reveals_the_requested_fraction_of_the_pattern() {
+        let mut g = PatternCompletionGame::new(6, 20, 0.25, 0);
+        for i in 0..g.n_patterns() {
+            assert!(g.pattern(i).iter().any(|&b| b));
+            for j in 0..i {
+                assert_ne!(g.pattern(i), g.pattern(j));
+            }
+        }
+
+        for _ in 0..10 {
+            assert_eq!(g.revealed_bits().iter().filter(|&&r| r).count(), 5);
+            let pattern = g.pattern(g.current_pattern()).to_vec();
+            for (i, c) in g.stimulus_key()["pc::".len()..].chars().enumerate() {
+                let expect = match (g.revealed_bits()[i], pattern[i]) {
+                    (false, _) => '?',
+                    (true, true) => '1',
+                    (true, false) => '0',
+                };
+                assert_eq!(c, expect);
+            }
+            g.new_trial();
+        }
+
+        g.set_param("reveal_fraction", 0.0).unwrap();
+        assert_eq!(g.revealed_count(), 1);
+        assert!(g.set_param("nope", 1.0).is_err());
+    }
+
+    fn answer_correctly(g: &mut PatternCompletionGame) {
+        let action = g.correct_action().to_string();
+        g.score_action(&action).unwrap();
+    }
+
+    #[test]
+    fn accuracy_is_tracked_per_pattern_and_reveal_fraction() {
+        let mut g = PatternCompletionGame::new(3, 8, 0.5, 6);
+        let before = g.pattern(0).to_vec();
+        for t in 0..4 {
+            let action = if t == 0 {
+                PatternCompletionGame::action_name((g.current_pattern() + 1) % 3)
+            } else {
+                g.correct_action().to_string()
+            };
+            assert!(g.score_action(&action).is_some());
+            assert_eq!(g.score_action(&action), None);
+            g.new_trial();
+        }
+        g.set_param("reveal_fraction", 1.0).unwrap();
+        answer_correctly(&mut g);
+        g.new_trial();
+
+        let curve = g.reveal_curve();
+        assert_eq!(curve.len(), 2);
+        assert_eq!((curve[0].reveal_percent, curve[0].trials), (50, 4));
+        assert_eq!(curve[0].correct, 3);
+        assert_eq!((curve[1].reveal_percent, curve[1].correct), (100, 1));
+        let per_pattern: u32 = g.pattern_accuracy().iter().map(|p| p.trials).sum();
+        assert_eq!(per_pattern, 5);
+        assert!((g.completion_rate() - 0.8).abs() < 1e-6);
+        assert_eq!(g.score_action("pc_pattern_9"), None);
+
+        // Sixth outcome redraws the patterns and clears per-pattern counts.
+        answer_correctly(&mut g);
+        assert_eq!(g.pattern_shifts, 1);
+        assert_ne!(g.pattern(0), before.as_slice());
+        assert!(g.pattern_accuracy().iter().all(|p| p.trials == 0));
+        // The shift does not start a new trial, so it cannot be answered twice.
+        let action = g.correct_action().to_string();
+        assert_eq!(g.score_action(&action), None);
+    }
+
+    #[test]
+    fn pattern_count_is_capped_by_distinct_patterns() {
+        let g = PatternCompletionGame::new(16, 4, 0.5, 0);
+        assert_eq!(g.n_patterns(), 15);
+        assert_eq!(g.allowed_actions().len(), 15);
+
+        let mut g = PatternCompletionGame::new(16, 8, 0.5, 0);
+        assert_eq!(g.n_patterns(), 16);
+        g.set_param("k_sensors", 4.0).unwrap();
+        assert_eq!(g.n_patterns(), 15);
+        g.set_param("n_patterns", 16.0).unwrap();
+        assert_eq!(g.n_patterns(), 15);
+    }
+}
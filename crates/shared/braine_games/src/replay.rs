@@ -1,6 +1,8 @@
 use crate::stats::GameStats;
 use crate::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::Path;
 
 #[cfg(feature = "braine")]
 use braine::substrate::{Brain, Stimulus};
@@ -76,6 +78,150 @@ impl ReplayDataset {
             trials,
         }
     }
+
+    /// Load a dataset from a CSV file; the dataset is named after the file stem.
+    ///
+    /// See [`ReplayDataset::from_csv_str`] for the format.
+    pub fn from_csv(path: &Path) -> Result<ReplayDataset, io::Error> {
+        let text = std::fs::read_to_string(path)?;
+        let mut dataset = Self::from_csv_str(&text)?;
+        dataset.name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(dataset)
+    }
+
+    /// Parse a dataset from CSV text.
+    ///
+    /// The header names the columns `trial_id, stimulus_name, amplitude,
+    /// allowed_actions, correct_action` (any order; `amplitude` may be omitted
+    /// and empty amplitudes default to 1.0). Each row adds one stimulus; rows
+    /// sharing a `trial_id` form one trial, in order of first appearance.
+    /// `allowed_actions` lists names separated by `;` or `|` (or `,` inside a
+    /// quoted field). Every trial needs at least one stimulus, at least one
+    /// allowed action, and a non-empty `correct_action`.
+    pub fn from_csv_str(text: &str) -> Result<ReplayDataset, io::Error> {
+        let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l.trim()))
+            .filter(|(_, l)| !l.is_empty());
+        let (_, header) = lines
+            .next()
+            .ok_or_else(|| invalid("CSV is empty (missing header)".to_string()))?;
+        let header: Vec<String> = split_csv_row(header)
+            .into_iter()
+            .map(|h| h.trim().to_ascii_lowercase())
+            .collect();
+        let column = |name: &str| header.iter().position(|h| h == name);
+        let require = |name: &str| {
+            column(name).ok_or_else(|| invalid(format!("CSV header is missing column '{name}'")))
+        };
+        let id_col = require("trial_id")?;
+        let stim_col = require("stimulus_name")?;
+        let allowed_col = require("allowed_actions")?;
+        let correct_col = require("correct_action")?;
+        let amp_col = column("amplitude");
+
+        let mut trials: Vec<ReplayTrial> = Vec::new();
+        for (line_no, line) in lines {
+            let fields = split_csv_row(line);
+            let field = |i: usize| fields.get(i).map(|f| f.trim()).unwrap_or("");
+
+            let id = field(id_col);
+            if id.is_empty() {
+                return Err(invalid(format!("line {line_no}: empty trial_id")));
+            }
+            let idx = match trials.iter().position(|t| t.id == id) {
+                Some(i) => i,
+                None => {
+                    trials.push(ReplayTrial {
+                        id: id.to_string(),
+                        ..ReplayTrial::default()
+                    });
+                    trials.len() - 1
+                }
+            };
+            let trial = &mut trials[idx];
+
+            let name = field(stim_col);
+            if !name.is_empty() {
+                let strength = match amp_col.map(field).filter(|a| !a.is_empty()) {
+                    Some(a) => a.parse::<f32>().map_err(|_| {
+                        invalid(format!("line {line_no}: amplitude '{a}' is not a number"))
+                    })?,
+                    None => default_strength(),
+                };
+                trial.stimuli.push(ReplayStimulus {
+                    name: name.to_string(),
+                    strength,
+                });
+            }
+
+            for action in field(allowed_col)
+                .split([';', '|', ','])
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+            {
+                if !trial.allowed_actions.iter().any(|a| a == action) {
+                    trial.allowed_actions.push(action.to_string());
+                }
+            }
+
+            let correct = field(correct_col);
+            if !correct.is_empty() {
+                if trial.correct_action.is_empty() {
+                    trial.correct_action = correct.to_string();
+                } else if trial.correct_action != correct {
+                    return Err(invalid(format!(
+                        "line {line_no}: trial '{id}' has conflicting correct_action '{correct}' (was '{}')",
+                        trial.correct_action
+                    )));
+                }
+            }
+        }
+
+        for t in &trials {
+            if t.stimuli.is_empty() {
+                return Err(invalid(format!("trial '{}' has no stimulus", t.id)));
+            }
+            if t.allowed_actions.is_empty() {
+                return Err(invalid(format!("trial '{}' has no allowed action", t.id)));
+            }
+            if t.correct_action.is_empty() {
+                return Err(invalid(format!("trial '{}' has no correct_action", t.id)));
+            }
+        }
+
+        Ok(Self {
+            name: String::new(),
+            trials,
+        })
+    }
+}
+
+/// Split one CSV row on commas, honouring double-quoted fields (`""` escapes a quote).
+fn split_csv_row(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut cur = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                cur.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut cur)),
+            _ => cur.push(c),
+        }
+    }
+    fields.push(cur);
+    fields
 }
 
 /// Dataset-driven game: each completed trial consumes one record from a list.
@@ -286,4 +432,40 @@ mod tests {
         let (r1, _done1) = g.score_action("not_a_real_action").unwrap();
         assert_eq!(r1, -1.0);
     }
+
+    #[test]
+    fn csv_groups_rows_by_trial_id_and_validates() {
+        let csv = "trial_id,stimulus_name,amplitude,allowed_actions,correct_action\n\
+                   t1,spot_left,,left;right,left\n\
+                   t2,spot_right,0.5,\"left,right\",right\n\
+                   t1,cue,2.0,,left\n";
+        let d = ReplayDataset::from_csv_str(csv).unwrap();
+        assert_eq!(d.trials.len(), 2);
+        let t1 = &d.trials[0];
+        assert_eq!(t1.id, "t1");
+        assert_eq!(t1.stimuli.len(), 2);
+        assert_eq!(t1.stimuli[0].strength, 1.0);
+        assert_eq!(t1.stimuli[1].name, "cue");
+        assert_eq!(t1.stimuli[1].strength, 2.0);
+        assert_eq!(t1.allowed_actions, ["left", "right"]);
+        assert_eq!(d.trials[1].allowed_actions, ["left", "right"]);
+        assert_eq!(d.trials[1].stimuli[0].strength, 0.5);
+
+        // Amplitude column is optional.
+        let d = ReplayDataset::from_csv_str(
+            "trial_id,stimulus_name,allowed_actions,correct_action\na,s,x|y,y\n",
+        )
+        .unwrap();
+        assert_eq!(d.trials[0].stimuli[0].strength, 1.0);
+
+        for bad in [
+            "trial_id,stimulus_name,allowed_actions,correct_action\na,,x,x\n",
+            "trial_id,stimulus_name,allowed_actions,correct_action\na,s,,x\n",
+            "trial_id,stimulus_name,allowed_actions,correct_action\na,s,x,\n",
+            "trial_id,stimulus_name,correct_action\na,s,x\n",
+        ] {
+            let err = ReplayDataset::from_csv_str(bad).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+    }
 }
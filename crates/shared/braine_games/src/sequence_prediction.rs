@@ -0,0 +1,281 @@
+//! Next-symbol prediction over a small synthetic grammar.
+//!
+//! The grammar is a single cycle over `n_symbols` (3..=8) symbols: every symbol
+//! has exactly one successor, and following successors visits the whole
+//! vocabulary before repeating. Each trial shows the current symbol and the
+//! agent predicts the next one. Every `shift_every_outcomes` scored trials the
+//! cycle is redrawn (a new order of the same symbols), so learned transitions
+//! go stale and have to be relearned.
+//!
+//! Unlike `TextNextTokenGame` the vocabulary is arbitrary `&'static str`
+//! labels, and the simulation itself is `no_std` friendly (no `Instant`, no
+//! `Vec`, no `String`), like Pong. Only the Brain helpers need `braine`.
+
+#[cfg(feature = "braine")]
+use braine::substrate::{Brain, Stimulus};
+
+/// Largest supported vocabulary.
+pub const MAX_SYMBOLS: usize = 8;
+
+/// Smallest supported vocabulary.
+pub const MIN_SYMBOLS: usize = 3;
+
+/// Sensor prefix of the current-symbol cue (`seqpred_tok_<label>`).
+pub const SENSOR_PREFIX: &str = "seqpred_tok";
+
+const DEFAULT_VOCAB: [&str; MAX_SYMBOLS] = ["A", "B", "C", "D", "E", "F", "G", "H"];
+
+#[derive(Debug, Clone)]
+pub struct SequencePredictionGame {
+    vocab: [&'static str; MAX_SYMBOLS],
+    n_symbols: usize,
+    /// `successor[i]` is the symbol that follows symbol `i`.
+    successor: [u8; MAX_SYMBOLS],
+    current: u8,
+
+    shift_every_outcomes: u32,
+    outcomes_since_shift: u32,
+    /// Number of times the grammar has been redrawn.
+    pub grammar_shifts: u32,
+
+    pub trials: u32,
+    pub correct: u32,
+    pub last_prediction: Option<u8>,
+
+    rng_seed: u64,
+}
+
+impl Default for SequencePredictionGame {
+    fn default() -> Self {
+        Self::new(4, 200)
+    }
+}
+
+impl SequencePredictionGame {
+    /// `n_symbols` is clamped to `MIN_SYMBOLS..=MAX_SYMBOLS`;
+    /// `shift_every_outcomes == 0` keeps the grammar fixed.
+    pub fn new(n_symbols: usize, shift_every_outcomes: u32) -> Self {
+        let mut g = Self {
+            vocab: DEFAULT_VOCAB,
+            n_symbols: n_symbols.clamp(MIN_SYMBOLS, MAX_SYMBOLS),
+            successor: [0; MAX_SYMBOLS],
+            current: 0,
+            shift_every_outcomes,
+            outcomes_since_shift: 0,
+            grammar_shifts: 0,
+            trials: 0,
+            correct: 0,
+            last_prediction: None,
+            rng_seed: 0x5E9_1D1C7u64,
+        };
+        g.draw_grammar();
+        g
+    }
+
+    /// Use custom symbol labels; the vocabulary size becomes `vocab.len()`
+    /// (clamped to `MIN_SYMBOLS..=MAX_SYMBOLS`, missing labels keep the defaults).
+    /// Labels should be distinct.
+    pub fn with_vocab(mut self, vocab: &[&'static str]) -> Self {
+        let n = vocab.len().clamp(MIN_SYMBOLS, MAX_SYMBOLS);
+        for (slot, &label) in self.vocab.iter_mut().zip(vocab.iter().take(n)) {
+            *slot = label;
+        }
+        self.n_symbols = n;
+        self.current = 0;
+        self.draw_grammar();
+        self
+    }
+
+    pub fn n_symbols(&self) -> usize {
+        self.n_symbols
+    }
+
+    /// Active labels, indexed by symbol.
+    pub fn vocab(&self) -> &[&'static str] {
+        &self.vocab[..self.n_symbols]
+    }
+
+    pub fn shift_every_outcomes(&self) -> u32 {
+        self.shift_every_outcomes
+    }
+
+    pub fn set_shift_every_outcomes(&mut self, every: u32) {
+        self.shift_every_outcomes = every;
+        self.outcomes_since_shift = 0;
+    }
+
+    /// Symbol shown this trial.
+    pub fn current(&self) -> usize {
+        self.current as usize
+    }
+
+    pub fn current_label(&self) -> &'static str {
+        self.vocab[self.current as usize]
+    }
+
+    /// Successor of `symbol` under the active grammar.
+    pub fn successor(&self, symbol: usize) -> usize {
+        self.successor[symbol.min(self.n_symbols - 1)] as usize
+    }
+
+    /// The correct prediction for this trial.
+    pub fn expected_next(&self) -> usize {
+        self.successor(self.current as usize)
+    }
+
+    /// Index of the symbol labelled `label`, if it is in the active vocabulary.
+    pub fn symbol_index(&self, label: &str) -> Option<usize> {
+        self.vocab().iter().position(|&l| l == label)
+    }
+
+    /// Actions are the vocabulary labels themselves.
+    pub fn allowed_actions(&self) -> &[&'static str] {
+        self.vocab()
+    }
+
+    pub fn accuracy(&self) -> f32 {
+        if self.trials == 0 {
+            0.0
+        } else {
+            self.correct as f32 / self.trials as f32
+        }
+    }
+
+    /// Score a prediction of `symbol` as the next one and advance the sequence.
+    ///
+    /// Returns `(reward, correct)` with reward `+1` or `-1`; out-of-range
+    /// symbols count as wrong.
+    pub fn score_prediction(&mut self, symbol: usize) -> (f32, bool) {
+        let expected = self.expected_next();
+        let correct = symbol == expected;
+
+        self.trials = self.trials.saturating_add(1);
+        if correct {
+            self.correct = self.correct.saturating_add(1);
+        }
+        self.last_prediction = (symbol < self.n_symbols).then_some(symbol as u8);
+        self.current = expected as u8;
+
+        self.outcomes_since_shift += 1;
+        if self.shift_every_outcomes > 0 && self.outcomes_since_shift >= self.shift_every_outcomes {
+            self.shift_grammar();
+        }
+
+        (if correct { 1.0 } else { -1.0 }, correct)
+    }
+
+    /// Like [`Self::score_prediction`], by label. Unknown labels return `None`
+    /// and do not advance the sequence.
+    pub fn score_action(&mut self, label: &str) -> Option<(f32, bool)> {
+        let symbol = self.symbol_index(label)?;
+        Some(self.score_prediction(symbol))
+    }
+
+    /// Redraw the cycle so that at least one transition changes.
+    fn shift_grammar(&mut self) {
+        let old = self.successor;
+        for _ in 0..8 {
+            self.draw_grammar();
+            if self.successor != old {
+                break;
+            }
+        }
+        self.outcomes_since_shift = 0;
+        self.grammar_shifts += 1;
+    }
+
+    /// Draw a uniformly random single cycle (Sattolo's algorithm).
+    fn draw_grammar(&mut self) {
+        let n = self.n_symbols;
+        let mut order = [0u8; MAX_SYMBOLS];
+        for (i, o) in order.iter_mut().enumerate().take(n) {
+            *o = i as u8;
+        }
+        for i in (1..n).rev() {
+            let j = (self.rng_next_u32() as usize) % i;
+            order.swap(i, j);
+        }
+        self.successor = [0; MAX_SYMBOLS];
+        for i in 0..n {
+            self.successor[order[i] as usize] = order[(i + 1) % n];
+        }
+    }
+
+    fn rng_next_u32(&mut self) -> u32 {
+        self.rng_seed = self
+            .rng_seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+        (self.rng_seed >> 33) as u32
+    }
+}
+
+#[cfg(feature = "braine")]
+impl SequencePredictionGame {
+    /// Sensor of symbol `i` (`seqpred_tok_<label>`).
+    pub fn sensor_name(&self, symbol: usize) -> String {
+        format!(
+            "{SENSOR_PREFIX}_{}",
+            self.vocab[symbol.min(self.n_symbols - 1)]
+        )
+    }
+
+    /// Make sure every symbol has a sensor and an action group.
+    pub fn ensure_io(&self, brain: &mut Brain, width: usize) {
+        for (i, label) in self.vocab().iter().enumerate() {
+            brain.ensure_sensor(&self.sensor_name(i), width);
+            brain.ensure_action(label, width);
+        }
+    }
+
+    /// Present the current symbol.
+    pub fn apply_stimuli(&self, brain: &mut Brain) {
+        let name = self.sensor_name(self.current as usize);
+        brain.apply_stimulus(Stimulus::new(&name, 1.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grammar_is_a_single_cycle_over_the_vocab() {
+        let g = SequencePredictionGame::new(6, 0).with_vocab(&["do", "re", "mi", "fa", "so"]);
+        assert_eq!(g.n_symbols(), 5);
+        assert_eq!(g.allowed_actions(), ["do", "re", "mi", "fa", "so"]);
+
+        let mut seen = [false; MAX_SYMBOLS];
+        let mut s = g.current();
+        for _ in 0..g.n_symbols() {
+            assert!(!seen[s]);
+            seen[s] = true;
+            s = g.successor(s);
+        }
+        assert_eq!(s, g.current());
+        assert_eq!(SequencePredictionGame::new(1, 0).n_symbols(), MIN_SYMBOLS);
+        assert_eq!(SequencePredictionGame::new(99, 0).n_symbols(), MAX_SYMBOLS);
+    }
+
+    #[test]
+    fn grammar_shifts_every_n_outcomes() {
+        let mut g = SequencePredictionGame::new(5, 10);
+        let mut grammars = [[0u8; MAX_SYMBOLS]; 4];
+        grammars[0] = g.successor;
+        for t in 1..=30 {
+            let next = g.expected_next();
+            assert_eq!(g.score_prediction(next), (1.0, true));
+            assert_eq!(g.current(), next);
+            if t % 10 == 0 {
+                grammars[t / 10] = g.successor;
+            }
+        }
+        assert_eq!(g.grammar_shifts, 3);
+        assert!(grammars.windows(2).all(|w| w[0] != w[1]));
+
+        let wrong = (g.expected_next() + 1) % g.n_symbols();
+        assert_eq!(g.score_prediction(wrong), (-1.0, false));
+        assert_eq!(g.score_action("nope"), None);
+        assert_eq!((g.trials, g.correct), (31, 30));
+    }
+}
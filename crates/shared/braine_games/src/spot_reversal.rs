@@ -1,6 +1,10 @@
-use crate::stats::GameStats;
+use crate::stats::{GameStats, LearningMilestone};
 use crate::time::{Duration, Instant};
 
+/// Milestone reached once the last 20 trials after the reversal are at
+/// least 80% correct.
+pub const MILESTONE_REVERSAL_RECOVERED: &str = "first_reversal_recovered";
+
 // ─────────────────────────────────────────────────────────────────────────
 // Spot reversal: the stimulus side stays the same, but the correct action
 // mapping flips once after a fixed number of trials.
@@ -42,6 +46,16 @@ impl SpotReversalGame {
     fn update_reversal(&mut self) {
         if !self.reversal_active && self.stats.trials >= self.flip_after_trials {
             self.reversal_active = true;
+            // The window only covers post-reversal trials once it is full.
+            let trials = self.stats.trials;
+            self.stats
+                .learning_milestone_detector_mut()
+                .add(LearningMilestone::new(
+                    MILESTONE_REVERSAL_RECOVERED,
+                    20,
+                    0.8,
+                    trials + 20,
+                ));
         }
     }
 
@@ -120,3 +134,31 @@ impl Default for SpotReversalGame {
         Self::new(200)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reversal_recovery_is_a_milestone() {
+        let mut g = SpotReversalGame::new(30);
+        let mut trial = 0;
+        while trial < 80 {
+            g.new_trial();
+            // Perfect before the flip, then wrong for 10 trials after it.
+            let correct = g.correct_action();
+            let wrong = if correct == "left" { "right" } else { "left" };
+            let action = if (30..40).contains(&trial) {
+                wrong
+            } else {
+                correct
+            };
+            g.score_action(action);
+            trial += 1;
+        }
+        assert!(g.reversal_active);
+        let m = g.stats.learning_milestone_detector();
+        // Flipped at trial 30; trials 41..=56 correct is 16/20.
+        assert_eq!(m.first_reached(MILESTONE_REVERSAL_RECOVERED), Some(56));
+    }
+}
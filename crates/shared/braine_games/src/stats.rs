@@ -1,5 +1,125 @@
+use std::collections::VecDeque;
+use std::string::{String, ToString};
 use std::vec::Vec;
 
+/// Names of the milestones every [`GameStats`] starts with.
+pub const MILESTONE_LEARNING: &str = "learning";
+pub const MILESTONE_LEARNED: &str = "learned";
+pub const MILESTONE_MASTERED: &str = "mastered";
+
+/// An accuracy level worth timestamping: the rate over the last
+/// `recent_window` trials reaches `threshold` once at least `min_trials`
+/// trials have been played.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LearningMilestone {
+    pub name: String,
+    pub recent_window: usize,
+    pub threshold: f32,
+    pub min_trials: u32,
+}
+
+impl LearningMilestone {
+    pub fn new(name: &str, recent_window: usize, threshold: f32, min_trials: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            recent_window: recent_window.max(1),
+            threshold,
+            min_trials,
+        }
+    }
+}
+
+/// Records the trial at which each [`LearningMilestone`] is first met.
+///
+/// Windows shorter than the history seen so far use what is available, so a
+/// `min_trials` gate below `recent_window` can fire on a partial window.
+#[derive(Debug, Clone, Default)]
+pub struct LearningMilestoneDetector {
+    milestones: Vec<LearningMilestone>,
+    /// `(name, trial)` in the order milestones were reached.
+    reached: Vec<(String, u32)>,
+    history: VecDeque<bool>,
+    trials: u32,
+}
+
+impl LearningMilestoneDetector {
+    pub fn new(milestones: Vec<LearningMilestone>) -> Self {
+        Self {
+            milestones,
+            ..Self::default()
+        }
+    }
+
+    /// `learning` / `learned` / `mastered` at 70% / 85% / 95% over the last
+    /// 100 trials, from trial 20 on (the levels the UIs label).
+    pub fn standard() -> Self {
+        Self::new(vec![
+            LearningMilestone::new(MILESTONE_LEARNING, 100, 0.70, 20),
+            LearningMilestone::new(MILESTONE_LEARNED, 100, 0.85, 20),
+            LearningMilestone::new(MILESTONE_MASTERED, 100, 0.95, 20),
+        ])
+    }
+
+    /// Add a milestone, replacing any definition with the same name (a trial
+    /// already recorded for that name is kept).
+    pub fn add(&mut self, milestone: LearningMilestone) {
+        self.milestones.retain(|m| m.name != milestone.name);
+        self.milestones.push(milestone);
+    }
+
+    pub fn milestones(&self) -> &[LearningMilestone] {
+        &self.milestones
+    }
+
+    /// Record one scored trial and check every milestone not reached yet.
+    pub fn note_trial(&mut self, correct: bool) {
+        let cap = self
+            .milestones
+            .iter()
+            .map(|m| m.recent_window)
+            .max()
+            .unwrap_or(1);
+        self.history.push_back(correct);
+        while self.history.len() > cap {
+            self.history.pop_front();
+        }
+        self.trials += 1;
+
+        for m in &self.milestones {
+            if self.trials < m.min_trials || self.reached.iter().any(|(n, _)| *n == m.name) {
+                continue;
+            }
+            let n = m.recent_window.min(self.history.len());
+            let hits = self.history.iter().rev().take(n).filter(|&&x| x).count();
+            if hits as f32 / n as f32 >= m.threshold {
+                self.reached.push((m.name.clone(), self.trials));
+            }
+        }
+    }
+
+    /// Trial at which `name` was first met.
+    pub fn first_reached(&self, name: &str) -> Option<u32> {
+        self.reached
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|&(_, t)| t)
+    }
+
+    /// Every milestone reached so far, in the order they were reached.
+    pub fn reached(&self) -> &[(String, u32)] {
+        &self.reached
+    }
+
+    /// Restore state saved elsewhere: the trial count, recent outcomes
+    /// (oldest first) and reached milestones. Nothing is re-checked, so
+    /// restored outcomes cannot trigger milestones retroactively.
+    pub fn restore(&mut self, trials: u32, recent: &[bool], reached: &[(String, u32)]) {
+        self.trials = trials;
+        self.history = recent.iter().copied().collect();
+        self.reached = reached.to_vec();
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GameStats {
     pub correct: u32,
@@ -9,6 +129,7 @@ pub struct GameStats {
     pub learning_at_trial: Option<u32>,
     pub learned_at_trial: Option<u32>,
     pub mastered_at_trial: Option<u32>,
+    milestones: LearningMilestoneDetector,
 }
 
 impl GameStats {
@@ -21,26 +142,36 @@ impl GameStats {
             learning_at_trial: None,
             learned_at_trial: None,
             mastered_at_trial: None,
+            milestones: LearningMilestoneDetector::standard(),
         }
     }
 
-    fn update_milestones(&mut self) {
-        // Keep milestone definitions consistent with the UI labels.
-        // Gate on a minimum number of trials to avoid “instant” mastery on tiny samples.
-        if self.trials < 20 {
-            return;
-        }
+    /// Milestones tracked for this game; starts with
+    /// [`LearningMilestoneDetector::standard`].
+    pub fn learning_milestone_detector(&self) -> &LearningMilestoneDetector {
+        &self.milestones
+    }
 
-        let r = self.last_100_rate();
-        if self.learning_at_trial.is_none() && r >= 0.70 {
-            self.learning_at_trial = Some(self.trials);
-        }
-        if self.learned_at_trial.is_none() && r >= 0.85 {
-            self.learned_at_trial = Some(self.trials);
-        }
-        if self.mastered_at_trial.is_none() && r >= 0.95 {
-            self.mastered_at_trial = Some(self.trials);
-        }
+    /// Mutable access, e.g. for a game to [`add`](LearningMilestoneDetector::add)
+    /// its own milestones.
+    pub fn learning_milestone_detector_mut(&mut self) -> &mut LearningMilestoneDetector {
+        &mut self.milestones
+    }
+
+    fn update_milestones(&mut self, is_correct: bool) {
+        self.milestones.note_trial(is_correct);
+
+        // Mirror the standard levels into the legacy fields.
+        let m = &self.milestones;
+        self.learning_at_trial = self
+            .learning_at_trial
+            .or_else(|| m.first_reached(MILESTONE_LEARNING));
+        self.learned_at_trial = self
+            .learned_at_trial
+            .or_else(|| m.first_reached(MILESTONE_LEARNED));
+        self.mastered_at_trial = self
+            .mastered_at_trial
+            .or_else(|| m.first_reached(MILESTONE_MASTERED));
     }
 
     pub fn record_trial(&mut self, is_correct: bool) {
@@ -56,7 +187,7 @@ impl GameStats {
         }
 
         self.trials += 1;
-        self.update_milestones();
+        self.update_milestones(is_correct);
     }
 
     pub fn accuracy(&self) -> f32 {
@@ -89,6 +220,38 @@ impl GameStats {
         let correct_count = slice.iter().filter(|&&x| x).count();
         correct_count as f32 / slice.len() as f32
     }
+
+    /// Least-squares slope of correctness (1/0) over the last `window` trials
+    /// in `recent`, in accuracy per trial. Positive = improving.
+    ///
+    /// Returns 0 with fewer than two trials available.
+    pub fn learning_curve_slope(&self, window: usize) -> f32 {
+        let n = window.min(self.recent.len());
+        if n < 2 {
+            return 0.0;
+        }
+        let slice = &self.recent[self.recent.len() - n..];
+        let x_mean = (n - 1) as f32 * 0.5;
+        let y_mean = slice.iter().filter(|&&x| x).count() as f32 / n as f32;
+
+        let mut cov = 0.0f32;
+        let mut var = 0.0f32;
+        for (i, &ok) in slice.iter().enumerate() {
+            let dx = i as f32 - x_mean;
+            let y = if ok { 1.0 } else { 0.0 };
+            cov += dx * (y - y_mean);
+            var += dx * dx;
+        }
+        cov / var
+    }
+
+    /// True when a full `window` of trials shows `|slope| < threshold`,
+    /// i.e. learning has stalled (at whatever level it reached).
+    pub fn plateau_detector(&self, window: usize, threshold: f32) -> bool {
+        window >= 2
+            && self.recent.len() >= window
+            && self.learning_curve_slope(window).abs() < threshold
+    }
 }
 
 impl Default for GameStats {
@@ -96,3 +259,344 @@ impl Default for GameStats {
         Self::new()
     }
 }
+
+/// Percentile-bootstrap confidence intervals for a win rate.
+///
+/// Resampling uses a fixed-seed generator, so the same outcomes always give
+/// the same interval (consolidation decisions stay reproducible).
+#[derive(Debug, Clone, Copy)]
+pub struct BootstrapCI;
+
+impl BootstrapCI {
+    pub const DEFAULT_N_BOOT: usize = 1000;
+    pub const DEFAULT_CONFIDENCE: f32 = 0.95;
+
+    /// Confidence interval `(lo, hi)` for the success rate of `recent`.
+    ///
+    /// Draws `n_boot` resamples (with replacement) of the outcomes and returns
+    /// the central `confidence` percentile range of their means. With no
+    /// outcomes the rate is unknown and `(0.0, 1.0)` is returned; with
+    /// `n_boot == 0` the interval collapses to the point estimate.
+    pub fn from_recent(recent: &[bool], n_boot: usize, confidence: f32) -> (f32, f32) {
+        let n = recent.len();
+        if n == 0 {
+            return (0.0, 1.0);
+        }
+        if n_boot == 0 {
+            let p = recent.iter().filter(|&&x| x).count() as f32 / n as f32;
+            return (p, p);
+        }
+
+        let mut rng = 0x9E37_79B9_7F4A_7C15u64 ^ n as u64;
+        let mut means: Vec<f32> = Vec::with_capacity(n_boot);
+        for _ in 0..n_boot {
+            let mut hits = 0u32;
+            for _ in 0..n {
+                rng = rng
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                hits += recent[Self::resample_index(rng, n)] as u32;
+            }
+            means.push(hits as f32 / n as f32);
+        }
+        means.sort_by(|a, b| a.total_cmp(b));
+
+        let tail = 0.5 * (1.0 - confidence.clamp(0.0, 1.0));
+        let last = n_boot - 1;
+        let lo = ((tail * n_boot as f32) as usize).min(last);
+        let hi = (((1.0 - tail) * n_boot as f32).ceil() as usize)
+            .saturating_sub(1)
+            .clamp(lo, last);
+        (means[lo], means[hi])
+    }
+
+    /// Map the high 32 bits of `rng` onto `0..n` (multiply-shift).
+    ///
+    /// Done in `u64` so it also holds where `usize` is 32 bits (wasm32).
+    fn resample_index(rng: u64, n: usize) -> usize {
+        (((rng >> 32) * n as u64) >> 32) as usize
+    }
+
+    /// [`Self::from_recent`] with the default resample count and confidence.
+    pub fn from_recent_default(recent: &[bool]) -> (f32, f32) {
+        Self::from_recent(recent, Self::DEFAULT_N_BOOT, Self::DEFAULT_CONFIDENCE)
+    }
+}
+
+/// A detected shift in the mean of a reward series.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangePoint {
+    /// Estimated step (0-based index into the fed series) where the shift began.
+    pub step: u64,
+    /// Post-change mean minus pre-change mean (negative for a drop).
+    pub magnitude: f32,
+}
+
+/// Online two-sided CUSUM detector for shifts in a running reward series.
+///
+/// The pre-change mean is tracked with a slow EMA (seeded by a plain average
+/// over the warmup samples). Upward and downward cumulative deviations beyond
+/// `drift` are accumulated separately; when either exceeds `threshold`, a
+/// [`ChangePoint`] is reported and the detector re-arms around the new mean.
+#[derive(Debug, Clone, Copy)]
+pub struct ChangePointDetector {
+    /// Allowance per sample; deviations smaller than this never accumulate.
+    /// Roughly half the smallest shift that should be detected.
+    pub drift: f32,
+    /// Decision threshold on the cumulative sums.
+    pub threshold: f32,
+    /// Samples used to seed the reference mean before detection is armed.
+    pub warmup: u32,
+    /// EMA rate used to follow slow drift of the reference mean.
+    pub mean_alpha: f32,
+
+    step: u64,
+    seen: u32,
+    mean: f32,
+    pos: Excursion,
+    neg: Excursion,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Excursion {
+    cusum: f32,
+    start: u64,
+    /// Reference mean when the excursion began.
+    base: f32,
+    sum: f32,
+    n: u32,
+}
+
+impl Excursion {
+    fn update(&mut self, step: u64, reward: f32, mean: f32, deviation: f32, drift: f32) {
+        let next = (self.cusum + deviation - drift).max(0.0);
+        if next <= 0.0 {
+            *self = Self::default();
+            return;
+        }
+        if self.cusum <= 0.0 {
+            self.start = step;
+            self.base = mean;
+            self.sum = 0.0;
+            self.n = 0;
+        }
+        self.cusum = next;
+        self.sum += reward;
+        self.n += 1;
+    }
+
+    fn mean(&self) -> f32 {
+        self.sum / self.n.max(1) as f32
+    }
+}
+
+impl ChangePointDetector {
+    pub fn new(drift: f32, threshold: f32) -> Self {
+        Self {
+            drift: drift.max(0.0),
+            threshold: threshold.max(0.0),
+            warmup: 10,
+            mean_alpha: 0.02,
+            step: 0,
+            seen: 0,
+            mean: 0.0,
+            pos: Excursion::default(),
+            neg: Excursion::default(),
+        }
+    }
+
+    /// Current estimate of the pre-change reward mean.
+    pub fn mean(&self) -> f32 {
+        self.mean
+    }
+
+    /// Number of samples fed so far.
+    pub fn steps(&self) -> u64 {
+        self.step
+    }
+
+    /// Forget all history (parameters are kept).
+    pub fn reset(&mut self) {
+        *self = Self {
+            warmup: self.warmup,
+            mean_alpha: self.mean_alpha,
+            ..Self::new(self.drift, self.threshold)
+        };
+    }
+
+    /// Feed one reward sample. Returns a change point when a shift is detected.
+    pub fn feed(&mut self, reward: f32) -> Option<ChangePoint> {
+        if !reward.is_finite() {
+            return None;
+        }
+        let step = self.step;
+        self.step += 1;
+
+        if self.seen < self.warmup.max(1) {
+            self.seen += 1;
+            self.mean += (reward - self.mean) / self.seen as f32;
+            return None;
+        }
+
+        let deviation = reward - self.mean;
+        self.pos
+            .update(step, reward, self.mean, deviation, self.drift);
+        self.neg
+            .update(step, reward, self.mean, -deviation, self.drift);
+
+        let fired = if self.pos.cusum > self.threshold {
+            Some(self.pos)
+        } else if self.neg.cusum > self.threshold {
+            Some(self.neg)
+        } else {
+            None
+        };
+
+        let Some(exc) = fired else {
+            self.mean += self.mean_alpha * (reward - self.mean);
+            return None;
+        };
+
+        let new_mean = exc.mean();
+        let cp = ChangePoint {
+            step: exc.start,
+            magnitude: new_mean - exc.base,
+        };
+
+        // Re-arm around the post-change regime; its samples count toward warmup.
+        self.mean = new_mean;
+        self.seen = exc.n.min(self.warmup);
+        self.pos = Excursion::default();
+        self.neg = Excursion::default();
+        Some(cp)
+    }
+}
+
+impl Default for ChangePointDetector {
+    fn default() -> Self {
+        Self::new(0.5, 8.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn learning_curve_slope_tracks_trend_and_plateau() {
+        let mut improving = GameStats::new();
+        for i in 0..100 {
+            // Correct on an increasing fraction of trials.
+            improving.record_trial(i % 10 < i / 10);
+        }
+        assert!(improving.learning_curve_slope(100) > 0.005);
+        assert!(!improving.plateau_detector(100, 0.001));
+
+        let mut flat = GameStats::new();
+        for i in 0..100 {
+            flat.record_trial(i % 2 == 0);
+        }
+        assert!(flat.learning_curve_slope(100).abs() < 0.001);
+        assert!(flat.plateau_detector(100, 0.001));
+        // Not enough history for the requested window.
+        assert!(!flat.plateau_detector(150, 0.001));
+
+        let mut degrading = GameStats::new();
+        for i in 0..60 {
+            degrading.record_trial(i < 30);
+        }
+        assert!(degrading.learning_curve_slope(60) < 0.0);
+        assert_eq!(GameStats::new().learning_curve_slope(10), 0.0);
+    }
+
+    #[test]
+    fn milestones_record_first_trial_reached() {
+        let mut stats = GameStats::new();
+        stats
+            .learning_milestone_detector_mut()
+            .add(LearningMilestone::new("streak", 5, 1.0, 1));
+        for i in 0..40 {
+            // 2 of 3 correct (trial 28 wrong), then perfect from trial 29 on.
+            stats.record_trial(i >= 30 || i % 3 != 0);
+        }
+        let m = stats.learning_milestone_detector();
+        assert_eq!(m.first_reached("streak"), Some(33));
+        assert_eq!(m.first_reached(MILESTONE_LEARNING), Some(34));
+        assert_eq!(stats.learning_at_trial, Some(34));
+        assert_eq!(m.first_reached(MILESTONE_MASTERED), None);
+        assert_eq!(m.first_reached("unknown"), None);
+        assert_eq!(m.reached()[0].0, "streak");
+
+        // Restored outcomes are not re-checked.
+        let mut restored = LearningMilestoneDetector::standard();
+        restored.restore(50, &[true; 50], &[]);
+        assert!(restored.reached().is_empty());
+        restored.note_trial(true);
+        assert_eq!(restored.first_reached(MILESTONE_MASTERED), Some(51));
+    }
+
+    #[test]
+    fn bootstrap_ci_brackets_rate_and_narrows_with_samples() {
+        let small: Vec<bool> = (0..20).map(|i| i % 4 != 0).collect();
+        let large: Vec<bool> = (0..200).map(|i| i % 4 != 0).collect();
+
+        let (lo, hi) = BootstrapCI::from_recent_default(&small);
+        assert!(lo < 0.75 && 0.75 < hi, "({lo}, {hi})");
+        let (llo, lhi) = BootstrapCI::from_recent_default(&large);
+        assert!(llo < 0.75 && 0.75 < lhi, "({llo}, {lhi})");
+        assert!(lhi - llo < hi - lo);
+
+        // Deterministic for identical inputs.
+        assert_eq!(BootstrapCI::from_recent_default(&small), (lo, hi));
+
+        assert_eq!(BootstrapCI::from_recent(&[], 1000, 0.95), (0.0, 1.0));
+        assert_eq!(BootstrapCI::from_recent(&small, 0, 0.95), (0.75, 0.75));
+        assert_eq!(BootstrapCI::from_recent(&[true; 8], 100, 0.95), (1.0, 1.0));
+    }
+
+    #[test]
+    fn bootstrap_resample_index_stays_in_range() {
+        for n in [1usize, 2, 7, 1000, u32::MAX as usize] {
+            assert_eq!(BootstrapCI::resample_index(0, n), 0);
+            assert_eq!(BootstrapCI::resample_index(u64::MAX, n), n - 1);
+            assert_eq!(BootstrapCI::resample_index(1 << 63, n), n / 2);
+        }
+        // u32 arithmetic would overflow here.
+        let idx = BootstrapCI::resample_index(0xFFFF_FFFF_0000_0000, 1 << 20);
+        assert_eq!(idx as u64, ((0xFFFF_FFFFu64) << 20) >> 32);
+    }
+
+    #[test]
+    fn change_point_detects_reward_drop_near_true_step() {
+        let mut d = ChangePointDetector::default();
+        let mut found = None;
+        for t in 0..80u64 {
+            let r = if t < 40 { 0.8 } else { -0.8 };
+            if let Some(cp) = d.feed(r) {
+                found = Some((t, cp));
+                break;
+            }
+        }
+        let (detected_at, cp) = found.expect("shift should be detected");
+        assert_eq!(cp.step, 40);
+        assert!(detected_at < 50, "detection too slow: {detected_at}");
+        assert!(
+            (cp.magnitude + 1.6).abs() < 1e-4,
+            "magnitude {}",
+            cp.magnitude
+        );
+        assert!((d.mean() + 0.8).abs() < 1e-4);
+    }
+
+    #[test]
+    fn change_point_ignores_stationary_noise() {
+        let mut d = ChangePointDetector::default();
+        let mut rng = 0x5EEDu64;
+        for _ in 0..2000 {
+            rng = rng.wrapping_mul(6364136223846793005).wrapping_add(1);
+            // Correct 75% of the time: rewards +1 / -1.
+            let r = if (rng >> 62) != 0 { 1.0 } else { -1.0 };
+            assert_eq!(d.feed(r), None);
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Temporal XOR (same/different across trials).
+//!
+//! Each trial shows one of two stimuli (`stim_A` or `stim_B`). The agent
+//! answers `respond` if it matches the stimulus of the *previous* trial and
+//! `inhibit` if it differs. Trials are separated by a blank inter-trial
+//! interval of `iti_steps` ticks with no input, so the previous stimulus has
+//! to survive as a trace in the substrate's own dynamics.
+//!
+//! The first trial after a reset has no predecessor; its response is accepted
+//! with zero reward and not counted in the stats.
+
+use crate::n_back::NBackCounts;
+use crate::stats::GameStats;
+use crate::time::{Duration, Instant};
+
+#[cfg(feature = "braine")]
+use braine::substrate::{Brain, Stimulus};
+
+pub const STIM_A: &str = "stim_A";
+pub const STIM_B: &str = "stim_B";
+pub const RESPOND_ACTION: &str = "respond";
+pub const INHIBIT_ACTION: &str = "inhibit";
+
+#[derive(Debug)]
+pub struct TemporalXORGame {
+    pub iti_steps: u32,
+    /// Blank ticks left before the next stimulus appears.
+    pub iti_remaining: u32,
+    pub stim_is_a: bool,
+    /// Stimulus of the previous trial (`None` on the first trial).
+    pub prev_is_a: Option<bool>,
+
+    pub trial_frame: u32,
+    pub response_made: bool,
+    pub last_action: Option<String>,
+    pub stats: GameStats,
+    /// Signal-detection counts; the "signal" class is a same-stimulus trial.
+    pub counts: NBackCounts,
+
+    action_names: Vec<String>,
+    stimulus_key: String,
+    rng_seed: u64,
+    trial_started_at: Instant,
+}
+
+impl Default for TemporalXORGame {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl TemporalXORGame {
+    pub const MIN_ITI_STEPS: u32 = 0;
+    pub const MAX_ITI_STEPS: u32 = 100;
+
+    pub fn new(iti_steps: u32) -> Self {
+        let mut g = Self {
+            iti_steps: iti_steps.clamp(Self::MIN_ITI_STEPS, Self::MAX_ITI_STEPS),
+            iti_remaining: 0,
+            stim_is_a: true,
+            prev_is_a: None,
+            trial_frame: 0,
+            response_made: false,
+            last_action: None,
+            stats: GameStats::new(),
+            counts: NBackCounts::default(),
+            action_names: vec![RESPOND_ACTION.to_string(), INHIBIT_ACTION.to_string()],
+            stimulus_key: String::new(),
+            rng_seed: 0x7E4D_0A11u64,
+            trial_started_at: Instant::now(),
+        };
+        g.begin_trial();
+        g
+    }
+
+    pub fn set_param(&mut self, key: &str, value: f32) -> Result<(), String> {
+        match key {
+            "iti_steps" => {
+                self.iti_steps =
+                    (value.round().max(0.0) as u32).clamp(Self::MIN_ITI_STEPS, Self::MAX_ITI_STEPS);
+                self.iti_remaining = self.iti_remaining.min(self.iti_steps);
+                Ok(())
+            }
+            _ => Err(format!(
+                "Unknown temporal XOR param '{key}'. Use iti_steps ({}..{})",
+                Self::MIN_ITI_STEPS,
+                Self::MAX_ITI_STEPS
+            )),
+        }
+    }
+
+    fn begin_trial(&mut self) {
+        self.trial_frame = 0;
+        self.response_made = false;
+        self.last_action = None;
+        self.trial_started_at = Instant::now();
+
+        self.rng_seed = self
+            .rng_seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+        let u = (self.rng_seed >> 33) as u32;
+        self.stim_is_a = (u & 1) == 0;
+
+        self.stimulus_key = format!("temporal_xor::{}", self.cue_name());
+    }
+
+    /// End the current trial: its stimulus becomes the reference and the
+    /// blank interval starts (or the next trial begins if `iti_steps == 0`).
+    fn end_trial(&mut self) {
+        self.prev_is_a = Some(self.stim_is_a);
+        self.response_made = true;
+        self.iti_remaining = self.iti_steps;
+        if self.iti_remaining == 0 {
+            self.begin_trial();
+        } else {
+            self.stimulus_key = "temporal_xor::iti".to_string();
+        }
+    }
+
+    /// Advance one tick. The trial clock only runs while a stimulus is shown;
+    /// each call during the inter-trial interval consumes one blank step.
+    pub fn update_timing(&mut self, trial_period_ms: u32) {
+        let trial_period_ms = trial_period_ms.clamp(10, 60_000);
+        let trial_period = Duration::from_millis(trial_period_ms as u64);
+
+        if self.iti_remaining > 0 {
+            self.iti_remaining -= 1;
+            if self.iti_remaining == 0 {
+                self.begin_trial();
+            }
+            self.trial_frame = 0;
+            return;
+        }
+
+        let now = Instant::now();
+        if now.duration_since(self.trial_started_at) >= trial_period {
+            self.end_trial();
+        }
+
+        let elapsed = Instant::now().duration_since(self.trial_started_at);
+        self.trial_frame = elapsed.as_millis().min(u32::MAX as u128) as u32;
+    }
+
+    pub fn in_iti(&self) -> bool {
+        self.iti_remaining > 0
+    }
+
+    pub fn cue_name(&self) -> &'static str {
+        if self.stim_is_a {
+            STIM_A
+        } else {
+            STIM_B
+        }
+    }
+
+    pub fn sensor_names() -> [&'static str; 2] {
+        [STIM_A, STIM_B]
+    }
+
+    pub fn stimulus_name(&self) -> &'static str {
+        "temporal_xor"
+    }
+
+    pub fn stimulus_key(&self) -> &str {
+        &self.stimulus_key
+    }
+
+    pub fn allowed_actions(&self) -> &[String] {
+        &self.action_names
+    }
+
+    /// Whether the current stimulus matches the previous trial's
+    /// (`None` on the first trial).
+    pub fn is_same(&self) -> Option<bool> {
+        self.prev_is_a.map(|p| p == self.stim_is_a)
+    }
+
+    /// Correct action for the current trial; empty on the first trial.
+    pub fn correct_action(&self) -> &'static str {
+        match self.is_same() {
+            Some(true) => RESPOND_ACTION,
+            Some(false) => INHIBIT_ACTION,
+            None => "",
+        }
+    }
+
+    pub fn d_prime(&self) -> f32 {
+        self.counts.d_prime()
+    }
+
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli(&self, brain: &mut Brain) {
+        if !self.in_iti() {
+            brain.apply_stimulus(Stimulus::new(self.cue_name(), 1.0));
+        }
+    }
+
+    /// Apply stimuli in **inference-only** mode (no imprinting between decisions).
+    #[cfg(feature = "braine")]
+    pub fn apply_stimuli_inference(&self, brain: &mut Brain) {
+        if !self.in_iti() {
+            brain.apply_stimulus_inference(Stimulus::new(self.cue_name(), 1.0));
+        }
+    }
+
+    pub fn score_action(&mut self, action: &str) -> Option<(f32, bool)> {
+        if self.response_made || self.in_iti() {
+            return None;
+        }
+
+        let said_same = match action {
+            RESPOND_ACTION => true,
+            INHIBIT_ACTION => false,
+            _ => return None,
+        };
+
+        self.response_made = true;
+        self.last_action = Some(action.to_string());
+
+        let Some(same) = self.is_same() else {
+            return Some((0.0, true));
+        };
+
+        match (same, said_same) {
+            (true, true) => self.counts.hits += 1,
+            (true, false) => self.counts.misses += 1,
+            (false, true) => self.counts.false_alarms += 1,
+            (false, false) => self.counts.correct_rejections += 1,
+        }
+
+        let is_correct = said_same == same;
+        self.stats.record_trial(is_correct);
+        Some((if is_correct { 1.0 } else { -1.0 }, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finish_trial(g: &mut TemporalXORGame) {
+        g.end_trial();
+        while g.in_iti() {
+            g.update_timing(60_000);
+        }
+    }
+
+    #[test]
+    fn correct_action_compares_with_previous_trial() {
+        let mut g = TemporalXORGame::new(0);
+        assert_eq!(g.score_action(RESPOND_ACTION), Some((0.0, true)));
+        assert_eq!(g.stats.trials, 0);
+
+        let mut prev = g.stim_is_a;
+        for _ in 0..100 {
+            finish_trial(&mut g);
+            let expect = if g.stim_is_a == prev {
+                RESPOND_ACTION
+            } else {
+                INHIBIT_ACTION
+            };
+            assert_eq!(g.correct_action(), expect);
+            assert_eq!(g.score_action(expect), Some((1.0, true)));
+            prev = g.stim_is_a;
+        }
+        assert_eq!(g.stats.trials, 100);
+        assert!(g.counts.hits > 20 && g.counts.correct_rejections > 20);
+        assert!(g.d_prime() > 2.0);
+    }
+
+    #[test]
+    fn iti_blanks_input_for_configured_steps() {
+        let mut g = TemporalXORGame::new(3);
+        let _ = g.score_action(INHIBIT_ACTION);
+        g.end_trial();
+        let mut blank = 0;
+        while g.in_iti() {
+            assert_eq!(g.score_action(RESPOND_ACTION), None);
+            assert_eq!(g.stimulus_key(), "temporal_xor::iti");
+            g.update_timing(60_000);
+            blank += 1;
+        }
+        assert_eq!(blank, 3);
+        assert!(!g.response_made);
+        assert!(g.stimulus_key().starts_with("temporal_xor::stim_"));
+    }
+}
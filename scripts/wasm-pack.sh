@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+# Build the npm package for the substrate JS bindings (braine_web `bindings` feature).
+#
+# Output: crates/braine_web/pkg (JS glue, .wasm and the generated .d.ts).
+# Publish with: (cd crates/braine_web/pkg && npm publish)
+set -euo pipefail
+
+ROOT=$(cd "$(dirname "$0")/.." && pwd)
+cd "$ROOT"
+
+TARGET="bundler"     # bundler|web|nodejs
+PROFILE="--release"
+
+for arg in "$@"; do
+  case "$arg" in
+    --web)
+      TARGET="web"
+      ;;
+    --nodejs)
+      TARGET="nodejs"
+      ;;
+    --dev)
+      PROFILE="--dev"
+      ;;
+    -h|--help)
+      cat <<'USAGE'
+Usage: ./scripts/wasm-pack.sh [--web|--nodejs] [--dev]
+
+  --web      ES module loadable directly in the browser (default: bundler)
+  --nodejs   CommonJS package for Node
+  --dev      Unoptimized debug build
+USAGE
+      exit 0
+      ;;
+    *)
+      echo "Unknown argument: $arg" >&2
+      exit 2
+      ;;
+  esac
+done
+
+if ! command -v wasm-pack >/dev/null 2>&1; then
+  echo "wasm-pack not found (cargo install wasm-pack)" >&2
+  exit 1
+fi
+
+wasm-pack build crates/braine_web "$PROFILE" --target "$TARGET" --out-dir pkg \
+  -- --no-default-features --features bindings